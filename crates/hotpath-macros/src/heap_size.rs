@@ -0,0 +1,70 @@
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::parse_macro_input;
+
+/// Implementation of `#[derive(hotpath::HeapSize)]`.
+///
+/// `HeapSize` is available with profiling disabled too, so the same impl is generated
+/// in both modes.
+pub fn derive_heap_size_impl(item: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(item as syn::DeriveInput);
+    let name = &input.ident;
+
+    let mut generics = input.generics.clone();
+    for param in generics.type_params_mut() {
+        param.bounds.push(syn::parse_quote!(hotpath::HeapSize));
+    }
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let body = match &input.data {
+        syn::Data::Struct(data) => {
+            let sizes = data.fields.iter().enumerate().map(|(index, field)| {
+                let member = match &field.ident {
+                    Some(ident) => quote!(#ident),
+                    None => {
+                        let index = syn::Index::from(index);
+                        quote!(#index)
+                    }
+                };
+                quote!(+ hotpath::HeapSize::heap_size(&self.#member))
+            });
+            quote!(0 #(#sizes)*)
+        }
+        syn::Data::Enum(data) => {
+            let arms = data.variants.iter().map(|variant| {
+                let variant_name = &variant.ident;
+                let bindings: Vec<syn::Ident> = (0..variant.fields.len())
+                    .map(|index| quote::format_ident!("__field{}", index))
+                    .collect();
+                let pattern = match &variant.fields {
+                    syn::Fields::Named(fields) => {
+                        let names = fields.named.iter().map(|field| &field.ident);
+                        quote!({ #(#names: #bindings),* })
+                    }
+                    syn::Fields::Unnamed(_) => quote!((#(#bindings),*)),
+                    syn::Fields::Unit => quote!(),
+                };
+                quote!(Self::#variant_name #pattern => 0 #(+ hotpath::HeapSize::heap_size(#bindings))*)
+            });
+            quote! {
+                match self {
+                    #(#arms,)*
+                }
+            }
+        }
+        syn::Data::Union(_) => {
+            return syn::Error::new_spanned(name, "HeapSize can't be derived for unions")
+                .to_compile_error()
+                .into();
+        }
+    };
+
+    quote! {
+        impl #impl_generics hotpath::HeapSize for #name #ty_generics #where_clause {
+            fn heap_size(&self) -> usize {
+                #body
+            }
+        }
+    }
+    .into()
+}
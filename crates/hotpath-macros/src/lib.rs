@@ -6,6 +6,8 @@ mod lib_on;
 #[cfg(any(not(feature = "hotpath"), feature = "hotpath-off"))]
 mod lib_off;
 
+mod heap_size;
+
 /// Initializes the hotpath profiling system and generates a performance report on program exit.
 ///
 /// This attribute macro should be applied to your program's main (or other entry point) function to enable profiling.
@@ -307,3 +309,32 @@ pub fn measure_all(attr: TokenStream, item: TokenStream) -> TokenStream {
         lib_off::measure_all_impl(attr, item)
     }
 }
+
+/// Derives `hotpath::HeapSize` by summing the heap size of every field.
+///
+/// Works for structs and enums. Every field type must implement `HeapSize`, and
+/// generic type parameters get a `HeapSize` bound. Used together with
+/// `channel!(..., measure_size = true)` to report the real memory held by queued messages.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// #[derive(hotpath::HeapSize)]
+/// struct Request {
+///     path: String,
+///     body: Vec<u8>,
+/// }
+///
+/// #[derive(hotpath::HeapSize)]
+/// enum Event {
+///     Tick,
+///     Message(String),
+/// }
+/// ```
+///
+/// The impl is generated with profiling disabled too, so `HeapSize` bounds and
+/// `.heap_size()` calls compile in both modes.
+#[proc_macro_derive(HeapSize)]
+pub fn derive_heap_size(item: TokenStream) -> TokenStream {
+    heap_size::derive_heap_size_impl(item)
+}
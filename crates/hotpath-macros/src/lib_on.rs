@@ -251,21 +251,11 @@ pub fn main_impl(attr: TokenStream, item: TokenStream) -> TokenStream {
 ///   - `hotpath-alloc` - Total bytes allocated
 ///   - `hotpath-alloc` - Total allocation count
 ///
-/// # Async Function Limitations
+/// # Async Functions
 ///
-/// When using allocation profiling features with async functions, you must use the
-/// `tokio` runtime in `current_thread` mode:
-///
-/// ```rust,no_run
-/// #[tokio::main(flavor = "current_thread")]
-/// async fn main() {
-///     // Your async code here
-/// }
-/// ```
-///
-/// This limitation exists because allocation tracking uses thread-local storage. In multi-threaded
-/// runtimes, async tasks can migrate between threads, making it impossible to accurately
-/// attribute allocations to specific function calls. Time-based profiling works with any runtime flavor.
+/// Allocation tracking uses thread-local storage. For async functions the macro wraps the
+/// function body in a future that attributes allocations around every `poll`, so results
+/// stay accurate when tasks migrate between threads of a multi-threaded runtime.
 ///
 /// When the `hotpath` feature is disabled, this macro compiles to zero overhead (no instrumentation).
 ///
@@ -337,20 +327,21 @@ pub fn measure_impl(attr: TokenStream, item: TokenStream) -> TokenStream {
                 hotpath::functions::measure_with_log(#loc, false, false, || #block)
             }
         }
+    } else if asyncness {
+        quote! {
+            hotpath::functions::measure_async(
+                concat!(module_path!(), "::", #name),
+                async #block,
+            ).await
+        }
     } else {
-        let guard_init = quote! {
+        quote! {
             let _guard = hotpath::functions::MeasurementGuard::build(
                 concat!(module_path!(), "::", #name),
                 false,
-                #asyncness
+                false
             );
             #block
-        };
-
-        if asyncness {
-            quote! { async { #guard_init }.await }
-        } else {
-            guard_init
         }
     };
 
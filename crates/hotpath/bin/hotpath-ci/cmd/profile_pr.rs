@@ -445,6 +445,10 @@ mod test {
             percentiles: vec![95],
             description: "Time metrics".to_string(),
             data: pr_data,
+            live_allocs: None,
+            depth_overflows: None,
+            call_sites: None,
+            runtime: None,
         };
 
         let main_data = vec![
@@ -487,6 +491,10 @@ mod test {
             percentiles: vec![95],
             description: "Time metrics".to_string(),
             data: main_data,
+            live_allocs: None,
+            depth_overflows: None,
+            call_sites: None,
+            runtime: None,
         };
 
         let comparison = compare_metrics(&main_metrics, &pr_metrics);
@@ -525,6 +533,10 @@ mod test {
             percentiles: vec![95],
             description: "Time metrics".to_string(),
             data: pr_data,
+            live_allocs: None,
+            depth_overflows: None,
+            call_sites: None,
+            runtime: None,
         };
 
         let main_data = vec![
@@ -557,6 +569,10 @@ mod test {
             percentiles: vec![95],
             description: "Time metrics".to_string(),
             data: main_data,
+            live_allocs: None,
+            depth_overflows: None,
+            call_sites: None,
+            runtime: None,
         };
 
         let comparison = compare_metrics(&main_metrics, &pr_metrics);
@@ -615,6 +631,10 @@ mod test {
             percentiles: vec![95],
             description: "Time metrics".to_string(),
             data: pr_data,
+            live_allocs: None,
+            depth_overflows: None,
+            call_sites: None,
+            runtime: None,
         };
 
         let main_data = vec![(
@@ -635,6 +655,10 @@ mod test {
             percentiles: vec![95],
             description: "Time metrics".to_string(),
             data: main_data,
+            live_allocs: None,
+            depth_overflows: None,
+            call_sites: None,
+            runtime: None,
         };
 
         let comparison = compare_metrics(&main_metrics, &pr_metrics);
@@ -693,6 +717,10 @@ mod test {
             percentiles: vec![95],
             description: "Time metrics".to_string(),
             data: pr_data,
+            live_allocs: None,
+            depth_overflows: None,
+            call_sites: None,
+            runtime: None,
         };
 
         let main_data = vec![
@@ -725,6 +753,10 @@ mod test {
             percentiles: vec![95],
             description: "Time metrics".to_string(),
             data: main_data,
+            live_allocs: None,
+            depth_overflows: None,
+            call_sites: None,
+            runtime: None,
         };
 
         let comparison = compare_metrics(&main_metrics, &pr_metrics);
@@ -2,8 +2,9 @@
 
 use crossbeam_channel::{Receiver, Sender};
 use hotpath::json::{
-    ChannelLogs, ChannelsJson, FunctionLogsJson, FunctionsJson, FutureCall, FutureCalls,
-    FuturesJson as FuturesJsonData, LogEntry, StreamLogs, StreamsJson, ThreadsJson,
+    BlockingPollsJson, ChannelHistory, ChannelLogs, ChannelsJson, FunctionLogsJson, FunctionsJson,
+    FutureCall, FutureCalls, FuturesJson as FuturesJsonData, InFlightFuturesJson, LocksJson,
+    LogEntry, RuntimeJson, SinksJson, StreamLogs, StreamsJson, TasksJson, ThreadsJson,
 };
 use ratatui::widgets::TableState;
 use std::collections::HashMap;
@@ -24,6 +25,10 @@ pub(crate) enum SelectedTab {
     Channels,
     Streams,
     Threads,
+    Locks,
+    Tasks,
+    Runtime,
+    Sinks,
 }
 
 impl SelectedTab {
@@ -35,6 +40,10 @@ impl SelectedTab {
             SelectedTab::Channels => 4,
             SelectedTab::Streams => 5,
             SelectedTab::Threads => 6,
+            SelectedTab::Locks => 7,
+            SelectedTab::Tasks => 8,
+            SelectedTab::Runtime => 9,
+            SelectedTab::Sinks => 10,
         }
     }
 
@@ -46,6 +55,10 @@ impl SelectedTab {
             SelectedTab::Channels => "Channels",
             SelectedTab::Streams => "Streams",
             SelectedTab::Threads => "Threads",
+            SelectedTab::Locks => "Locks",
+            SelectedTab::Tasks => "Tasks",
+            SelectedTab::Runtime => "Runtime",
+            SelectedTab::Sinks => "Sinks",
         }
     }
 
@@ -148,12 +161,17 @@ pub(crate) struct App {
     pub(crate) loading_channels: bool,
     pub(crate) loading_streams: bool,
     pub(crate) loading_threads: bool,
+    pub(crate) loading_locks: bool,
+    pub(crate) loading_tasks: bool,
+    pub(crate) loading_runtime: bool,
+    pub(crate) loading_sinks: bool,
     pub(crate) loading_futures: bool,
 
     pub(crate) channel_logs_table_state: TableState,
     pub(crate) channels_focus: ChannelsFocus,
     pub(crate) show_logs: bool,
     pub(crate) logs: Option<CachedLogs>,
+    pub(crate) channel_history: Option<ChannelHistory>,
     pub(crate) inspected_log: Option<LogEntry>,
 
     pub(crate) stream_logs_table_state: TableState,
@@ -163,14 +181,29 @@ pub(crate) struct App {
     pub(crate) inspected_stream_log: Option<LogEntry>,
     pub(crate) threads: ThreadsJson,
     pub(crate) threads_table_state: TableState,
+    pub(crate) locks: LocksJson,
+    pub(crate) locks_table_state: TableState,
+    pub(crate) tasks: TasksJson,
+    pub(crate) tasks_table_state: TableState,
+    pub(crate) runtime: RuntimeJson,
+    pub(crate) runtime_table_state: TableState,
+    pub(crate) sinks: SinksJson,
+    pub(crate) sinks_table_state: TableState,
 
     pub(crate) futures: FuturesJsonData,
+    /// Tree depth of each entry in `futures`, which are kept under the future they were created in
+    pub(crate) future_depths: Vec<usize>,
     pub(crate) futures_table_state: TableState,
     pub(crate) futures_focus: FuturesFocus,
     pub(crate) show_future_calls: bool,
     pub(crate) future_calls_table_state: TableState,
     pub(crate) future_calls: Option<FutureCalls>,
     pub(crate) inspected_future_call: Option<FutureCall>,
+    /// Oldest in-flight calls, fetched along with the futures
+    pub(crate) in_flight_futures: InFlightFuturesJson,
+
+    /// Fetched on every refresh, shown in the status bar on all tabs
+    pub(crate) blocking_polls: BlockingPollsJson,
 }
 
 #[hotpath::measure_all]
@@ -190,6 +223,10 @@ impl App {
             caller_name: "unknown".to_string(),
             percentiles: vec![95],
             data: Vec::new(),
+            live_allocs: None,
+            depth_overflows: None,
+            call_sites: None,
+            runtime: None,
         };
 
         Self {
@@ -228,11 +265,16 @@ impl App {
             loading_channels: false,
             loading_streams: false,
             loading_threads: false,
+            loading_locks: false,
+            loading_tasks: false,
+            loading_runtime: false,
+            loading_sinks: false,
             loading_futures: false,
             channel_logs_table_state: TableState::default(),
             channels_focus: ChannelsFocus::Channels,
             show_logs: false,
             logs: None,
+            channel_history: None,
             inspected_log: None,
             stream_logs_table_state: TableState::default(),
             streams_focus: StreamsFocus::Streams,
@@ -245,18 +287,33 @@ impl App {
                 threads: vec![],
                 thread_count: 0,
                 rss_bytes: None,
+                untracked_threads: None,
             },
             threads_table_state: TableState::default().with_selected(0),
+            locks: LocksJson {
+                current_elapsed_ns: 0,
+                locks: vec![],
+            },
+            locks_table_state: TableState::default().with_selected(0),
+            tasks: TasksJson::default(),
+            tasks_table_state: TableState::default().with_selected(0),
+            runtime: RuntimeJson::default(),
+            runtime_table_state: TableState::default().with_selected(0),
+            sinks: SinksJson::default(),
+            sinks_table_state: TableState::default().with_selected(0),
             futures: FuturesJsonData {
                 current_elapsed_ns: 0,
                 futures: vec![],
             },
+            future_depths: vec![],
             futures_table_state: TableState::default().with_selected(0),
             futures_focus: FuturesFocus::Futures,
             show_future_calls: false,
             future_calls_table_state: TableState::default(),
             future_calls: None,
             inspected_future_call: None,
+            in_flight_futures: InFlightFuturesJson::default(),
+            blocking_polls: BlockingPollsJson::default(),
         }
     }
 
@@ -279,6 +336,10 @@ impl App {
             SelectedTab::Channels => &mut self.channels_table_state,
             SelectedTab::Streams => &mut self.streams_table_state,
             SelectedTab::Threads => &mut self.threads_table_state,
+            SelectedTab::Locks => &mut self.locks_table_state,
+            SelectedTab::Tasks => &mut self.tasks_table_state,
+            SelectedTab::Runtime => &mut self.runtime_table_state,
+            SelectedTab::Sinks => &mut self.sinks_table_state,
             SelectedTab::Futures => &mut self.futures_table_state,
         }
     }
@@ -299,7 +360,7 @@ impl App {
                     if let Ok(event) = event {
                         match event {
                             AppEvent::Key(key_code) => self.handle_key_event(key_code),
-                            AppEvent::Data(response) => self.handle_data_response(response),
+                            AppEvent::Data(response) => self.handle_data_response(*response),
                         }
                     }
                 }
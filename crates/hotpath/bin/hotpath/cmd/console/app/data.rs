@@ -3,13 +3,61 @@
 use super::{App, CachedLogs, CachedStreamLogs, SelectedTab};
 use crate::cmd::console::events::{DataRequest, DataResponse};
 use hotpath::json::{
-    ChannelLogs, FunctionLogsJson, FunctionsJson, FutureCalls, FuturesJson as FuturesJsonData,
-    StreamLogs, StreamsJson, ThreadsJson,
+    ChannelHistory, ChannelLogs, FunctionLogsJson, FunctionsJson, FutureCalls,
+    FuturesJson as FuturesJsonData, LocksJson, RuntimeJson, SerializableFutureStats, SinksJson,
+    StreamLogs, StreamsJson, TasksJson, ThreadsJson,
 };
 use std::collections::HashMap;
 use std::time::Instant;
 use tracing::{trace, warn};
 
+/// Orders futures depth first under the first parent they were created in and returns the
+/// depth of each. Futures without a known parent stay roots in their original order.
+fn order_futures_as_tree(stats: &mut Vec<SerializableFutureStats>) -> Vec<usize> {
+    let index_by_id: HashMap<u64, usize> = stats
+        .iter()
+        .enumerate()
+        .map(|(idx, stat)| (stat.id, idx))
+        .collect();
+
+    let mut children = vec![Vec::new(); stats.len()];
+    let mut roots = Vec::new();
+    for (idx, stat) in stats.iter().enumerate() {
+        // Recursive futures list themselves as a parent
+        let parent = stat
+            .parent_ids
+            .iter()
+            .filter(|&&parent_id| parent_id != stat.id)
+            .find_map(|parent_id| index_by_id.get(parent_id));
+        match parent {
+            Some(&parent_idx) => children[parent_idx].push(idx),
+            None => roots.push(idx),
+        }
+    }
+
+    let mut visited = vec![false; stats.len()];
+    let mut order = Vec::with_capacity(stats.len());
+    // Futures only reachable through a cycle of parents are placed as roots afterwards
+    for start in roots.into_iter().chain(0..stats.len()) {
+        let mut stack = vec![(start, 0)];
+        while let Some((idx, depth)) = stack.pop() {
+            if std::mem::replace(&mut visited[idx], true) {
+                continue;
+            }
+            order.push((idx, depth));
+            stack.extend(children[idx].iter().rev().map(|&child| (child, depth + 1)));
+        }
+    }
+
+    let mut slots: Vec<Option<SerializableFutureStats>> =
+        std::mem::take(stats).into_iter().map(Some).collect();
+    *stats = order
+        .iter()
+        .filter_map(|&(idx, _)| slots[idx].take())
+        .collect();
+    order.into_iter().map(|(_, depth)| depth).collect()
+}
+
 #[hotpath::measure_all]
 impl App {
     pub(crate) fn update_timing_metrics(&mut self, metrics: FunctionsJson) {
@@ -130,6 +178,9 @@ impl App {
                 let _ = self
                     .request_tx
                     .send(DataRequest::FetchChannelLogs(channel_id));
+                let _ = self
+                    .request_tx
+                    .send(DataRequest::FetchChannelHistory(channel_id));
             }
         }
     }
@@ -154,6 +205,18 @@ impl App {
         }
     }
 
+    pub(crate) fn handle_channel_history(&mut self, channel_id: u64, history: ChannelHistory) {
+        // Drop late responses for a channel that is no longer selected
+        let selected_id = self
+            .channels_table_state
+            .selected()
+            .and_then(|idx| self.channels.channels.get(idx))
+            .map(|stat| stat.id);
+        if selected_id == Some(channel_id) {
+            self.channel_history = Some(history);
+        }
+    }
+
     #[hotpath::measure(log = true)]
     pub(crate) fn get_timing_measurements(&self) -> &[(String, Vec<hotpath::MetricType>)] {
         &self.timing_functions.data
@@ -328,6 +391,100 @@ impl App {
         }
     }
 
+    pub(crate) fn update_locks(&mut self, locks: LocksJson) {
+        // Capture the currently selected lock ID (not index!)
+        let selected_lock_id = self
+            .locks_table_state
+            .selected()
+            .and_then(|idx| self.locks.locks.get(idx))
+            .map(|stat| stat.id);
+
+        self.locks = locks;
+        self.last_successful_fetch = Some(Instant::now());
+        self.error_message = None;
+
+        if let Some(lock_id) = selected_lock_id {
+            if let Some(new_idx) = self.locks.locks.iter().position(|stat| stat.id == lock_id) {
+                self.locks_table_state.select(Some(new_idx));
+            } else if !self.locks.locks.is_empty() {
+                self.locks_table_state
+                    .select(Some(self.locks.locks.len() - 1));
+            }
+        } else if let Some(selected) = self.locks_table_state.selected() {
+            if selected >= self.locks.locks.len() && !self.locks.locks.is_empty() {
+                self.locks_table_state
+                    .select(Some(self.locks.locks.len() - 1));
+            }
+        }
+    }
+
+    pub(crate) fn update_runtime(&mut self, runtime: RuntimeJson) {
+        self.runtime = runtime;
+        self.last_successful_fetch = Some(Instant::now());
+        self.error_message = None;
+
+        let workers = self.runtime_worker_count();
+        if let Some(selected) = self.runtime_table_state.selected() {
+            if selected >= workers && workers > 0 {
+                self.runtime_table_state.select(Some(workers - 1));
+            }
+        }
+    }
+
+    pub(crate) fn update_tasks(&mut self, tasks: TasksJson) {
+        // Capture the currently selected task ID (not index!)
+        let selected_task_id = self
+            .tasks_table_state
+            .selected()
+            .and_then(|idx| self.tasks.tasks.get(idx))
+            .map(|task| task.id);
+
+        self.tasks = tasks;
+        self.last_successful_fetch = Some(Instant::now());
+        self.error_message = None;
+
+        if let Some(task_id) = selected_task_id {
+            if let Some(new_idx) = self.tasks.tasks.iter().position(|task| task.id == task_id) {
+                self.tasks_table_state.select(Some(new_idx));
+            } else if !self.tasks.tasks.is_empty() {
+                self.tasks_table_state
+                    .select(Some(self.tasks.tasks.len() - 1));
+            }
+        } else if let Some(selected) = self.tasks_table_state.selected() {
+            if selected >= self.tasks.tasks.len() && !self.tasks.tasks.is_empty() {
+                self.tasks_table_state
+                    .select(Some(self.tasks.tasks.len() - 1));
+            }
+        }
+    }
+
+    pub(crate) fn update_sinks(&mut self, sinks: SinksJson) {
+        // Capture the currently selected sink ID (not index!)
+        let selected_sink_id = self
+            .sinks_table_state
+            .selected()
+            .and_then(|idx| self.sinks.sinks.get(idx))
+            .map(|sink| sink.id);
+
+        self.sinks = sinks;
+        self.last_successful_fetch = Some(Instant::now());
+        self.error_message = None;
+
+        if let Some(sink_id) = selected_sink_id {
+            if let Some(new_idx) = self.sinks.sinks.iter().position(|sink| sink.id == sink_id) {
+                self.sinks_table_state.select(Some(new_idx));
+            } else if !self.sinks.sinks.is_empty() {
+                self.sinks_table_state
+                    .select(Some(self.sinks.sinks.len() - 1));
+            }
+        } else if let Some(selected) = self.sinks_table_state.selected() {
+            if selected >= self.sinks.sinks.len() && !self.sinks.sinks.is_empty() {
+                self.sinks_table_state
+                    .select(Some(self.sinks.sinks.len() - 1));
+            }
+        }
+    }
+
     pub(crate) fn request_refresh_for_current_tab(&mut self) {
         let request = match self.selected_tab {
             SelectedTab::Timing => {
@@ -350,6 +507,22 @@ impl App {
                 self.loading_threads = true;
                 DataRequest::RefreshThreads
             }
+            SelectedTab::Locks => {
+                self.loading_locks = true;
+                DataRequest::RefreshLocks
+            }
+            SelectedTab::Tasks => {
+                self.loading_tasks = true;
+                DataRequest::RefreshTasks
+            }
+            SelectedTab::Runtime => {
+                self.loading_runtime = true;
+                DataRequest::RefreshRuntime
+            }
+            SelectedTab::Sinks => {
+                self.loading_sinks = true;
+                DataRequest::RefreshSinks
+            }
             SelectedTab::Futures => {
                 self.loading_futures = true;
                 DataRequest::RefreshFutures
@@ -357,6 +530,10 @@ impl App {
         };
         trace!("Requesting refresh for tab: {}", self.selected_tab.name());
         let _ = self.request_tx.send(request);
+        if self.selected_tab == SelectedTab::Futures {
+            let _ = self.request_tx.send(DataRequest::RefreshInFlightFutures);
+        }
+        let _ = self.request_tx.send(DataRequest::RefreshBlockingPolls);
         self.last_refresh = Instant::now();
     }
 
@@ -417,6 +594,17 @@ impl App {
                 );
                 self.handle_channel_logs(channel_id, logs);
             }
+            DataResponse::ChannelHistory {
+                channel_id,
+                history,
+            } => {
+                trace!(
+                    "Received channel {} history: {} samples",
+                    channel_id,
+                    history.samples.len()
+                );
+                self.handle_channel_history(channel_id, history);
+            }
             DataResponse::Streams(data) => {
                 trace!("Received streams data: {} streams", data.streams.len());
                 self.loading_streams = false;
@@ -430,16 +618,44 @@ impl App {
                 );
                 self.handle_stream_logs(stream_id, logs);
             }
+            DataResponse::Sinks(data) => {
+                trace!("Received sinks data: {} sinks", data.sinks.len());
+                self.loading_sinks = false;
+                self.update_sinks(data);
+            }
             DataResponse::Threads(data) => {
                 trace!("Received threads data: {} threads", data.threads.len());
                 self.loading_threads = false;
                 self.update_threads(data);
             }
+            DataResponse::Locks(data) => {
+                trace!("Received locks data: {} locks", data.locks.len());
+                self.loading_locks = false;
+                self.update_locks(data);
+            }
+            DataResponse::Tasks(data) => {
+                trace!("Received tasks data: {} tasks", data.tasks.len());
+                self.loading_tasks = false;
+                self.update_tasks(data);
+            }
+            DataResponse::Runtime(data) => {
+                trace!("Received runtime data: {} samples", data.samples.len());
+                self.loading_runtime = false;
+                self.update_runtime(data);
+            }
             DataResponse::Futures(data) => {
                 trace!("Received futures data: {} futures", data.futures.len());
                 self.loading_futures = false;
                 self.update_futures(data);
             }
+            DataResponse::BlockingPolls(data) => {
+                trace!("Received blocking polls: {} total", data.total);
+                self.blocking_polls = data;
+            }
+            DataResponse::InFlightFutures(data) => {
+                trace!("Received in-flight futures: {} total", data.total);
+                self.in_flight_futures = data;
+            }
             DataResponse::FutureCalls { future_id, calls } => {
                 trace!(
                     "Received future {} calls: {} entries",
@@ -454,6 +670,10 @@ impl App {
                 self.loading_channels = false;
                 self.loading_streams = false;
                 self.loading_threads = false;
+                self.loading_locks = false;
+                self.loading_tasks = false;
+                self.loading_runtime = false;
+                self.loading_sinks = false;
                 self.loading_futures = false;
                 self.set_error(e);
             }
@@ -469,6 +689,7 @@ impl App {
             .map(|stat| stat.id);
 
         self.futures = futures;
+        self.future_depths = order_futures_as_tree(&mut self.futures.futures);
         self.last_successful_fetch = Some(Instant::now());
         self.error_message = None;
 
@@ -32,6 +32,18 @@ impl App {
             KeyCode::Char('6') => {
                 self.switch_to_tab(SelectedTab::Threads);
             }
+            KeyCode::Char('7') => {
+                self.switch_to_tab(SelectedTab::Locks);
+            }
+            KeyCode::Char('8') => {
+                self.switch_to_tab(SelectedTab::Tasks);
+            }
+            KeyCode::Char('9') => {
+                self.switch_to_tab(SelectedTab::Runtime);
+            }
+            KeyCode::Char('0') => {
+                self.switch_to_tab(SelectedTab::Sinks);
+            }
             KeyCode::Char('o') | KeyCode::Char('O') => {
                 if self.selected_tab == SelectedTab::Channels {
                     match self.channels_focus {
@@ -51,8 +63,13 @@ impl App {
                         FuturesFocus::Calls => self.hide_future_calls(),
                         FuturesFocus::Futures => self.toggle_future_calls(),
                     }
-                } else if self.selected_tab == SelectedTab::Threads {
-                    // No logs panel for threads tab - do nothing
+                } else if self.selected_tab == SelectedTab::Threads
+                    || self.selected_tab == SelectedTab::Locks
+                    || self.selected_tab == SelectedTab::Tasks
+                    || self.selected_tab == SelectedTab::Runtime
+                    || self.selected_tab == SelectedTab::Sinks
+                {
+                    // No logs panel for threads, locks, tasks, runtime and sinks tabs - do nothing
                 } else if self.selected_tab.is_functions_tab() {
                     match self.functions_focus {
                         FunctionsFocus::Inspect => {
@@ -137,6 +154,14 @@ impl App {
                     }
                 } else if self.selected_tab == SelectedTab::Threads {
                     self.select_next_thread();
+                } else if self.selected_tab == SelectedTab::Locks {
+                    self.select_next_lock();
+                } else if self.selected_tab == SelectedTab::Tasks {
+                    self.select_next_task();
+                } else if self.selected_tab == SelectedTab::Runtime {
+                    self.select_next_worker();
+                } else if self.selected_tab == SelectedTab::Sinks {
+                    self.select_next_sink();
                 } else if self.selected_tab.is_functions_tab() {
                     match self.functions_focus {
                         FunctionsFocus::Functions => {
@@ -171,6 +196,14 @@ impl App {
                     }
                 } else if self.selected_tab == SelectedTab::Threads {
                     self.select_previous_thread();
+                } else if self.selected_tab == SelectedTab::Locks {
+                    self.select_previous_lock();
+                } else if self.selected_tab == SelectedTab::Tasks {
+                    self.select_previous_task();
+                } else if self.selected_tab == SelectedTab::Runtime {
+                    self.select_previous_worker();
+                } else if self.selected_tab == SelectedTab::Sinks {
+                    self.select_previous_sink();
                 } else if self.selected_tab.is_functions_tab() {
                     match self.functions_focus {
                         FunctionsFocus::Functions => {
@@ -61,6 +61,7 @@ impl App {
 
         if self.paused && self.show_logs {
             self.logs = None;
+            self.channel_history = None;
         } else if self.show_logs {
             self.request_channel_logs();
         }
@@ -80,6 +81,7 @@ impl App {
 
         if self.paused && self.show_logs {
             self.logs = None;
+            self.channel_history = None;
         } else if self.show_logs {
             self.request_channel_logs();
         }
@@ -99,6 +101,7 @@ impl App {
                 self.show_logs = true;
                 if self.paused {
                     self.logs = None;
+                    self.channel_history = None;
                 } else {
                     self.request_channel_logs();
                 }
@@ -109,6 +112,7 @@ impl App {
     pub(crate) fn hide_logs(&mut self) {
         self.show_logs = false;
         self.logs = None;
+        self.channel_history = None;
         self.channel_logs_table_state.select(None);
         self.channels_focus = ChannelsFocus::Channels;
     }
@@ -516,6 +520,117 @@ impl App {
         self.threads_table_state.select(Some(i));
     }
 
+    pub(crate) fn select_previous_lock(&mut self) {
+        let count = self.locks.locks.len();
+        if count == 0 {
+            return;
+        }
+
+        let i = match self.locks_table_state.selected() {
+            Some(i) => i.saturating_sub(1),
+            None => 0,
+        };
+        self.locks_table_state.select(Some(i));
+    }
+
+    pub(crate) fn select_next_lock(&mut self) {
+        let count = self.locks.locks.len();
+        if count == 0 {
+            return;
+        }
+
+        let i = match self.locks_table_state.selected() {
+            Some(i) => (i + 1).min(count - 1),
+            None => 0,
+        };
+        self.locks_table_state.select(Some(i));
+    }
+
+    /// Workers listed in the most recent runtime sample
+    pub(crate) fn runtime_worker_count(&self) -> usize {
+        self.runtime
+            .samples
+            .last()
+            .map_or(0, |sample| sample.worker_park_counts.len())
+    }
+
+    pub(crate) fn select_previous_worker(&mut self) {
+        if self.runtime_worker_count() == 0 {
+            return;
+        }
+
+        let i = match self.runtime_table_state.selected() {
+            Some(i) => i.saturating_sub(1),
+            None => 0,
+        };
+        self.runtime_table_state.select(Some(i));
+    }
+
+    pub(crate) fn select_next_worker(&mut self) {
+        let count = self.runtime_worker_count();
+        if count == 0 {
+            return;
+        }
+
+        let i = match self.runtime_table_state.selected() {
+            Some(i) => (i + 1).min(count - 1),
+            None => 0,
+        };
+        self.runtime_table_state.select(Some(i));
+    }
+
+    pub(crate) fn select_previous_task(&mut self) {
+        let count = self.tasks.tasks.len();
+        if count == 0 {
+            return;
+        }
+
+        let i = match self.tasks_table_state.selected() {
+            Some(i) => i.saturating_sub(1),
+            None => 0,
+        };
+        self.tasks_table_state.select(Some(i));
+    }
+
+    pub(crate) fn select_next_task(&mut self) {
+        let count = self.tasks.tasks.len();
+        if count == 0 {
+            return;
+        }
+
+        let i = match self.tasks_table_state.selected() {
+            Some(i) => (i + 1).min(count - 1),
+            None => 0,
+        };
+        self.tasks_table_state.select(Some(i));
+    }
+
+    pub(crate) fn select_previous_sink(&mut self) {
+        let count = self.sinks.sinks.len();
+        if count == 0 {
+            return;
+        }
+
+        let i = match self.sinks_table_state.selected() {
+            Some(i) => i.saturating_sub(1),
+            None => 0,
+        };
+        self.sinks_table_state.select(Some(i));
+    }
+
+    pub(crate) fn select_next_sink(&mut self) {
+        let count = self.sinks.sinks.len();
+        if count == 0 {
+            return;
+        }
+
+        let i = match self.sinks_table_state.selected() {
+            Some(i) => (i + 1).min(count - 1),
+            None => 0,
+        };
+        self.sinks_table_state.select(Some(i));
+    }
+
     pub(crate) fn select_previous_future(&mut self) {
         let count = self.futures.futures.len();
         if count == 0 {
@@ -1,3 +1,4 @@
+use futures_util::sink::{self, SinkExt};
 use futures_util::stream::{self, StreamExt};
 use std::thread;
 use std::time::Duration;
@@ -5,6 +6,7 @@ use std::time::Duration;
 pub fn init() {
     spawn_bounded_channel();
     spawn_unbounded_channel();
+    spawn_shared_counter();
     spawn_tokio_demo();
 }
 
@@ -55,6 +57,25 @@ fn spawn_unbounded_channel() {
     });
 }
 
+fn spawn_shared_counter() {
+    let counter = std::sync::Arc::new(hotpath::mutex!(
+        std::sync::Mutex::new(0u64),
+        label = "demo-counter"
+    ));
+
+    for worker in 0..3u64 {
+        let counter = std::sync::Arc::clone(&counter);
+        thread::spawn(move || loop {
+            {
+                let mut value = counter.lock().unwrap();
+                *value += 1;
+                thread::sleep(Duration::from_millis(10 + worker * 5));
+            }
+            thread::sleep(Duration::from_millis(40));
+        });
+    }
+}
+
 async fn sleep_ms(ms: u64) {
     let _ = tokio::task::spawn_blocking(move || {
         thread::sleep(Duration::from_millis(ms));
@@ -71,6 +92,7 @@ fn spawn_tokio_demo() {
 
         rt.block_on(async {
             spawn_streams().await;
+            spawn_sinks();
             spawn_futures_demo().await;
             std::future::pending::<()>().await;
         });
@@ -130,53 +152,86 @@ async fn spawn_streams() {
     });
 }
 
-async fn spawn_futures_demo() {
-    // Spawn multiple futures that run concurrently
-    tokio::spawn(async {
-        loop {
-            let result = hotpath::future!(
-                async {
-                    sleep_ms(100).await;
-                    42u64
-                },
-                log = true
-            )
-            .await;
-            std::hint::black_box(result);
-            sleep_ms(50).await;
-        }
-    });
+fn spawn_sinks() {
+    // Writer slower than its producer, so every send waits on backpressure
+    let writer = hotpath::sink!(
+        sink::unfold((), |(), line: String| async move {
+            sleep_ms(120).await;
+            std::hint::black_box(line);
+            Ok::<_, std::io::Error>(())
+        }),
+        label = "demo-slow-writer",
+        log = true
+    );
 
-    tokio::spawn(async {
-        loop {
-            let result = hotpath::future!(
-                async {
-                    let mut sum = 0u64;
-                    for i in 0..5 {
-                        sleep_ms(50).await;
-                        sum += i;
-                    }
-                    sum
-                },
-                log = true
-            )
-            .await;
-            std::hint::black_box(result);
-            sleep_ms(100).await;
+    tokio::spawn(async move {
+        let mut writer = Box::pin(writer);
+        for i in 0u64.. {
+            // `feed` doesn't flush, the next send waits in `poll_ready` instead
+            if writer.feed(format!("line {i}")).await.is_err() {
+                break;
+            }
+            sleep_ms(40).await;
         }
     });
+}
 
-    tokio::spawn(async {
-        loop {
-            let result = hotpath::future!(async {
-                tokio::task::yield_now().await;
-                tokio::task::yield_now().await;
-                sleep_ms(30).await;
-                "yielded"
-            })
-            .await;
-            std::hint::black_box(result);
-            sleep_ms(70).await;
-        }
-    });
+async fn spawn_futures_demo() {
+    // Spawn multiple futures that run concurrently, listed in the Tasks tab
+    hotpath::spawn!(
+        async {
+            loop {
+                let result = hotpath::future!(
+                    async {
+                        sleep_ms(100).await;
+                        42u64
+                    },
+                    log = true
+                )
+                .await;
+                std::hint::black_box(result);
+                sleep_ms(50).await;
+            }
+        },
+        label = "demo-answer-task"
+    );
+
+    hotpath::spawn!(
+        async {
+            loop {
+                let result = hotpath::future!(
+                    async {
+                        let mut sum = 0u64;
+                        for i in 0..5 {
+                            sleep_ms(50).await;
+                            sum += i;
+                        }
+                        sum
+                    },
+                    log = true
+                )
+                .await;
+                std::hint::black_box(result);
+                sleep_ms(100).await;
+            }
+        },
+        label = "demo-sum-task"
+    );
+
+    hotpath::spawn!(
+        async {
+            loop {
+                let result = hotpath::future!(async {
+                    tokio::task::yield_now().await;
+                    tokio::task::yield_now().await;
+                    sleep_ms(30).await;
+                    "yielded"
+                })
+                .await;
+                std::hint::black_box(result);
+                sleep_ms(70).await;
+            }
+        },
+        label = "demo-yield-task"
+    );
 }
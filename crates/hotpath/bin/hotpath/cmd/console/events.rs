@@ -2,8 +2,9 @@
 
 use crossterm::event::KeyCode;
 use hotpath::json::{
-    ChannelLogs, ChannelsJson, FunctionLogsJson, FunctionsJson, FutureCalls, FuturesJson, Route,
-    StreamLogs, StreamsJson, ThreadsJson,
+    BlockingPollsJson, ChannelHistory, ChannelLogs, ChannelsJson, FunctionLogsJson, FunctionsJson,
+    FutureCalls, FuturesJson, InFlightFuturesJson, LocksJson, Route, RuntimeJson, SinksJson,
+    StreamLogs, StreamsJson, TasksJson, ThreadsJson,
 };
 
 #[derive(Debug)]
@@ -12,11 +13,18 @@ pub(crate) enum DataRequest {
     RefreshMemory,
     RefreshChannels,
     RefreshStreams,
+    RefreshSinks,
     RefreshThreads,
+    RefreshLocks,
+    RefreshTasks,
+    RefreshRuntime,
     RefreshFutures,
+    RefreshBlockingPolls,
+    RefreshInFlightFutures,
     FetchFunctionLogsTiming(String),
     FetchFunctionLogsAlloc(String),
     FetchChannelLogs(u64),
+    FetchChannelHistory(u64),
     FetchStreamLogs(u64),
     FetchFutureCalls(u64),
 }
@@ -28,8 +36,14 @@ impl DataRequest {
             DataRequest::RefreshMemory => Route::FunctionsAlloc,
             DataRequest::RefreshChannels => Route::Channels,
             DataRequest::RefreshStreams => Route::Streams,
+            DataRequest::RefreshSinks => Route::Sinks,
             DataRequest::RefreshThreads => Route::Threads,
+            DataRequest::RefreshLocks => Route::Locks,
+            DataRequest::RefreshTasks => Route::Tasks,
+            DataRequest::RefreshRuntime => Route::Runtime,
             DataRequest::RefreshFutures => Route::Futures,
+            DataRequest::RefreshBlockingPolls => Route::BlockingPolls,
+            DataRequest::RefreshInFlightFutures => Route::InFlightFutures,
             DataRequest::FetchFunctionLogsTiming(name) => Route::FunctionTimingLogs {
                 function_name: name.clone(),
             },
@@ -37,6 +51,7 @@ impl DataRequest {
                 function_name: name.clone(),
             },
             DataRequest::FetchChannelLogs(id) => Route::ChannelLogs { channel_id: *id },
+            DataRequest::FetchChannelHistory(id) => Route::ChannelHistory { channel_id: *id },
             DataRequest::FetchStreamLogs(id) => Route::StreamLogs { stream_id: *id },
             DataRequest::FetchFutureCalls(id) => Route::FutureCalls { future_id: *id },
         }
@@ -64,13 +79,23 @@ pub(crate) enum DataResponse {
         channel_id: u64,
         logs: ChannelLogs,
     },
+    ChannelHistory {
+        channel_id: u64,
+        history: ChannelHistory,
+    },
     Streams(StreamsJson),
     StreamLogs {
         stream_id: u64,
         logs: StreamLogs,
     },
+    Sinks(SinksJson),
     Threads(ThreadsJson),
+    Locks(LocksJson),
+    Tasks(TasksJson),
+    Runtime(RuntimeJson),
     Futures(FuturesJson),
+    BlockingPolls(BlockingPollsJson),
+    InFlightFutures(InFlightFuturesJson),
     FutureCalls {
         future_id: u64,
         calls: FutureCalls,
@@ -81,5 +106,5 @@ pub(crate) enum DataResponse {
 #[derive(Debug)]
 pub(crate) enum AppEvent {
     Key(KeyCode),
-    Data(DataResponse),
+    Data(Box<DataResponse>),
 }
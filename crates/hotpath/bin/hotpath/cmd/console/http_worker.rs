@@ -2,8 +2,9 @@
 
 use crossbeam_channel::{Receiver, Sender};
 use hotpath::json::{
-    ChannelLogs, ChannelsJson, FunctionLogsJson, FunctionsJson, FutureCalls, FuturesJson, Route,
-    StreamLogs, StreamsJson, ThreadsJson,
+    BlockingPollsJson, ChannelHistory, ChannelLogs, ChannelsJson, FunctionLogsJson, FunctionsJson,
+    FutureCalls, FuturesJson, InFlightFuturesJson, LocksJson, Route, RuntimeJson, SinksJson,
+    StreamLogs, StreamsJson, TasksJson, ThreadsJson,
 };
 use reqwest::StatusCode;
 use serde::de::DeserializeOwned;
@@ -21,11 +22,18 @@ enum RequestKey {
     Memory,
     Channels,
     Streams,
+    Sinks,
     Threads,
+    Locks,
+    Tasks,
+    Runtime,
     Futures,
+    BlockingPolls,
+    InFlightFutures,
     FunctionLogsTiming,
     FunctionLogsAlloc,
     ChannelLogs,
+    ChannelHistory,
     StreamLogs,
     FutureCalls,
 }
@@ -37,11 +45,18 @@ impl DataRequest {
             DataRequest::RefreshMemory => RequestKey::Memory,
             DataRequest::RefreshChannels => RequestKey::Channels,
             DataRequest::RefreshStreams => RequestKey::Streams,
+            DataRequest::RefreshSinks => RequestKey::Sinks,
             DataRequest::RefreshThreads => RequestKey::Threads,
+            DataRequest::RefreshLocks => RequestKey::Locks,
+            DataRequest::RefreshTasks => RequestKey::Tasks,
+            DataRequest::RefreshRuntime => RequestKey::Runtime,
             DataRequest::RefreshFutures => RequestKey::Futures,
+            DataRequest::RefreshBlockingPolls => RequestKey::BlockingPolls,
+            DataRequest::RefreshInFlightFutures => RequestKey::InFlightFutures,
             DataRequest::FetchFunctionLogsTiming(_) => RequestKey::FunctionLogsTiming,
             DataRequest::FetchFunctionLogsAlloc(_) => RequestKey::FunctionLogsAlloc,
             DataRequest::FetchChannelLogs(_) => RequestKey::ChannelLogs,
+            DataRequest::FetchChannelHistory(_) => RequestKey::ChannelHistory,
             DataRequest::FetchStreamLogs(_) => RequestKey::StreamLogs,
             DataRequest::FetchFutureCalls(_) => RequestKey::FutureCalls,
         }
@@ -81,7 +96,7 @@ pub(crate) fn spawn_http_worker(
 
             let handle = rt.spawn(async move {
                 let response = request.to_route().fetch(&client, &base_url).await;
-                let _ = event_tx.send(AppEvent::Data(response));
+                let _ = event_tx.send(AppEvent::Data(Box::new(response)));
             });
 
             active_tasks.insert(key, handle);
@@ -162,8 +177,18 @@ impl RouteExt for Route {
             }
             Route::Channels => parse_json::<ChannelsJson>(bytes).map(DataResponse::Channels),
             Route::Streams => parse_json::<StreamsJson>(bytes).map(DataResponse::Streams),
+            Route::Sinks => parse_json::<SinksJson>(bytes).map(DataResponse::Sinks),
             Route::Threads => parse_json::<ThreadsJson>(bytes).map(DataResponse::Threads),
+            Route::Locks => parse_json::<LocksJson>(bytes).map(DataResponse::Locks),
+            Route::Tasks => parse_json::<TasksJson>(bytes).map(DataResponse::Tasks),
+            Route::Runtime => parse_json::<RuntimeJson>(bytes).map(DataResponse::Runtime),
             Route::Futures => parse_json::<FuturesJson>(bytes).map(DataResponse::Futures),
+            Route::BlockingPolls => {
+                parse_json::<BlockingPollsJson>(bytes).map(DataResponse::BlockingPolls)
+            }
+            Route::InFlightFutures => {
+                parse_json::<InFlightFuturesJson>(bytes).map(DataResponse::InFlightFutures)
+            }
             Route::FunctionTimingLogs { function_name } => parse_json::<FunctionLogsJson>(bytes)
                 .map(|logs| DataResponse::FunctionLogsTiming {
                     function_name: function_name.clone(),
@@ -180,6 +205,12 @@ impl RouteExt for Route {
                     logs,
                 })
             }
+            Route::ChannelHistory { channel_id } => {
+                parse_json::<ChannelHistory>(bytes).map(|history| DataResponse::ChannelHistory {
+                    channel_id: *channel_id,
+                    history,
+                })
+            }
             Route::StreamLogs { stream_id } => {
                 parse_json::<StreamLogs>(bytes).map(|logs| DataResponse::StreamLogs {
                     stream_id: *stream_id,
@@ -192,6 +223,7 @@ impl RouteExt for Route {
                     calls,
                 })
             }
+            Route::SinkLogs { .. } => unreachable!("sink logs are not requested by the TUI"),
         }
         .unwrap_or_else(|e| DataResponse::Error(format!("JSON parse error: {}", e)))
     }
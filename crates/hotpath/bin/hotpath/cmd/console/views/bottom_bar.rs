@@ -37,8 +37,15 @@ pub(crate) fn render_help_bar(
     functions_focus: FunctionsFocus,
     futures_focus: FuturesFocus,
 ) {
-    let controls_line = if selected_tab == SelectedTab::Threads {
-        // Threads tab - simple controls, no logs
+    let controls_line = if matches!(
+        selected_tab,
+        SelectedTab::Threads
+            | SelectedTab::Locks
+            | SelectedTab::Tasks
+            | SelectedTab::Runtime
+            | SelectedTab::Sinks
+    ) {
+        // Threads, Locks, Tasks, Runtime and Sinks tabs - simple controls, no logs
         Line::from(vec![
             NAV_KEYS_FULL.blue().bold(),
             PAUSE_LABEL.into(),
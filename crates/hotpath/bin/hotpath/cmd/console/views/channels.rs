@@ -1,11 +1,14 @@
+pub(crate) mod history;
 pub(crate) mod inspect;
 pub(crate) mod logs;
 
 use super::common_styles;
 use crate::cmd::console::app::ChannelsFocus;
-use crate::cmd::console::widgets::formatters::{queue_status, truncate_left};
-use hotpath::format_bytes;
+use crate::cmd::console::widgets::formatters::{
+    format_time_ago, queue_status, text_sparkline, truncate_left,
+};
 use hotpath::json::{ChannelState, ChannelType, SerializableChannelStats};
+use hotpath::{format_bytes, format_duration};
 use ratatui::{
     layout::{Constraint, Rect},
     style::{Color, Style},
@@ -14,6 +17,9 @@ use ratatui::{
     Frame,
 };
 
+/// Number of recent per-second samples drawn in the Rate column
+const RATE_SPARKLINE_LEN: usize = 8;
+
 /// Renders the channels table with channel statistics
 #[hotpath::measure]
 #[allow(clippy::too_many_arguments)]
@@ -37,7 +43,14 @@ pub(crate) fn render_channels_panel(
         Cell::from("Sent"),
         Cell::from("Receive"),
         Cell::from("Queue"),
+        Cell::from("Peak"),
+        Cell::from("Full"),
+        Cell::from("Blocked"),
         Cell::from("Mem"),
+        Cell::from("Rate"),
+        Cell::from("Wait P50"),
+        Cell::from("P95"),
+        Cell::from("P99"),
     ])
     .style(common_styles::HEADER_STYLE)
     .height(1);
@@ -59,10 +72,44 @@ pub(crate) fn render_channels_panel(
             };
 
             let mem_cell = match &stat.channel_type {
-                ChannelType::Unbounded => Cell::from("N/A"),
+                ChannelType::Unbounded | ChannelType::Watch => Cell::from("N/A"),
                 _ => Cell::from(format_bytes(stat.queued_bytes)),
             };
             let queue_cell = queue_status(stat.queued, &stat.channel_type, 8);
+            let wait_cell =
+                |ns: Option<u64>| Cell::from(ns.map_or("-".to_string(), format_duration));
+            let full_cell = match stat.channel_type {
+                ChannelType::Unbounded | ChannelType::Watch => Cell::from("-"),
+                _ => {
+                    let color = if stat.full_time_percent >= 50.0 {
+                        Color::Red
+                    } else if stat.full_time_percent >= 10.0 {
+                        Color::Yellow
+                    } else {
+                        Color::Green
+                    };
+                    Cell::from(format!(
+                        "{:.0}% ({}x)",
+                        stat.full_time_percent, stat.full_transitions
+                    ))
+                    .style(Style::default().fg(color))
+                }
+            };
+            let blocked_cell = match (stat.blocked_sends, stat.send_block_p95_ns) {
+                (Some(count), Some(p95)) => Cell::from(format!("{count} {}", format_duration(p95)))
+                    .style(Style::default().fg(Color::Yellow)),
+                (Some(count), None) => Cell::from(count.to_string()),
+                (None, _) => Cell::from("-"),
+            };
+            let rates = &stat.sent_rate_history;
+            let rate_cell = match rates.last() {
+                Some(last) => Cell::from(format!(
+                    "{} {}/s",
+                    text_sparkline(&rates[rates.len().saturating_sub(RATE_SPARKLINE_LEN)..]),
+                    last
+                )),
+                None => Cell::from("-"),
+            };
 
             Row::new(vec![
                 Cell::from(truncate_left(&stat.label, channel_width)),
@@ -71,21 +118,43 @@ pub(crate) fn render_channels_panel(
                 Cell::from(stat.sent_count.to_string()),
                 Cell::from(stat.received_count.to_string()),
                 queue_cell,
+                Cell::from(stat.max_queued.to_string()),
+                full_cell,
+                blocked_cell,
                 mem_cell,
+                rate_cell,
+                wait_cell(stat.queue_latency_p50_ns),
+                wait_cell(stat.queue_latency_p95_ns),
+                wait_cell(stat.queue_latency_p99_ns),
             ])
         })
         .collect();
 
     let widths = [
-        Constraint::Percentage(30), // Channel
-        Constraint::Percentage(14), // Type
-        Constraint::Percentage(10), // State
-        Constraint::Percentage(9),  // Sent
-        Constraint::Percentage(11), // Received
-        Constraint::Percentage(16), // Queue
-        Constraint::Percentage(10), // Mem
+        Constraint::Percentage(15), // Channel
+        Constraint::Percentage(8),  // Type
+        Constraint::Percentage(7),  // State
+        Constraint::Percentage(6),  // Sent
+        Constraint::Percentage(6),  // Received
+        Constraint::Percentage(9),  // Queue
+        Constraint::Percentage(5),  // Peak
+        Constraint::Percentage(8),  // Full
+        Constraint::Percentage(8),  // Blocked
+        Constraint::Percentage(5),  // Mem
+        Constraint::Percentage(10), // Rate
+        Constraint::Percentage(5),  // Wait P50
+        Constraint::Percentage(4),  // Wait P95
+        Constraint::Percentage(4),  // Wait P99
     ];
 
+    // Proxy mode leaves the caller's sender unwrapped, so its sends can't be timed
+    let mut title = format!(" [{}/{}] ", channel_position, total_channels);
+    if stats.iter().any(|stat| {
+        matches!(stat.channel_type, ChannelType::Bounded(_)) && stat.blocked_sends.is_none()
+    }) {
+        title.push_str("Blocked: inline mode only ");
+    }
+
     let table_block = if show_logs {
         let border_set = if focus == ChannelsFocus::Channels {
             border::THICK
@@ -93,7 +162,7 @@ pub(crate) fn render_channels_panel(
             border::PLAIN
         };
         Block::bordered()
-            .title(format!(" [{}/{}] ", channel_position, total_channels))
+            .title(title)
             .border_set(border_set)
             .border_style(if focus == ChannelsFocus::Channels {
                 Style::default()
@@ -101,9 +170,7 @@ pub(crate) fn render_channels_panel(
                 common_styles::UNFOCUSED_BORDER_STYLE
             })
     } else {
-        Block::bordered()
-            .title(format!(" [{}/{}] ", channel_position, total_channels))
-            .border_set(border::THICK)
+        Block::bordered().title(title).border_set(border::THICK)
     };
 
     let table = Table::new(rows, widths)
@@ -116,3 +183,42 @@ pub(crate) fn render_channels_panel(
 
     frame.render_stateful_widget(table, area, table_state);
 }
+
+/// Receiver lag for broadcast channels, change recency for watch channels and
+/// receiver counts for multi-consumer channels, shown in the logs panel title.
+pub(crate) fn fan_out_summary(
+    stat: &SerializableChannelStats,
+    current_elapsed_ns: u64,
+) -> Option<String> {
+    match stat.channel_type {
+        ChannelType::Broadcast(_) => {
+            let lags: Vec<String> = stat
+                .receivers
+                .iter()
+                .flatten()
+                .map(|receiver| {
+                    let suffix = if receiver.dropped { " (dropped)" } else { "" };
+                    format!("#{} {}{}", receiver.id, receiver.lag, suffix)
+                })
+                .collect();
+            Some(format!(
+                "lagged {} | dropped {} | lag: {}",
+                stat.lagged_count.unwrap_or(0),
+                stat.dropped_count.unwrap_or(0),
+                lags.join(", ")
+            ))
+        }
+        ChannelType::Watch => {
+            let last_change = stat.last_change_ns.map_or("never".to_string(), |ns| {
+                format_time_ago(current_elapsed_ns.saturating_sub(ns))
+            });
+            Some(format!(
+                "{} changes | changed {}",
+                stat.sent_count, last_change
+            ))
+        }
+        _ => stat
+            .receiver_count
+            .map(|count| format!("receivers: {count}")),
+    }
+}
@@ -0,0 +1,88 @@
+use super::super::common_styles;
+use hotpath::json::ChannelHistory;
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Style},
+    symbols::border,
+    text::Line,
+    widgets::{Block, Paragraph, Sparkline},
+    Frame,
+};
+
+/// Renders send rate, receive rate and queue depth sparklines for the selected channel
+pub(crate) fn render_history_panel(
+    history: Option<&ChannelHistory>,
+    area: Rect,
+    frame: &mut Frame,
+) {
+    let title = match history {
+        Some(history) if !history.samples.is_empty() => format!(
+            " Throughput (last {}s) ",
+            history.samples.len() as u64 * history.interval_ms / 1000
+        ),
+        _ => " Throughput ".to_string(),
+    };
+    let block = Block::bordered()
+        .title(title)
+        .border_set(border::PLAIN)
+        .border_style(common_styles::UNFOCUSED_BORDER_STYLE);
+    let inner_area = block.inner(area);
+    frame.render_widget(block, area);
+
+    let Some(history) = history.filter(|history| !history.samples.is_empty()) else {
+        frame.render_widget(
+            Paragraph::new(Line::from("(collecting samples)").centered())
+                .style(common_styles::PLACEHOLDER_STYLE),
+            inner_area,
+        );
+        return;
+    };
+
+    let rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(2), // Sent
+            Constraint::Length(2), // Received
+            Constraint::Length(2), // Queued
+        ])
+        .split(inner_area);
+
+    // Keep the most recent samples that fit the panel width
+    let width = inner_area.width.saturating_sub(14) as usize;
+    let recent = &history.samples[history.samples.len().saturating_sub(width)..];
+    let series: [(&str, Vec<u64>, Color); 3] = [
+        (
+            "sent/s",
+            recent.iter().map(|sample| sample.sent_per_sec).collect(),
+            Color::Green,
+        ),
+        (
+            "recv/s",
+            recent
+                .iter()
+                .map(|sample| sample.received_per_sec)
+                .collect(),
+            Color::Cyan,
+        ),
+        (
+            "queued",
+            recent.iter().map(|sample| sample.queued).collect(),
+            Color::Yellow,
+        ),
+    ];
+
+    for ((name, data, color), row) in series.iter().zip(rows.iter()) {
+        let columns = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Length(14), Constraint::Min(0)])
+            .split(*row);
+        let last = data.last().copied().unwrap_or(0);
+        frame.render_widget(Paragraph::new(format!("{name} {last}")), columns[0]);
+        frame.render_widget(
+            Sparkline::default()
+                .data(data)
+                .style(Style::default().fg(*color)),
+            columns[1],
+        );
+    }
+}
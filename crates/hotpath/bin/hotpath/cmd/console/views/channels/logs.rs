@@ -1,6 +1,7 @@
 use super::super::common_styles;
 use crate::cmd::console::app::CachedLogs;
 use crate::cmd::console::widgets::formatters::{format_delay, format_time_ago, truncate_message};
+use hotpath::format_bytes;
 use ratatui::{
     layout::Rect,
     style::Style,
@@ -65,9 +66,9 @@ pub(crate) fn render_logs_panel(
     let received_map = &cached_logs.received_map;
 
     let available_width = inner_area.width.saturating_sub(2);
-    let msg_width = (available_width.saturating_sub(30) as usize).max(20);
+    let msg_width = (available_width.saturating_sub(40) as usize).max(20);
 
-    let header = Row::new(vec!["Index", "Message", "Delay", "Ago"])
+    let header = Row::new(vec!["Index", "Message", "Size", "Delay", "Ago"])
         .style(common_styles::HEADER_STYLE)
         .height(1);
 
@@ -95,6 +96,7 @@ pub(crate) fn render_logs_panel(
             Row::new(vec![
                 entry.index.to_string(),
                 truncated_msg,
+                entry.size.map_or_else(|| "-".to_string(), format_bytes),
                 delay_str,
                 time_ago,
             ])
@@ -104,6 +106,7 @@ pub(crate) fn render_logs_panel(
     let widths = [
         ratatui::layout::Constraint::Length(6),  // Index
         ratatui::layout::Constraint::Min(20),    // Message
+        ratatui::layout::Constraint::Length(10), // Size
         ratatui::layout::Constraint::Length(12), // Delay
         ratatui::layout::Constraint::Length(13), // Ago
     ];
@@ -1,15 +1,19 @@
 use super::super::super::app::InspectedFunctionLog;
+use hotpath::CallSiteEntry;
 use ratatui::{
     layout::Rect,
+    style::{Modifier, Style},
     symbols::border,
-    text::Line,
+    text::{Line, Span},
     widgets::{Block, Clear, Paragraph, Wrap},
     Frame,
 };
 
-/// Renders a centered popup displaying the full result value for a function log entry (memory mode)
+/// Renders a centered popup displaying the full result value for a function log entry (memory mode),
+/// preceded by the function's top allocating call sites when call site sampling is enabled
 pub(crate) fn render_inspect_popup(
     entry: &InspectedFunctionLog,
+    call_sites: &[&CallSiteEntry],
     area: Rect,
     frame: &mut Frame,
     total_elapsed: u64,
@@ -57,7 +61,24 @@ pub(crate) fn render_inspect_popup(
 
     frame.render_widget(block, popup_area);
 
-    let text_lines: Vec<Line> = result_text
+    let mut text_lines: Vec<Line> = Vec::new();
+    if !call_sites.is_empty() {
+        text_lines.push(Line::from(Span::styled(
+            "Top call sites (sampled):",
+            Style::default().add_modifier(Modifier::BOLD),
+        )));
+        for site in call_sites {
+            text_lines.push(Line::from(format!(
+                "  {:>10}  {:>6} samples  {}",
+                hotpath::format_bytes(site.bytes),
+                site.samples,
+                site.location
+            )));
+        }
+        text_lines.push(Line::from(""));
+    }
+
+    let result_lines: Vec<Line> = result_text
         .lines()
         .flat_map(|line| {
             let max_width = inner_area.width.saturating_sub(2) as usize;
@@ -79,6 +100,7 @@ pub(crate) fn render_inspect_popup(
             }
         })
         .collect();
+    text_lines.extend(result_lines);
 
     let paragraph = Paragraph::new(text_lines).wrap(Wrap { trim: false });
 
@@ -1,23 +1,38 @@
 pub(crate) mod calls;
+pub(crate) mod in_flight;
 pub(crate) mod inspect;
 
 use super::common_styles;
 use crate::cmd::console::app::FuturesFocus;
 use crate::cmd::console::widgets::formatters::truncate_left;
+use hotpath::format_duration;
 use hotpath::json::SerializableFutureStats;
 use ratatui::{
     layout::{Constraint, Rect},
-    style::Style,
+    style::{Color, Style},
     symbols::border,
     widgets::{Block, Cell, HighlightSpacing, Row, Table, TableState},
     Frame,
 };
 
-/// Renders the futures table with future statistics
+/// Share of the parent's summed lifetime spent in calls of the child future
+fn parent_share(parent: &SerializableFutureStats, child_id: u64) -> Option<f64> {
+    if parent.total_lifetime_ns == 0 {
+        return None;
+    }
+    parent
+        .children
+        .iter()
+        .find(|child| child.future_id == child_id)
+        .map(|child| child.total_lifetime_ns as f64 / parent.total_lifetime_ns as f64 * 100.0)
+}
+
+/// Renders the futures table with future statistics, children indented under their parent
 #[hotpath::measure]
 #[allow(clippy::too_many_arguments)]
 pub(crate) fn render_futures_panel(
     stats: &[SerializableFutureStats],
+    depths: &[usize],
     area: Rect,
     frame: &mut Frame,
     table_state: &mut TableState,
@@ -27,31 +42,100 @@ pub(crate) fn render_futures_panel(
     total_futures: usize,
 ) {
     let available_width = area.width.saturating_sub(10);
-    let future_width = ((available_width as f32 * 0.50) as usize).max(30);
+    let future_width = ((available_width as f32 * 0.20) as usize).max(24);
 
     let header = Row::new(vec![
         Cell::from("Future"),
         Cell::from("Calls"),
         Cell::from("Polls"),
+        Cell::from("Wakes"),
+        Cell::from("Spurious"),
+        Cell::from("Poll P95"),
+        Cell::from("Max Poll"),
+        Cell::from("Wake→Poll P95"),
+        Cell::from("In Flight"),
+        Cell::from("Cancelled"),
+        Cell::from("Lifetime P95"),
+        Cell::from("In Parent"),
     ])
     .style(common_styles::HEADER_STYLE)
     .height(1);
 
+    let depth_at = |idx: usize| depths.get(idx).copied().unwrap_or(0);
+
     let rows: Vec<Row> = stats
         .iter()
-        .map(|stat| {
+        .enumerate()
+        .map(|(idx, stat)| {
+            let depth = depth_at(idx);
+            // The parent is the closest preceding row one level up
+            let parent = (depth > 0)
+                .then(|| (0..idx).rev().find(|&prev| depth_at(prev) + 1 == depth))
+                .flatten()
+                .map(|prev| &stats[prev]);
+            let in_parent = parent
+                .and_then(|parent| parent_share(parent, stat.id))
+                .map(|share| format!("{share:.1}%"))
+                .unwrap_or_else(|| "-".to_string());
+            let indent = if depth > 0 {
+                format!("{}└ ", "  ".repeat(depth - 1))
+            } else {
+                String::new()
+            };
+
+            // Futures that were never polled have no poll durations yet
+            let (p95, max) = match stat.poll_p95_ns {
+                Some(p95) => (format_duration(p95), format_duration(stat.poll_max_ns)),
+                None => ("-".to_string(), "-".to_string()),
+            };
+            let wake_to_poll = stat
+                .wake_to_poll_p95_ns
+                .map(format_duration)
+                .unwrap_or_else(|| "-".to_string());
+            let lifetime = stat
+                .lifetime_p95_ns
+                .map(format_duration)
+                .unwrap_or_else(|| "-".to_string());
             Row::new(vec![
-                Cell::from(truncate_left(&stat.label, future_width)),
+                Cell::from(format!(
+                    "{indent}{}",
+                    truncate_left(
+                        &stat.label,
+                        future_width.saturating_sub(indent.chars().count())
+                    )
+                )),
                 Cell::from(stat.call_count.to_string()),
                 Cell::from(stat.total_polls.to_string()),
+                Cell::from(stat.total_wakes.to_string()),
+                Cell::from(stat.spurious_polls.to_string()),
+                Cell::from(p95),
+                if stat.blocking_polls > 0 {
+                    Cell::from(format!("⚠ {max}")).style(Style::default().fg(Color::Red))
+                } else {
+                    Cell::from(max)
+                },
+                Cell::from(wake_to_poll),
+                Cell::from(stat.in_flight.to_string()),
+                Cell::from(stat.cancelled_count.to_string()),
+                Cell::from(lifetime),
+                Cell::from(in_parent),
             ])
         })
         .collect();
 
     let widths = [
-        Constraint::Percentage(50), // Future
-        Constraint::Percentage(25), // Calls
-        Constraint::Percentage(25), // Polls
+        Constraint::Percentage(20), // Future
+        Constraint::Percentage(6),  // Calls
+        Constraint::Percentage(6),  // Polls
+        Constraint::Percentage(6),  // Wakes
+        Constraint::Percentage(6),  // Spurious
+        Constraint::Percentage(8),  // Poll P95
+        Constraint::Percentage(8),  // Max Poll
+        Constraint::Percentage(10), // Wake→Poll P95
+        Constraint::Percentage(7),  // In Flight
+        Constraint::Percentage(8),  // Cancelled
+        Constraint::Percentage(8),  // Lifetime P95
+        Constraint::Percentage(7),  // In Parent
     ];
 
     let table_block = if show_calls {
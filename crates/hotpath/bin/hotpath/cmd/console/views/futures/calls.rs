@@ -1,5 +1,6 @@
 use super::super::common_styles;
 use crate::cmd::console::widgets::formatters::truncate_message;
+use hotpath::format_duration;
 use hotpath::json::{FutureCall, FutureCalls, FutureState};
 use ratatui::{
     layout::Rect,
@@ -53,6 +54,9 @@ fn render_call_row(call: &FutureCall, result_width: usize) -> Row<'static> {
         Cell::from(state_text).style(state_style(&call.state)),
         Cell::from(result_text),
         Cell::from(call.poll_count.to_string()),
+        Cell::from(call.wake_count.to_string()),
+        Cell::from(format_duration(call.busy_ns)),
+        Cell::from(format_duration(call.max_poll_ns)),
     ])
 }
 
@@ -83,11 +87,13 @@ pub(crate) fn render_calls_panel(
     frame.render_widget(block, area);
 
     let available_width = inner_area.width.saturating_sub(4);
-    let result_width = (available_width.saturating_sub(25) as usize).max(10);
+    let result_width = (available_width.saturating_sub(54) as usize).max(10);
 
-    let header = Row::new(vec!["ID", "State", "Result", "Polls"])
-        .style(common_styles::HEADER_STYLE)
-        .height(1);
+    let header = Row::new(vec![
+        "ID", "State", "Result", "Polls", "Wakes", "Busy", "Max Poll",
+    ])
+    .style(common_styles::HEADER_STYLE)
+    .height(1);
 
     let rows: Vec<Row> = future_calls
         .calls
@@ -96,10 +102,13 @@ pub(crate) fn render_calls_panel(
         .collect();
 
     let widths = [
-        ratatui::layout::Constraint::Length(8), // ID
-        ratatui::layout::Constraint::Length(9), // State
-        ratatui::layout::Constraint::Min(10),   // Result
-        ratatui::layout::Constraint::Length(6), // Polls
+        ratatui::layout::Constraint::Length(8),  // ID
+        ratatui::layout::Constraint::Length(9),  // State
+        ratatui::layout::Constraint::Min(10),    // Result
+        ratatui::layout::Constraint::Length(6),  // Polls
+        ratatui::layout::Constraint::Length(6),  // Wakes
+        ratatui::layout::Constraint::Length(10), // Busy
+        ratatui::layout::Constraint::Length(10), // Max Poll
     ];
 
     let table = Table::new(rows, widths)
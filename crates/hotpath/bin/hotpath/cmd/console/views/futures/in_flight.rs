@@ -0,0 +1,91 @@
+use super::super::common_styles;
+use crate::cmd::console::widgets::formatters::{format_time_ago, truncate_left};
+use hotpath::format_duration;
+use hotpath::json::InFlightFuturesJson;
+use ratatui::{
+    layout::{Constraint, Rect},
+    style::{Color, Style},
+    symbols::border,
+    widgets::{Block, Cell, Row, Table},
+    Frame,
+};
+
+/// Renders the oldest in-flight calls across all futures, stuck ones highlighted
+pub(crate) fn render_in_flight_panel(
+    in_flight: &InFlightFuturesJson,
+    area: Rect,
+    frame: &mut Frame,
+) {
+    let future_width = ((area.width.saturating_sub(4) as f32 * 0.40) as usize).max(24);
+
+    let header = Row::new(vec![
+        Cell::from("Future"),
+        Cell::from("Call"),
+        Cell::from("State"),
+        Cell::from("Polls"),
+        Cell::from("Age"),
+        Cell::from("Last Poll"),
+    ])
+    .style(common_styles::HEADER_STYLE)
+    .height(1);
+
+    let rows: Vec<Row> = in_flight
+        .calls
+        .iter()
+        .map(|call| {
+            let last_poll = call
+                .last_poll_ns
+                .map(|ns| format_time_ago(in_flight.current_elapsed_ns.saturating_sub(ns)))
+                .unwrap_or_else(|| "never".to_string());
+            let row = Row::new(vec![
+                Cell::from(truncate_left(&call.label, future_width)),
+                Cell::from(call.call_id.to_string()),
+                Cell::from(call.state.as_str()),
+                Cell::from(call.poll_count.to_string()),
+                Cell::from(format_duration(call.age_ns)),
+                Cell::from(last_poll),
+            ]);
+            if call.stuck {
+                row.style(Style::default().fg(Color::Red))
+            } else {
+                row
+            }
+        })
+        .collect();
+
+    let widths = [
+        Constraint::Percentage(40), // Future
+        Constraint::Percentage(10), // Call
+        Constraint::Percentage(12), // State
+        Constraint::Percentage(10), // Polls
+        Constraint::Percentage(14), // Age
+        Constraint::Percentage(14), // Last Poll
+    ];
+
+    let mut title = format!(
+        " Oldest in flight [{}/{}] ",
+        in_flight.calls.len(),
+        in_flight.total
+    );
+    if let Some(threshold_ns) = in_flight.threshold_ns {
+        if in_flight.stuck > 0 {
+            title.push_str(&format!(
+                "⚠ {} stuck (> {}) ",
+                in_flight.stuck,
+                format_duration(threshold_ns)
+            ));
+        }
+    }
+
+    let block = Block::bordered()
+        .title(title)
+        .border_set(border::PLAIN)
+        .border_style(common_styles::UNFOCUSED_BORDER_STYLE);
+
+    let table = Table::new(rows, widths)
+        .header(header)
+        .block(block)
+        .column_spacing(1);
+
+    frame.render_widget(table, area);
+}
@@ -26,13 +26,27 @@ pub(crate) fn render_inspect_popup(call: &FutureCall, area: Rect, frame: &mut Fr
 
     frame.render_widget(Clear, popup_area);
 
+    let mut details = format!(
+        "Call ID: {}, State: {}, Polls: {}, Wakes: {}, Busy: {}, Max Poll: {}",
+        call.id,
+        call.state.as_str(),
+        call.poll_count,
+        call.wake_count,
+        hotpath::format_duration(call.busy_ns),
+        hotpath::format_duration(call.max_poll_ns)
+    );
+    if let Some(lifetime_ns) = call.lifetime_ns {
+        details.push_str(&format!(
+            ", Lifetime: {}",
+            hotpath::format_duration(lifetime_ns)
+        ));
+    }
+    if let Some(parent_call_id) = call.parent_call_id {
+        details.push_str(&format!(", Parent Call: {parent_call_id}"));
+    }
+
     let block = Block::bordered()
-        .title(format!(
-            " Result (Call ID: {}, State: {}, Polls: {}) ",
-            call.id,
-            call.state.as_str(),
-            call.poll_count
-        ))
+        .title(format!(" Result ({details}) "))
         .border_set(border::DOUBLE);
 
     let inner_area = block.inner(popup_area);
@@ -0,0 +1,107 @@
+use super::common_styles;
+use crate::cmd::console::widgets::formatters::{format_delay, truncate_left};
+use hotpath::json::SerializableLockStats;
+use ratatui::{
+    layout::{Constraint, Rect},
+    style::{Color, Style},
+    symbols::border,
+    widgets::{Block, Cell, HighlightSpacing, Row, Table, TableState},
+    Frame,
+};
+
+/// Share of acquisitions that had to wait, highlighted when it gets high
+fn contention_cell(stat: &SerializableLockStats) -> Cell<'static> {
+    if stat.acquisitions == 0 {
+        return Cell::from("0");
+    }
+    let percentage = stat.contentions as f64 / stat.acquisitions as f64 * 100.0;
+    let color = if percentage >= 50.0 {
+        Color::Red
+    } else if percentage >= 10.0 {
+        Color::Yellow
+    } else {
+        Color::Green
+    };
+    Cell::from(format!("{} ({:.0}%)", stat.contentions, percentage))
+        .style(Style::default().fg(color))
+}
+
+/// Renders the locks table with acquisition and contention statistics
+#[hotpath::measure]
+pub(crate) fn render_locks_panel(
+    stats: &[SerializableLockStats],
+    area: Rect,
+    frame: &mut Frame,
+    table_state: &mut TableState,
+    lock_position: usize,
+    total_locks: usize,
+) {
+    let available_width = area.width.saturating_sub(10);
+    let lock_width = ((available_width as f32 * 0.24) as usize).max(16);
+
+    let header = Row::new(vec![
+        Cell::from("Lock"),
+        Cell::from("Type"),
+        Cell::from("Acquired"),
+        Cell::from("Contended"),
+        Cell::from("Wait P50"),
+        Cell::from("Wait P99"),
+        Cell::from("Wait Max"),
+        Cell::from("Hold P95"),
+        Cell::from("Holder"),
+    ])
+    .style(common_styles::HEADER_STYLE)
+    .height(1);
+
+    let rows: Vec<Row> = stats
+        .iter()
+        .map(|stat| {
+            let duration = |ns: Option<u64>| ns.map_or("-".to_string(), format_delay);
+            let holder = match (stat.holder_tid, stat.readers) {
+                (Some(tid), _) => {
+                    Cell::from(format!("tid {tid}")).style(Style::default().fg(Color::Yellow))
+                }
+                (None, 0) => Cell::from("-"),
+                (None, readers) => Cell::from(format!("{readers} readers")),
+            };
+
+            Row::new(vec![
+                Cell::from(truncate_left(&stat.label, lock_width)),
+                Cell::from(format!("{}::{}", stat.backend, stat.lock_type)),
+                Cell::from(stat.acquisitions.to_string()),
+                contention_cell(stat),
+                Cell::from(duration(stat.wait_p50_ns)),
+                Cell::from(duration(stat.wait_p99_ns)),
+                Cell::from(format_delay(stat.wait_max_ns)),
+                Cell::from(duration(stat.hold_p95_ns)),
+                holder,
+            ])
+        })
+        .collect();
+
+    let widths = [
+        Constraint::Percentage(24), // Lock
+        Constraint::Percentage(15), // Type
+        Constraint::Percentage(8),  // Acquired
+        Constraint::Percentage(11), // Contended
+        Constraint::Percentage(8),  // Wait P50
+        Constraint::Percentage(8),  // Wait P99
+        Constraint::Percentage(8),  // Wait Max
+        Constraint::Percentage(8),  // Hold P95
+        Constraint::Percentage(10), // Holder
+    ];
+
+    let table_block = Block::bordered()
+        .title(format!(" [{}/{}] ", lock_position, total_locks))
+        .border_set(border::THICK);
+
+    let table = Table::new(rows, widths)
+        .header(header)
+        .block(table_block)
+        .column_spacing(1)
+        .row_highlight_style(common_styles::SELECTED_ROW_STYLE)
+        .highlight_symbol(">> ")
+        .highlight_spacing(HighlightSpacing::Always);
+
+    frame.render_stateful_widget(table, area, table_state);
+}
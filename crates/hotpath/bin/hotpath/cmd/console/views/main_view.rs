@@ -1,14 +1,18 @@
 use super::super::app::{
     App, ChannelsFocus, FunctionsFocus, FuturesFocus, SelectedTab, StreamsFocus,
 };
-use super::channels::{inspect, logs as channel_logs};
+use super::channels::{history as channel_history, inspect, logs as channel_logs};
 use super::functions_memory::{inspect as memory_inspect, logs as memory_logs};
 use super::functions_timing::{inspect as timing_inspect, logs as timing_logs};
-use super::futures::{calls as future_calls, inspect as future_inspect};
+use super::futures::{
+    calls as future_calls, in_flight as future_in_flight, inspect as future_inspect,
+};
 use super::streams::{inspect as stream_inspect, logs as stream_logs};
 use super::{
-    bottom_bar, channels, functions_memory, functions_timing, futures, streams, threads, top_bar,
+    bottom_bar, channels, functions_memory, functions_timing, futures, locks, runtime, sinks,
+    streams, tasks, threads, top_bar,
 };
+use hotpath::CallSiteEntry;
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style, Stylize},
@@ -36,6 +40,10 @@ pub(crate) fn render_ui(frame: &mut Frame, app: &mut App) {
         SelectedTab::Channels => !app.channels.channels.is_empty(),
         SelectedTab::Streams => !app.streams.streams.is_empty(),
         SelectedTab::Threads => !app.threads.threads.is_empty(),
+        SelectedTab::Locks => !app.locks.locks.is_empty(),
+        SelectedTab::Tasks => !app.tasks.tasks.is_empty(),
+        SelectedTab::Runtime => !app.runtime.samples.is_empty(),
+        SelectedTab::Sinks => !app.sinks.sinks.is_empty(),
         SelectedTab::Futures => !app.futures.futures.is_empty(),
     };
 
@@ -46,6 +54,7 @@ pub(crate) fn render_ui(frame: &mut Frame, app: &mut App) {
         app.last_successful_fetch,
         app.error_message.is_some(),
         has_data,
+        &app.blocking_polls,
     );
 
     render_tabs(frame, main_chunks[0], app.selected_tab);
@@ -106,8 +115,22 @@ pub(crate) fn render_ui(frame: &mut Frame, app: &mut App) {
 
                 if app.functions_focus == FunctionsFocus::Inspect {
                     if let Some(ref inspected_log) = app.inspected_function_log {
+                        let selected_name = app.selected_function_name();
+                        let call_sites: Vec<&CallSiteEntry> = app
+                            .memory_functions
+                            .call_sites
+                            .as_ref()
+                            .map(|call_sites| {
+                                call_sites
+                                    .data
+                                    .iter()
+                                    .filter(|entry| Some(&entry.name) == selected_name.as_ref())
+                                    .collect()
+                            })
+                            .unwrap_or_default();
                         memory_inspect::render_inspect_popup(
                             inspected_log,
+                            &call_sites,
                             main_chunks[2],
                             frame,
                             app.memory_functions.total_elapsed,
@@ -127,6 +150,18 @@ pub(crate) fn render_ui(frame: &mut Frame, app: &mut App) {
         SelectedTab::Threads => {
             render_threads_view(frame, app, main_chunks[2]);
         }
+        SelectedTab::Locks => {
+            render_locks_view(frame, app, main_chunks[2]);
+        }
+        SelectedTab::Tasks => {
+            render_tasks_view(frame, app, main_chunks[2]);
+        }
+        SelectedTab::Runtime => {
+            render_runtime_view(frame, app, main_chunks[2]);
+        }
+        SelectedTab::Sinks => {
+            render_sinks_view(frame, app, main_chunks[2]);
+        }
         SelectedTab::Futures => {
             render_futures_view(frame, app, main_chunks[2]);
         }
@@ -210,17 +245,28 @@ fn render_channels_view(frame: &mut Frame, app: &mut App, area: Rect) {
         total_channels,
     );
 
-    // Render logs panel if visible
-    if let Some(logs_area) = logs_area {
+    // Render history and logs panels if visible
+    if let Some(side_area) = logs_area {
+        let side_chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(8), Constraint::Min(0)])
+            .split(side_area);
+        let logs_area = side_chunks[1];
+        channel_history::render_history_panel(app.channel_history.as_ref(), side_chunks[0], frame);
+
         let channel_label = app
             .channels_table_state
             .selected()
             .and_then(|i| stats.get(i))
             .map(|stat| {
-                if stat.label.is_empty() {
+                let label = if stat.label.is_empty() {
                     stat.id.to_string()
                 } else {
                     stat.label.clone()
+                };
+                match channels::fan_out_summary(stat, app.channels.current_elapsed_ns) {
+                    Some(summary) => format!("{} | {}", label, summary),
+                    None => label,
                 }
             })
             .unwrap_or_else(|| "Unknown".to_string());
@@ -435,9 +481,211 @@ fn render_threads_view(frame: &mut Frame, app: &mut App, area: Rect) {
         thread_position,
         total_threads,
         app.threads.rss_bytes,
+        app.threads.untracked_threads,
+    );
+}
+
+#[hotpath::measure]
+fn render_locks_view(frame: &mut Frame, app: &mut App, area: Rect) {
+    let lock_list = &app.locks.locks;
+
+    if let Some(ref error_msg) = app.error_message {
+        if lock_list.is_empty() {
+            let error_text = vec![
+                Line::from(""),
+                Line::from("Error").red().bold().centered(),
+                Line::from(""),
+                Line::from(error_msg.as_str()).red().centered(),
+                Line::from(""),
+                Line::from(format!(
+                    "Make sure the metrics server is running on {}",
+                    app.metrics_host
+                ))
+                .yellow()
+                .centered(),
+            ];
+
+            let block = Block::bordered().border_set(border::THICK);
+            frame.render_widget(Paragraph::new(error_text).block(block), area);
+            return;
+        }
+    }
+
+    if lock_list.is_empty() {
+        let empty_text = vec![
+            Line::from(""),
+            Line::from("No instrumented locks found")
+                .yellow()
+                .centered(),
+            Line::from(""),
+            Line::from("Wrap locks with hotpath::mutex! or hotpath::rwlock! to track contention")
+                .centered(),
+        ];
+
+        let block = Block::bordered().border_set(border::THICK);
+        frame.render_widget(Paragraph::new(empty_text).block(block), area);
+        return;
+    }
+
+    let selected_index = app.locks_table_state.selected().unwrap_or(0);
+    let lock_position = selected_index + 1; // 1-indexed
+    let total_locks = lock_list.len();
+
+    locks::render_locks_panel(
+        lock_list,
+        area,
+        frame,
+        &mut app.locks_table_state,
+        lock_position,
+        total_locks,
+    );
+}
+
+#[hotpath::measure]
+fn render_tasks_view(frame: &mut Frame, app: &mut App, area: Rect) {
+    if let Some(ref error_msg) = app.error_message {
+        if app.tasks.tasks.is_empty() {
+            let error_text = vec![
+                Line::from(""),
+                Line::from("Error").red().bold().centered(),
+                Line::from(""),
+                Line::from(error_msg.as_str()).red().centered(),
+                Line::from(""),
+                Line::from(format!(
+                    "Make sure the metrics server is running on {}",
+                    app.metrics_host
+                ))
+                .yellow()
+                .centered(),
+            ];
+
+            let block = Block::bordered().border_set(border::THICK);
+            frame.render_widget(Paragraph::new(error_text).block(block), area);
+            return;
+        }
+    }
+
+    if app.tasks.tasks.is_empty() {
+        let empty_text = vec![
+            Line::from(""),
+            Line::from("No instrumented tasks found")
+                .yellow()
+                .centered(),
+            Line::from(""),
+            Line::from("Spawn tasks with hotpath::spawn! to list them here").centered(),
+        ];
+
+        let block = Block::bordered().border_set(border::THICK);
+        frame.render_widget(Paragraph::new(empty_text).block(block), area);
+        return;
+    }
+
+    let selected_index = app.tasks_table_state.selected().unwrap_or(0);
+    let task_position = selected_index + 1; // 1-indexed
+
+    tasks::render_tasks_panel(
+        &app.tasks,
+        area,
+        frame,
+        &mut app.tasks_table_state,
+        task_position,
+    );
+}
+
+#[hotpath::measure]
+fn render_sinks_view(frame: &mut Frame, app: &mut App, area: Rect) {
+    if let Some(ref error_msg) = app.error_message {
+        if app.sinks.sinks.is_empty() {
+            let error_text = vec![
+                Line::from(""),
+                Line::from("Error").red().bold().centered(),
+                Line::from(""),
+                Line::from(error_msg.as_str()).red().centered(),
+                Line::from(""),
+                Line::from(format!(
+                    "Make sure the metrics server is running on {}",
+                    app.metrics_host
+                ))
+                .yellow()
+                .centered(),
+            ];
+
+            let block = Block::bordered().border_set(border::THICK);
+            frame.render_widget(Paragraph::new(error_text).block(block), area);
+            return;
+        }
+    }
+
+    if app.sinks.sinks.is_empty() {
+        let empty_text = vec![
+            Line::from(""),
+            Line::from("No instrumented sinks found")
+                .yellow()
+                .centered(),
+            Line::from(""),
+            Line::from("Wrap sinks with hotpath::sink! to list them here").centered(),
+        ];
+
+        let block = Block::bordered().border_set(border::THICK);
+        frame.render_widget(Paragraph::new(empty_text).block(block), area);
+        return;
+    }
+
+    let selected_index = app.sinks_table_state.selected().unwrap_or(0);
+    let sink_position = selected_index + 1; // 1-indexed
+
+    sinks::render_sinks_panel(
+        &app.sinks.sinks,
+        area,
+        frame,
+        &mut app.sinks_table_state,
+        sink_position,
     );
 }
 
+#[hotpath::measure]
+fn render_runtime_view(frame: &mut Frame, app: &mut App, area: Rect) {
+    if let Some(ref error_msg) = app.error_message {
+        if app.runtime.samples.is_empty() {
+            let error_text = vec![
+                Line::from(""),
+                Line::from("Error").red().bold().centered(),
+                Line::from(""),
+                Line::from(error_msg.as_str()).red().centered(),
+                Line::from(""),
+                Line::from(format!(
+                    "Make sure the metrics server is running on {}",
+                    app.metrics_host
+                ))
+                .yellow()
+                .centered(),
+            ];
+
+            let block = Block::bordered().border_set(border::THICK);
+            frame.render_widget(Paragraph::new(error_text).block(block), area);
+            return;
+        }
+    }
+
+    if app.runtime.samples.is_empty() {
+        let empty_text = vec![
+            Line::from(""),
+            Line::from("No tokio runtime registered").yellow().centered(),
+            Line::from(""),
+            Line::from(
+                "Enable the tokio-runtime-metrics feature and call hotpath::runtime::register_handle",
+            )
+            .centered(),
+        ];
+
+        let block = Block::bordered().border_set(border::THICK);
+        frame.render_widget(Paragraph::new(empty_text).block(block), area);
+        return;
+    }
+
+    runtime::render_runtime_panel(&app.runtime, area, frame, &mut app.runtime_table_state);
+}
+
 #[hotpath::measure]
 fn render_futures_view(frame: &mut Frame, app: &mut App, area: Rect) {
     let stats = &app.futures.futures;
@@ -477,6 +725,17 @@ fn render_futures_view(frame: &mut Frame, app: &mut App, area: Rect) {
         return;
     }
 
+    // Keep the oldest in-flight calls visible below the futures
+    let area = if app.in_flight_futures.calls.is_empty() {
+        area
+    } else {
+        let panel_height = (app.in_flight_futures.calls.len() as u16 + 3).min(10);
+        let chunks =
+            Layout::vertical([Constraint::Min(0), Constraint::Length(panel_height)]).split(area);
+        future_in_flight::render_in_flight_panel(&app.in_flight_futures, chunks[1], frame);
+        chunks[0]
+    };
+
     // Split the area if calls are being shown
     let (table_area, calls_area) = if app.show_future_calls {
         let chunks = Layout::default()
@@ -494,6 +753,7 @@ fn render_futures_view(frame: &mut Frame, app: &mut App, area: Rect) {
 
     futures::render_futures_panel(
         stats,
+        &app.future_depths,
         table_area,
         frame,
         &mut app.futures_table_state,
@@ -556,7 +816,8 @@ fn render_tabs(frame: &mut Frame, area: ratatui::layout::Rect, selected_tab: Sel
         };
         Line::from(vec![
             Span::styled(
-                format!("[{}]", tab.number()),
+                // The tenth tab is bound to the 0 key
+                format!("[{}]", tab.number() % 10),
                 Style::default()
                     .fg(Color::Blue)
                     .add_modifier(Modifier::BOLD),
@@ -572,6 +833,10 @@ fn render_tabs(frame: &mut Frame, area: ratatui::layout::Rect, selected_tab: Sel
         create_tab_line(SelectedTab::Channels),
         create_tab_line(SelectedTab::Streams),
         create_tab_line(SelectedTab::Threads),
+        create_tab_line(SelectedTab::Locks),
+        create_tab_line(SelectedTab::Tasks),
+        create_tab_line(SelectedTab::Runtime),
+        create_tab_line(SelectedTab::Sinks),
     ];
 
     let selected_index = (selected_tab.number() - 1) as usize;
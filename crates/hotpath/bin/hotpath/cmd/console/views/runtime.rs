@@ -0,0 +1,201 @@
+use super::common_styles;
+use hotpath::json::RuntimeJson;
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Style},
+    symbols::border,
+    text::{Line, Span},
+    widgets::{Block, Cell, HighlightSpacing, Paragraph, Row, Sparkline, Table, TableState},
+    Frame,
+};
+
+/// Renders runtime totals, history sparklines and the per-worker park table
+#[hotpath::measure]
+pub(crate) fn render_runtime_panel(
+    runtime: &RuntimeJson,
+    area: Rect,
+    frame: &mut Frame,
+    table_state: &mut TableState,
+) {
+    let Some(last) = runtime.samples.last() else {
+        return;
+    };
+
+    let chunks = Layout::vertical([
+        Constraint::Length(1),
+        Constraint::Length(8),
+        Constraint::Min(0),
+    ])
+    .split(area);
+
+    let blocking = last
+        .num_blocking_threads
+        .map_or("-".to_string(), |count| count.to_string());
+    let info = Line::from(vec![
+        Span::raw(" Flavor: "),
+        Span::styled(
+            runtime.flavor.as_deref().unwrap_or("-"),
+            Style::default().fg(Color::Yellow),
+        ),
+        Span::raw("  Workers: "),
+        Span::styled(
+            last.num_workers.to_string(),
+            Style::default().fg(Color::Yellow),
+        ),
+        Span::raw("  Alive tasks: "),
+        Span::styled(
+            last.num_alive_tasks.to_string(),
+            Style::default().fg(Color::Green),
+        ),
+        Span::raw("  Global queue: "),
+        Span::styled(
+            last.global_queue_depth.to_string(),
+            Style::default().fg(Color::Cyan),
+        ),
+        Span::raw("  Blocking threads: "),
+        Span::styled(blocking, Style::default().fg(Color::Magenta)),
+    ]);
+    frame.render_widget(Paragraph::new(info), chunks[0]);
+
+    render_history(runtime, chunks[1], frame);
+    render_workers_table(runtime, chunks[2], frame, table_state);
+}
+
+fn render_history(runtime: &RuntimeJson, area: Rect, frame: &mut Frame) {
+    let block = Block::bordered()
+        .title(format!(
+            " History (last {}s) ",
+            runtime.samples.len() as u64 * runtime.sample_interval_ms / 1000
+        ))
+        .border_set(border::PLAIN)
+        .border_style(common_styles::UNFOCUSED_BORDER_STYLE);
+    let inner_area = block.inner(area);
+    frame.render_widget(block, area);
+
+    let rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(2), // Alive tasks
+            Constraint::Length(2), // Global queue
+            Constraint::Length(2), // Blocking threads
+        ])
+        .split(inner_area);
+
+    // Keep the most recent samples that fit the panel width
+    let width = inner_area.width.saturating_sub(16) as usize;
+    let recent = &runtime.samples[runtime.samples.len().saturating_sub(width)..];
+    let series: [(&str, Vec<u64>, Color); 3] = [
+        (
+            "tasks",
+            recent
+                .iter()
+                .map(|sample| sample.num_alive_tasks as u64)
+                .collect(),
+            Color::Green,
+        ),
+        (
+            "queue",
+            recent
+                .iter()
+                .map(|sample| sample.global_queue_depth as u64)
+                .collect(),
+            Color::Cyan,
+        ),
+        (
+            "blocking",
+            recent
+                .iter()
+                .map(|sample| sample.num_blocking_threads.unwrap_or(0) as u64)
+                .collect(),
+            Color::Magenta,
+        ),
+    ];
+
+    for ((name, data, color), row) in series.iter().zip(rows.iter()) {
+        let columns = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Length(16), Constraint::Min(0)])
+            .split(*row);
+        let last = data.last().copied().unwrap_or(0);
+        frame.render_widget(Paragraph::new(format!("{name} {last}")), columns[0]);
+        frame.render_widget(
+            Sparkline::default()
+                .data(data)
+                .style(Style::default().fg(*color)),
+            columns[1],
+        );
+    }
+}
+
+fn render_workers_table(
+    runtime: &RuntimeJson,
+    area: Rect,
+    frame: &mut Frame,
+    table_state: &mut TableState,
+) {
+    let last = runtime.samples.last();
+    let previous = runtime
+        .samples
+        .len()
+        .checked_sub(2)
+        .and_then(|idx| runtime.samples.get(idx));
+
+    let header = Row::new(vec![
+        Cell::from("Worker"),
+        Cell::from("Parks"),
+        Cell::from("Parks/s"),
+    ])
+    .style(common_styles::HEADER_STYLE)
+    .height(1);
+
+    let park_counts = last.map_or(&[][..], |sample| &sample.worker_park_counts[..]);
+    let rows: Vec<Row> = park_counts
+        .iter()
+        .enumerate()
+        .map(|(worker, &parks)| {
+            let rate = match (last, previous) {
+                (Some(last), Some(previous)) if last.timestamp_ns > previous.timestamp_ns => {
+                    let prev_parks = previous
+                        .worker_park_counts
+                        .get(worker)
+                        .copied()
+                        .unwrap_or(0);
+                    let elapsed_secs =
+                        (last.timestamp_ns - previous.timestamp_ns) as f64 / 1_000_000_000.0;
+                    format!(
+                        "{:.1}",
+                        parks.saturating_sub(prev_parks) as f64 / elapsed_secs
+                    )
+                }
+                _ => "-".to_string(),
+            };
+
+            Row::new(vec![
+                Cell::from(worker.to_string()),
+                Cell::from(parks.to_string()),
+                Cell::from(rate),
+            ])
+        })
+        .collect();
+
+    let widths = [
+        Constraint::Percentage(20), // Worker
+        Constraint::Percentage(40), // Parks
+        Constraint::Percentage(40), // Parks/s
+    ];
+
+    let position = table_state.selected().map_or(0, |idx| idx + 1);
+    let table_block = Block::bordered()
+        .title(format!(" [{}/{}] Workers ", position, park_counts.len()))
+        .border_set(border::THICK);
+
+    let table = Table::new(rows, widths)
+        .header(header)
+        .block(table_block)
+        .column_spacing(1)
+        .row_highlight_style(common_styles::SELECTED_ROW_STYLE)
+        .highlight_symbol(">> ")
+        .highlight_spacing(HighlightSpacing::Always);
+
+    frame.render_stateful_widget(table, area, table_state);
+}
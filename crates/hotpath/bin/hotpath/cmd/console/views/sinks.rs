@@ -0,0 +1,85 @@
+use super::common_styles;
+use crate::cmd::console::widgets::formatters::truncate_left;
+use hotpath::format_duration;
+use hotpath::json::{ChannelState, SerializableSinkStats};
+use ratatui::{
+    layout::{Constraint, Rect},
+    style::{Color, Style},
+    symbols::border,
+    widgets::{Block, Cell, HighlightSpacing, Row, Table, TableState},
+    Frame,
+};
+
+/// Renders the sinks table with backpressure and flush statistics
+#[hotpath::measure]
+pub(crate) fn render_sinks_panel(
+    stats: &[SerializableSinkStats],
+    area: Rect,
+    frame: &mut Frame,
+    table_state: &mut TableState,
+    sink_position: usize,
+) {
+    let available_width = area.width.saturating_sub(10);
+    let sink_width = ((available_width as f32 * 0.30) as usize).max(24);
+
+    let header = Row::new(vec![
+        Cell::from("Sink"),
+        Cell::from("State"),
+        Cell::from("Sent"),
+        Cell::from("Backpressure"),
+        Cell::from("Blocked"),
+        Cell::from("Flushes"),
+        Cell::from("Flush P95"),
+        Cell::from("Close"),
+    ])
+    .style(common_styles::HEADER_STYLE)
+    .height(1);
+
+    let rows: Vec<Row> = stats
+        .iter()
+        .map(|stat| {
+            let state_style = match stat.state {
+                ChannelState::Active => Style::default().fg(Color::Green),
+                ChannelState::Closed => Style::default().fg(Color::Yellow),
+                _ => Style::default().fg(Color::Gray),
+            };
+            let duration = |ns: Option<u64>| ns.map_or("-".to_string(), format_duration);
+
+            Row::new(vec![
+                Cell::from(truncate_left(&stat.label, sink_width)),
+                Cell::from(stat.state.to_string()).style(state_style),
+                Cell::from(stat.items_sent.to_string()),
+                Cell::from(stat.pending_ready_polls.to_string()),
+                Cell::from(format_duration(stat.backpressure_ns)),
+                Cell::from(stat.flushes.to_string()),
+                Cell::from(duration(stat.flush_p95_ns)),
+                Cell::from(duration(stat.close_ns)),
+            ])
+        })
+        .collect();
+
+    let widths = [
+        Constraint::Percentage(30), // Sink
+        Constraint::Percentage(9),  // State
+        Constraint::Percentage(8),  // Sent
+        Constraint::Percentage(11), // Backpressure
+        Constraint::Percentage(11), // Blocked
+        Constraint::Percentage(9),  // Flushes
+        Constraint::Percentage(11), // Flush P95
+        Constraint::Percentage(11), // Close
+    ];
+
+    let table_block = Block::bordered()
+        .title(format!(" [{}/{}] ", sink_position, stats.len()))
+        .border_set(border::THICK);
+
+    let table = Table::new(rows, widths)
+        .header(header)
+        .block(table_block)
+        .column_spacing(1)
+        .row_highlight_style(common_styles::SELECTED_ROW_STYLE)
+        .highlight_symbol(">> ")
+        .highlight_spacing(HighlightSpacing::Always);
+
+    frame.render_stateful_widget(table, area, table_state);
+}
@@ -4,6 +4,7 @@ pub(crate) mod logs;
 use super::common_styles;
 use crate::cmd::console::app::StreamsFocus;
 use crate::cmd::console::widgets::formatters::truncate_left;
+use hotpath::format_duration;
 use hotpath::json::{ChannelState, SerializableStreamStats};
 use ratatui::{
     layout::{Constraint, Rect},
@@ -27,12 +28,16 @@ pub(crate) fn render_streams_panel(
     total_streams: usize,
 ) {
     let available_width = area.width.saturating_sub(10);
-    let stream_width = ((available_width as f32 * 0.60) as usize).max(36);
+    let stream_width = ((available_width as f32 * 0.34) as usize).max(24);
 
     let header = Row::new(vec![
         Cell::from("Stream"),
         Cell::from("State"),
         Cell::from("Yielded"),
+        Cell::from("Pending"),
+        Cell::from("Busy"),
+        Cell::from("Item P95"),
+        Cell::from("First Item"),
     ])
     .style(common_styles::HEADER_STYLE)
     .height(1);
@@ -48,18 +53,35 @@ pub(crate) fn render_streams_panel(
                 _ => (stat.state.to_string(), Style::default().fg(Color::Gray)),
             };
 
+            let item_p95 = stat
+                .inter_item_p95_ns
+                .map(format_duration)
+                .unwrap_or_else(|| "-".to_string());
+            let first_item = stat
+                .time_to_first_item_ns
+                .map(format_duration)
+                .unwrap_or_else(|| "-".to_string());
+
             Row::new(vec![
                 Cell::from(truncate_left(&stat.label, stream_width)),
                 Cell::from(state_text).style(state_style),
                 Cell::from(stat.items_yielded.to_string()),
+                Cell::from(stat.pending_polls.to_string()),
+                Cell::from(format_duration(stat.busy_ns)),
+                Cell::from(item_p95),
+                Cell::from(first_item),
             ])
         })
         .collect();
 
     let widths = [
-        Constraint::Percentage(60), // Stream
-        Constraint::Percentage(20), // State
-        Constraint::Percentage(20), // Yielded
+        Constraint::Percentage(34), // Stream
+        Constraint::Percentage(10), // State
+        Constraint::Percentage(10), // Yielded
+        Constraint::Percentage(10), // Pending
+        Constraint::Percentage(12), // Busy
+        Constraint::Percentage(12), // Item P95
+        Constraint::Percentage(12), // First Item
     ];
 
     let table_block = if show_logs {
@@ -1,6 +1,7 @@
 use super::super::common_styles;
 use crate::cmd::console::app::CachedStreamLogs;
 use crate::cmd::console::widgets::formatters::{format_time_ago, truncate_message};
+use hotpath::format_bytes;
 use ratatui::{
     layout::Rect,
     style::Style,
@@ -63,9 +64,9 @@ pub(crate) fn render_logs_panel(
     frame.render_widget(block, area);
 
     let available_width = inner_area.width.saturating_sub(2);
-    let msg_width = (available_width.saturating_sub(20) as usize).max(20);
+    let msg_width = (available_width.saturating_sub(30) as usize).max(20);
 
-    let header = Row::new(vec!["Index", "Message", "Ago"])
+    let header = Row::new(vec!["Index", "Message", "Size", "Ago"])
         .style(common_styles::HEADER_STYLE)
         .height(1);
 
@@ -79,13 +80,19 @@ pub(crate) fn render_logs_panel(
             let msg = entry.message.as_deref().unwrap_or("");
             let truncated_msg = truncate_message(msg, msg_width);
 
-            Row::new(vec![entry.index.to_string(), truncated_msg, time_ago])
+            Row::new(vec![
+                entry.index.to_string(),
+                truncated_msg,
+                entry.size.map_or_else(|| "-".to_string(), format_bytes),
+                time_ago,
+            ])
         })
         .collect();
 
     let widths = [
         ratatui::layout::Constraint::Length(6),  // Index
         ratatui::layout::Constraint::Min(20),    // Message
+        ratatui::layout::Constraint::Length(10), // Size
         ratatui::layout::Constraint::Length(13), // Ago
     ];
 
@@ -0,0 +1,103 @@
+use super::common_styles;
+use crate::cmd::console::widgets::formatters::truncate_left;
+use hotpath::format_duration;
+use hotpath::json::{TaskState, TasksJson};
+use ratatui::{
+    layout::{Constraint, Rect},
+    style::{Color, Style},
+    symbols::border,
+    widgets::{Block, Cell, HighlightSpacing, Row, Table, TableState},
+    Frame,
+};
+
+fn state_style(state: TaskState) -> Style {
+    match state {
+        TaskState::Completed => Style::default().fg(Color::Green),
+        TaskState::Aborted => Style::default().fg(Color::Yellow),
+        TaskState::Panicked => Style::default().fg(Color::Red),
+        TaskState::Running => Style::default().fg(Color::Blue),
+        TaskState::Idle => Style::default().fg(Color::DarkGray),
+    }
+}
+
+/// Renders the task registry table, live tasks first
+#[hotpath::measure]
+pub(crate) fn render_tasks_panel(
+    tasks: &TasksJson,
+    area: Rect,
+    frame: &mut Frame,
+    table_state: &mut TableState,
+    task_position: usize,
+) {
+    let available_width = area.width.saturating_sub(10);
+    let task_width = ((available_width as f32 * 0.28) as usize).max(16);
+
+    let header = Row::new(vec![
+        Cell::from("ID"),
+        Cell::from("Task"),
+        Cell::from("Kind"),
+        Cell::from("Parent"),
+        Cell::from("State"),
+        Cell::from("Polls"),
+        Cell::from("Busy"),
+        Cell::from("Max Poll"),
+        Cell::from("Lifetime"),
+    ])
+    .style(common_styles::HEADER_STYLE)
+    .height(1);
+
+    let rows: Vec<Row> = tasks
+        .tasks
+        .iter()
+        .map(|task| {
+            let lifetime_ns = task
+                .finished_at_ns
+                .unwrap_or(tasks.current_elapsed_ns)
+                .saturating_sub(task.spawned_at_ns);
+
+            Row::new(vec![
+                Cell::from(task.id.to_string()),
+                Cell::from(truncate_left(&task.label, task_width)),
+                Cell::from(task.kind.to_string()),
+                Cell::from(task.parent_id.map_or("-".to_string(), |id| id.to_string())),
+                Cell::from(task.state.as_str()).style(state_style(task.state)),
+                Cell::from(task.poll_count.to_string()),
+                Cell::from(format_duration(task.busy_ns)),
+                Cell::from(format_duration(task.max_poll_ns)),
+                Cell::from(format_duration(lifetime_ns)),
+            ])
+        })
+        .collect();
+
+    let widths = [
+        Constraint::Percentage(6),  // ID
+        Constraint::Percentage(28), // Task
+        Constraint::Percentage(12), // Kind
+        Constraint::Percentage(7),  // Parent
+        Constraint::Percentage(9),  // State
+        Constraint::Percentage(7),  // Polls
+        Constraint::Percentage(10), // Busy
+        Constraint::Percentage(10), // Max Poll
+        Constraint::Percentage(11), // Lifetime
+    ];
+
+    let table_block = Block::bordered()
+        .title(format!(
+            " [{}/{}] live: {}, spawned: {} ",
+            task_position,
+            tasks.tasks.len(),
+            tasks.live,
+            tasks.total_spawned
+        ))
+        .border_set(border::THICK);
+
+    let table = Table::new(rows, widths)
+        .header(header)
+        .block(table_block)
+        .column_spacing(1)
+        .row_highlight_style(common_styles::SELECTED_ROW_STYLE)
+        .highlight_symbol(">> ")
+        .highlight_spacing(HighlightSpacing::Always);
+
+    frame.render_stateful_widget(table, area, table_state);
+}
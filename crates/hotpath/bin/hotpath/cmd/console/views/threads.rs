@@ -31,6 +31,7 @@ pub(crate) fn render_threads_panel(
     thread_position: usize,
     total_threads: usize,
     rss_bytes: Option<u64>,
+    untracked_threads: Option<u64>,
 ) {
     let chunks = Layout::vertical([Constraint::Length(1), Constraint::Min(0)]).split(area);
     let info_area = chunks[0];
@@ -59,6 +60,14 @@ pub(crate) fn render_threads_panel(
             tracked_str,
             ratatui::style::Style::default().fg(ratatui::style::Color::Green),
         ));
+
+        if let Some(untracked) = untracked_threads.filter(|&count| count > 0) {
+            spans.push(Span::raw("  Untracked threads: "));
+            spans.push(Span::styled(
+                untracked.to_string(),
+                ratatui::style::Style::default().fg(ratatui::style::Color::Red),
+            ));
+        }
     } else {
         spans.push(Span::raw("  "));
         spans.push(Span::styled(
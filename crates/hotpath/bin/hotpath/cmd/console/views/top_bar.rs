@@ -1,3 +1,5 @@
+use crate::cmd::console::widgets::formatters::format_time_ago;
+use hotpath::json::BlockingPollsJson;
 use ratatui::{
     layout::Rect,
     style::Stylize,
@@ -17,8 +19,9 @@ pub(crate) fn render_status_bar(
     last_successful_fetch: Option<Instant>,
     has_error: bool,
     has_data: bool,
+    blocking_polls: &BlockingPollsJson,
 ) {
-    let status_text = if is_paused {
+    let mut status_text = if is_paused {
         Line::from(vec!["⏸ ".yellow(), "PAUSED".yellow().bold()])
     } else if let Some(last_fetch) = last_successful_fetch {
         let elapsed = Instant::now().duration_since(last_fetch);
@@ -43,6 +46,24 @@ pub(crate) fn render_status_bar(
         Line::from(vec!["⋯ ".into(), "Connecting...".into()])
     };
 
+    if let Some(last) = blocking_polls.polls.first() {
+        status_text.push_span("   ⚠ ".red());
+        status_text.push_span(
+            format!(
+                "{} blocking polls, last {} in {} ({})",
+                blocking_polls.total,
+                hotpath::format_duration(last.duration_ns),
+                last.site,
+                format_time_ago(
+                    blocking_polls
+                        .current_elapsed_ns
+                        .saturating_sub(last.timestamp_ns)
+                ),
+            )
+            .red(),
+        );
+    }
+
     let block = Block::bordered()
         .title(" Status ")
         .border_set(border::PLAIN);
@@ -30,9 +30,9 @@ pub(crate) fn queue_status(
     _width: usize,
 ) -> Cell<'static> {
     let capacity = match channel_type {
-        ChannelType::Bounded(cap) => Some(*cap),
+        ChannelType::Bounded(cap) | ChannelType::Broadcast(cap) => Some(*cap),
         ChannelType::Oneshot => Some(1),
-        ChannelType::Unbounded => None,
+        ChannelType::Unbounded | ChannelType::Watch => None,
     };
 
     match capacity {
@@ -55,6 +55,16 @@ pub(crate) fn queue_status(
     }
 }
 
+/// Renders values as a row of block characters scaled to the largest one
+pub(crate) fn text_sparkline(values: &[u64]) -> String {
+    const BARS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+    let max = values.iter().copied().max().unwrap_or(0).max(1) as u128;
+    values
+        .iter()
+        .map(|&value| BARS[(value as u128 * 7 / max) as usize])
+        .collect()
+}
+
 pub(crate) fn format_delay(delay_ns: u64) -> String {
     if delay_ns < 1_000 {
         format!("{}ns", delay_ns)
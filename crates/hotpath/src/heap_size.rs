@@ -0,0 +1,229 @@
+//! Heap size estimation for messages, used by `channel!(..., measure_size = true)` and
+//! `stream!(..., measure_size = true)` to report the real memory held by queued items.
+
+use std::borrow::Cow;
+use std::collections::{BTreeMap, BTreeSet, BinaryHeap, HashMap, HashSet, LinkedList, VecDeque};
+use std::mem::size_of;
+use std::rc::Rc;
+use std::sync::Arc;
+
+/// Bytes a value owns on the heap, on top of its inline `size_of`.
+///
+/// Implementations are provided for primitives and common std types, and
+/// `#[derive(hotpath::HeapSize)]` sums the heap size of all fields of a struct or enum.
+/// Collections report their allocated capacity, not just their length.
+///
+/// Shared pointers (`Arc`, `Rc`) count their pointee for every handle, so values shared
+/// across messages are counted more than once.
+///
+/// # Examples
+///
+/// ```rust
+/// use hotpath::HeapSize;
+///
+/// let msg = String::with_capacity(64);
+/// assert_eq!(msg.heap_size(), 64);
+/// assert_eq!(msg.total_size(), 64 + std::mem::size_of::<String>());
+/// ```
+pub trait HeapSize {
+    /// Bytes allocated on the heap and owned by this value.
+    fn heap_size(&self) -> usize;
+
+    /// Inline size plus heap size.
+    fn total_size(&self) -> usize {
+        std::mem::size_of_val(self) + self.heap_size()
+    }
+}
+
+/// Total size of a message, passed to channel and stream wrappers when `measure_size = true`.
+#[doc(hidden)]
+pub fn heap_size_of<T: HeapSize>(value: &T) -> usize {
+    value.total_size()
+}
+
+macro_rules! impl_heap_size_zero {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl HeapSize for $ty {
+                #[inline]
+                fn heap_size(&self) -> usize {
+                    0
+                }
+            }
+        )*
+    };
+}
+
+impl_heap_size_zero!(
+    (),
+    bool,
+    char,
+    u8,
+    u16,
+    u32,
+    u64,
+    u128,
+    usize,
+    i8,
+    i16,
+    i32,
+    i64,
+    i128,
+    isize,
+    f32,
+    f64,
+    &'static str,
+    std::time::Duration,
+    std::time::Instant,
+    std::time::SystemTime,
+    std::net::IpAddr,
+    std::net::SocketAddr,
+);
+
+impl HeapSize for String {
+    fn heap_size(&self) -> usize {
+        self.capacity()
+    }
+}
+
+impl<T: HeapSize + ?Sized> HeapSize for Box<T> {
+    fn heap_size(&self) -> usize {
+        std::mem::size_of_val::<T>(self) + T::heap_size(self)
+    }
+}
+
+impl<T: HeapSize> HeapSize for [T] {
+    fn heap_size(&self) -> usize {
+        self.iter().map(HeapSize::heap_size).sum()
+    }
+}
+
+impl<T: HeapSize, const N: usize> HeapSize for [T; N] {
+    fn heap_size(&self) -> usize {
+        self.iter().map(HeapSize::heap_size).sum()
+    }
+}
+
+impl<T: HeapSize> HeapSize for Vec<T> {
+    fn heap_size(&self) -> usize {
+        self.capacity() * size_of::<T>() + self.iter().map(HeapSize::heap_size).sum::<usize>()
+    }
+}
+
+impl<T: HeapSize> HeapSize for VecDeque<T> {
+    fn heap_size(&self) -> usize {
+        self.capacity() * size_of::<T>() + self.iter().map(HeapSize::heap_size).sum::<usize>()
+    }
+}
+
+impl<T: HeapSize> HeapSize for BinaryHeap<T> {
+    fn heap_size(&self) -> usize {
+        self.capacity() * size_of::<T>() + self.iter().map(HeapSize::heap_size).sum::<usize>()
+    }
+}
+
+impl<T: HeapSize> HeapSize for LinkedList<T> {
+    fn heap_size(&self) -> usize {
+        // Every node holds the value and two links
+        self.len() * (size_of::<T>() + 2 * size_of::<usize>())
+            + self.iter().map(HeapSize::heap_size).sum::<usize>()
+    }
+}
+
+impl<K: HeapSize, V: HeapSize, S> HeapSize for HashMap<K, V, S> {
+    fn heap_size(&self) -> usize {
+        self.capacity() * size_of::<(K, V)>()
+            + self
+                .iter()
+                .map(|(k, v)| k.heap_size() + v.heap_size())
+                .sum::<usize>()
+    }
+}
+
+impl<T: HeapSize, S> HeapSize for HashSet<T, S> {
+    fn heap_size(&self) -> usize {
+        self.capacity() * size_of::<T>() + self.iter().map(HeapSize::heap_size).sum::<usize>()
+    }
+}
+
+impl<K: HeapSize, V: HeapSize> HeapSize for BTreeMap<K, V> {
+    fn heap_size(&self) -> usize {
+        // B-tree nodes are not exposed, approximate them with the stored entries
+        self.len() * size_of::<(K, V)>()
+            + self
+                .iter()
+                .map(|(k, v)| k.heap_size() + v.heap_size())
+                .sum::<usize>()
+    }
+}
+
+impl<T: HeapSize> HeapSize for BTreeSet<T> {
+    fn heap_size(&self) -> usize {
+        self.len() * size_of::<T>() + self.iter().map(HeapSize::heap_size).sum::<usize>()
+    }
+}
+
+impl<T: HeapSize + ?Sized> HeapSize for Arc<T> {
+    fn heap_size(&self) -> usize {
+        // Strong and weak counters precede the value
+        2 * size_of::<usize>() + std::mem::size_of_val::<T>(self) + T::heap_size(self)
+    }
+}
+
+impl<T: HeapSize + ?Sized> HeapSize for Rc<T> {
+    fn heap_size(&self) -> usize {
+        2 * size_of::<usize>() + std::mem::size_of_val::<T>(self) + T::heap_size(self)
+    }
+}
+
+impl<T: HeapSize + ToOwned + ?Sized> HeapSize for Cow<'_, T>
+where
+    T::Owned: HeapSize,
+{
+    fn heap_size(&self) -> usize {
+        match self {
+            Cow::Borrowed(_) => 0,
+            Cow::Owned(owned) => owned.heap_size(),
+        }
+    }
+}
+
+impl<T: HeapSize> HeapSize for Option<T> {
+    fn heap_size(&self) -> usize {
+        self.as_ref().map_or(0, HeapSize::heap_size)
+    }
+}
+
+impl<T: HeapSize, E: HeapSize> HeapSize for Result<T, E> {
+    fn heap_size(&self) -> usize {
+        match self {
+            Ok(value) => value.heap_size(),
+            Err(err) => err.heap_size(),
+        }
+    }
+}
+
+impl HeapSize for str {
+    fn heap_size(&self) -> usize {
+        0
+    }
+}
+
+macro_rules! impl_heap_size_tuple {
+    ($($name:ident),+) => {
+        impl<$($name: HeapSize),+> HeapSize for ($($name,)+) {
+            #[allow(non_snake_case)]
+            fn heap_size(&self) -> usize {
+                let ($($name,)+) = self;
+                0 $(+ $name.heap_size())+
+            }
+        }
+    };
+}
+
+impl_heap_size_tuple!(A);
+impl_heap_size_tuple!(A, B);
+impl_heap_size_tuple!(A, B, C);
+impl_heap_size_tuple!(A, B, C, D);
+impl_heap_size_tuple!(A, B, C, D, E);
+impl_heap_size_tuple!(A, B, C, D, E, F);
@@ -44,6 +44,8 @@ pub enum ChannelType {
     Bounded(usize),
     Unbounded,
     Oneshot,
+    Broadcast(usize),
+    Watch,
 }
 
 impl std::fmt::Display for ChannelType {
@@ -52,6 +54,8 @@ impl std::fmt::Display for ChannelType {
             ChannelType::Bounded(size) => write!(f, "bounded[{}]", size),
             ChannelType::Unbounded => write!(f, "unbounded"),
             ChannelType::Oneshot => write!(f, "oneshot"),
+            ChannelType::Broadcast(size) => write!(f, "broadcast[{}]", size),
+            ChannelType::Watch => write!(f, "watch"),
         }
     }
 }
@@ -75,12 +79,21 @@ impl<'de> Deserialize<'de> for ChannelType {
         match s.as_str() {
             "unbounded" => Ok(ChannelType::Unbounded),
             "oneshot" => Ok(ChannelType::Oneshot),
+            "watch" => Ok(ChannelType::Watch),
             _ => {
                 if let Some(inner) = s.strip_prefix("bounded[").and_then(|x| x.strip_suffix(']')) {
                     let size = inner
                         .parse()
                         .map_err(|_| serde::de::Error::custom("invalid bounded size"))?;
                     Ok(ChannelType::Bounded(size))
+                } else if let Some(inner) = s
+                    .strip_prefix("broadcast[")
+                    .and_then(|x| x.strip_suffix(']'))
+                {
+                    let size = inner
+                        .parse()
+                        .map_err(|_| serde::de::Error::custom("invalid broadcast size"))?;
+                    Ok(ChannelType::Broadcast(size))
                 } else {
                     Err(serde::de::Error::custom("invalid channel type"))
                 }
@@ -96,6 +109,9 @@ pub struct LogEntry {
     pub timestamp: u64,
     pub message: Option<String>,
     pub tid: Option<u64>,
+    /// Measured size in bytes, when instrumented with `measure_size = true`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub size: Option<u64>,
 }
 
 impl LogEntry {
@@ -105,8 +121,14 @@ impl LogEntry {
             timestamp,
             message,
             tid,
+            size: None,
         }
     }
+
+    pub fn with_size(mut self, size: Option<usize>) -> Self {
+        self.size = size.map(|size| size as u64);
+        self
+    }
 }
 
 /// Wrapper for channels-only JSON response.
@@ -134,6 +156,69 @@ pub struct SerializableChannelStats {
     pub type_size: usize,
     pub queued_bytes: u64,
     pub iter: u32,
+    /// Median time messages spent queued between send and receive
+    #[serde(default)]
+    pub queue_latency_p50_ns: Option<u64>,
+    #[serde(default)]
+    pub queue_latency_p95_ns: Option<u64>,
+    #[serde(default)]
+    pub queue_latency_p99_ns: Option<u64>,
+    /// Messages overwritten before a lagging receiver could read them, broadcast only
+    #[serde(default)]
+    pub lagged_count: Option<u64>,
+    /// Messages sent while no receiver was subscribed, broadcast only
+    #[serde(default)]
+    pub dropped_count: Option<u64>,
+    /// Per-receiver progress, broadcast only
+    #[serde(default)]
+    pub receivers: Option<Vec<BroadcastReceiverStats>>,
+    /// Time of the last value change in nanoseconds since start, watch only
+    #[serde(default)]
+    pub last_change_ns: Option<u64>,
+    /// Live receiver handles, multi-consumer channels (flume, async-channel, kanal) only
+    #[serde(default)]
+    pub receiver_count: Option<u64>,
+    /// Highest queue depth observed
+    #[serde(default)]
+    pub max_queued: u64,
+    /// Total time spent in the `full` state
+    #[serde(default)]
+    pub full_time_ns: u64,
+    /// Share of the channel lifetime spent in the `full` state, in percent
+    #[serde(default)]
+    pub full_time_percent: f64,
+    /// Number of times the channel became full
+    #[serde(default)]
+    pub full_transitions: u64,
+    /// Sends that waited for capacity on a full channel. Only measured for bounded channels
+    /// in inline mode, `None` in proxy mode where the caller's sender isn't wrapped.
+    #[serde(default)]
+    pub blocked_sends: Option<u64>,
+    /// Median time a blocked send waited for capacity, inline bounded channels only
+    #[serde(default)]
+    pub send_block_p50_ns: Option<u64>,
+    #[serde(default)]
+    pub send_block_p95_ns: Option<u64>,
+    #[serde(default)]
+    pub send_block_p99_ns: Option<u64>,
+    #[serde(default)]
+    pub send_block_max_ns: Option<u64>,
+    /// Messages sent per second over the most recent history samples, oldest first
+    #[serde(default)]
+    pub sent_rate_history: Vec<u64>,
+}
+
+/// Progress of a single broadcast receiver.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BroadcastReceiverStats {
+    pub id: u64,
+    pub received: u64,
+    pub lagged: u64,
+    /// Messages sent but not yet read by this receiver
+    pub lag: u64,
+    /// The receiver was dropped, `lag` is its final value
+    #[serde(default)]
+    pub dropped: bool,
 }
 
 /// Serializable log response containing sent and received logs for channels.
@@ -144,6 +229,25 @@ pub struct ChannelLogs {
     pub received_logs: Vec<LogEntry>,
 }
 
+/// Throughput and queue depth of a channel over one sampling interval.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChannelHistorySample {
+    /// End of the interval in nanoseconds since start
+    pub timestamp_ns: u64,
+    pub sent_per_sec: u64,
+    pub received_per_sec: u64,
+    /// Messages queued at the end of the interval
+    pub queued: u64,
+}
+
+/// Serializable history response containing throughput samples for a channel, oldest first.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChannelHistory {
+    pub id: String,
+    pub interval_ms: u64,
+    pub samples: Vec<ChannelHistorySample>,
+}
+
 /// Wrapper for streams-only JSON response.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StreamsJson {
@@ -164,7 +268,22 @@ pub struct SerializableStreamStats {
     pub items_yielded: u64,
     pub type_name: String,
     pub type_size: usize,
+    /// Total measured size of yielded items, present with `stream!(..., measure_size = true)`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub yielded_bytes: Option<u64>,
     pub iter: u32,
+    /// Time between consecutive items, `None` before the second item
+    pub inter_item_p50_ns: Option<u64>,
+    pub inter_item_p95_ns: Option<u64>,
+    pub inter_item_p99_ns: Option<u64>,
+    pub inter_item_max_ns: u64,
+    /// Polls that returned `Poll::Pending`
+    pub pending_polls: u64,
+    /// Time spent inside `poll_next`, summed over all polls
+    pub busy_ns: u64,
+    /// Time from creation of the stream until its first item and until it completed
+    pub time_to_first_item_ns: Option<u64>,
+    pub time_to_completion_ns: Option<u64>,
 }
 
 /// Serializable log response containing yielded logs for streams.
@@ -174,6 +293,49 @@ pub struct StreamLogs {
     pub logs: Vec<LogEntry>,
 }
 
+/// Wrapper for sinks-only JSON response.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SinksJson {
+    /// Current elapsed time since program start in nanoseconds
+    pub current_elapsed_ns: u64,
+    /// Sink statistics
+    pub sinks: Vec<SerializableSinkStats>,
+}
+
+/// Serializable version of sink statistics for JSON responses.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SerializableSinkStats {
+    pub id: u64,
+    pub source: String,
+    pub label: String,
+    pub has_custom_label: bool,
+    /// Active until `poll_close` completes
+    pub state: ChannelState,
+    pub items_sent: u64,
+    pub type_name: String,
+    pub type_size: usize,
+    pub iter: u32,
+    /// `poll_ready` calls that returned `Poll::Pending`, i.e. the sink applied backpressure
+    pub pending_ready_polls: u64,
+    /// Time from the first pending `poll_ready` until the sink became ready, summed
+    pub backpressure_ns: u64,
+    /// Completed `poll_flush` calls, with latency measured from the first poll of each flush
+    pub flushes: u64,
+    pub flush_p50_ns: Option<u64>,
+    pub flush_p95_ns: Option<u64>,
+    pub flush_p99_ns: Option<u64>,
+    pub flush_max_ns: u64,
+    /// Time spent in `poll_close`, present once the sink is closed
+    pub close_ns: Option<u64>,
+}
+
+/// Serializable log response containing sent logs for sinks.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SinkLogs {
+    pub id: String,
+    pub logs: Vec<LogEntry>,
+}
+
 /// State of an instrumented future.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
@@ -211,17 +373,37 @@ pub struct FutureCall {
     pub future_id: u64,
     pub state: FutureState,
     pub poll_count: u64,
+    /// Time spent inside `poll`, summed over all polls of this call
+    pub busy_ns: u64,
+    /// Longest single poll of this call
+    pub max_poll_ns: u64,
+    /// Times the waker handed to this call was woken
+    pub wake_count: u64,
     pub result: Option<String>,
+    /// Nanoseconds since start when the call was created
+    pub created_at_ns: u64,
+    /// Time from creation until the call completed or was dropped
+    pub lifetime_ns: Option<u64>,
+    /// Instrumented call that was being polled when this one was created
+    pub parent_future_id: Option<u64>,
+    pub parent_call_id: Option<u64>,
 }
 
 impl FutureCall {
-    pub fn new(id: u64, future_id: u64) -> Self {
+    pub fn new(id: u64, future_id: u64, created_at_ns: u64) -> Self {
         Self {
             id,
             future_id,
             state: FutureState::default(),
             poll_count: 0,
+            busy_ns: 0,
+            max_poll_ns: 0,
+            wake_count: 0,
             result: None,
+            created_at_ns,
+            lifetime_ns: None,
+            parent_future_id: None,
+            parent_call_id: None,
         }
     }
 }
@@ -242,6 +424,109 @@ pub struct SerializableFutureStats {
     pub has_custom_label: bool,
     pub call_count: u64,
     pub total_polls: u64,
+    /// Poll duration percentiles across all calls, `None` before the first poll
+    pub poll_p50_ns: Option<u64>,
+    pub poll_p95_ns: Option<u64>,
+    pub poll_p99_ns: Option<u64>,
+    pub poll_max_ns: u64,
+    /// Polls that exceeded the blocking poll threshold
+    pub blocking_polls: u64,
+    /// Wakes across all calls
+    pub total_wakes: u64,
+    /// Polls of a call that was not woken since its previous poll
+    pub spurious_polls: u64,
+    /// Scheduling delay between a wake and the next poll, `None` before the first wake
+    pub wake_to_poll_p50_ns: Option<u64>,
+    pub wake_to_poll_p95_ns: Option<u64>,
+    pub wake_to_poll_max_ns: u64,
+    /// Lifetime call outcomes, independent of the retained call logs
+    pub completed_count: u64,
+    pub cancelled_count: u64,
+    pub in_flight: u64,
+    /// Time from creation until ready of completed calls, `None` before the first completion
+    pub lifetime_p50_ns: Option<u64>,
+    pub lifetime_p95_ns: Option<u64>,
+    pub lifetime_p99_ns: Option<u64>,
+    pub lifetime_max_ns: u64,
+    /// Summed lifetime of completed and cancelled calls
+    pub total_lifetime_ns: u64,
+    /// Futures whose calls created calls of this one, in order of first appearance
+    pub parent_ids: Vec<u64>,
+    /// Time spent in calls of other futures created inside calls of this one
+    pub children: Vec<FutureChildStats>,
+}
+
+/// Finished calls of a child future created inside calls of its parent.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FutureChildStats {
+    pub future_id: u64,
+    pub calls: u64,
+    /// Summed lifetime of those calls, comparable to the parent's `total_lifetime_ns`
+    pub total_lifetime_ns: u64,
+}
+
+/// Kind of instrumented code a blocking poll was detected in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BlockingPollSource {
+    /// A `future!` or `#[future_fn]` instrumented future
+    Future,
+    /// An async function measured with `#[hotpath::measure]`
+    Function,
+}
+
+/// A single poll that took longer than the blocking poll threshold.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BlockingPoll {
+    /// Source location of the future or name of the measured function
+    pub site: String,
+    pub source: BlockingPollSource,
+    pub tid: u64,
+    pub duration_ns: u64,
+    pub threshold_ns: u64,
+    /// Nanoseconds since start when the poll returned
+    pub timestamp_ns: u64,
+}
+
+/// Recent blocking polls, most recent first.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BlockingPollsJson {
+    pub current_elapsed_ns: u64,
+    /// Global threshold from `HOTPATH_BLOCKING_POLL_MS`, futures can override it
+    pub threshold_ns: Option<u64>,
+    /// Blocking polls detected since start, including the ones no longer kept
+    pub total: u64,
+    pub polls: Vec<BlockingPoll>,
+}
+
+/// A future call that has not completed nor been dropped yet.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InFlightFuture {
+    pub future_id: u64,
+    pub call_id: u64,
+    pub label: String,
+    pub state: FutureState,
+    pub poll_count: u64,
+    /// Nanoseconds since start when the call was created
+    pub created_at_ns: u64,
+    pub age_ns: u64,
+    /// Nanoseconds since start of the most recent poll, `None` if never polled
+    pub last_poll_ns: Option<u64>,
+    /// Whether the call is older than the stuck future threshold
+    pub stuck: bool,
+}
+
+/// In-flight future calls, oldest first.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct InFlightFuturesJson {
+    pub current_elapsed_ns: u64,
+    /// Age threshold from `HOTPATH_STUCK_FUTURE_MS`, `None` when unset
+    pub threshold_ns: Option<u64>,
+    /// All in-flight calls, including the ones past `HOTPATH_LOGS_LIMIT` not listed
+    pub total: u64,
+    /// In-flight calls older than the threshold
+    pub stuck: u64,
+    pub calls: Vec<InFlightFuture>,
 }
 
 /// Serializable response for future calls.
@@ -321,6 +606,228 @@ pub struct ThreadsJson {
     /// Process RSS (Resident Set Size) in bytes
     #[serde(skip_serializing_if = "Option::is_none")]
     pub rss_bytes: Option<u64>,
+    /// Number of threads whose allocations could not be tracked (only with hotpath-alloc)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub untracked_threads: Option<u64>,
+}
+
+/// Kind of an instrumented lock.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LockType {
+    Mutex,
+    RwLock,
+}
+
+/// Library providing an instrumented lock.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LockBackend {
+    Std,
+    ParkingLot,
+    Tokio,
+}
+
+impl std::fmt::Display for LockType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LockType::Mutex => write!(f, "Mutex"),
+            LockType::RwLock => write!(f, "RwLock"),
+        }
+    }
+}
+
+impl std::fmt::Display for LockBackend {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LockBackend::Std => write!(f, "std"),
+            LockBackend::ParkingLot => write!(f, "parking_lot"),
+            LockBackend::Tokio => write!(f, "tokio"),
+        }
+    }
+}
+
+/// JSON response structure for /locks endpoint.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LocksJson {
+    /// Current elapsed time since program start in nanoseconds
+    pub current_elapsed_ns: u64,
+    /// Lock statistics
+    pub locks: Vec<SerializableLockStats>,
+}
+
+/// Serializable version of lock statistics for JSON responses.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SerializableLockStats {
+    pub id: u64,
+    pub source: String,
+    pub label: String,
+    pub has_custom_label: bool,
+    pub lock_type: LockType,
+    pub backend: LockBackend,
+    pub type_name: String,
+    pub iter: u32,
+    /// Successful acquisitions, including shared (read) ones
+    pub acquisitions: u64,
+    /// Shared (read) acquisitions, RwLock only
+    pub read_acquisitions: u64,
+    /// Acquisitions that found the lock already held and had to wait
+    pub contentions: u64,
+    pub wait_total_ns: u64,
+    pub wait_max_ns: u64,
+    pub wait_p50_ns: Option<u64>,
+    pub wait_p95_ns: Option<u64>,
+    pub wait_p99_ns: Option<u64>,
+    /// Time between acquiring and releasing, summed over released guards
+    pub hold_total_ns: u64,
+    pub hold_max_ns: u64,
+    pub hold_p95_ns: Option<u64>,
+    /// OS thread ID of the current exclusive holder, if the lock is held
+    pub holder_tid: Option<u64>,
+    /// Currently held shared (read) guards, RwLock only
+    pub readers: u64,
+}
+
+/// Runtime API a task was spawned with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TaskKind {
+    /// `tokio::spawn`
+    Tokio,
+    /// `tokio::task::spawn_blocking`
+    TokioBlocking,
+    /// `smol::spawn`
+    Smol,
+}
+
+impl std::fmt::Display for TaskKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TaskKind::Tokio => write!(f, "tokio"),
+            TaskKind::TokioBlocking => write!(f, "tokio blocking"),
+            TaskKind::Smol => write!(f, "smol"),
+        }
+    }
+}
+
+/// Lifecycle state of a spawned task.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TaskState {
+    /// Waiting to be polled
+    #[default]
+    Idle,
+    /// Currently inside `poll`, or running a blocking closure
+    Running,
+    Completed,
+    /// Dropped before completing, by `JoinHandle::abort` or runtime shutdown
+    Aborted,
+    Panicked,
+}
+
+impl TaskState {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TaskState::Idle => "idle",
+            TaskState::Running => "running",
+            TaskState::Completed => "completed",
+            TaskState::Aborted => "aborted",
+            TaskState::Panicked => "panicked",
+        }
+    }
+
+    /// Whether the task will not be polled anymore
+    pub fn is_finished(&self) -> bool {
+        matches!(
+            self,
+            TaskState::Completed | TaskState::Aborted | TaskState::Panicked
+        )
+    }
+}
+
+impl std::fmt::Display for TaskState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// JSON response structure for /tasks endpoint.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TasksJson {
+    /// Current elapsed time since program start in nanoseconds
+    pub current_elapsed_ns: u64,
+    /// Tasks spawned so far, including ones no longer listed
+    pub total_spawned: u64,
+    /// Tasks that have not finished yet
+    pub live: u64,
+    /// Every live task, followed by the most recently finished ones
+    pub tasks: Vec<SerializableTaskStats>,
+}
+
+/// Serializable version of a task registry entry for JSON responses.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SerializableTaskStats {
+    pub id: u64,
+    /// Spawn location
+    pub source: String,
+    pub label: String,
+    pub has_custom_label: bool,
+    pub kind: TaskKind,
+    /// Instrumented task that was running when this one was spawned
+    pub parent_id: Option<u64>,
+    pub state: TaskState,
+    pub spawned_at_ns: u64,
+    pub finished_at_ns: Option<u64>,
+    pub poll_count: u64,
+    /// Time spent inside `poll`, or running the closure for blocking tasks
+    pub busy_ns: u64,
+    pub max_poll_ns: u64,
+    pub panic_message: Option<String>,
+}
+
+/// A single sample of tokio runtime metrics.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RuntimeSample {
+    /// Nanoseconds since start when the sample was taken
+    pub timestamp_ns: u64,
+    pub num_workers: usize,
+    pub num_alive_tasks: usize,
+    /// Tasks waiting in the runtime's global injection queue
+    pub global_queue_depth: usize,
+    /// Only available when the profiled program is built with `--cfg tokio_unstable`
+    pub num_blocking_threads: Option<usize>,
+    /// Times each worker thread has parked since the runtime started
+    pub worker_park_counts: Vec<u64>,
+}
+
+/// JSON response structure for /runtime endpoint.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RuntimeJson {
+    /// Current elapsed time since program start in nanoseconds
+    pub current_elapsed_ns: u64,
+    /// Whether a runtime handle was registered with `hotpath::runtime::register_handle`
+    pub registered: bool,
+    /// "current_thread" or "multi_thread"
+    pub flavor: Option<String>,
+    pub sample_interval_ms: u64,
+    /// Most recent samples, oldest first
+    pub samples: Vec<RuntimeSample>,
+}
+
+/// Tokio runtime summary included in the exit report.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RuntimeSummaryJson {
+    pub flavor: String,
+    pub num_workers: usize,
+    pub samples: u64,
+    pub num_alive_tasks: usize,
+    pub peak_alive_tasks: usize,
+    pub global_queue_depth: usize,
+    pub peak_global_queue_depth: usize,
+    pub num_blocking_threads: Option<usize>,
+    pub peak_blocking_threads: Option<usize>,
+    /// Parks across all worker threads
+    pub total_parks: u64,
 }
 
 /// HTTP routes for the hotpath metrics server.
@@ -338,14 +845,30 @@ pub enum Route {
     Futures,
     /// GET /threads - Returns thread metrics
     Threads,
+    /// GET /locks - Returns all lock statistics
+    Locks,
+    /// GET /blocking_polls - Returns recent polls exceeding the blocking threshold
+    BlockingPolls,
+    /// GET /in_flight_futures - Returns the oldest in-flight future calls
+    InFlightFutures,
+    /// GET /tasks - Returns live and recently finished spawned tasks
+    Tasks,
+    /// GET /runtime - Returns sampled tokio runtime metrics
+    Runtime,
+    /// GET /sinks - Returns all sink statistics
+    Sinks,
     /// GET /functions_timing/{base64_name}/logs - Returns timing logs for a function
     FunctionTimingLogs { function_name: String },
     /// GET /functions_alloc/{base64_name}/logs - Returns allocation logs for a function
     FunctionAllocLogs { function_name: String },
     /// GET /channels/{id}/logs - Returns logs for a specific channel
     ChannelLogs { channel_id: u64 },
+    /// GET /channels/{id}/history - Returns throughput history for a specific channel
+    ChannelHistory { channel_id: u64 },
     /// GET /streams/{id}/logs - Returns logs for a specific stream
     StreamLogs { stream_id: u64 },
+    /// GET /sinks/{id}/logs - Returns logs for a specific sink
+    SinkLogs { sink_id: u64 },
     /// GET /futures/{id}/calls - Returns calls for a specific future
     FutureCalls { future_id: u64 },
 }
@@ -361,6 +884,12 @@ impl Route {
             Route::Streams => "/streams".to_string(),
             Route::Futures => "/futures".to_string(),
             Route::Threads => "/threads".to_string(),
+            Route::Locks => "/locks".to_string(),
+            Route::BlockingPolls => "/blocking_polls".to_string(),
+            Route::InFlightFutures => "/in_flight_futures".to_string(),
+            Route::Tasks => "/tasks".to_string(),
+            Route::Runtime => "/runtime".to_string(),
+            Route::Sinks => "/sinks".to_string(),
             Route::FunctionTimingLogs { function_name } => {
                 let encoded =
                     base64::engine::general_purpose::STANDARD.encode(function_name.as_bytes());
@@ -372,7 +901,9 @@ impl Route {
                 format!("/functions_alloc/{}/logs", encoded)
             }
             Route::ChannelLogs { channel_id } => format!("/channels/{}/logs", channel_id),
+            Route::ChannelHistory { channel_id } => format!("/channels/{}/history", channel_id),
             Route::StreamLogs { stream_id } => format!("/streams/{}/logs", stream_id),
+            Route::SinkLogs { sink_id } => format!("/sinks/{}/logs", sink_id),
             Route::FutureCalls { future_id } => format!("/futures/{}/calls", future_id),
         }
     }
@@ -385,8 +916,12 @@ impl Route {
 
 static RE_CHANNEL_LOGS: LazyLock<Regex> =
     LazyLock::new(|| Regex::new(r"^/channels/(\d+)/logs$").unwrap());
+static RE_CHANNEL_HISTORY: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^/channels/(\d+)/history$").unwrap());
 static RE_STREAM_LOGS: LazyLock<Regex> =
     LazyLock::new(|| Regex::new(r"^/streams/(\d+)/logs$").unwrap());
+static RE_SINK_LOGS: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^/sinks/(\d+)/logs$").unwrap());
 static RE_FUTURE_CALLS: LazyLock<Regex> =
     LazyLock::new(|| Regex::new(r"^/futures/(\d+)/calls$").unwrap());
 static RE_FUNCTION_LOGS_TIMING: LazyLock<Regex> =
@@ -417,6 +952,12 @@ impl FromStr for Route {
             "/streams" => return Ok(Route::Streams),
             "/futures" => return Ok(Route::Futures),
             "/threads" => return Ok(Route::Threads),
+            "/locks" => return Ok(Route::Locks),
+            "/blocking_polls" => return Ok(Route::BlockingPolls),
+            "/in_flight_futures" => return Ok(Route::InFlightFutures),
+            "/tasks" => return Ok(Route::Tasks),
+            "/runtime" => return Ok(Route::Runtime),
+            "/sinks" => return Ok(Route::Sinks),
             _ => {}
         }
 
@@ -435,11 +976,21 @@ impl FromStr for Route {
             return Ok(Route::ChannelLogs { channel_id });
         }
 
+        if let Some(caps) = RE_CHANNEL_HISTORY.captures(path) {
+            let channel_id = caps[1].parse().map_err(|_| ())?;
+            return Ok(Route::ChannelHistory { channel_id });
+        }
+
         if let Some(caps) = RE_STREAM_LOGS.captures(path) {
             let stream_id = caps[1].parse().map_err(|_| ())?;
             return Ok(Route::StreamLogs { stream_id });
         }
 
+        if let Some(caps) = RE_SINK_LOGS.captures(path) {
+            let sink_id = caps[1].parse().map_err(|_| ())?;
+            return Ok(Route::SinkLogs { sink_id });
+        }
+
         if let Some(caps) = RE_FUTURE_CALLS.captures(path) {
             let future_id = caps[1].parse().map_err(|_| ())?;
             return Ok(Route::FutureCalls { future_id });
@@ -1,4 +1,4 @@
-//! hotpath-rs is a simple async Rust profiler. It instruments functions, channels, futures, and streams to quickly find bottlenecks and focus optimizations where they matter most.
+//! hotpath-rs is a simple async Rust profiler. It instruments functions, channels, futures, streams, and locks to quickly find bottlenecks and focus optimizations where they matter most.
 //! It can provide actionable insights into time, memory, and data flow with minimal setup.
 //! ## Setup & Usage
 //! For a complete setup guide, examples, and advanced configuration, visit
@@ -21,18 +21,39 @@ pub enum Format {
     JsonPretty,
 }
 
+mod heap_size;
+#[doc(hidden)]
+pub use heap_size::heap_size_of;
+pub use heap_size::HeapSize;
+/// Derives [`HeapSize`] by summing the heap size of every field.
+pub use hotpath_macros::HeapSize;
+
 #[cfg(all(feature = "hotpath", not(feature = "hotpath-off")))]
 #[doc(inline)]
 pub use lib_on::*;
 #[cfg(all(feature = "hotpath", not(feature = "hotpath-off")))]
 mod lib_on;
 
+#[cfg(all(feature = "hotpath", not(feature = "hotpath-off")))]
+pub use lib_on::blocking;
 #[cfg(all(feature = "hotpath", not(feature = "hotpath-off")))]
 pub use lib_on::channels;
 #[cfg(all(feature = "hotpath", not(feature = "hotpath-off")))]
 pub use lib_on::futures;
 #[cfg(all(feature = "hotpath", not(feature = "hotpath-off")))]
+pub use lib_on::locks;
+#[cfg(all(
+    feature = "hotpath",
+    not(feature = "hotpath-off"),
+    feature = "tokio-runtime-metrics"
+))]
+pub use lib_on::runtime;
+#[cfg(all(feature = "hotpath", not(feature = "hotpath-off")))]
+pub use lib_on::sinks;
+#[cfg(all(feature = "hotpath", not(feature = "hotpath-off")))]
 pub use lib_on::streams;
+#[cfg(all(feature = "hotpath", not(feature = "hotpath-off")))]
+pub use lib_on::tasks;
 #[cfg(all(feature = "hotpath", not(feature = "hotpath-off"), feature = "threads"))]
 pub use lib_on::threads;
 
@@ -41,8 +62,9 @@ pub(crate) mod output;
 #[cfg(any(feature = "hotpath", feature = "ci", feature = "tui"))]
 pub use output::{
     ceil_char_boundary, floor_char_boundary, format_bytes, format_duration, shorten_function_name,
-    truncate_result, FunctionLogsJson, FunctionsDataJson, FunctionsJson, MetricType,
-    MetricsProvider, ProfilingMode, Reporter, MAX_RESULT_LEN,
+    truncate_result, CallSiteEntry, CallSitesJson, DepthOverflowEntry, FunctionLogsJson,
+    FunctionsDataJson, FunctionsJson, LiveAllocEntry, LiveAllocsJson, MetricType, MetricsProvider,
+    ProfilingMode, Reporter, MAX_RESULT_LEN,
 };
 
 #[cfg(all(feature = "hotpath", not(feature = "hotpath-off")))]
@@ -74,4 +96,13 @@ pub use lib_off::channels;
 #[cfg(any(not(feature = "hotpath"), feature = "hotpath-off"))]
 pub use lib_off::futures;
 #[cfg(any(not(feature = "hotpath"), feature = "hotpath-off"))]
+pub use lib_off::locks;
+#[cfg(all(
+    any(not(feature = "hotpath"), feature = "hotpath-off"),
+    feature = "tokio-runtime-metrics"
+))]
+pub use lib_off::runtime;
+#[cfg(any(not(feature = "hotpath"), feature = "hotpath-off"))]
+pub use lib_off::sinks;
+#[cfg(any(not(feature = "hotpath"), feature = "hotpath-off"))]
 pub use lib_off::streams;
@@ -57,6 +57,9 @@ macro_rules! channel {
     ($expr:expr, log = true, capacity = $capacity:expr, label = $label:expr) => {
         $expr
     };
+    ($expr:expr, $($rest:tt)+) => {
+        $expr
+    };
 }
 
 #[macro_export]
@@ -76,6 +79,19 @@ macro_rules! stream {
     ($expr:expr, log = true, label = $label:expr) => {
         $expr
     };
+    ($expr:expr, $($rest:tt)+) => {
+        $expr
+    };
+}
+
+#[macro_export]
+macro_rules! sink {
+    ($expr:expr) => {
+        $expr
+    };
+    ($expr:expr, $($rest:tt)+) => {
+        $expr
+    };
 }
 
 #[macro_export]
@@ -86,6 +102,57 @@ macro_rules! future {
     ($fut:expr, log = true) => {
         $fut
     };
+    ($fut:expr, $($rest:tt)+) => {
+        $fut
+    };
+}
+
+#[macro_export]
+macro_rules! mutex {
+    ($expr:expr) => {
+        $expr
+    };
+    ($expr:expr, label = $label:expr) => {
+        $expr
+    };
+}
+
+#[macro_export]
+macro_rules! rwlock {
+    ($expr:expr) => {
+        $expr
+    };
+    ($expr:expr, label = $label:expr) => {
+        $expr
+    };
+}
+
+#[macro_export]
+macro_rules! spawn {
+    ($fut:expr) => {
+        ::tokio::spawn($fut)
+    };
+    ($fut:expr, label = $label:expr) => {
+        ::tokio::spawn($fut)
+    };
+    ($f:expr, blocking = true) => {
+        ::tokio::task::spawn_blocking($f)
+    };
+    ($f:expr, label = $label:expr, blocking = true) => {
+        ::tokio::task::spawn_blocking($f)
+    };
+    ($f:expr, blocking = true, label = $label:expr) => {
+        ::tokio::task::spawn_blocking($f)
+    };
+    ($fut:expr, runtime = "smol") => {
+        ::smol::spawn($fut)
+    };
+    ($fut:expr, label = $label:expr, runtime = "smol") => {
+        ::smol::spawn($fut)
+    };
+    ($fut:expr, runtime = "smol", label = $label:expr) => {
+        ::smol::spawn($fut)
+    };
 }
 
 pub use crate::Format;
@@ -128,6 +195,13 @@ pub fn measure_with_log<T: std::fmt::Debug, F: FnOnce() -> T>(
     f()
 }
 
+pub async fn measure_async<T, Fut>(_name: &'static str, fut: Fut) -> T
+where
+    Fut: std::future::Future<Output = T>,
+{
+    fut.await
+}
+
 pub async fn measure_with_log_async<T: std::fmt::Debug, F, Fut>(_name: &'static str, f: F) -> T
 where
     F: FnOnce() -> Fut,
@@ -284,6 +358,96 @@ pub mod streams {
     }
 }
 
+pub mod sinks {
+    use super::Format;
+
+    pub struct SinksGuardBuilder;
+
+    impl SinksGuardBuilder {
+        pub fn new() -> Self {
+            Self
+        }
+        pub fn format(self, _format: Format) -> Self {
+            self
+        }
+        pub fn build(self) -> SinksGuard {
+            SinksGuard
+        }
+    }
+
+    impl Default for SinksGuardBuilder {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+
+    pub struct SinksGuard;
+
+    impl SinksGuard {
+        pub fn new() -> Self {
+            Self
+        }
+        pub fn format(self, _format: Format) -> Self {
+            self
+        }
+    }
+
+    impl Default for SinksGuard {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+
+    impl Drop for SinksGuard {
+        fn drop(&mut self) {}
+    }
+}
+
+pub mod locks {
+    use super::Format;
+
+    pub struct LocksGuardBuilder;
+
+    impl LocksGuardBuilder {
+        pub fn new() -> Self {
+            Self
+        }
+        pub fn format(self, _format: Format) -> Self {
+            self
+        }
+        pub fn build(self) -> LocksGuard {
+            LocksGuard
+        }
+    }
+
+    impl Default for LocksGuardBuilder {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+
+    pub struct LocksGuard;
+
+    impl LocksGuard {
+        pub fn new() -> Self {
+            Self
+        }
+        pub fn format(self, _format: Format) -> Self {
+            self
+        }
+    }
+
+    impl Default for LocksGuard {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+
+    impl Drop for LocksGuard {
+        fn drop(&mut self) {}
+    }
+}
+
 pub mod futures {
     use super::Format;
 
@@ -328,3 +492,8 @@ pub mod futures {
         fn drop(&mut self) {}
     }
 }
+
+#[cfg(feature = "tokio-runtime-metrics")]
+pub mod runtime {
+    pub fn register_handle(_handle: &tokio::runtime::Handle) {}
+}
@@ -2,21 +2,32 @@
 pub use cfg_if::cfg_if;
 pub use hotpath_macros::{future_fn, main, measure, measure_all, skip};
 
+pub mod blocking;
 pub mod channels;
 pub mod futures;
+pub mod locks;
+#[cfg(feature = "tokio-runtime-metrics")]
+pub mod runtime;
+pub mod sinks;
 pub mod streams;
+pub mod tasks;
 #[cfg(feature = "threads")]
 pub mod threads;
 
 pub mod functions;
 
-pub use channels::{InstrumentChannel, InstrumentChannelLog};
+pub use channels::{
+    InstrumentChannel, InstrumentChannelInline, InstrumentChannelInlineLog,
+    InstrumentChannelInlineOptions, InstrumentChannelLog, InstrumentChannelOptions,
+};
 pub use futures::{InstrumentFuture, InstrumentFutureLog};
-pub use streams::{InstrumentStream, InstrumentStreamLog};
+pub use locks::{InstrumentMutex, InstrumentRwLock};
+pub use sinks::InstrumentSink;
+pub use streams::{InstrumentStream, InstrumentStreamLog, InstrumentStreamOptions};
 
 pub use functions::guard::{FunctionsGuard, FunctionsGuardBuilder};
 pub use functions::{
-    measure_with_log, measure_with_log_async, FunctionStats, MeasurementGuard,
+    measure_async, measure_with_log, measure_with_log_async, FunctionStats, MeasurementGuard,
     MeasurementGuardWithLog,
 };
 
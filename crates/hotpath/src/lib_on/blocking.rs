@@ -0,0 +1,140 @@
+//! Blocking poll detection - flags polls of instrumented futures and measured async
+//! functions that run longer than a threshold and stall the executor thread.
+
+use crate::channels::{get_log_limit, START_TIME};
+use crate::output::format_duration;
+use pin_project_lite::pin_project;
+use std::collections::VecDeque;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{LazyLock, Mutex};
+use std::task::{Context, Poll};
+
+#[cfg(target_os = "linux")]
+use quanta::Instant;
+
+#[cfg(not(target_os = "linux"))]
+use std::time::Instant;
+
+pub use crate::json::{BlockingPoll, BlockingPollSource, BlockingPollsJson};
+
+/// Global threshold from `HOTPATH_BLOCKING_POLL_MS`, detection is off when unset.
+pub(crate) static BLOCKING_POLL_THRESHOLD_NS: LazyLock<Option<u64>> = LazyLock::new(|| {
+    std::env::var("HOTPATH_BLOCKING_POLL_MS")
+        .ok()
+        .and_then(|ms| ms.parse::<u64>().ok())
+        .filter(|&ms| ms > 0)
+        .map(|ms| ms.saturating_mul(1_000_000))
+});
+
+static BLOCKING_POLL_WARN: LazyLock<bool> = LazyLock::new(|| {
+    std::env::var("HOTPATH_BLOCKING_POLL_WARN")
+        .map(|v| v.eq_ignore_ascii_case("true") || v == "1")
+        .unwrap_or(false)
+});
+
+static BLOCKING_POLLS: Mutex<VecDeque<BlockingPoll>> = Mutex::new(VecDeque::new());
+static BLOCKING_POLLS_TOTAL: AtomicU64 = AtomicU64::new(0);
+
+/// Records the poll if it exceeded the threshold. Returns whether it did.
+pub(crate) fn check_poll(
+    site: &'static str,
+    source: BlockingPollSource,
+    duration_ns: u64,
+    threshold_ns: Option<u64>,
+) -> bool {
+    let Some(threshold_ns) = threshold_ns else {
+        return false;
+    };
+    if duration_ns <= threshold_ns {
+        return false;
+    }
+
+    let tid = crate::tid::current_tid();
+    if *BLOCKING_POLL_WARN {
+        eprintln!(
+            "[hotpath] Blocking poll in {site}: {} on thread {tid} (threshold {})",
+            format_duration(duration_ns),
+            format_duration(threshold_ns),
+        );
+    }
+
+    BLOCKING_POLLS_TOTAL.fetch_add(1, Ordering::Relaxed);
+    let timestamp_ns = START_TIME
+        .get()
+        .map(|t| t.elapsed().as_nanos() as u64)
+        .unwrap_or(0);
+    let mut polls = BLOCKING_POLLS.lock().unwrap();
+    if polls.len() >= get_log_limit() {
+        polls.pop_front();
+    }
+    polls.push_back(BlockingPoll {
+        site: site.to_string(),
+        source,
+        tid,
+        duration_ns,
+        threshold_ns,
+        timestamp_ns,
+    });
+    true
+}
+
+pub fn get_blocking_polls_json() -> BlockingPollsJson {
+    let current_elapsed_ns = START_TIME
+        .get()
+        .map(|t| t.elapsed().as_nanos() as u64)
+        .unwrap_or(0);
+
+    BlockingPollsJson {
+        current_elapsed_ns,
+        threshold_ns: *BLOCKING_POLL_THRESHOLD_NS,
+        total: BLOCKING_POLLS_TOTAL.load(Ordering::Relaxed),
+        polls: BLOCKING_POLLS
+            .lock()
+            .unwrap()
+            .iter()
+            .rev()
+            .cloned()
+            .collect(),
+    }
+}
+
+pin_project! {
+    /// Times every poll of a measured async function against the global threshold.
+    pub(crate) struct DetectBlockingPolls<F> {
+        #[pin]
+        inner: F,
+        name: &'static str,
+        threshold_ns: u64,
+    }
+}
+
+// Alloc mode times polls inside `MeasuredFuture` instead
+#[cfg_attr(feature = "hotpath-alloc", allow(dead_code))]
+impl<F: Future> DetectBlockingPolls<F> {
+    pub(crate) fn new(name: &'static str, inner: F, threshold_ns: u64) -> Self {
+        Self {
+            inner,
+            name,
+            threshold_ns,
+        }
+    }
+}
+
+impl<F: Future> Future for DetectBlockingPolls<F> {
+    type Output = F::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        let start = Instant::now();
+        let result = this.inner.poll(cx);
+        check_poll(
+            this.name,
+            BlockingPollSource::Function,
+            start.elapsed().as_nanos() as u64,
+            Some(*this.threshold_ns),
+        );
+        result
+    }
+}
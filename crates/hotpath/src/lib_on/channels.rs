@@ -1,7 +1,8 @@
 //! Channel instrumentation module - tracks message flow, queue sizes, and channel state.
 
-use crossbeam_channel::{unbounded, Sender as CbSender};
-use std::collections::{HashMap, VecDeque};
+use crossbeam_channel::{unbounded, RecvTimeoutError, Sender as CbSender};
+use hdrhistogram::Histogram;
+use std::collections::{BTreeMap, HashMap, VecDeque};
 use std::sync::atomic::AtomicU64;
 use std::sync::{Arc, OnceLock, RwLock};
 
@@ -14,13 +15,18 @@ use std::time::Instant;
 pub mod guard;
 pub use guard::{ChannelsGuard, ChannelsGuardBuilder};
 
+pub mod inline;
+mod message_log;
 mod wrapper;
 
+pub use message_log::MessageLog;
+pub(crate) use message_log::{redact_log, LogRecord};
+
 pub use crate::json::{
-    ChannelLogs, ChannelState, ChannelType, ChannelsJson, LogEntry, SerializableChannelStats,
+    BroadcastReceiverStats, ChannelHistory, ChannelHistorySample, ChannelLogs, ChannelState,
+    ChannelType, ChannelsJson, LogEntry, SerializableChannelStats,
 };
 use crate::metrics_server::METRICS_SERVER_PORT;
-use crate::output::truncate_result;
 
 pub use crate::Format;
 
@@ -44,17 +50,306 @@ pub(crate) struct ChannelStats {
     pub(crate) sent_logs: VecDeque<LogEntry>,
     pub(crate) received_logs: VecDeque<LogEntry>,
     pub(crate) iter: u32,
+    /// Messages that were sent but not received yet, oldest first
+    pub(crate) pending_sends: VecDeque<PendingSend>,
+    /// Total measured size of `pending_sends`
+    pending_bytes: u64,
+    /// Messages report their own size, see `channel!(..., measure_size = true)`
+    pub(crate) measures_size: bool,
+    /// Time messages spent in the queue between send and receive
+    pub(crate) queue_latency: Histogram<u64>,
+    /// Receive timestamps that arrived before their matching send event, inline channels only
+    pub(crate) early_receives: VecDeque<u64>,
+    /// Instrumented without a proxy, see `channel!(..., mode = "inline")`
+    pub(crate) inline: bool,
+    /// Messages overwritten before a receiver read them, broadcast only
+    pub(crate) lagged_count: u64,
+    /// Sends that found no subscribed receiver, broadcast only
+    pub(crate) dropped_count: u64,
+    /// Broadcast receivers by receiver id, including recently dropped ones
+    pub(crate) receivers: BTreeMap<u64, ReceiverStats>,
+    /// Timestamp (ns since start) of the last value change, watch only
+    pub(crate) last_change_ns: Option<u64>,
+    /// Live receiver handles of a multi-consumer channel, sampled as messages are forwarded
+    pub(crate) receiver_count: Option<usize>,
+    /// Per-interval throughput samples, oldest first
+    pub(crate) history: VecDeque<ChannelHistorySample>,
+    /// Counters and timestamp (ns since start) at the previous history sample
+    history_mark: (u64, u64, u64),
+    /// Highest queue depth observed
+    pub(crate) max_queued: u64,
+    /// Time spent in the `Full` state, excluding the current full period
+    full_total_ns: u64,
+    /// Start (ns since start) of the current full period
+    full_since_ns: Option<u64>,
+    /// Number of times the channel became full
+    pub(crate) full_transitions: u64,
+    created_ns: u64,
+    /// Time (ns since start) the channel was closed or notified
+    finished_ns: Option<u64>,
+    /// Sends that had to wait for capacity, inline bounded channels only
+    pub(crate) blocked_sends: u64,
+    /// Time blocked senders waited for capacity
+    pub(crate) send_block: Histogram<u64>,
+}
+
+/// A message waiting in the queue.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct PendingSend {
+    /// Send timestamp in nanoseconds since start
+    sent_ns: u64,
+    /// Measured message size in bytes, 0 unless the channel measures sizes
+    size: u64,
+}
+
+/// Progress of a single broadcast receiver.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct ReceiverStats {
+    /// Channel `sent_count` when the receiver subscribed
+    subscribed_at: u64,
+    received: u64,
+    lagged: u64,
+    /// Lag when the receiver was dropped, `None` while it is alive
+    final_lag: Option<u64>,
 }
 
 impl ChannelStats {
+    const LOW_NS: u64 = 1;
+    const HIGH_NS: u64 = 3_600_000_000_000; // 1h
+    const SIGFIGS: u8 = 2;
+
     pub fn queued(&self) -> u64 {
-        self.sent_count
-            .saturating_sub(self.received_count)
-            .saturating_sub(1)
+        match self.channel_type {
+            // Every receiver reads its own copy, the queue is as long as the slowest one
+            ChannelType::Broadcast(_) => {
+                return self
+                    .receivers
+                    .values()
+                    .filter(|receiver| receiver.final_lag.is_none())
+                    .map(|receiver| self.receiver_lag(receiver))
+                    .max()
+                    .unwrap_or(0);
+            }
+            ChannelType::Watch => return 0,
+            _ => {}
+        }
+
+        let queued = self.sent_count.saturating_sub(self.received_count);
+        if self.inline {
+            queued
+        } else {
+            // The proxy holds one extra message in flight
+            queued.saturating_sub(1)
+        }
     }
 
     pub fn queued_bytes(&self) -> u64 {
-        self.queued() * self.type_size as u64
+        if !self.measures_size {
+            return self.queued() * self.type_size as u64;
+        }
+        // The oldest pending messages may already sit in the proxy and are not queued
+        let in_flight = self
+            .pending_sends
+            .len()
+            .saturating_sub(self.queued() as usize);
+        let in_flight_bytes: u64 = self
+            .pending_sends
+            .iter()
+            .take(in_flight)
+            .map(|pending| pending.size)
+            .sum();
+        self.pending_bytes.saturating_sub(in_flight_bytes)
+    }
+
+    /// Queue latency percentile in nanoseconds, `None` until a message was received.
+    pub fn queue_latency_percentile(&self, p: f64) -> Option<u64> {
+        if self.queue_latency.is_empty() {
+            return None;
+        }
+        Some(self.queue_latency.value_at_quantile(p / 100.0))
+    }
+
+    /// Send blocking time percentile in nanoseconds, `None` until a send had to wait.
+    pub fn send_block_percentile(&self, p: f64) -> Option<u64> {
+        if self.send_block.is_empty() {
+            return None;
+        }
+        Some(self.send_block.value_at_quantile(p / 100.0))
+    }
+
+    /// Lag of each broadcast receiver, in receiver id order.
+    pub(crate) fn receiver_lags(&self) -> impl Iterator<Item = u64> + '_ {
+        self.receivers
+            .values()
+            .map(|receiver| self.receiver_lag(receiver))
+    }
+
+    fn receiver_lag(&self, receiver: &ReceiverStats) -> u64 {
+        if let Some(lag) = receiver.final_lag {
+            return lag;
+        }
+        let lag = self
+            .sent_count
+            .saturating_sub(receiver.subscribed_at)
+            .saturating_sub(receiver.received + receiver.lagged);
+        match self.channel_type {
+            // Older messages are overwritten, so a receiver can't fall further behind
+            ChannelType::Broadcast(cap) => lag.min(cap as u64),
+            _ => lag,
+        }
+    }
+
+    /// Freezes the lag of a dropped broadcast receiver so it still shows up in reports,
+    /// keeping only the most recent dropped receivers.
+    fn receiver_dropped(&mut self, receiver: u64) {
+        const MAX_DROPPED_RECEIVERS: usize = 32;
+
+        let Some(stats) = self.receivers.get(&receiver) else {
+            return;
+        };
+        let lag = self.receiver_lag(stats);
+        if let Some(stats) = self.receivers.get_mut(&receiver) {
+            stats.final_lag = Some(lag);
+        }
+
+        let dropped: Vec<u64> = self
+            .receivers
+            .iter()
+            .filter(|(_, stats)| stats.final_lag.is_some())
+            .map(|(id, _)| *id)
+            .collect();
+        for id in dropped
+            .iter()
+            .take(dropped.len().saturating_sub(MAX_DROPPED_RECEIVERS))
+        {
+            self.receivers.remove(id);
+        }
+    }
+
+    /// Broadcast and watch messages have no single receiver to pair a send with.
+    fn tracks_queue_latency(&self) -> bool {
+        !matches!(
+            self.channel_type,
+            ChannelType::Broadcast(_) | ChannelType::Watch
+        )
+    }
+
+    fn record_sent(&mut self, timestamp_ns: u64, size: Option<usize>) {
+        if let ChannelType::Watch = self.channel_type {
+            self.last_change_ns = Some(timestamp_ns);
+        }
+        if !self.tracks_queue_latency() {
+            return;
+        }
+        let size = size.map_or(0, |size| {
+            self.measures_size = true;
+            size as u64
+        });
+        match self.early_receives.pop_front() {
+            Some(received_ns) => self.record_latency(timestamp_ns, received_ns),
+            None => {
+                self.pending_bytes += size;
+                self.pending_sends.push_back(PendingSend {
+                    sent_ns: timestamp_ns,
+                    size,
+                });
+            }
+        }
+    }
+
+    /// Pairs a receive with the oldest pending send, channels deliver in FIFO order.
+    fn record_received(&mut self, timestamp_ns: u64) {
+        if !self.tracks_queue_latency() {
+            return;
+        }
+        match self.pending_sends.pop_front() {
+            Some(pending) => {
+                self.pending_bytes -= pending.size;
+                self.record_latency(pending.sent_ns, timestamp_ns);
+            }
+            // Inline senders report after the message is already visible to the
+            // receiver, so a fast receiver can win the race to the stats channel
+            None if self.inline => self.early_receives.push_back(timestamp_ns),
+            None => {}
+        }
+    }
+
+    /// Closes the current history interval, converting message counts since the
+    /// previous sample into per-second rates.
+    fn record_history_sample(&mut self, timestamp_ns: u64) {
+        let (sent_mark, received_mark, sampled_at) = self.history_mark;
+        let elapsed_ns = timestamp_ns.saturating_sub(sampled_at);
+        if elapsed_ns == 0 {
+            return;
+        }
+        let sent = self.sent_count - sent_mark;
+        let received = self.received_count - received_mark;
+        // Closed channels stop growing a flat history once their last messages are accounted for
+        if self.state == ChannelState::Closed && sent == 0 && received == 0 {
+            return;
+        }
+
+        let per_sec = |count: u64| (count as u128 * 1_000_000_000 / elapsed_ns as u128) as u64;
+        if self.history.len() >= HISTORY_LIMIT {
+            self.history.pop_front();
+        }
+        self.history.push_back(ChannelHistorySample {
+            timestamp_ns,
+            sent_per_sec: per_sec(sent),
+            received_per_sec: per_sec(received),
+            queued: self.queued(),
+        });
+        self.history_mark = (self.sent_count, self.received_count, timestamp_ns);
+    }
+
+    /// Time spent full up to `now_ns`, including a full period that is still open.
+    pub(crate) fn full_time_ns(&self, now_ns: u64) -> u64 {
+        let current = self
+            .full_since_ns
+            .map_or(0, |since| now_ns.saturating_sub(since));
+        self.full_total_ns + current
+    }
+
+    /// Share of the channel lifetime spent full, in percent.
+    pub(crate) fn full_time_percent(&self, now_ns: u64) -> f64 {
+        let end_ns = self.finished_ns.unwrap_or(now_ns);
+        let lifetime_ns = end_ns.saturating_sub(self.created_ns);
+        if lifetime_ns == 0 {
+            return 0.0;
+        }
+        (self.full_time_ns(end_ns) as f64 / lifetime_ns as f64 * 100.0).min(100.0)
+    }
+
+    /// Moves the channel to a terminal state, closing any open full period.
+    fn finish(&mut self, state: ChannelState, timestamp_ns: u64) {
+        self.end_full_period(timestamp_ns);
+        self.finished_ns.get_or_insert(timestamp_ns);
+        self.state = state;
+    }
+
+    fn end_full_period(&mut self, timestamp_ns: u64) {
+        if let Some(since) = self.full_since_ns.take() {
+            self.full_total_ns += timestamp_ns.saturating_sub(since);
+        }
+    }
+
+    fn record_latency(&mut self, sent_ns: u64, received_ns: u64) {
+        let latency = received_ns
+            .saturating_sub(sent_ns)
+            .clamp(Self::LOW_NS, Self::HIGH_NS);
+        let _ = self.queue_latency.record(latency);
+    }
+
+    /// Only inline senders can time their sends, and only a bounded channel makes them wait.
+    pub(crate) fn measures_send_blocking(&self) -> bool {
+        self.inline && matches!(self.channel_type, ChannelType::Bounded(_))
+    }
+
+    fn record_send_blocked(&mut self, blocked_ns: u64) {
+        self.blocked_sends += 1;
+        let _ = self
+            .send_block
+            .record(blocked_ns.clamp(Self::LOW_NS, Self::HIGH_NS));
     }
 }
 
@@ -65,6 +360,8 @@ impl From<&ChannelStats> for SerializableChannelStats {
             channel_stats.label.as_deref(),
             Some(channel_stats.iter),
         );
+        let is_broadcast = matches!(channel_stats.channel_type, ChannelType::Broadcast(_));
+        let now_ns = timestamp_nanos(Instant::now());
 
         Self {
             id: channel_stats.id,
@@ -80,11 +377,55 @@ impl From<&ChannelStats> for SerializableChannelStats {
             type_size: channel_stats.type_size,
             queued_bytes: channel_stats.queued_bytes(),
             iter: channel_stats.iter,
+            queue_latency_p50_ns: channel_stats.queue_latency_percentile(50.0),
+            queue_latency_p95_ns: channel_stats.queue_latency_percentile(95.0),
+            queue_latency_p99_ns: channel_stats.queue_latency_percentile(99.0),
+            lagged_count: is_broadcast.then_some(channel_stats.lagged_count),
+            dropped_count: is_broadcast.then_some(channel_stats.dropped_count),
+            receivers: is_broadcast.then(|| {
+                channel_stats
+                    .receivers
+                    .iter()
+                    .map(|(id, receiver)| BroadcastReceiverStats {
+                        id: *id,
+                        received: receiver.received,
+                        lagged: receiver.lagged,
+                        lag: channel_stats.receiver_lag(receiver),
+                        dropped: receiver.final_lag.is_some(),
+                    })
+                    .collect()
+            }),
+            last_change_ns: channel_stats.last_change_ns,
+            receiver_count: channel_stats.receiver_count.map(|count| count as u64),
+            max_queued: channel_stats.max_queued,
+            full_time_ns: channel_stats.full_time_ns(now_ns),
+            full_time_percent: channel_stats.full_time_percent(now_ns),
+            full_transitions: channel_stats.full_transitions,
+            blocked_sends: channel_stats
+                .measures_send_blocking()
+                .then_some(channel_stats.blocked_sends),
+            send_block_p50_ns: channel_stats.send_block_percentile(50.0),
+            send_block_p95_ns: channel_stats.send_block_percentile(95.0),
+            send_block_p99_ns: channel_stats.send_block_percentile(99.0),
+            send_block_max_ns: (!channel_stats.send_block.is_empty())
+                .then(|| channel_stats.send_block.max()),
+            sent_rate_history: channel_stats
+                .history
+                .iter()
+                .skip(
+                    channel_stats
+                        .history
+                        .len()
+                        .saturating_sub(RECENT_RATES_LIMIT),
+                )
+                .map(|sample| sample.sent_per_sec)
+                .collect(),
         }
     }
 }
 
 impl ChannelStats {
+    #[allow(clippy::too_many_arguments)]
     fn new(
         id: u64,
         source: &'static str,
@@ -93,6 +434,8 @@ impl ChannelStats {
         type_name: &'static str,
         type_size: usize,
         iter: u32,
+        inline: bool,
+        created_ns: u64,
     ) -> Self {
         Self {
             id,
@@ -107,24 +450,62 @@ impl ChannelStats {
             sent_logs: VecDeque::new(),
             received_logs: VecDeque::new(),
             iter,
+            pending_sends: VecDeque::new(),
+            pending_bytes: 0,
+            measures_size: false,
+            queue_latency: Histogram::<u64>::new_with_bounds(
+                Self::LOW_NS,
+                Self::HIGH_NS,
+                Self::SIGFIGS,
+            )
+            .expect("hdrhistogram init"),
+            early_receives: VecDeque::new(),
+            inline,
+            lagged_count: 0,
+            dropped_count: 0,
+            receivers: BTreeMap::new(),
+            last_change_ns: None,
+            receiver_count: None,
+            history: VecDeque::new(),
+            history_mark: (0, 0, created_ns),
+            max_queued: 0,
+            full_total_ns: 0,
+            full_since_ns: None,
+            full_transitions: 0,
+            created_ns,
+            finished_ns: None,
+            blocked_sends: 0,
+            send_block: Histogram::<u64>::new_with_bounds(
+                Self::LOW_NS,
+                Self::HIGH_NS,
+                Self::SIGFIGS,
+            )
+            .expect("hdrhistogram init"),
         }
     }
 
-    fn update_state(&mut self) {
+    fn update_state(&mut self, timestamp_ns: u64) {
         if self.state == ChannelState::Closed || self.state == ChannelState::Notified {
             return;
         }
 
         let queued = self.queued();
+        self.max_queued = self.max_queued.max(queued);
         let is_full = match self.channel_type {
             ChannelType::Bounded(cap) => queued >= cap as u64,
             ChannelType::Oneshot => queued >= 1,
-            ChannelType::Unbounded => false,
+            ChannelType::Broadcast(cap) => queued >= cap as u64,
+            ChannelType::Unbounded | ChannelType::Watch => false,
         };
 
         if is_full {
+            if self.state != ChannelState::Full {
+                self.full_transitions += 1;
+                self.full_since_ns = Some(timestamp_ns);
+            }
             self.state = ChannelState::Full;
         } else {
+            self.end_full_period(timestamp_ns);
             self.state = ChannelState::Active;
         }
     }
@@ -132,6 +513,7 @@ impl ChannelStats {
 
 /// Events sent to the background channel statistics collection thread.
 #[derive(Debug)]
+#[cfg_attr(not(feature = "tokio"), allow(dead_code))]
 pub(crate) enum ChannelEvent {
     Created {
         id: u64,
@@ -140,10 +522,14 @@ pub(crate) enum ChannelEvent {
         channel_type: ChannelType,
         type_name: &'static str,
         type_size: usize,
+        inline: bool,
+        timestamp: Instant,
     },
     MessageSent {
         id: u64,
-        log: Option<String>,
+        log: LogRecord,
+        /// Measured message size in bytes, see `channel!(..., measure_size = true)`
+        size: Option<usize>,
         timestamp: Instant,
     },
     MessageReceived {
@@ -157,6 +543,48 @@ pub(crate) enum ChannelEvent {
     Notified {
         id: u64,
     },
+    ReceiverSubscribed {
+        id: u64,
+        receiver: u64,
+    },
+    ReceiverDropped {
+        id: u64,
+        receiver: u64,
+    },
+    /// A broadcast message read by one of the receivers
+    BroadcastReceived {
+        id: u64,
+        receiver: u64,
+        timestamp: Instant,
+    },
+    /// A broadcast receiver fell behind and `skipped` messages were overwritten
+    Lagged {
+        id: u64,
+        receiver: u64,
+        skipped: u64,
+    },
+    /// A broadcast send failed because there were no receivers
+    SendDropped {
+        id: u64,
+    },
+    /// A send on a full bounded channel waited `blocked_ns` for capacity
+    SendBlocked {
+        id: u64,
+        blocked_ns: u64,
+    },
+    /// Number of receiver handles of a multi-consumer channel changed
+    #[cfg_attr(
+        not(any(feature = "flume", feature = "async-channel", feature = "kanal")),
+        allow(dead_code)
+    )]
+    ReceiverCount {
+        id: u64,
+        count: usize,
+    },
+    /// Acknowledged once every event sent before it has been applied.
+    Flush {
+        done: CbSender<()>,
+    },
 }
 
 type ChannelStatsState = (
@@ -172,6 +600,13 @@ pub(crate) static CHANNEL_ID_COUNTER: AtomicU64 = AtomicU64::new(0);
 
 const DEFAULT_LOG_LIMIT: usize = 50;
 
+/// How often the collector closes a throughput history interval
+const HISTORY_INTERVAL: std::time::Duration = std::time::Duration::from_secs(1);
+/// Number of history samples kept per channel
+const HISTORY_LIMIT: usize = 120;
+/// Number of most recent sent rates embedded in the channel stats for sparklines
+const RECENT_RATES_LIMIT: usize = 20;
+
 pub(crate) fn get_log_limit() -> usize {
     std::env::var("HOTPATH_LOGS_LIMIT")
         .ok()
@@ -191,8 +626,31 @@ pub(crate) fn init_channels_state() -> &'static ChannelStatsState {
         std::thread::Builder::new()
             .name("hp-channels".into())
             .spawn(move || {
-                while let Ok(event) = rx.recv() {
+                let mut next_sample = std::time::Instant::now() + HISTORY_INTERVAL;
+                loop {
+                    let event = match rx.recv_deadline(next_sample) {
+                        Ok(event) => Some(event),
+                        Err(RecvTimeoutError::Timeout) => None,
+                        Err(RecvTimeoutError::Disconnected) => break,
+                    };
                     let mut stats = stats_map_clone.write().unwrap();
+
+                    let now = std::time::Instant::now();
+                    if now >= next_sample {
+                        let timestamp_ns = timestamp_nanos(Instant::now());
+                        for channel_stats in stats.values_mut() {
+                            channel_stats.record_history_sample(timestamp_ns);
+                        }
+                        next_sample += HISTORY_INTERVAL;
+                        if next_sample <= now {
+                            // Fell behind, e.g. while the process was suspended
+                            next_sample = now + HISTORY_INTERVAL;
+                        }
+                    }
+
+                    let Some(event) = event else {
+                        continue;
+                    };
                     match event {
                         ChannelEvent::Created {
                             id,
@@ -201,6 +659,8 @@ pub(crate) fn init_channels_state() -> &'static ChannelStatsState {
                             channel_type,
                             type_name,
                             type_size,
+                            inline,
+                            timestamp,
                         } => {
                             // Count existing items with the same source location
                             let iter = stats.values().filter(|s| s.source == source).count() as u32;
@@ -215,30 +675,102 @@ pub(crate) fn init_channels_state() -> &'static ChannelStatsState {
                                     type_name,
                                     type_size,
                                     iter,
+                                    inline,
+                                    timestamp_nanos(timestamp),
                                 ),
                             );
                         }
-                        ChannelEvent::MessageSent { id, log, timestamp } => {
+                        ChannelEvent::MessageSent {
+                            id,
+                            log,
+                            size,
+                            timestamp,
+                        } => {
                             if let Some(channel_stats) = stats.get_mut(&id) {
                                 channel_stats.sent_count += 1;
-                                channel_stats.update_state();
+                                channel_stats.record_sent(timestamp_nanos(timestamp), size);
+                                channel_stats.update_state(timestamp_nanos(timestamp));
+
+                                // Messages dropped by `log_filter` or `log_sample` leave
+                                // the log buffer to the ones that matched
+                                if !log.is_skipped() {
+                                    let limit = get_log_limit();
+                                    if channel_stats.sent_logs.len() >= limit {
+                                        channel_stats.sent_logs.pop_front();
+                                    }
+                                    channel_stats.sent_logs.push_back(
+                                        LogEntry::new(
+                                            channel_stats.sent_count,
+                                            timestamp_nanos(timestamp),
+                                            log.into_message(),
+                                            None,
+                                        )
+                                        .with_size(size),
+                                    );
+                                }
+                            }
+                        }
+                        ChannelEvent::MessageReceived { id, timestamp } => {
+                            if let Some(channel_stats) = stats.get_mut(&id) {
+                                channel_stats.received_count += 1;
+                                channel_stats.record_received(timestamp_nanos(timestamp));
+                                channel_stats.update_state(timestamp_nanos(timestamp));
 
                                 let limit = get_log_limit();
-                                if channel_stats.sent_logs.len() >= limit {
-                                    channel_stats.sent_logs.pop_front();
+                                if channel_stats.received_logs.len() >= limit {
+                                    channel_stats.received_logs.pop_front();
                                 }
-                                channel_stats.sent_logs.push_back(LogEntry::new(
-                                    channel_stats.sent_count,
+                                channel_stats.received_logs.push_back(LogEntry::new(
+                                    channel_stats.received_count,
                                     timestamp_nanos(timestamp),
-                                    log.map(truncate_result),
+                                    None,
                                     None,
                                 ));
                             }
                         }
-                        ChannelEvent::MessageReceived { id, timestamp } => {
+                        ChannelEvent::Closed { id } => {
+                            if let Some(channel_stats) = stats.get_mut(&id) {
+                                channel_stats
+                                    .finish(ChannelState::Closed, timestamp_nanos(Instant::now()));
+                            }
+                        }
+                        ChannelEvent::Notified { id } => {
+                            if let Some(channel_stats) = stats.get_mut(&id) {
+                                channel_stats.finish(
+                                    ChannelState::Notified,
+                                    timestamp_nanos(Instant::now()),
+                                );
+                            }
+                        }
+                        ChannelEvent::ReceiverSubscribed { id, receiver } => {
+                            if let Some(channel_stats) = stats.get_mut(&id) {
+                                let subscribed_at = channel_stats.sent_count;
+                                channel_stats.receivers.insert(
+                                    receiver,
+                                    ReceiverStats {
+                                        subscribed_at,
+                                        ..Default::default()
+                                    },
+                                );
+                            }
+                        }
+                        ChannelEvent::ReceiverDropped { id, receiver } => {
+                            if let Some(channel_stats) = stats.get_mut(&id) {
+                                channel_stats.receiver_dropped(receiver);
+                                channel_stats.update_state(timestamp_nanos(Instant::now()));
+                            }
+                        }
+                        ChannelEvent::BroadcastReceived {
+                            id,
+                            receiver,
+                            timestamp,
+                        } => {
                             if let Some(channel_stats) = stats.get_mut(&id) {
                                 channel_stats.received_count += 1;
-                                channel_stats.update_state();
+                                if let Some(receiver) = channel_stats.receivers.get_mut(&receiver) {
+                                    receiver.received += 1;
+                                }
+                                channel_stats.update_state(timestamp_nanos(timestamp));
 
                                 let limit = get_log_limit();
                                 if channel_stats.received_logs.len() >= limit {
@@ -252,16 +784,37 @@ pub(crate) fn init_channels_state() -> &'static ChannelStatsState {
                                 ));
                             }
                         }
-                        ChannelEvent::Closed { id } => {
+                        ChannelEvent::Lagged {
+                            id,
+                            receiver,
+                            skipped,
+                        } => {
                             if let Some(channel_stats) = stats.get_mut(&id) {
-                                channel_stats.state = ChannelState::Closed;
+                                channel_stats.lagged_count += skipped;
+                                if let Some(receiver) = channel_stats.receivers.get_mut(&receiver) {
+                                    receiver.lagged += skipped;
+                                }
+                                channel_stats.update_state(timestamp_nanos(Instant::now()));
                             }
                         }
-                        ChannelEvent::Notified { id } => {
+                        ChannelEvent::SendDropped { id } => {
+                            if let Some(channel_stats) = stats.get_mut(&id) {
+                                channel_stats.dropped_count += 1;
+                            }
+                        }
+                        ChannelEvent::SendBlocked { id, blocked_ns } => {
                             if let Some(channel_stats) = stats.get_mut(&id) {
-                                channel_stats.state = ChannelState::Notified;
+                                channel_stats.record_send_blocked(blocked_ns);
                             }
                         }
+                        ChannelEvent::ReceiverCount { id, count } => {
+                            if let Some(channel_stats) = stats.get_mut(&id) {
+                                channel_stats.receiver_count = Some(count);
+                            }
+                        }
+                        ChannelEvent::Flush { done } => {
+                            let _ = done.send(());
+                        }
                     }
                 }
             })
@@ -290,6 +843,15 @@ pub(crate) fn resolve_label(id: &'static str, provided: Option<&str>, iter: Opti
     }
 }
 
+/// Percentile `p` of a nanosecond histogram, `None` if nothing was recorded.
+pub(crate) fn percentile(histogram: &Histogram<u64>, max_ns: u64, p: f64) -> Option<u64> {
+    if histogram.is_empty() {
+        return None;
+    }
+    // Histogram buckets round up, never report more than the recorded max
+    Some(histogram.value_at_quantile(p / 100.0).min(max_ns))
+}
+
 pub(crate) fn extract_filename(path: &str) -> String {
     let components: Vec<&str> = path.split('/').collect();
     if components.len() >= 2 {
@@ -331,6 +893,71 @@ pub trait InstrumentChannelLog {
     ) -> Self::Output;
 }
 
+/// Trait for instrumenting channels without a forwarding proxy.
+///
+/// This trait is not intended for direct use. Use the `channel!` macro with `mode = "inline"` instead.
+#[doc(hidden)]
+pub trait InstrumentChannelInline {
+    type Output;
+    fn instrument_inline(
+        self,
+        source: &'static str,
+        label: Option<String>,
+        capacity: Option<usize>,
+    ) -> Self::Output;
+}
+
+/// Trait for instrumenting channels without a forwarding proxy, with message logging.
+///
+/// This trait is not intended for direct use. Use the `channel!` macro with `mode = "inline", log = true` instead.
+#[doc(hidden)]
+pub trait InstrumentChannelInlineLog {
+    type Output;
+    fn instrument_inline_log(
+        self,
+        source: &'static str,
+        label: Option<String>,
+        capacity: Option<usize>,
+    ) -> Self::Output;
+}
+
+/// Trait for instrumenting channels with message size measurement or custom logging.
+///
+/// This trait is not intended for direct use. Use the `channel!` macro with `measure_size = true`
+/// or the `log_filter`, `log_format` and `log_sample` options instead.
+#[doc(hidden)]
+pub trait InstrumentChannelOptions {
+    type Message;
+    type Output;
+    fn instrument_with_options(
+        self,
+        source: &'static str,
+        label: Option<String>,
+        capacity: Option<usize>,
+        log: Option<MessageLog<Self::Message>>,
+        size: Option<fn(&Self::Message) -> usize>,
+    ) -> Self::Output;
+}
+
+/// Trait for instrumenting channels without a forwarding proxy, with message size measurement
+/// or custom logging.
+///
+/// This trait is not intended for direct use. Use the `channel!` macro with `mode = "inline"`
+/// and `measure_size = true` or the `log_filter`, `log_format` and `log_sample` options instead.
+#[doc(hidden)]
+pub trait InstrumentChannelInlineOptions {
+    type Message;
+    type Output;
+    fn instrument_inline_with_options(
+        self,
+        source: &'static str,
+        label: Option<String>,
+        capacity: Option<usize>,
+        log: Option<MessageLog<Self::Message>>,
+        size: Option<fn(&Self::Message) -> usize>,
+    ) -> Self::Output;
+}
+
 cfg_if::cfg_if! {
     if #[cfg(any(feature = "tokio", feature = "futures"))] {
         use std::sync::LazyLock;
@@ -366,9 +993,95 @@ cfg_if::cfg_if! {
 /// }
 /// ```
 ///
+/// Blocked sends (`blocked_sends` and `send_block_*` in JSON, `Blocked` in reports) are
+/// only measured for bounded channels with `mode = "inline"`, where the returned sender
+/// is a wrapper. In the default proxy mode the caller keeps the original sender, so its
+/// waits for capacity can't be observed.
+///
 /// See the `channel!` macro documentation for full usage details.
 #[macro_export]
 macro_rules! channel {
+    // Internal rules for option combinations not covered by the explicit arms below,
+    // e.g. any ordering that includes `mode = "inline"`. The `[$log]` slot is `custom` once
+    // a `log_*` option is set, which also enables logging.
+    (@opts $expr:expr, $id:ident, [$label:expr] [$capacity:expr] [$log:tt] [$mode:tt] [$size:tt] [$format:expr] [$filter:expr] [$sample:expr]) => {
+        $crate::channel!(@finish $expr, $id, $label, $capacity, $log, $mode, $size, $format, $filter, $sample)
+    };
+    (@opts $expr:expr, $id:ident, [$label:expr] [$capacity:expr] [$log:tt] [$mode:tt] [$size:tt] [$format:expr] [$filter:expr] [$sample:expr] label = $l:expr $(, $($rest:tt)*)?) => {
+        $crate::channel!(@opts $expr, $id, [Some($l.to_string())] [$capacity] [$log] [$mode] [$size] [$format] [$filter] [$sample] $($($rest)*)?)
+    };
+    (@opts $expr:expr, $id:ident, [$label:expr] [$capacity:expr] [$log:tt] [$mode:tt] [$size:tt] [$format:expr] [$filter:expr] [$sample:expr] capacity = $c:expr $(, $($rest:tt)*)?) => {
+        $crate::channel!(@opts $expr, $id, [$label] [Some({ const _: usize = $c; $c })] [$log] [$mode] [$size] [$format] [$filter] [$sample] $($($rest)*)?)
+    };
+    (@opts $expr:expr, $id:ident, [$label:expr] [$capacity:expr] [custom] [$mode:tt] [$size:tt] [$format:expr] [$filter:expr] [$sample:expr] log = true $(, $($rest:tt)*)?) => {
+        $crate::channel!(@opts $expr, $id, [$label] [$capacity] [custom] [$mode] [$size] [$format] [$filter] [$sample] $($($rest)*)?)
+    };
+    (@opts $expr:expr, $id:ident, [$label:expr] [$capacity:expr] [$log:tt] [$mode:tt] [$size:tt] [$format:expr] [$filter:expr] [$sample:expr] log = true $(, $($rest:tt)*)?) => {
+        $crate::channel!(@opts $expr, $id, [$label] [$capacity] [true] [$mode] [$size] [$format] [$filter] [$sample] $($($rest)*)?)
+    };
+    (@opts $expr:expr, $id:ident, [$label:expr] [$capacity:expr] [$log:tt] [$mode:tt] [$size:tt] [$format:expr] [$filter:expr] [$sample:expr] log_format = $f:expr $(, $($rest:tt)*)?) => {
+        $crate::channel!(@opts $expr, $id, [$label] [$capacity] [custom] [$mode] [$size] [$f] [$filter] [$sample] $($($rest)*)?)
+    };
+    (@opts $expr:expr, $id:ident, [$label:expr] [$capacity:expr] [$log:tt] [$mode:tt] [$size:tt] [$format:expr] [$filter:expr] [$sample:expr] log_filter = $f:expr $(, $($rest:tt)*)?) => {
+        $crate::channel!(@opts $expr, $id, [$label] [$capacity] [custom] [$mode] [$size] [$format] [$f] [$sample] $($($rest)*)?)
+    };
+    (@opts $expr:expr, $id:ident, [$label:expr] [$capacity:expr] [$log:tt] [$mode:tt] [$size:tt] [$format:expr] [$filter:expr] [$sample:expr] log_sample = $n:expr $(, $($rest:tt)*)?) => {
+        $crate::channel!(@opts $expr, $id, [$label] [$capacity] [custom] [$mode] [$size] [$format] [$filter] [$n] $($($rest)*)?)
+    };
+    (@opts $expr:expr, $id:ident, [$label:expr] [$capacity:expr] [$log:tt] [$mode:tt] [$size:tt] [$format:expr] [$filter:expr] [$sample:expr] mode = "inline" $(, $($rest:tt)*)?) => {
+        $crate::channel!(@opts $expr, $id, [$label] [$capacity] [$log] [inline] [$size] [$format] [$filter] [$sample] $($($rest)*)?)
+    };
+    (@opts $expr:expr, $id:ident, [$label:expr] [$capacity:expr] [$log:tt] [$mode:tt] [$size:tt] [$format:expr] [$filter:expr] [$sample:expr] mode = "proxy" $(, $($rest:tt)*)?) => {
+        $crate::channel!(@opts $expr, $id, [$label] [$capacity] [$log] [proxy] [$size] [$format] [$filter] [$sample] $($($rest)*)?)
+    };
+    (@opts $expr:expr, $id:ident, [$label:expr] [$capacity:expr] [$log:tt] [$mode:tt] [$size:tt] [$format:expr] [$filter:expr] [$sample:expr] measure_size = true $(, $($rest:tt)*)?) => {
+        $crate::channel!(@opts $expr, $id, [$label] [$capacity] [$log] [$mode] [true] [$format] [$filter] [$sample] $($($rest)*)?)
+    };
+    (@finish $expr:expr, $id:ident, $label:expr, $capacity:expr, false, proxy, false, $format:expr, $filter:expr, $sample:expr) => {
+        $crate::InstrumentChannel::instrument($expr, $id, $label, $capacity)
+    };
+    (@finish $expr:expr, $id:ident, $label:expr, $capacity:expr, true, proxy, false, $format:expr, $filter:expr, $sample:expr) => {
+        $crate::InstrumentChannelLog::instrument_log($expr, $id, $label, $capacity)
+    };
+    (@finish $expr:expr, $id:ident, $label:expr, $capacity:expr, false, inline, false, $format:expr, $filter:expr, $sample:expr) => {
+        $crate::InstrumentChannelInline::instrument_inline($expr, $id, $label, $capacity)
+    };
+    (@finish $expr:expr, $id:ident, $label:expr, $capacity:expr, true, inline, false, $format:expr, $filter:expr, $sample:expr) => {
+        $crate::InstrumentChannelInlineLog::instrument_inline_log($expr, $id, $label, $capacity)
+    };
+    (@finish $expr:expr, $id:ident, $label:expr, $capacity:expr, $log:tt, proxy, $size:tt, $format:expr, $filter:expr, $sample:expr) => {
+        $crate::InstrumentChannelOptions::instrument_with_options(
+            $expr,
+            $id,
+            $label,
+            $capacity,
+            $crate::channel!(@log $log, $format, $filter, $sample),
+            $crate::channel!(@size $size),
+        )
+    };
+    (@finish $expr:expr, $id:ident, $label:expr, $capacity:expr, $log:tt, inline, $size:tt, $format:expr, $filter:expr, $sample:expr) => {
+        $crate::InstrumentChannelInlineOptions::instrument_inline_with_options(
+            $expr,
+            $id,
+            $label,
+            $capacity,
+            $crate::channel!(@log $log, $format, $filter, $sample),
+            $crate::channel!(@size $size),
+        )
+    };
+    (@log false, $format:expr, $filter:expr, $sample:expr) => {
+        None
+    };
+    (@log $log:tt, $format:expr, $filter:expr, $sample:expr) => {
+        Some($crate::channels::MessageLog::new($format, $filter, $sample))
+    };
+    (@size false) => {
+        None
+    };
+    (@size true) => {
+        Some($crate::heap_size_of)
+    };
+
     ($expr:expr) => {{
         const CHANNEL_ID: &'static str = concat!(file!(), ":", line!());
         $crate::InstrumentChannel::instrument($expr, CHANNEL_ID, None, None)
@@ -510,6 +1223,12 @@ macro_rules! channel {
             Some($capacity),
         )
     }};
+
+    // Any other combination of options, including `mode`, `measure_size` and `log_*`
+    ($expr:expr, $($rest:tt)+) => {{
+        const CHANNEL_ID: &'static str = concat!(file!(), ":", line!());
+        $crate::channel!(@opts $expr, CHANNEL_ID, [None] [None] [false] [proxy] [false] [$crate::channels::inline::log_debug] [|_| true] [1] $($rest)+)
+    }};
 }
 
 fn get_all_channel_stats() -> HashMap<u64, ChannelStats> {
@@ -539,6 +1258,20 @@ fn compare_channel_stats(a: &ChannelStats, b: &ChannelStats) -> std::cmp::Orderi
     }
 }
 
+/// Waits until the stats collector has applied all events sent so far.
+///
+/// Inline channels report from the calling thread, so their last events may still be
+/// in flight when the final report is printed.
+pub(crate) fn flush_channel_events() {
+    let Some((tx, _)) = CHANNELS_STATE.get() else {
+        return;
+    };
+    let (done_tx, done_rx) = crossbeam_channel::bounded(1);
+    if tx.send(ChannelEvent::Flush { done: done_tx }).is_ok() {
+        let _ = done_rx.recv_timeout(std::time::Duration::from_secs(1));
+    }
+}
+
 pub(crate) fn get_sorted_channel_stats() -> Vec<ChannelStats> {
     let mut stats: Vec<ChannelStats> = get_all_channel_stats().into_values().collect();
     stats.sort_by(compare_channel_stats);
@@ -572,3 +1305,13 @@ pub fn get_channel_logs(channel_id: &str) -> Option<ChannelLogs> {
         received_logs: channel_stats.received_logs.iter().rev().cloned().collect(),
     })
 }
+
+pub fn get_channel_history(channel_id: &str) -> Option<ChannelHistory> {
+    let id = channel_id.parse::<u64>().ok()?;
+    let stats = get_all_channel_stats();
+    stats.get(&id).map(|channel_stats| ChannelHistory {
+        id: channel_id.to_string(),
+        interval_ms: HISTORY_INTERVAL.as_millis() as u64,
+        samples: channel_stats.history.iter().cloned().collect(),
+    })
+}
@@ -6,8 +6,11 @@ use std::time::Instant;
 
 use prettytable::{Cell, Row, Table};
 
-use crate::channels::{get_sorted_channel_stats, resolve_label};
-use crate::output::format_bytes;
+use crate::channels::{
+    flush_channel_events, get_sorted_channel_stats, resolve_label, timestamp_nanos, ChannelStats,
+    ChannelType,
+};
+use crate::output::{format_bytes, format_duration};
 use crate::Format;
 
 /// Builder for creating a ChannelsGuard with custom configuration.
@@ -124,6 +127,7 @@ impl Default for ChannelsGuard {
 impl Drop for ChannelsGuard {
     fn drop(&mut self) {
         let elapsed = self.start_time.elapsed();
+        flush_channel_events();
         let channels = get_sorted_channel_stats();
 
         if channels.is_empty() {
@@ -147,15 +151,27 @@ impl Drop for ChannelsGuard {
                     Cell::new("Sent"),
                     Cell::new("Received"),
                     Cell::new("Queued"),
+                    Cell::new("Peak"),
+                    Cell::new("Full"),
+                    Cell::new("Blocked"),
                     Cell::new("Mem"),
+                    Cell::new("Wait P50"),
+                    Cell::new("Wait P95"),
+                    Cell::new("Wait P99"),
                 ]));
 
-                for channel_stats in channels {
+                let now_ns = timestamp_nanos(Instant::now());
+                for channel_stats in &channels {
                     let label = resolve_label(
                         channel_stats.source,
                         channel_stats.label.as_deref(),
                         Some(channel_stats.iter),
                     );
+                    let wait = |p: f64| {
+                        channel_stats
+                            .queue_latency_percentile(p)
+                            .map_or("-".to_string(), format_duration)
+                    };
                     table.add_row(Row::new(vec![
                         Cell::new(&label),
                         Cell::new(&channel_stats.channel_type.to_string()),
@@ -163,12 +179,19 @@ impl Drop for ChannelsGuard {
                         Cell::new(&channel_stats.sent_count.to_string()),
                         Cell::new(&channel_stats.received_count.to_string()),
                         Cell::new(&channel_stats.queued().to_string()),
+                        Cell::new(&channel_stats.max_queued.to_string()),
+                        Cell::new(&full_summary(channel_stats, now_ns)),
+                        Cell::new(&blocked_summary(channel_stats)),
                         Cell::new(&format_bytes(channel_stats.queued_bytes())),
+                        Cell::new(&wait(50.0)),
+                        Cell::new(&wait(95.0)),
+                        Cell::new(&wait(99.0)),
                     ]));
                 }
 
                 println!("\nChannels:");
                 table.printstd();
+                print_fan_out_details(&channels);
             }
             Format::Json => {
                 let channels_json = crate::channels::ChannelsJson {
@@ -199,3 +222,76 @@ impl Drop for ChannelsGuard {
         }
     }
 }
+
+/// Share of the lifetime spent full and how many times the channel filled up,
+/// e.g. `40% (3x)`. Channels without a capacity are never full.
+fn full_summary(channel_stats: &ChannelStats, now_ns: u64) -> String {
+    match channel_stats.channel_type {
+        ChannelType::Unbounded | ChannelType::Watch => "-".to_string(),
+        _ => format!(
+            "{:.0}% ({}x)",
+            channel_stats.full_time_percent(now_ns),
+            channel_stats.full_transitions
+        ),
+    }
+}
+
+/// Number of sends that waited for capacity and their p95 wait, e.g. `12 (p95 1.20ms)`.
+fn blocked_summary(channel_stats: &ChannelStats) -> String {
+    if !channel_stats.measures_send_blocking() {
+        return "-".to_string();
+    }
+    match channel_stats.send_block_percentile(95.0) {
+        Some(p95) => format!(
+            "{} (p95 {})",
+            channel_stats.blocked_sends,
+            format_duration(p95)
+        ),
+        None => channel_stats.blocked_sends.to_string(),
+    }
+}
+
+/// Prints receiver lag for broadcast channels, change recency for watch channels and
+/// receiver counts for multi-consumer channels, which don't fit the table columns.
+fn print_fan_out_details(channels: &[ChannelStats]) {
+    let now_ns = timestamp_nanos(Instant::now());
+    for channel_stats in channels {
+        let label = resolve_label(
+            channel_stats.source,
+            channel_stats.label.as_deref(),
+            Some(channel_stats.iter),
+        );
+        match channel_stats.channel_type {
+            ChannelType::Broadcast(_) => {
+                let lags: Vec<String> = channel_stats
+                    .receivers
+                    .keys()
+                    .zip(channel_stats.receiver_lags())
+                    .map(|(id, lag)| format!("#{id}: {lag}"))
+                    .collect();
+                println!(
+                    "{label}: lagged {}, dropped {}, receiver lag [{}]",
+                    channel_stats.lagged_count,
+                    channel_stats.dropped_count,
+                    lags.join(", ")
+                );
+            }
+            ChannelType::Watch => {
+                let last_change = channel_stats
+                    .last_change_ns
+                    .map_or("never".to_string(), |ns| {
+                        format!("{} ago", format_duration(now_ns.saturating_sub(ns)))
+                    });
+                println!(
+                    "{label}: {} changes, last change {last_change}",
+                    channel_stats.sent_count
+                );
+            }
+            _ => {
+                if let Some(count) = channel_stats.receiver_count.filter(|&count| count > 1) {
+                    println!("{label}: {count} receivers");
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,180 @@
+//! Proxy-free channel instrumentation, selected with `channel!(..., mode = "inline")`.
+//!
+//! The default instrumentation forwards every message through a capacity-1 proxy channel
+//! and a forwarding task or thread. In inline mode `channel!` instead returns thin
+//! `Sender`/`Receiver` newtypes around the original ends, which report each successful
+//! send and receive from the calling thread. Capacity, `try_send` and closing behave
+//! exactly like the wrapped channel, and no forwarding threads are spawned.
+//!
+//! Methods that move messages are mirrored on the newtypes so they can be counted. Other
+//! methods of the wrapped type are reachable through `Deref`, except where they could move
+//! messages past the counters (e.g. tokio's `reserve` or `recv_many`), in which case the
+//! wrapper forwards its methods by hand and has no `DerefMut`.
+//!
+//! Bounded senders also time sends that find the channel full, so backpressure on
+//! producers shows up as blocked sends. The proxy mode can't measure this, because the
+//! caller keeps the original sender.
+
+#[cfg(feature = "tokio")]
+use ::std::sync::atomic::AtomicU64;
+use ::std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use ::std::sync::Arc;
+
+use crossbeam_channel::Sender as CbSender;
+
+use crate::channels::wrapper::common::{register_inline_channel, Instant, RegisteredChannel};
+use crate::channels::{ChannelEvent, ChannelType, LogRecord};
+
+#[cfg(feature = "tokio")]
+pub mod broadcast;
+#[cfg(feature = "crossbeam")]
+pub mod crossbeam;
+#[cfg(feature = "futures")]
+pub mod futures;
+pub mod std;
+#[cfg(feature = "tokio")]
+pub mod tokio;
+#[cfg(feature = "tokio")]
+pub mod watch;
+
+/// Measures a message in bytes, set when instrumented with `measure_size = true`
+pub(crate) type SizeFn<T> = fn(&T) -> usize;
+
+/// State shared by all ends of an inline-instrumented channel.
+pub(crate) struct InlineChannel {
+    id: u64,
+    stats_tx: CbSender<ChannelEvent>,
+    senders: AtomicUsize,
+    receivers: AtomicUsize,
+    closed: AtomicBool,
+    #[cfg(feature = "tokio")]
+    next_receiver: AtomicU64,
+}
+
+impl InlineChannel {
+    pub(crate) fn register<T>(
+        source: &'static str,
+        label: Option<String>,
+        channel_type: ChannelType,
+    ) -> Arc<Self> {
+        let RegisteredChannel { id, stats_tx } =
+            register_inline_channel::<T>(source, label, channel_type);
+
+        Arc::new(Self {
+            id,
+            stats_tx,
+            senders: AtomicUsize::new(1),
+            receivers: AtomicUsize::new(1),
+            closed: AtomicBool::new(false),
+            #[cfg(feature = "tokio")]
+            next_receiver: AtomicU64::new(0),
+        })
+    }
+
+    pub(crate) fn sent(&self, log: LogRecord, size: Option<usize>) {
+        let _ = self.stats_tx.send(ChannelEvent::MessageSent {
+            id: self.id,
+            log,
+            size,
+            timestamp: Instant::now(),
+        });
+    }
+
+    /// Reports a send that found the channel full and waited for capacity since `since`.
+    pub(crate) fn send_blocked(&self, since: Instant) {
+        let _ = self.stats_tx.send(ChannelEvent::SendBlocked {
+            id: self.id,
+            blocked_ns: Instant::now().duration_since(since).as_nanos() as u64,
+        });
+    }
+
+    pub(crate) fn received(&self) {
+        let _ = self.stats_tx.send(ChannelEvent::MessageReceived {
+            id: self.id,
+            timestamp: Instant::now(),
+        });
+    }
+
+    /// Marks a oneshot channel as delivered, which is its final state.
+    #[cfg(feature = "tokio")]
+    pub(crate) fn notified(&self) {
+        if !self.closed.swap(true, Ordering::AcqRel) {
+            let _ = self.stats_tx.send(ChannelEvent::Notified { id: self.id });
+        }
+    }
+
+    pub(crate) fn close(&self) {
+        if !self.closed.swap(true, Ordering::AcqRel) {
+            let _ = self.stats_tx.send(ChannelEvent::Closed { id: self.id });
+        }
+    }
+
+    pub(crate) fn sender_cloned(&self) {
+        self.senders.fetch_add(1, Ordering::Relaxed);
+    }
+
+    #[cfg(any(feature = "crossbeam", feature = "tokio"))]
+    pub(crate) fn receiver_cloned(&self) {
+        self.receivers.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// The channel is closed once every sender or every receiver is gone.
+    pub(crate) fn sender_dropped(&self) {
+        if self.senders.fetch_sub(1, Ordering::AcqRel) == 1 {
+            self.close();
+        }
+    }
+
+    pub(crate) fn receiver_dropped(&self) {
+        if self.receivers.fetch_sub(1, Ordering::AcqRel) == 1 {
+            self.close();
+        }
+    }
+}
+
+/// Per-receiver reporting for broadcast channels, where every receiver reads its own copy.
+#[cfg(feature = "tokio")]
+impl InlineChannel {
+    pub(crate) fn receiver_subscribed(&self) -> u64 {
+        let receiver = self.next_receiver.fetch_add(1, Ordering::Relaxed);
+        let _ = self.stats_tx.send(ChannelEvent::ReceiverSubscribed {
+            id: self.id,
+            receiver,
+        });
+        receiver
+    }
+
+    pub(crate) fn receiver_unsubscribed(&self, receiver: u64) {
+        let _ = self.stats_tx.send(ChannelEvent::ReceiverDropped {
+            id: self.id,
+            receiver,
+        });
+    }
+
+    pub(crate) fn broadcast_received(&self, receiver: u64) {
+        let _ = self.stats_tx.send(ChannelEvent::BroadcastReceived {
+            id: self.id,
+            receiver,
+            timestamp: Instant::now(),
+        });
+    }
+
+    pub(crate) fn lagged(&self, receiver: u64, skipped: u64) {
+        let _ = self.stats_tx.send(ChannelEvent::Lagged {
+            id: self.id,
+            receiver,
+            skipped,
+        });
+    }
+
+    pub(crate) fn send_dropped(&self) {
+        let _ = self
+            .stats_tx
+            .send(ChannelEvent::SendDropped { id: self.id });
+    }
+}
+
+#[doc(hidden)]
+pub fn log_debug<T: ::std::fmt::Debug>(msg: &T) -> String {
+    format!("{:?}", msg)
+}
@@ -0,0 +1,284 @@
+//! Instrumentation for `tokio::sync::broadcast` channels.
+//!
+//! Broadcast channels have no single consumer to proxy, so they are always instrumented
+//! inline. Each receiver is tracked separately to report how far behind it is and how
+//! many messages it missed after lagging.
+
+use std::ops::Deref;
+use std::sync::Arc;
+
+use tokio::sync::broadcast::{
+    self,
+    error::{RecvError, SendError, TryRecvError},
+};
+
+use super::{InlineChannel, SizeFn};
+use crate::channels::{
+    ChannelType, InstrumentChannel, InstrumentChannelInline, InstrumentChannelInlineLog,
+    InstrumentChannelInlineOptions, InstrumentChannelLog, InstrumentChannelOptions, LogRecord,
+    MessageLog,
+};
+
+/// Instrumented `tokio::sync::broadcast::Sender`.
+pub struct Sender<T> {
+    inner: broadcast::Sender<T>,
+    channel: Arc<InlineChannel>,
+    log: Option<MessageLog<T>>,
+    size: Option<SizeFn<T>>,
+}
+
+impl<T> Sender<T> {
+    /// See [`tokio::sync::broadcast::Sender::send`].
+    pub fn send(&self, value: T) -> Result<usize, SendError<T>> {
+        let log = LogRecord::capture(self.log.as_ref(), &value);
+        let size = self.size.map(|size| size(&value));
+        match self.inner.send(value) {
+            Ok(receivers) => {
+                self.channel.sent(log, size);
+                Ok(receivers)
+            }
+            Err(err) => {
+                self.channel.send_dropped();
+                Err(err)
+            }
+        }
+    }
+
+    /// See [`tokio::sync::broadcast::Sender::subscribe`].
+    pub fn subscribe(&self) -> Receiver<T> {
+        Receiver::new(self.inner.subscribe(), Arc::clone(&self.channel))
+    }
+}
+
+impl<T> Clone for Sender<T> {
+    fn clone(&self) -> Self {
+        self.channel.sender_cloned();
+        Self {
+            inner: self.inner.clone(),
+            channel: Arc::clone(&self.channel),
+            log: self.log.clone(),
+            size: self.size,
+        }
+    }
+}
+
+impl<T> Drop for Sender<T> {
+    fn drop(&mut self) {
+        self.channel.sender_dropped();
+    }
+}
+
+impl<T> Deref for Sender<T> {
+    type Target = broadcast::Sender<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<T> std::fmt::Debug for Sender<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.inner.fmt(f)
+    }
+}
+
+/// Instrumented `tokio::sync::broadcast::Receiver`.
+pub struct Receiver<T> {
+    inner: broadcast::Receiver<T>,
+    channel: Arc<InlineChannel>,
+    receiver: u64,
+}
+
+impl<T> Receiver<T> {
+    fn new(inner: broadcast::Receiver<T>, channel: Arc<InlineChannel>) -> Self {
+        let receiver = channel.receiver_subscribed();
+        Self {
+            inner,
+            channel,
+            receiver,
+        }
+    }
+}
+
+impl<T: Clone> Receiver<T> {
+    /// See [`tokio::sync::broadcast::Receiver::resubscribe`].
+    pub fn resubscribe(&self) -> Self {
+        Self::new(self.inner.resubscribe(), Arc::clone(&self.channel))
+    }
+
+    /// See [`tokio::sync::broadcast::Receiver::recv`].
+    pub async fn recv(&mut self) -> Result<T, RecvError> {
+        let result = self.inner.recv().await;
+        self.record(&result);
+        result
+    }
+
+    /// See [`tokio::sync::broadcast::Receiver::try_recv`].
+    pub fn try_recv(&mut self) -> Result<T, TryRecvError> {
+        let result = self.inner.try_recv();
+        match &result {
+            Ok(_) => self.channel.broadcast_received(self.receiver),
+            Err(TryRecvError::Lagged(skipped)) => self.channel.lagged(self.receiver, *skipped),
+            Err(TryRecvError::Closed) => self.channel.close(),
+            Err(TryRecvError::Empty) => {}
+        }
+        result
+    }
+
+    /// See [`tokio::sync::broadcast::Receiver::blocking_recv`].
+    pub fn blocking_recv(&mut self) -> Result<T, RecvError> {
+        let result = self.inner.blocking_recv();
+        self.record(&result);
+        result
+    }
+
+    fn record(&self, result: &Result<T, RecvError>) {
+        match result {
+            Ok(_) => self.channel.broadcast_received(self.receiver),
+            Err(RecvError::Lagged(skipped)) => self.channel.lagged(self.receiver, *skipped),
+            Err(RecvError::Closed) => self.channel.close(),
+        }
+    }
+}
+
+impl<T> Drop for Receiver<T> {
+    fn drop(&mut self) {
+        self.channel.receiver_unsubscribed(self.receiver);
+    }
+}
+
+impl<T> Deref for Receiver<T> {
+    type Target = broadcast::Receiver<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<T> std::fmt::Debug for Receiver<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.inner.fmt(f)
+    }
+}
+
+fn wrap_broadcast<T>(
+    (tx, rx): (broadcast::Sender<T>, broadcast::Receiver<T>),
+    source: &'static str,
+    label: Option<String>,
+    capacity: Option<usize>,
+    log: Option<MessageLog<T>>,
+    size: Option<SizeFn<T>>,
+) -> (Sender<T>, Receiver<T>) {
+    let Some(capacity) = capacity else {
+        panic!("Capacity is required for broadcast channels, because they don't expose their capacity in a public API");
+    };
+    let channel = InlineChannel::register::<T>(source, label, ChannelType::Broadcast(capacity));
+    let receiver = Receiver::new(rx, Arc::clone(&channel));
+    let sender = Sender {
+        inner: tx,
+        channel,
+        log,
+        size,
+    };
+    (sender, receiver)
+}
+
+impl<T: Send + 'static> InstrumentChannel for (broadcast::Sender<T>, broadcast::Receiver<T>) {
+    type Output = (Sender<T>, Receiver<T>);
+    fn instrument(
+        self,
+        source: &'static str,
+        label: Option<String>,
+        capacity: Option<usize>,
+    ) -> Self::Output {
+        wrap_broadcast(self, source, label, capacity, None, None)
+    }
+}
+
+impl<T: Send + std::fmt::Debug + 'static> InstrumentChannelLog
+    for (broadcast::Sender<T>, broadcast::Receiver<T>)
+{
+    type Output = (Sender<T>, Receiver<T>);
+    fn instrument_log(
+        self,
+        source: &'static str,
+        label: Option<String>,
+        capacity: Option<usize>,
+    ) -> Self::Output {
+        wrap_broadcast(
+            self,
+            source,
+            label,
+            capacity,
+            Some(MessageLog::debug()),
+            None,
+        )
+    }
+}
+
+impl<T: Send + 'static> InstrumentChannelInline for (broadcast::Sender<T>, broadcast::Receiver<T>) {
+    type Output = (Sender<T>, Receiver<T>);
+    fn instrument_inline(
+        self,
+        source: &'static str,
+        label: Option<String>,
+        capacity: Option<usize>,
+    ) -> Self::Output {
+        wrap_broadcast(self, source, label, capacity, None, None)
+    }
+}
+
+impl<T: Send + std::fmt::Debug + 'static> InstrumentChannelInlineLog
+    for (broadcast::Sender<T>, broadcast::Receiver<T>)
+{
+    type Output = (Sender<T>, Receiver<T>);
+    fn instrument_inline_log(
+        self,
+        source: &'static str,
+        label: Option<String>,
+        capacity: Option<usize>,
+    ) -> Self::Output {
+        wrap_broadcast(
+            self,
+            source,
+            label,
+            capacity,
+            Some(MessageLog::debug()),
+            None,
+        )
+    }
+}
+
+impl<T: Send + 'static> InstrumentChannelOptions
+    for (broadcast::Sender<T>, broadcast::Receiver<T>)
+{
+    type Message = T;
+    type Output = (Sender<T>, Receiver<T>);
+    fn instrument_with_options(
+        self,
+        source: &'static str,
+        label: Option<String>,
+        capacity: Option<usize>,
+        log: Option<MessageLog<T>>,
+        size: Option<SizeFn<T>>,
+    ) -> Self::Output {
+        wrap_broadcast(self, source, label, capacity, log, size)
+    }
+}
+
+impl<T: Send + 'static> InstrumentChannelInlineOptions
+    for (broadcast::Sender<T>, broadcast::Receiver<T>)
+{
+    type Message = T;
+    type Output = (Sender<T>, Receiver<T>);
+    fn instrument_inline_with_options(
+        self,
+        source: &'static str,
+        label: Option<String>,
+        capacity: Option<usize>,
+        log: Option<MessageLog<T>>,
+        size: Option<SizeFn<T>>,
+    ) -> Self::Output {
+        wrap_broadcast(self, source, label, capacity, log, size)
+    }
+}
@@ -0,0 +1,333 @@
+//! Inline instrumentation for `crossbeam_channel` channels.
+
+use std::ops::Deref;
+use std::sync::Arc;
+use std::time::Duration;
+
+use crossbeam_channel::{
+    RecvError, RecvTimeoutError, SendError, SendTimeoutError, TryRecvError, TrySendError,
+};
+
+use super::{InlineChannel, Instant, SizeFn};
+use crate::channels::{
+    ChannelType, InstrumentChannelInline, InstrumentChannelInlineLog,
+    InstrumentChannelInlineOptions, LogRecord, MessageLog,
+};
+
+/// Instrumented `crossbeam_channel::Sender`.
+pub struct Sender<T> {
+    inner: crossbeam_channel::Sender<T>,
+    channel: Arc<InlineChannel>,
+    log: Option<MessageLog<T>>,
+    size: Option<SizeFn<T>>,
+}
+
+impl<T> Sender<T> {
+    /// See [`crossbeam_channel::Sender::send`].
+    ///
+    /// A send that finds a bounded channel full reports how long it waited for capacity.
+    pub fn send(&self, value: T) -> Result<(), SendError<T>> {
+        let log = LogRecord::capture(self.log.as_ref(), &value);
+        let size = self.size.map(|size| size(&value));
+        let value = match self.inner.try_send(value) {
+            Ok(()) => {
+                self.channel.sent(log, size);
+                return Ok(());
+            }
+            Err(TrySendError::Full(value)) => value,
+            Err(TrySendError::Disconnected(value)) => return Err(SendError(value)),
+        };
+        let blocked_since = Instant::now();
+        let result = self.inner.send(value);
+        self.channel.send_blocked(blocked_since);
+        result?;
+        self.channel.sent(log, size);
+        Ok(())
+    }
+
+    /// See [`crossbeam_channel::Sender::try_send`].
+    pub fn try_send(&self, value: T) -> Result<(), TrySendError<T>> {
+        let log = LogRecord::capture(self.log.as_ref(), &value);
+        let size = self.size.map(|size| size(&value));
+        self.inner.try_send(value)?;
+        self.channel.sent(log, size);
+        Ok(())
+    }
+
+    /// See [`crossbeam_channel::Sender::send_timeout`].
+    ///
+    /// Time spent waiting for capacity is reported even if the send times out.
+    pub fn send_timeout(&self, value: T, timeout: Duration) -> Result<(), SendTimeoutError<T>> {
+        let log = LogRecord::capture(self.log.as_ref(), &value);
+        let size = self.size.map(|size| size(&value));
+        let value = match self.inner.try_send(value) {
+            Ok(()) => {
+                self.channel.sent(log, size);
+                return Ok(());
+            }
+            Err(TrySendError::Full(value)) => value,
+            Err(TrySendError::Disconnected(value)) => {
+                return Err(SendTimeoutError::Disconnected(value))
+            }
+        };
+        let blocked_since = Instant::now();
+        let result = self.inner.send_timeout(value, timeout);
+        self.channel.send_blocked(blocked_since);
+        result?;
+        self.channel.sent(log, size);
+        Ok(())
+    }
+
+    /// See [`crossbeam_channel::Sender::send_deadline`].
+    pub fn send_deadline(
+        &self,
+        value: T,
+        deadline: std::time::Instant,
+    ) -> Result<(), SendTimeoutError<T>> {
+        self.send_timeout(
+            value,
+            deadline.saturating_duration_since(std::time::Instant::now()),
+        )
+    }
+}
+
+impl<T> Clone for Sender<T> {
+    fn clone(&self) -> Self {
+        self.channel.sender_cloned();
+        Self {
+            inner: self.inner.clone(),
+            channel: Arc::clone(&self.channel),
+            log: self.log.clone(),
+            size: self.size,
+        }
+    }
+}
+
+impl<T> Drop for Sender<T> {
+    fn drop(&mut self) {
+        self.channel.sender_dropped();
+    }
+}
+
+impl<T> Deref for Sender<T> {
+    type Target = crossbeam_channel::Sender<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<T> std::fmt::Debug for Sender<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.inner.fmt(f)
+    }
+}
+
+/// Instrumented `crossbeam_channel::Receiver`.
+pub struct Receiver<T> {
+    inner: crossbeam_channel::Receiver<T>,
+    channel: Arc<InlineChannel>,
+}
+
+impl<T> Receiver<T> {
+    /// See [`crossbeam_channel::Receiver::recv`].
+    pub fn recv(&self) -> Result<T, RecvError> {
+        let result = self.inner.recv();
+        match &result {
+            Ok(_) => self.channel.received(),
+            Err(RecvError) => self.channel.close(),
+        }
+        result
+    }
+
+    /// See [`crossbeam_channel::Receiver::try_recv`].
+    pub fn try_recv(&self) -> Result<T, TryRecvError> {
+        let result = self.inner.try_recv();
+        match &result {
+            Ok(_) => self.channel.received(),
+            Err(TryRecvError::Disconnected) => self.channel.close(),
+            Err(TryRecvError::Empty) => {}
+        }
+        result
+    }
+
+    /// See [`crossbeam_channel::Receiver::recv_timeout`].
+    pub fn recv_timeout(&self, timeout: Duration) -> Result<T, RecvTimeoutError> {
+        let result = self.inner.recv_timeout(timeout);
+        match &result {
+            Ok(_) => self.channel.received(),
+            Err(RecvTimeoutError::Disconnected) => self.channel.close(),
+            Err(RecvTimeoutError::Timeout) => {}
+        }
+        result
+    }
+
+    /// See [`crossbeam_channel::Receiver::recv_deadline`].
+    pub fn recv_deadline(&self, deadline: std::time::Instant) -> Result<T, RecvTimeoutError> {
+        self.recv_timeout(deadline.saturating_duration_since(std::time::Instant::now()))
+    }
+
+    /// See [`crossbeam_channel::Receiver::iter`].
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter { rx: self }
+    }
+
+    /// See [`crossbeam_channel::Receiver::try_iter`].
+    pub fn try_iter(&self) -> TryIter<'_, T> {
+        TryIter { rx: self }
+    }
+}
+
+impl<T> Clone for Receiver<T> {
+    fn clone(&self) -> Self {
+        self.channel.receiver_cloned();
+        Self {
+            inner: self.inner.clone(),
+            channel: Arc::clone(&self.channel),
+        }
+    }
+}
+
+impl<T> Drop for Receiver<T> {
+    fn drop(&mut self) {
+        self.channel.receiver_dropped();
+    }
+}
+
+impl<T> Deref for Receiver<T> {
+    type Target = crossbeam_channel::Receiver<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<T> std::fmt::Debug for Receiver<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.inner.fmt(f)
+    }
+}
+
+/// Blocking iterator over received messages, see [`Receiver::iter`].
+pub struct Iter<'a, T> {
+    rx: &'a Receiver<T>,
+}
+
+impl<T> Iterator for Iter<'_, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.rx.recv().ok()
+    }
+}
+
+/// Non-blocking iterator over pending messages, see [`Receiver::try_iter`].
+pub struct TryIter<'a, T> {
+    rx: &'a Receiver<T>,
+}
+
+impl<T> Iterator for TryIter<'_, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.rx.try_recv().ok()
+    }
+}
+
+/// Owning blocking iterator over received messages.
+pub struct IntoIter<T> {
+    rx: Receiver<T>,
+}
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.rx.recv().ok()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Receiver<T> {
+    type Item = T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+impl<T> IntoIterator for Receiver<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> IntoIter<T> {
+        IntoIter { rx: self }
+    }
+}
+
+fn wrap_channel<T>(
+    (tx, rx): (crossbeam_channel::Sender<T>, crossbeam_channel::Receiver<T>),
+    source: &'static str,
+    label: Option<String>,
+    log: Option<MessageLog<T>>,
+    size: Option<SizeFn<T>>,
+) -> (Sender<T>, Receiver<T>) {
+    // Crossbeam uses the same Sender/Receiver types for both bounded and unbounded
+    let channel_type = match tx.capacity() {
+        Some(capacity) => ChannelType::Bounded(capacity),
+        None => ChannelType::Unbounded,
+    };
+    let channel = InlineChannel::register::<T>(source, label, channel_type);
+    let sender = Sender {
+        inner: tx,
+        channel: Arc::clone(&channel),
+        log,
+        size,
+    };
+    (sender, Receiver { inner: rx, channel })
+}
+
+impl<T: Send + 'static> InstrumentChannelInline
+    for (crossbeam_channel::Sender<T>, crossbeam_channel::Receiver<T>)
+{
+    type Output = (Sender<T>, Receiver<T>);
+    fn instrument_inline(
+        self,
+        source: &'static str,
+        label: Option<String>,
+        _capacity: Option<usize>,
+    ) -> Self::Output {
+        wrap_channel(self, source, label, None, None)
+    }
+}
+
+impl<T: Send + std::fmt::Debug + 'static> InstrumentChannelInlineLog
+    for (crossbeam_channel::Sender<T>, crossbeam_channel::Receiver<T>)
+{
+    type Output = (Sender<T>, Receiver<T>);
+    fn instrument_inline_log(
+        self,
+        source: &'static str,
+        label: Option<String>,
+        _capacity: Option<usize>,
+    ) -> Self::Output {
+        wrap_channel(self, source, label, Some(MessageLog::debug()), None)
+    }
+}
+
+impl<T: Send + 'static> InstrumentChannelInlineOptions
+    for (crossbeam_channel::Sender<T>, crossbeam_channel::Receiver<T>)
+{
+    type Message = T;
+    type Output = (Sender<T>, Receiver<T>);
+    fn instrument_inline_with_options(
+        self,
+        source: &'static str,
+        label: Option<String>,
+        _capacity: Option<usize>,
+        log: Option<MessageLog<T>>,
+        size: Option<SizeFn<T>>,
+    ) -> Self::Output {
+        wrap_channel(self, source, label, log, size)
+    }
+}
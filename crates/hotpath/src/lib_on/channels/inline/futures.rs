@@ -0,0 +1,470 @@
+//! Inline instrumentation for `futures_channel::mpsc` channels.
+
+use std::ops::Deref;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+use futures_channel::mpsc::{self, SendError, TryRecvError, TrySendError};
+use futures_util::sink::Sink;
+use futures_util::stream::{FusedStream, Stream};
+
+use super::{InlineChannel, Instant, SizeFn};
+use crate::channels::{
+    ChannelType, InstrumentChannelInline, InstrumentChannelInlineLog,
+    InstrumentChannelInlineOptions, LogRecord, MessageLog,
+};
+
+/// Instrumented bounded `futures_channel::mpsc::Sender`.
+///
+/// Messages are sent through the [`Sink`] implementation, e.g. `SinkExt::send`, or
+/// [`Sender::try_send`].
+pub struct Sender<T> {
+    inner: mpsc::Sender<T>,
+    channel: Arc<InlineChannel>,
+    log: Option<MessageLog<T>>,
+    size: Option<SizeFn<T>>,
+    /// Set once `poll_ready` found the channel full, until it has capacity again. Any other
+    /// operation in between means the blocked send was abandoned (e.g. dropped by
+    /// `select!`), so the idle time until the next `poll_ready` isn't reported as blocked.
+    blocked_since: Option<Instant>,
+}
+
+impl<T> Sender<T> {
+    /// See [`futures_channel::mpsc::Sender::try_send`].
+    pub fn try_send(&mut self, msg: T) -> Result<(), TrySendError<T>> {
+        self.blocked_since = None;
+        let log = LogRecord::capture(self.log.as_ref(), &msg);
+        let size = self.size.map(|size| size(&msg));
+        self.inner.try_send(msg)?;
+        self.channel.sent(log, size);
+        Ok(())
+    }
+
+    /// See [`futures_channel::mpsc::Sender::close_channel`].
+    pub fn close_channel(&mut self) {
+        self.inner.close_channel();
+    }
+
+    /// See [`futures_channel::mpsc::Sender::disconnect`].
+    pub fn disconnect(&mut self) {
+        self.inner.disconnect();
+    }
+}
+
+impl<T> Sink<T> for Sender<T> {
+    type Error = SendError;
+
+    /// Reports how long the sender waited when the channel was full.
+    fn poll_ready(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), SendError>> {
+        let this = self.get_mut();
+        let poll = this.inner.poll_ready(cx);
+        match poll {
+            Poll::Pending => {
+                this.blocked_since.get_or_insert_with(Instant::now);
+            }
+            Poll::Ready(_) => {
+                if let Some(since) = this.blocked_since.take() {
+                    this.channel.send_blocked(since);
+                }
+            }
+        }
+        poll
+    }
+
+    fn start_send(self: Pin<&mut Self>, msg: T) -> Result<(), SendError> {
+        let this = self.get_mut();
+        this.blocked_since = None;
+        let log = LogRecord::capture(this.log.as_ref(), &msg);
+        let size = this.size.map(|size| size(&msg));
+        this.inner.start_send(msg)?;
+        this.channel.sent(log, size);
+        Ok(())
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), SendError>> {
+        let this = self.get_mut();
+        this.blocked_since = None;
+        Pin::new(&mut this.inner).poll_flush(cx)
+    }
+
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), SendError>> {
+        let this = self.get_mut();
+        this.blocked_since = None;
+        Pin::new(&mut this.inner).poll_close(cx)
+    }
+}
+
+impl<T> Clone for Sender<T> {
+    fn clone(&self) -> Self {
+        self.channel.sender_cloned();
+        Self {
+            inner: self.inner.clone(),
+            channel: Arc::clone(&self.channel),
+            log: self.log.clone(),
+            size: self.size,
+            blocked_since: None,
+        }
+    }
+}
+
+impl<T> Drop for Sender<T> {
+    fn drop(&mut self) {
+        self.channel.sender_dropped();
+    }
+}
+
+impl<T> Deref for Sender<T> {
+    type Target = mpsc::Sender<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<T> std::fmt::Debug for Sender<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.inner.fmt(f)
+    }
+}
+
+/// Instrumented bounded `futures_channel::mpsc::Receiver`, read through [`Stream`].
+pub struct Receiver<T> {
+    inner: mpsc::Receiver<T>,
+    channel: Arc<InlineChannel>,
+}
+
+impl<T> Receiver<T> {
+    /// See [`futures_channel::mpsc::Receiver::try_recv`].
+    pub fn try_recv(&mut self) -> Result<T, TryRecvError> {
+        let result = self.inner.try_recv();
+        observe_try_recv(&self.channel, &result);
+        result
+    }
+
+    /// See [`futures_channel::mpsc::Receiver::close`].
+    pub fn close(&mut self) {
+        self.inner.close();
+    }
+}
+
+impl<T> Stream for Receiver<T> {
+    type Item = T;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<T>> {
+        let this = self.get_mut();
+        let poll = Pin::new(&mut this.inner).poll_next(cx);
+        poll.map(|msg| observe(&this.channel, msg))
+    }
+}
+
+impl<T> FusedStream for Receiver<T> {
+    fn is_terminated(&self) -> bool {
+        self.inner.is_terminated()
+    }
+}
+
+impl<T> Drop for Receiver<T> {
+    fn drop(&mut self) {
+        self.channel.receiver_dropped();
+    }
+}
+
+impl<T> Deref for Receiver<T> {
+    type Target = mpsc::Receiver<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<T> std::fmt::Debug for Receiver<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.inner.fmt(f)
+    }
+}
+
+/// Instrumented `futures_channel::mpsc::UnboundedSender`.
+pub struct UnboundedSender<T> {
+    inner: mpsc::UnboundedSender<T>,
+    channel: Arc<InlineChannel>,
+    log: Option<MessageLog<T>>,
+    size: Option<SizeFn<T>>,
+}
+
+impl<T> UnboundedSender<T> {
+    /// See [`futures_channel::mpsc::UnboundedSender::unbounded_send`].
+    pub fn unbounded_send(&self, msg: T) -> Result<(), TrySendError<T>> {
+        let log = LogRecord::capture(self.log.as_ref(), &msg);
+        let size = self.size.map(|size| size(&msg));
+        self.inner.unbounded_send(msg)?;
+        self.channel.sent(log, size);
+        Ok(())
+    }
+}
+
+impl<T> Sink<T> for UnboundedSender<T> {
+    type Error = SendError;
+
+    fn poll_ready(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), SendError>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn start_send(self: Pin<&mut Self>, msg: T) -> Result<(), SendError> {
+        let this = self.get_mut();
+        let log = LogRecord::capture(this.log.as_ref(), &msg);
+        let size = this.size.map(|size| size(&msg));
+        this.inner.start_send(msg)?;
+        this.channel.sent(log, size);
+        Ok(())
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), SendError>> {
+        Pin::new(&mut self.get_mut().inner).poll_flush(cx)
+    }
+
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), SendError>> {
+        Pin::new(&mut self.get_mut().inner).poll_close(cx)
+    }
+}
+
+impl<T> Clone for UnboundedSender<T> {
+    fn clone(&self) -> Self {
+        self.channel.sender_cloned();
+        Self {
+            inner: self.inner.clone(),
+            channel: Arc::clone(&self.channel),
+            log: self.log.clone(),
+            size: self.size,
+        }
+    }
+}
+
+impl<T> Drop for UnboundedSender<T> {
+    fn drop(&mut self) {
+        self.channel.sender_dropped();
+    }
+}
+
+impl<T> Deref for UnboundedSender<T> {
+    type Target = mpsc::UnboundedSender<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<T> std::fmt::Debug for UnboundedSender<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.inner.fmt(f)
+    }
+}
+
+/// Instrumented `futures_channel::mpsc::UnboundedReceiver`, read through [`Stream`].
+pub struct UnboundedReceiver<T> {
+    inner: mpsc::UnboundedReceiver<T>,
+    channel: Arc<InlineChannel>,
+}
+
+impl<T> UnboundedReceiver<T> {
+    /// See [`futures_channel::mpsc::UnboundedReceiver::try_recv`].
+    pub fn try_recv(&mut self) -> Result<T, TryRecvError> {
+        let result = self.inner.try_recv();
+        observe_try_recv(&self.channel, &result);
+        result
+    }
+
+    /// See [`futures_channel::mpsc::UnboundedReceiver::close`].
+    pub fn close(&mut self) {
+        self.inner.close();
+    }
+}
+
+impl<T> Stream for UnboundedReceiver<T> {
+    type Item = T;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<T>> {
+        let this = self.get_mut();
+        let poll = Pin::new(&mut this.inner).poll_next(cx);
+        poll.map(|msg| observe(&this.channel, msg))
+    }
+}
+
+impl<T> FusedStream for UnboundedReceiver<T> {
+    fn is_terminated(&self) -> bool {
+        self.inner.is_terminated()
+    }
+}
+
+impl<T> Drop for UnboundedReceiver<T> {
+    fn drop(&mut self) {
+        self.channel.receiver_dropped();
+    }
+}
+
+impl<T> Deref for UnboundedReceiver<T> {
+    type Target = mpsc::UnboundedReceiver<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<T> std::fmt::Debug for UnboundedReceiver<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.inner.fmt(f)
+    }
+}
+
+fn observe<T>(channel: &InlineChannel, msg: Option<T>) -> Option<T> {
+    match msg {
+        Some(msg) => {
+            channel.received();
+            Some(msg)
+        }
+        None => {
+            channel.close();
+            None
+        }
+    }
+}
+
+fn observe_try_recv<T>(channel: &InlineChannel, result: &Result<T, TryRecvError>) {
+    match result {
+        Ok(_) => channel.received(),
+        Err(TryRecvError::Closed) => channel.close(),
+        Err(TryRecvError::Empty) => {}
+    }
+}
+
+fn wrap_channel<T>(
+    (tx, rx): (mpsc::Sender<T>, mpsc::Receiver<T>),
+    source: &'static str,
+    label: Option<String>,
+    capacity: Option<usize>,
+    log: Option<MessageLog<T>>,
+    size: Option<SizeFn<T>>,
+) -> (Sender<T>, Receiver<T>) {
+    let Some(capacity) = capacity else {
+        panic!("Capacity is required for bounded futures channels, because they don't expose their capacity in a public API");
+    };
+    let channel = InlineChannel::register::<T>(source, label, ChannelType::Bounded(capacity));
+    let sender = Sender {
+        inner: tx,
+        channel: Arc::clone(&channel),
+        log,
+        size,
+        blocked_since: None,
+    };
+    (sender, Receiver { inner: rx, channel })
+}
+
+fn wrap_unbounded<T>(
+    (tx, rx): (mpsc::UnboundedSender<T>, mpsc::UnboundedReceiver<T>),
+    source: &'static str,
+    label: Option<String>,
+    log: Option<MessageLog<T>>,
+    size: Option<SizeFn<T>>,
+) -> (UnboundedSender<T>, UnboundedReceiver<T>) {
+    let channel = InlineChannel::register::<T>(source, label, ChannelType::Unbounded);
+    let sender = UnboundedSender {
+        inner: tx,
+        channel: Arc::clone(&channel),
+        log,
+        size,
+    };
+    (sender, UnboundedReceiver { inner: rx, channel })
+}
+
+impl<T: Send + 'static> InstrumentChannelInline for (mpsc::Sender<T>, mpsc::Receiver<T>) {
+    type Output = (Sender<T>, Receiver<T>);
+    fn instrument_inline(
+        self,
+        source: &'static str,
+        label: Option<String>,
+        capacity: Option<usize>,
+    ) -> Self::Output {
+        wrap_channel(self, source, label, capacity, None, None)
+    }
+}
+
+impl<T: Send + 'static> InstrumentChannelInline
+    for (mpsc::UnboundedSender<T>, mpsc::UnboundedReceiver<T>)
+{
+    type Output = (UnboundedSender<T>, UnboundedReceiver<T>);
+    fn instrument_inline(
+        self,
+        source: &'static str,
+        label: Option<String>,
+        _capacity: Option<usize>,
+    ) -> Self::Output {
+        wrap_unbounded(self, source, label, None, None)
+    }
+}
+
+impl<T: Send + std::fmt::Debug + 'static> InstrumentChannelInlineLog
+    for (mpsc::Sender<T>, mpsc::Receiver<T>)
+{
+    type Output = (Sender<T>, Receiver<T>);
+    fn instrument_inline_log(
+        self,
+        source: &'static str,
+        label: Option<String>,
+        capacity: Option<usize>,
+    ) -> Self::Output {
+        wrap_channel(
+            self,
+            source,
+            label,
+            capacity,
+            Some(MessageLog::debug()),
+            None,
+        )
+    }
+}
+
+impl<T: Send + std::fmt::Debug + 'static> InstrumentChannelInlineLog
+    for (mpsc::UnboundedSender<T>, mpsc::UnboundedReceiver<T>)
+{
+    type Output = (UnboundedSender<T>, UnboundedReceiver<T>);
+    fn instrument_inline_log(
+        self,
+        source: &'static str,
+        label: Option<String>,
+        _capacity: Option<usize>,
+    ) -> Self::Output {
+        wrap_unbounded(self, source, label, Some(MessageLog::debug()), None)
+    }
+}
+
+impl<T: Send + 'static> InstrumentChannelInlineOptions for (mpsc::Sender<T>, mpsc::Receiver<T>) {
+    type Message = T;
+    type Output = (Sender<T>, Receiver<T>);
+    fn instrument_inline_with_options(
+        self,
+        source: &'static str,
+        label: Option<String>,
+        capacity: Option<usize>,
+        log: Option<MessageLog<T>>,
+        size: Option<SizeFn<T>>,
+    ) -> Self::Output {
+        wrap_channel(self, source, label, capacity, log, size)
+    }
+}
+
+impl<T: Send + 'static> InstrumentChannelInlineOptions
+    for (mpsc::UnboundedSender<T>, mpsc::UnboundedReceiver<T>)
+{
+    type Message = T;
+    type Output = (UnboundedSender<T>, UnboundedReceiver<T>);
+    fn instrument_inline_with_options(
+        self,
+        source: &'static str,
+        label: Option<String>,
+        _capacity: Option<usize>,
+        log: Option<MessageLog<T>>,
+        size: Option<SizeFn<T>>,
+    ) -> Self::Output {
+        wrap_unbounded(self, source, label, log, size)
+    }
+}
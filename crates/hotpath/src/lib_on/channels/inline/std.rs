@@ -0,0 +1,392 @@
+//! Inline instrumentation for `std::sync::mpsc` channels.
+
+use std::ops::Deref;
+use std::sync::mpsc::{self, RecvError, RecvTimeoutError, SendError, TryRecvError, TrySendError};
+use std::sync::Arc;
+use std::time::Duration;
+
+use super::{InlineChannel, Instant, SizeFn};
+use crate::channels::{
+    ChannelType, InstrumentChannelInline, InstrumentChannelInlineLog,
+    InstrumentChannelInlineOptions, LogRecord, MessageLog,
+};
+
+/// Instrumented `std::sync::mpsc::Sender`.
+pub struct Sender<T> {
+    inner: mpsc::Sender<T>,
+    channel: Arc<InlineChannel>,
+    log: Option<MessageLog<T>>,
+    size: Option<SizeFn<T>>,
+}
+
+impl<T> Sender<T> {
+    /// See [`std::sync::mpsc::Sender::send`].
+    pub fn send(&self, value: T) -> Result<(), SendError<T>> {
+        let log = LogRecord::capture(self.log.as_ref(), &value);
+        let size = self.size.map(|size| size(&value));
+        self.inner.send(value)?;
+        self.channel.sent(log, size);
+        Ok(())
+    }
+}
+
+impl<T> Clone for Sender<T> {
+    fn clone(&self) -> Self {
+        self.channel.sender_cloned();
+        Self {
+            inner: self.inner.clone(),
+            channel: Arc::clone(&self.channel),
+            log: self.log.clone(),
+            size: self.size,
+        }
+    }
+}
+
+impl<T> Drop for Sender<T> {
+    fn drop(&mut self) {
+        self.channel.sender_dropped();
+    }
+}
+
+impl<T> Deref for Sender<T> {
+    type Target = mpsc::Sender<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<T> std::fmt::Debug for Sender<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.inner.fmt(f)
+    }
+}
+
+/// Instrumented `std::sync::mpsc::SyncSender`.
+pub struct SyncSender<T> {
+    inner: mpsc::SyncSender<T>,
+    channel: Arc<InlineChannel>,
+    log: Option<MessageLog<T>>,
+    size: Option<SizeFn<T>>,
+}
+
+impl<T> SyncSender<T> {
+    /// See [`std::sync::mpsc::SyncSender::send`].
+    ///
+    /// A send that finds the channel full reports how long it waited for capacity.
+    pub fn send(&self, value: T) -> Result<(), SendError<T>> {
+        let log = LogRecord::capture(self.log.as_ref(), &value);
+        let size = self.size.map(|size| size(&value));
+        let value = match self.inner.try_send(value) {
+            Ok(()) => {
+                self.channel.sent(log, size);
+                return Ok(());
+            }
+            Err(TrySendError::Full(value)) => value,
+            Err(TrySendError::Disconnected(value)) => return Err(SendError(value)),
+        };
+        let blocked_since = Instant::now();
+        let result = self.inner.send(value);
+        self.channel.send_blocked(blocked_since);
+        result?;
+        self.channel.sent(log, size);
+        Ok(())
+    }
+
+    /// See [`std::sync::mpsc::SyncSender::try_send`].
+    pub fn try_send(&self, value: T) -> Result<(), TrySendError<T>> {
+        let log = LogRecord::capture(self.log.as_ref(), &value);
+        let size = self.size.map(|size| size(&value));
+        self.inner.try_send(value)?;
+        self.channel.sent(log, size);
+        Ok(())
+    }
+}
+
+impl<T> Clone for SyncSender<T> {
+    fn clone(&self) -> Self {
+        self.channel.sender_cloned();
+        Self {
+            inner: self.inner.clone(),
+            channel: Arc::clone(&self.channel),
+            log: self.log.clone(),
+            size: self.size,
+        }
+    }
+}
+
+impl<T> Drop for SyncSender<T> {
+    fn drop(&mut self) {
+        self.channel.sender_dropped();
+    }
+}
+
+impl<T> Deref for SyncSender<T> {
+    type Target = mpsc::SyncSender<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<T> std::fmt::Debug for SyncSender<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.inner.fmt(f)
+    }
+}
+
+/// Instrumented `std::sync::mpsc::Receiver`.
+pub struct Receiver<T> {
+    inner: mpsc::Receiver<T>,
+    channel: Arc<InlineChannel>,
+}
+
+impl<T> Receiver<T> {
+    /// See [`std::sync::mpsc::Receiver::recv`].
+    pub fn recv(&self) -> Result<T, RecvError> {
+        let result = self.inner.recv();
+        match &result {
+            Ok(_) => self.channel.received(),
+            Err(RecvError) => self.channel.close(),
+        }
+        result
+    }
+
+    /// See [`std::sync::mpsc::Receiver::try_recv`].
+    pub fn try_recv(&self) -> Result<T, TryRecvError> {
+        let result = self.inner.try_recv();
+        match &result {
+            Ok(_) => self.channel.received(),
+            Err(TryRecvError::Disconnected) => self.channel.close(),
+            Err(TryRecvError::Empty) => {}
+        }
+        result
+    }
+
+    /// See [`std::sync::mpsc::Receiver::recv_timeout`].
+    pub fn recv_timeout(&self, timeout: Duration) -> Result<T, RecvTimeoutError> {
+        let result = self.inner.recv_timeout(timeout);
+        match &result {
+            Ok(_) => self.channel.received(),
+            Err(RecvTimeoutError::Disconnected) => self.channel.close(),
+            Err(RecvTimeoutError::Timeout) => {}
+        }
+        result
+    }
+
+    /// See [`std::sync::mpsc::Receiver::iter`].
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter { rx: self }
+    }
+
+    /// See [`std::sync::mpsc::Receiver::try_iter`].
+    pub fn try_iter(&self) -> TryIter<'_, T> {
+        TryIter { rx: self }
+    }
+}
+
+impl<T> Drop for Receiver<T> {
+    fn drop(&mut self) {
+        self.channel.receiver_dropped();
+    }
+}
+
+impl<T> Deref for Receiver<T> {
+    type Target = mpsc::Receiver<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<T> std::fmt::Debug for Receiver<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.inner.fmt(f)
+    }
+}
+
+/// Blocking iterator over received messages, see [`Receiver::iter`].
+pub struct Iter<'a, T> {
+    rx: &'a Receiver<T>,
+}
+
+impl<T> Iterator for Iter<'_, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.rx.recv().ok()
+    }
+}
+
+/// Non-blocking iterator over pending messages, see [`Receiver::try_iter`].
+pub struct TryIter<'a, T> {
+    rx: &'a Receiver<T>,
+}
+
+impl<T> Iterator for TryIter<'_, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.rx.try_recv().ok()
+    }
+}
+
+/// Owning blocking iterator over received messages.
+pub struct IntoIter<T> {
+    rx: Receiver<T>,
+}
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.rx.recv().ok()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Receiver<T> {
+    type Item = T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+impl<T> IntoIterator for Receiver<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> IntoIter<T> {
+        IntoIter { rx: self }
+    }
+}
+
+fn wrap_channel<T>(
+    (tx, rx): (mpsc::Sender<T>, mpsc::Receiver<T>),
+    source: &'static str,
+    label: Option<String>,
+    log: Option<MessageLog<T>>,
+    size: Option<SizeFn<T>>,
+) -> (Sender<T>, Receiver<T>) {
+    let channel = InlineChannel::register::<T>(source, label, ChannelType::Unbounded);
+    let sender = Sender {
+        inner: tx,
+        channel: Arc::clone(&channel),
+        log,
+        size,
+    };
+    (sender, Receiver { inner: rx, channel })
+}
+
+fn wrap_sync_channel<T>(
+    (tx, rx): (mpsc::SyncSender<T>, mpsc::Receiver<T>),
+    source: &'static str,
+    label: Option<String>,
+    capacity: Option<usize>,
+    log: Option<MessageLog<T>>,
+    size: Option<SizeFn<T>>,
+) -> (SyncSender<T>, Receiver<T>) {
+    let Some(capacity) = capacity else {
+        panic!("Capacity is required for bounded std channels, because they don't expose their capacity in a public API");
+    };
+    let channel = InlineChannel::register::<T>(source, label, ChannelType::Bounded(capacity));
+    let sender = SyncSender {
+        inner: tx,
+        channel: Arc::clone(&channel),
+        log,
+        size,
+    };
+    (sender, Receiver { inner: rx, channel })
+}
+
+impl<T: Send + 'static> InstrumentChannelInline for (mpsc::Sender<T>, mpsc::Receiver<T>) {
+    type Output = (Sender<T>, Receiver<T>);
+    fn instrument_inline(
+        self,
+        source: &'static str,
+        label: Option<String>,
+        _capacity: Option<usize>,
+    ) -> Self::Output {
+        wrap_channel(self, source, label, None, None)
+    }
+}
+
+impl<T: Send + 'static> InstrumentChannelInline for (mpsc::SyncSender<T>, mpsc::Receiver<T>) {
+    type Output = (SyncSender<T>, Receiver<T>);
+    fn instrument_inline(
+        self,
+        source: &'static str,
+        label: Option<String>,
+        capacity: Option<usize>,
+    ) -> Self::Output {
+        wrap_sync_channel(self, source, label, capacity, None, None)
+    }
+}
+
+impl<T: Send + std::fmt::Debug + 'static> InstrumentChannelInlineLog
+    for (mpsc::Sender<T>, mpsc::Receiver<T>)
+{
+    type Output = (Sender<T>, Receiver<T>);
+    fn instrument_inline_log(
+        self,
+        source: &'static str,
+        label: Option<String>,
+        _capacity: Option<usize>,
+    ) -> Self::Output {
+        wrap_channel(self, source, label, Some(MessageLog::debug()), None)
+    }
+}
+
+impl<T: Send + std::fmt::Debug + 'static> InstrumentChannelInlineLog
+    for (mpsc::SyncSender<T>, mpsc::Receiver<T>)
+{
+    type Output = (SyncSender<T>, Receiver<T>);
+    fn instrument_inline_log(
+        self,
+        source: &'static str,
+        label: Option<String>,
+        capacity: Option<usize>,
+    ) -> Self::Output {
+        wrap_sync_channel(
+            self,
+            source,
+            label,
+            capacity,
+            Some(MessageLog::debug()),
+            None,
+        )
+    }
+}
+
+impl<T: Send + 'static> InstrumentChannelInlineOptions for (mpsc::Sender<T>, mpsc::Receiver<T>) {
+    type Message = T;
+    type Output = (Sender<T>, Receiver<T>);
+    fn instrument_inline_with_options(
+        self,
+        source: &'static str,
+        label: Option<String>,
+        _capacity: Option<usize>,
+        log: Option<MessageLog<T>>,
+        size: Option<SizeFn<T>>,
+    ) -> Self::Output {
+        wrap_channel(self, source, label, log, size)
+    }
+}
+
+impl<T: Send + 'static> InstrumentChannelInlineOptions
+    for (mpsc::SyncSender<T>, mpsc::Receiver<T>)
+{
+    type Message = T;
+    type Output = (SyncSender<T>, Receiver<T>);
+    fn instrument_inline_with_options(
+        self,
+        source: &'static str,
+        label: Option<String>,
+        capacity: Option<usize>,
+        log: Option<MessageLog<T>>,
+        size: Option<SizeFn<T>>,
+    ) -> Self::Output {
+        wrap_sync_channel(self, source, label, capacity, log, size)
+    }
+}
@@ -0,0 +1,782 @@
+//! Inline instrumentation for `tokio::sync::mpsc` and `tokio::sync::oneshot` channels.
+
+use std::future::Future;
+use std::ops::{Deref, DerefMut};
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+use tokio::sync::mpsc::error::{SendError, SendTimeoutError, TryRecvError, TrySendError};
+use tokio::sync::{mpsc, oneshot};
+
+use super::{InlineChannel, Instant, SizeFn};
+use crate::channels::{
+    ChannelType, InstrumentChannelInline, InstrumentChannelInlineLog,
+    InstrumentChannelInlineOptions, LogRecord, MessageLog,
+};
+
+/// Instrumented bounded `tokio::sync::mpsc::Sender`.
+pub struct Sender<T> {
+    inner: mpsc::Sender<T>,
+    channel: Arc<InlineChannel>,
+    log: Option<MessageLog<T>>,
+    size: Option<SizeFn<T>>,
+}
+
+impl<T> Sender<T> {
+    /// See [`tokio::sync::mpsc::Sender::send`].
+    ///
+    /// A send that finds the channel full reports how long it waited for capacity.
+    pub async fn send(&self, value: T) -> Result<(), SendError<T>> {
+        let log = LogRecord::capture(self.log.as_ref(), &value);
+        let size = self.size.map(|size| size(&value));
+        let value = match self.inner.try_send(value) {
+            Ok(()) => {
+                self.channel.sent(log, size);
+                return Ok(());
+            }
+            Err(TrySendError::Full(value)) => value,
+            Err(TrySendError::Closed(value)) => return Err(SendError(value)),
+        };
+        let blocked_since = Instant::now();
+        let result = self.inner.send(value).await;
+        self.channel.send_blocked(blocked_since);
+        result?;
+        self.channel.sent(log, size);
+        Ok(())
+    }
+
+    /// See [`tokio::sync::mpsc::Sender::try_send`].
+    pub fn try_send(&self, value: T) -> Result<(), TrySendError<T>> {
+        let log = LogRecord::capture(self.log.as_ref(), &value);
+        let size = self.size.map(|size| size(&value));
+        self.inner.try_send(value)?;
+        self.channel.sent(log, size);
+        Ok(())
+    }
+
+    /// See [`tokio::sync::mpsc::Sender::send_timeout`].
+    pub async fn send_timeout(
+        &self,
+        value: T,
+        timeout: Duration,
+    ) -> Result<(), SendTimeoutError<T>> {
+        let log = LogRecord::capture(self.log.as_ref(), &value);
+        let size = self.size.map(|size| size(&value));
+        let value = match self.inner.try_send(value) {
+            Ok(()) => {
+                self.channel.sent(log, size);
+                return Ok(());
+            }
+            Err(TrySendError::Full(value)) => value,
+            Err(TrySendError::Closed(value)) => return Err(SendTimeoutError::Closed(value)),
+        };
+        let blocked_since = Instant::now();
+        let result = self.inner.send_timeout(value, timeout).await;
+        self.channel.send_blocked(blocked_since);
+        result?;
+        self.channel.sent(log, size);
+        Ok(())
+    }
+
+    /// See [`tokio::sync::mpsc::Sender::blocking_send`].
+    pub fn blocking_send(&self, value: T) -> Result<(), SendError<T>> {
+        let log = LogRecord::capture(self.log.as_ref(), &value);
+        let size = self.size.map(|size| size(&value));
+        let value = match self.inner.try_send(value) {
+            Ok(()) => {
+                self.channel.sent(log, size);
+                return Ok(());
+            }
+            Err(TrySendError::Full(value)) => value,
+            Err(TrySendError::Closed(value)) => return Err(SendError(value)),
+        };
+        let blocked_since = Instant::now();
+        let result = self.inner.blocking_send(value);
+        self.channel.send_blocked(blocked_since);
+        result?;
+        self.channel.sent(log, size);
+        Ok(())
+    }
+
+    /// See [`tokio::sync::mpsc::Sender::reserve`].
+    ///
+    /// Waiting for capacity is reported like a blocked send, the message is counted once
+    /// it is sent through the returned permit.
+    pub async fn reserve(&self) -> Result<Permit<'_, T>, SendError<()>> {
+        let inner = match self.inner.try_reserve() {
+            Ok(permit) => permit,
+            Err(TrySendError::Full(())) => {
+                let blocked_since = Instant::now();
+                let result = self.inner.reserve().await;
+                self.channel.send_blocked(blocked_since);
+                result?
+            }
+            Err(TrySendError::Closed(())) => return Err(SendError(())),
+        };
+        Ok(Permit {
+            inner,
+            sender: self,
+        })
+    }
+
+    /// See [`tokio::sync::mpsc::Sender::try_reserve`].
+    pub fn try_reserve(&self) -> Result<Permit<'_, T>, TrySendError<()>> {
+        let inner = self.inner.try_reserve()?;
+        Ok(Permit {
+            inner,
+            sender: self,
+        })
+    }
+
+    /// See [`tokio::sync::mpsc::Sender::closed`].
+    pub async fn closed(&self) {
+        self.inner.closed().await
+    }
+
+    /// See [`tokio::sync::mpsc::Sender::is_closed`].
+    pub fn is_closed(&self) -> bool {
+        self.inner.is_closed()
+    }
+
+    /// See [`tokio::sync::mpsc::Sender::capacity`].
+    pub fn capacity(&self) -> usize {
+        self.inner.capacity()
+    }
+
+    /// See [`tokio::sync::mpsc::Sender::max_capacity`].
+    pub fn max_capacity(&self) -> usize {
+        self.inner.max_capacity()
+    }
+
+    /// See [`tokio::sync::mpsc::Sender::same_channel`].
+    pub fn same_channel(&self, other: &Self) -> bool {
+        self.inner.same_channel(&other.inner)
+    }
+}
+
+/// Instrumented [`tokio::sync::mpsc::Permit`], counts the message sent through it.
+pub struct Permit<'a, T> {
+    inner: mpsc::Permit<'a, T>,
+    sender: &'a Sender<T>,
+}
+
+impl<T> Permit<'_, T> {
+    /// See [`tokio::sync::mpsc::Permit::send`].
+    pub fn send(self, value: T) {
+        let log = LogRecord::capture(self.sender.log.as_ref(), &value);
+        let size = self.sender.size.map(|size| size(&value));
+        self.inner.send(value);
+        self.sender.channel.sent(log, size);
+    }
+}
+
+impl<T> std::fmt::Debug for Permit<'_, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.inner.fmt(f)
+    }
+}
+
+impl<T> Clone for Sender<T> {
+    fn clone(&self) -> Self {
+        self.channel.sender_cloned();
+        Self {
+            inner: self.inner.clone(),
+            channel: Arc::clone(&self.channel),
+            log: self.log.clone(),
+            size: self.size,
+        }
+    }
+}
+
+impl<T> Drop for Sender<T> {
+    fn drop(&mut self) {
+        self.channel.sender_dropped();
+    }
+}
+
+impl<T> std::fmt::Debug for Sender<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.inner.fmt(f)
+    }
+}
+
+/// Instrumented bounded `tokio::sync::mpsc::Receiver`.
+pub struct Receiver<T> {
+    inner: mpsc::Receiver<T>,
+    channel: Arc<InlineChannel>,
+}
+
+impl<T> Receiver<T> {
+    fn observe(&self, msg: Option<T>) -> Option<T> {
+        match msg {
+            Some(msg) => {
+                self.channel.received();
+                Some(msg)
+            }
+            None => {
+                self.channel.close();
+                None
+            }
+        }
+    }
+
+    /// `recv_many` returns 0 only once the channel is closed and drained
+    fn observe_many(&self, received: usize, limit: usize) -> usize {
+        if received == 0 && limit > 0 {
+            self.channel.close();
+        }
+        for _ in 0..received {
+            self.channel.received();
+        }
+        received
+    }
+
+    /// See [`tokio::sync::mpsc::Receiver::recv`].
+    pub async fn recv(&mut self) -> Option<T> {
+        let msg = self.inner.recv().await;
+        self.observe(msg)
+    }
+
+    /// See [`tokio::sync::mpsc::Receiver::try_recv`].
+    pub fn try_recv(&mut self) -> Result<T, TryRecvError> {
+        let result = self.inner.try_recv();
+        match &result {
+            Ok(_) => self.channel.received(),
+            Err(TryRecvError::Disconnected) => self.channel.close(),
+            Err(TryRecvError::Empty) => {}
+        }
+        result
+    }
+
+    /// See [`tokio::sync::mpsc::Receiver::blocking_recv`].
+    pub fn blocking_recv(&mut self) -> Option<T> {
+        let msg = self.inner.blocking_recv();
+        self.observe(msg)
+    }
+
+    /// See [`tokio::sync::mpsc::Receiver::poll_recv`].
+    pub fn poll_recv(&mut self, cx: &mut Context<'_>) -> Poll<Option<T>> {
+        self.inner.poll_recv(cx).map(|msg| self.observe(msg))
+    }
+
+    /// See [`tokio::sync::mpsc::Receiver::recv_many`].
+    pub async fn recv_many(&mut self, buffer: &mut Vec<T>, limit: usize) -> usize {
+        let received = self.inner.recv_many(buffer, limit).await;
+        self.observe_many(received, limit)
+    }
+
+    /// See [`tokio::sync::mpsc::Receiver::blocking_recv_many`].
+    pub fn blocking_recv_many(&mut self, buffer: &mut Vec<T>, limit: usize) -> usize {
+        let received = self.inner.blocking_recv_many(buffer, limit);
+        self.observe_many(received, limit)
+    }
+
+    /// See [`tokio::sync::mpsc::Receiver::poll_recv_many`].
+    pub fn poll_recv_many(
+        &mut self,
+        cx: &mut Context<'_>,
+        buffer: &mut Vec<T>,
+        limit: usize,
+    ) -> Poll<usize> {
+        self.inner
+            .poll_recv_many(cx, buffer, limit)
+            .map(|received| self.observe_many(received, limit))
+    }
+
+    /// See [`tokio::sync::mpsc::Receiver::close`].
+    pub fn close(&mut self) {
+        self.inner.close();
+    }
+}
+
+impl<T> Drop for Receiver<T> {
+    fn drop(&mut self) {
+        self.channel.receiver_dropped();
+    }
+}
+
+impl<T> Deref for Receiver<T> {
+    type Target = mpsc::Receiver<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<T> std::fmt::Debug for Receiver<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.inner.fmt(f)
+    }
+}
+
+/// Instrumented `tokio::sync::mpsc::UnboundedSender`.
+pub struct UnboundedSender<T> {
+    inner: mpsc::UnboundedSender<T>,
+    channel: Arc<InlineChannel>,
+    log: Option<MessageLog<T>>,
+    size: Option<SizeFn<T>>,
+}
+
+impl<T> UnboundedSender<T> {
+    /// See [`tokio::sync::mpsc::UnboundedSender::send`].
+    pub fn send(&self, value: T) -> Result<(), SendError<T>> {
+        let log = LogRecord::capture(self.log.as_ref(), &value);
+        let size = self.size.map(|size| size(&value));
+        self.inner.send(value)?;
+        self.channel.sent(log, size);
+        Ok(())
+    }
+
+    /// See [`tokio::sync::mpsc::UnboundedSender::closed`].
+    pub async fn closed(&self) {
+        self.inner.closed().await
+    }
+
+    /// See [`tokio::sync::mpsc::UnboundedSender::is_closed`].
+    pub fn is_closed(&self) -> bool {
+        self.inner.is_closed()
+    }
+
+    /// See [`tokio::sync::mpsc::UnboundedSender::same_channel`].
+    pub fn same_channel(&self, other: &Self) -> bool {
+        self.inner.same_channel(&other.inner)
+    }
+}
+
+impl<T> Clone for UnboundedSender<T> {
+    fn clone(&self) -> Self {
+        self.channel.sender_cloned();
+        Self {
+            inner: self.inner.clone(),
+            channel: Arc::clone(&self.channel),
+            log: self.log.clone(),
+            size: self.size,
+        }
+    }
+}
+
+impl<T> Drop for UnboundedSender<T> {
+    fn drop(&mut self) {
+        self.channel.sender_dropped();
+    }
+}
+
+impl<T> std::fmt::Debug for UnboundedSender<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.inner.fmt(f)
+    }
+}
+
+/// Instrumented `tokio::sync::mpsc::UnboundedReceiver`.
+pub struct UnboundedReceiver<T> {
+    inner: mpsc::UnboundedReceiver<T>,
+    channel: Arc<InlineChannel>,
+}
+
+impl<T> UnboundedReceiver<T> {
+    fn observe(&self, msg: Option<T>) -> Option<T> {
+        match msg {
+            Some(msg) => {
+                self.channel.received();
+                Some(msg)
+            }
+            None => {
+                self.channel.close();
+                None
+            }
+        }
+    }
+
+    /// `recv_many` returns 0 only once the channel is closed and drained
+    fn observe_many(&self, received: usize, limit: usize) -> usize {
+        if received == 0 && limit > 0 {
+            self.channel.close();
+        }
+        for _ in 0..received {
+            self.channel.received();
+        }
+        received
+    }
+
+    /// See [`tokio::sync::mpsc::UnboundedReceiver::recv`].
+    pub async fn recv(&mut self) -> Option<T> {
+        let msg = self.inner.recv().await;
+        self.observe(msg)
+    }
+
+    /// See [`tokio::sync::mpsc::UnboundedReceiver::try_recv`].
+    pub fn try_recv(&mut self) -> Result<T, TryRecvError> {
+        let result = self.inner.try_recv();
+        match &result {
+            Ok(_) => self.channel.received(),
+            Err(TryRecvError::Disconnected) => self.channel.close(),
+            Err(TryRecvError::Empty) => {}
+        }
+        result
+    }
+
+    /// See [`tokio::sync::mpsc::UnboundedReceiver::blocking_recv`].
+    pub fn blocking_recv(&mut self) -> Option<T> {
+        let msg = self.inner.blocking_recv();
+        self.observe(msg)
+    }
+
+    /// See [`tokio::sync::mpsc::UnboundedReceiver::poll_recv`].
+    pub fn poll_recv(&mut self, cx: &mut Context<'_>) -> Poll<Option<T>> {
+        self.inner.poll_recv(cx).map(|msg| self.observe(msg))
+    }
+
+    /// See [`tokio::sync::mpsc::UnboundedReceiver::recv_many`].
+    pub async fn recv_many(&mut self, buffer: &mut Vec<T>, limit: usize) -> usize {
+        let received = self.inner.recv_many(buffer, limit).await;
+        self.observe_many(received, limit)
+    }
+
+    /// See [`tokio::sync::mpsc::UnboundedReceiver::blocking_recv_many`].
+    pub fn blocking_recv_many(&mut self, buffer: &mut Vec<T>, limit: usize) -> usize {
+        let received = self.inner.blocking_recv_many(buffer, limit);
+        self.observe_many(received, limit)
+    }
+
+    /// See [`tokio::sync::mpsc::UnboundedReceiver::poll_recv_many`].
+    pub fn poll_recv_many(
+        &mut self,
+        cx: &mut Context<'_>,
+        buffer: &mut Vec<T>,
+        limit: usize,
+    ) -> Poll<usize> {
+        self.inner
+            .poll_recv_many(cx, buffer, limit)
+            .map(|received| self.observe_many(received, limit))
+    }
+
+    /// See [`tokio::sync::mpsc::UnboundedReceiver::close`].
+    pub fn close(&mut self) {
+        self.inner.close();
+    }
+}
+
+impl<T> Drop for UnboundedReceiver<T> {
+    fn drop(&mut self) {
+        self.channel.receiver_dropped();
+    }
+}
+
+impl<T> Deref for UnboundedReceiver<T> {
+    type Target = mpsc::UnboundedReceiver<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<T> std::fmt::Debug for UnboundedReceiver<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.inner.fmt(f)
+    }
+}
+
+/// Instrumented `tokio::sync::oneshot::Sender`.
+pub struct OneshotSender<T> {
+    inner: Option<oneshot::Sender<T>>,
+    channel: Arc<InlineChannel>,
+    log: Option<MessageLog<T>>,
+    size: Option<SizeFn<T>>,
+}
+
+impl<T> OneshotSender<T> {
+    /// See [`tokio::sync::oneshot::Sender::send`].
+    pub fn send(mut self, value: T) -> Result<(), T> {
+        let log = LogRecord::capture(self.log.as_ref(), &value);
+        let size = self.size.map(|size| size(&value));
+        let inner = self.inner.take().expect("oneshot sender used after send");
+        inner.send(value)?;
+        self.channel.sent(log, size);
+        Ok(())
+    }
+}
+
+impl<T> Drop for OneshotSender<T> {
+    fn drop(&mut self) {
+        // Dropped without sending, the receiver will observe an error
+        if self.inner.is_some() {
+            self.channel.close();
+        }
+    }
+}
+
+impl<T> Deref for OneshotSender<T> {
+    type Target = oneshot::Sender<T>;
+
+    fn deref(&self) -> &Self::Target {
+        self.inner.as_ref().expect("oneshot sender used after send")
+    }
+}
+
+impl<T> DerefMut for OneshotSender<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.inner.as_mut().expect("oneshot sender used after send")
+    }
+}
+
+impl<T: std::fmt::Debug> std::fmt::Debug for OneshotSender<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.inner.fmt(f)
+    }
+}
+
+/// Instrumented `tokio::sync::oneshot::Receiver`. Await it like the original.
+pub struct OneshotReceiver<T> {
+    inner: oneshot::Receiver<T>,
+    channel: Arc<InlineChannel>,
+}
+
+impl<T> OneshotReceiver<T> {
+    fn observe(
+        &self,
+        result: Result<T, oneshot::error::RecvError>,
+    ) -> Result<T, oneshot::error::RecvError> {
+        match &result {
+            Ok(_) => {
+                self.channel.received();
+                self.channel.notified();
+            }
+            Err(_) => self.channel.close(),
+        }
+        result
+    }
+
+    /// See [`tokio::sync::oneshot::Receiver::try_recv`].
+    pub fn try_recv(&mut self) -> Result<T, oneshot::error::TryRecvError> {
+        let result = self.inner.try_recv();
+        match &result {
+            Ok(_) => {
+                self.channel.received();
+                self.channel.notified();
+            }
+            Err(oneshot::error::TryRecvError::Closed) => self.channel.close(),
+            Err(oneshot::error::TryRecvError::Empty) => {}
+        }
+        result
+    }
+}
+
+impl<T> Future for OneshotReceiver<T> {
+    type Output = Result<T, oneshot::error::RecvError>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = &mut *self;
+        Pin::new(&mut this.inner)
+            .poll(cx)
+            .map(|result| this.observe(result))
+    }
+}
+
+impl<T> Drop for OneshotReceiver<T> {
+    fn drop(&mut self) {
+        self.channel.close();
+    }
+}
+
+impl<T> Deref for OneshotReceiver<T> {
+    type Target = oneshot::Receiver<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<T> DerefMut for OneshotReceiver<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.inner
+    }
+}
+
+impl<T: std::fmt::Debug> std::fmt::Debug for OneshotReceiver<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.inner.fmt(f)
+    }
+}
+
+fn wrap_channel<T>(
+    (tx, rx): (mpsc::Sender<T>, mpsc::Receiver<T>),
+    source: &'static str,
+    label: Option<String>,
+    log: Option<MessageLog<T>>,
+    size: Option<SizeFn<T>>,
+) -> (Sender<T>, Receiver<T>) {
+    let channel =
+        InlineChannel::register::<T>(source, label, ChannelType::Bounded(tx.max_capacity()));
+    let sender = Sender {
+        inner: tx,
+        channel: Arc::clone(&channel),
+        log,
+        size,
+    };
+    (sender, Receiver { inner: rx, channel })
+}
+
+fn wrap_unbounded<T>(
+    (tx, rx): (mpsc::UnboundedSender<T>, mpsc::UnboundedReceiver<T>),
+    source: &'static str,
+    label: Option<String>,
+    log: Option<MessageLog<T>>,
+    size: Option<SizeFn<T>>,
+) -> (UnboundedSender<T>, UnboundedReceiver<T>) {
+    let channel = InlineChannel::register::<T>(source, label, ChannelType::Unbounded);
+    let sender = UnboundedSender {
+        inner: tx,
+        channel: Arc::clone(&channel),
+        log,
+        size,
+    };
+    (sender, UnboundedReceiver { inner: rx, channel })
+}
+
+fn wrap_oneshot<T>(
+    (tx, rx): (oneshot::Sender<T>, oneshot::Receiver<T>),
+    source: &'static str,
+    label: Option<String>,
+    log: Option<MessageLog<T>>,
+    size: Option<SizeFn<T>>,
+) -> (OneshotSender<T>, OneshotReceiver<T>) {
+    let channel = InlineChannel::register::<T>(source, label, ChannelType::Oneshot);
+    let sender = OneshotSender {
+        inner: Some(tx),
+        channel: Arc::clone(&channel),
+        log,
+        size,
+    };
+    (sender, OneshotReceiver { inner: rx, channel })
+}
+
+impl<T: Send + 'static> InstrumentChannelInline for (mpsc::Sender<T>, mpsc::Receiver<T>) {
+    type Output = (Sender<T>, Receiver<T>);
+    fn instrument_inline(
+        self,
+        source: &'static str,
+        label: Option<String>,
+        _capacity: Option<usize>,
+    ) -> Self::Output {
+        wrap_channel(self, source, label, None, None)
+    }
+}
+
+impl<T: Send + 'static> InstrumentChannelInline
+    for (mpsc::UnboundedSender<T>, mpsc::UnboundedReceiver<T>)
+{
+    type Output = (UnboundedSender<T>, UnboundedReceiver<T>);
+    fn instrument_inline(
+        self,
+        source: &'static str,
+        label: Option<String>,
+        _capacity: Option<usize>,
+    ) -> Self::Output {
+        wrap_unbounded(self, source, label, None, None)
+    }
+}
+
+impl<T: Send + 'static> InstrumentChannelInline for (oneshot::Sender<T>, oneshot::Receiver<T>) {
+    type Output = (OneshotSender<T>, OneshotReceiver<T>);
+    fn instrument_inline(
+        self,
+        source: &'static str,
+        label: Option<String>,
+        _capacity: Option<usize>,
+    ) -> Self::Output {
+        wrap_oneshot(self, source, label, None, None)
+    }
+}
+
+impl<T: Send + std::fmt::Debug + 'static> InstrumentChannelInlineLog
+    for (mpsc::Sender<T>, mpsc::Receiver<T>)
+{
+    type Output = (Sender<T>, Receiver<T>);
+    fn instrument_inline_log(
+        self,
+        source: &'static str,
+        label: Option<String>,
+        _capacity: Option<usize>,
+    ) -> Self::Output {
+        wrap_channel(self, source, label, Some(MessageLog::debug()), None)
+    }
+}
+
+impl<T: Send + std::fmt::Debug + 'static> InstrumentChannelInlineLog
+    for (mpsc::UnboundedSender<T>, mpsc::UnboundedReceiver<T>)
+{
+    type Output = (UnboundedSender<T>, UnboundedReceiver<T>);
+    fn instrument_inline_log(
+        self,
+        source: &'static str,
+        label: Option<String>,
+        _capacity: Option<usize>,
+    ) -> Self::Output {
+        wrap_unbounded(self, source, label, Some(MessageLog::debug()), None)
+    }
+}
+
+impl<T: Send + std::fmt::Debug + 'static> InstrumentChannelInlineLog
+    for (oneshot::Sender<T>, oneshot::Receiver<T>)
+{
+    type Output = (OneshotSender<T>, OneshotReceiver<T>);
+    fn instrument_inline_log(
+        self,
+        source: &'static str,
+        label: Option<String>,
+        _capacity: Option<usize>,
+    ) -> Self::Output {
+        wrap_oneshot(self, source, label, Some(MessageLog::debug()), None)
+    }
+}
+
+impl<T: Send + 'static> InstrumentChannelInlineOptions for (mpsc::Sender<T>, mpsc::Receiver<T>) {
+    type Message = T;
+    type Output = (Sender<T>, Receiver<T>);
+    fn instrument_inline_with_options(
+        self,
+        source: &'static str,
+        label: Option<String>,
+        _capacity: Option<usize>,
+        log: Option<MessageLog<T>>,
+        size: Option<SizeFn<T>>,
+    ) -> Self::Output {
+        wrap_channel(self, source, label, log, size)
+    }
+}
+
+impl<T: Send + 'static> InstrumentChannelInlineOptions
+    for (mpsc::UnboundedSender<T>, mpsc::UnboundedReceiver<T>)
+{
+    type Message = T;
+    type Output = (UnboundedSender<T>, UnboundedReceiver<T>);
+    fn instrument_inline_with_options(
+        self,
+        source: &'static str,
+        label: Option<String>,
+        _capacity: Option<usize>,
+        log: Option<MessageLog<T>>,
+        size: Option<SizeFn<T>>,
+    ) -> Self::Output {
+        wrap_unbounded(self, source, label, log, size)
+    }
+}
+
+impl<T: Send + 'static> InstrumentChannelInlineOptions
+    for (oneshot::Sender<T>, oneshot::Receiver<T>)
+{
+    type Message = T;
+    type Output = (OneshotSender<T>, OneshotReceiver<T>);
+    fn instrument_inline_with_options(
+        self,
+        source: &'static str,
+        label: Option<String>,
+        _capacity: Option<usize>,
+        log: Option<MessageLog<T>>,
+        size: Option<SizeFn<T>>,
+    ) -> Self::Output {
+        wrap_oneshot(self, source, label, log, size)
+    }
+}
@@ -0,0 +1,300 @@
+//! Instrumentation for `tokio::sync::watch` channels.
+//!
+//! Watch channels hold a single value instead of a queue, so they are always instrumented
+//! inline. Every value change counts as a sent message, and every change observed by a
+//! receiver counts as a received one.
+
+use std::ops::Deref;
+use std::sync::Arc;
+
+use tokio::sync::watch::{
+    self,
+    error::{RecvError, SendError},
+    Ref,
+};
+
+use super::{InlineChannel, SizeFn};
+use crate::channels::{
+    ChannelType, InstrumentChannel, InstrumentChannelInline, InstrumentChannelInlineLog,
+    InstrumentChannelInlineOptions, InstrumentChannelLog, InstrumentChannelOptions, LogRecord,
+    MessageLog,
+};
+
+/// Instrumented `tokio::sync::watch::Sender`.
+pub struct Sender<T> {
+    inner: watch::Sender<T>,
+    channel: Arc<InlineChannel>,
+    log: Option<MessageLog<T>>,
+    size: Option<SizeFn<T>>,
+}
+
+impl<T> Sender<T> {
+    /// See [`tokio::sync::watch::Sender::send`].
+    pub fn send(&self, value: T) -> Result<(), SendError<T>> {
+        let log = LogRecord::capture(self.log.as_ref(), &value);
+        let size = self.size.map(|size| size(&value));
+        self.inner.send(value)?;
+        self.channel.sent(log, size);
+        Ok(())
+    }
+
+    /// See [`tokio::sync::watch::Sender::send_replace`].
+    pub fn send_replace(&self, value: T) -> T {
+        let log = LogRecord::capture(self.log.as_ref(), &value);
+        let size = self.size.map(|size| size(&value));
+        let previous = self.inner.send_replace(value);
+        self.channel.sent(log, size);
+        previous
+    }
+
+    /// See [`tokio::sync::watch::Sender::send_modify`].
+    pub fn send_modify<F>(&self, modify: F)
+    where
+        F: FnOnce(&mut T),
+    {
+        self.inner.send_modify(modify);
+        self.sent_current();
+    }
+
+    /// See [`tokio::sync::watch::Sender::send_if_modified`].
+    pub fn send_if_modified<F>(&self, modify: F) -> bool
+    where
+        F: FnOnce(&mut T) -> bool,
+    {
+        let modified = self.inner.send_if_modified(modify);
+        if modified {
+            self.sent_current();
+        }
+        modified
+    }
+
+    /// See [`tokio::sync::watch::Sender::subscribe`].
+    pub fn subscribe(&self) -> Receiver<T> {
+        self.channel.receiver_cloned();
+        Receiver {
+            inner: self.inner.subscribe(),
+            channel: Arc::clone(&self.channel),
+        }
+    }
+
+    /// Reports a change made in place, logging and measuring the new value.
+    fn sent_current(&self) {
+        let (log, size) = {
+            let current = self.inner.borrow();
+            (
+                LogRecord::capture(self.log.as_ref(), &*current),
+                self.size.map(|size| size(&current)),
+            )
+        };
+        self.channel.sent(log, size);
+    }
+}
+
+impl<T> Clone for Sender<T> {
+    fn clone(&self) -> Self {
+        self.channel.sender_cloned();
+        Self {
+            inner: self.inner.clone(),
+            channel: Arc::clone(&self.channel),
+            log: self.log.clone(),
+            size: self.size,
+        }
+    }
+}
+
+impl<T> Drop for Sender<T> {
+    fn drop(&mut self) {
+        self.channel.sender_dropped();
+    }
+}
+
+impl<T> Deref for Sender<T> {
+    type Target = watch::Sender<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<T: std::fmt::Debug> std::fmt::Debug for Sender<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.inner.fmt(f)
+    }
+}
+
+/// Instrumented `tokio::sync::watch::Receiver`.
+pub struct Receiver<T> {
+    inner: watch::Receiver<T>,
+    channel: Arc<InlineChannel>,
+}
+
+impl<T> Receiver<T> {
+    /// See [`tokio::sync::watch::Receiver::changed`].
+    pub async fn changed(&mut self) -> Result<(), RecvError> {
+        let result = self.inner.changed().await;
+        match result {
+            Ok(()) => self.channel.received(),
+            Err(_) => self.channel.close(),
+        }
+        result
+    }
+
+    /// See [`tokio::sync::watch::Receiver::borrow_and_update`].
+    pub fn borrow_and_update(&mut self) -> Ref<'_, T> {
+        if self.inner.has_changed().unwrap_or(false) {
+            self.channel.received();
+        }
+        self.inner.borrow_and_update()
+    }
+
+    /// See [`tokio::sync::watch::Receiver::wait_for`].
+    pub async fn wait_for(&mut self, f: impl FnMut(&T) -> bool) -> Result<Ref<'_, T>, RecvError> {
+        let result = self.inner.wait_for(f).await;
+        match &result {
+            Ok(_) => self.channel.received(),
+            Err(_) => self.channel.close(),
+        }
+        result
+    }
+
+    /// See [`tokio::sync::watch::Receiver::mark_changed`].
+    pub fn mark_changed(&mut self) {
+        self.inner.mark_changed();
+    }
+
+    /// See [`tokio::sync::watch::Receiver::mark_unchanged`].
+    pub fn mark_unchanged(&mut self) {
+        self.inner.mark_unchanged();
+    }
+}
+
+impl<T> Clone for Receiver<T> {
+    fn clone(&self) -> Self {
+        self.channel.receiver_cloned();
+        Self {
+            inner: self.inner.clone(),
+            channel: Arc::clone(&self.channel),
+        }
+    }
+}
+
+impl<T> Drop for Receiver<T> {
+    fn drop(&mut self) {
+        self.channel.receiver_dropped();
+    }
+}
+
+impl<T> Deref for Receiver<T> {
+    type Target = watch::Receiver<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<T: std::fmt::Debug> std::fmt::Debug for Receiver<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.inner.fmt(f)
+    }
+}
+
+fn wrap_watch<T>(
+    (tx, rx): (watch::Sender<T>, watch::Receiver<T>),
+    source: &'static str,
+    label: Option<String>,
+    log: Option<MessageLog<T>>,
+    size: Option<SizeFn<T>>,
+) -> (Sender<T>, Receiver<T>) {
+    let channel = InlineChannel::register::<T>(source, label, ChannelType::Watch);
+    let sender = Sender {
+        inner: tx,
+        channel: Arc::clone(&channel),
+        log,
+        size,
+    };
+    (sender, Receiver { inner: rx, channel })
+}
+
+impl<T: Send + Sync + 'static> InstrumentChannel for (watch::Sender<T>, watch::Receiver<T>) {
+    type Output = (Sender<T>, Receiver<T>);
+    fn instrument(
+        self,
+        source: &'static str,
+        label: Option<String>,
+        _capacity: Option<usize>,
+    ) -> Self::Output {
+        wrap_watch(self, source, label, None, None)
+    }
+}
+
+impl<T: Send + Sync + std::fmt::Debug + 'static> InstrumentChannelLog
+    for (watch::Sender<T>, watch::Receiver<T>)
+{
+    type Output = (Sender<T>, Receiver<T>);
+    fn instrument_log(
+        self,
+        source: &'static str,
+        label: Option<String>,
+        _capacity: Option<usize>,
+    ) -> Self::Output {
+        wrap_watch(self, source, label, Some(MessageLog::debug()), None)
+    }
+}
+
+impl<T: Send + Sync + 'static> InstrumentChannelInline for (watch::Sender<T>, watch::Receiver<T>) {
+    type Output = (Sender<T>, Receiver<T>);
+    fn instrument_inline(
+        self,
+        source: &'static str,
+        label: Option<String>,
+        _capacity: Option<usize>,
+    ) -> Self::Output {
+        wrap_watch(self, source, label, None, None)
+    }
+}
+
+impl<T: Send + Sync + std::fmt::Debug + 'static> InstrumentChannelInlineLog
+    for (watch::Sender<T>, watch::Receiver<T>)
+{
+    type Output = (Sender<T>, Receiver<T>);
+    fn instrument_inline_log(
+        self,
+        source: &'static str,
+        label: Option<String>,
+        _capacity: Option<usize>,
+    ) -> Self::Output {
+        wrap_watch(self, source, label, Some(MessageLog::debug()), None)
+    }
+}
+
+impl<T: Send + Sync + 'static> InstrumentChannelOptions for (watch::Sender<T>, watch::Receiver<T>) {
+    type Message = T;
+    type Output = (Sender<T>, Receiver<T>);
+    fn instrument_with_options(
+        self,
+        source: &'static str,
+        label: Option<String>,
+        _capacity: Option<usize>,
+        log: Option<MessageLog<T>>,
+        size: Option<SizeFn<T>>,
+    ) -> Self::Output {
+        wrap_watch(self, source, label, log, size)
+    }
+}
+
+impl<T: Send + Sync + 'static> InstrumentChannelInlineOptions
+    for (watch::Sender<T>, watch::Receiver<T>)
+{
+    type Message = T;
+    type Output = (Sender<T>, Receiver<T>);
+    fn instrument_inline_with_options(
+        self,
+        source: &'static str,
+        label: Option<String>,
+        _capacity: Option<usize>,
+        log: Option<MessageLog<T>>,
+        size: Option<SizeFn<T>>,
+    ) -> Self::Output {
+        wrap_watch(self, source, label, log, size)
+    }
+}
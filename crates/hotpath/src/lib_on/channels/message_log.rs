@@ -0,0 +1,128 @@
+//! Message logging options for `log = true`, `log_filter`, `log_format` and `log_sample`,
+//! and the `HOTPATH_LOGS_REDACT` redaction applied to every stored log.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, OnceLock};
+
+use regex::Regex;
+
+use crate::output::truncate_result;
+
+const REDACTED: &str = "[REDACTED]";
+
+/// Formats, filters and samples the messages of one instrumented channel or stream.
+///
+/// This type is not intended for direct use. It is built by the `channel!` and `stream!`
+/// macros from the `log_format`, `log_filter` and `log_sample` options.
+#[doc(hidden)]
+pub struct MessageLog<T> {
+    format: Arc<dyn Fn(&T) -> String + Send + Sync>,
+    filter: Arc<dyn Fn(&T) -> bool + Send + Sync>,
+    sample: u64,
+    // Shared by cloned senders, so sampling is per channel
+    matched: Arc<AtomicU64>,
+}
+
+impl<T> MessageLog<T> {
+    pub fn new(
+        format: impl Fn(&T) -> String + Send + Sync + 'static,
+        filter: impl Fn(&T) -> bool + Send + Sync + 'static,
+        sample: u64,
+    ) -> Self {
+        assert!(sample > 0, "log_sample must be at least 1");
+        Self {
+            format: Arc::new(format),
+            filter: Arc::new(filter),
+            sample,
+            matched: Arc::new(AtomicU64::new(0)),
+        }
+    }
+
+    /// Logs every message with its `Debug` output, as with plain `log = true`.
+    pub(crate) fn debug() -> Self
+    where
+        T: std::fmt::Debug + 'static,
+    {
+        Self::new(super::inline::log_debug, |_| true, 1)
+    }
+
+    /// Formats the message if it passes the filter and falls on the sampling interval.
+    pub(crate) fn record(&self, msg: &T) -> LogRecord {
+        if !(self.filter)(msg) {
+            return LogRecord::Skipped;
+        }
+        if self.sample > 1
+            && !self
+                .matched
+                .fetch_add(1, Ordering::Relaxed)
+                .is_multiple_of(self.sample)
+        {
+            return LogRecord::Skipped;
+        }
+        LogRecord::Message((self.format)(msg))
+    }
+}
+
+impl<T> Clone for MessageLog<T> {
+    fn clone(&self) -> Self {
+        Self {
+            format: Arc::clone(&self.format),
+            filter: Arc::clone(&self.filter),
+            sample: self.sample,
+            matched: Arc::clone(&self.matched),
+        }
+    }
+}
+
+/// Log captured for a single sent message or yielded item.
+#[derive(Debug)]
+pub(crate) enum LogRecord {
+    /// Logging is not enabled, the log entry only carries timing
+    Off,
+    /// Filtered out or not sampled, no log entry is kept
+    Skipped,
+    Message(String),
+}
+
+impl LogRecord {
+    pub(crate) fn capture<T>(log: Option<&MessageLog<T>>, msg: &T) -> Self {
+        log.map_or(Self::Off, |log| log.record(msg))
+    }
+
+    pub(crate) fn is_skipped(&self) -> bool {
+        matches!(self, Self::Skipped)
+    }
+
+    /// The message to store in the logs, redacted and truncated.
+    pub(crate) fn into_message(self) -> Option<String> {
+        match self {
+            Self::Message(msg) => Some(truncate_result(redact_log(msg))),
+            Self::Off | Self::Skipped => None,
+        }
+    }
+}
+
+/// Replaces every match of the `HOTPATH_LOGS_REDACT` regex with `[REDACTED]`.
+///
+/// An invalid pattern redacts whole messages rather than letting them through.
+pub(crate) fn redact_log(msg: String) -> String {
+    static PATTERN: OnceLock<Option<Result<Regex, regex::Error>>> = OnceLock::new();
+
+    let pattern = PATTERN.get_or_init(|| {
+        let pattern = std::env::var("HOTPATH_LOGS_REDACT").ok()?;
+        let regex = Regex::new(&pattern);
+        if let Err(e) = &regex {
+            eprintln!("[hotpath] Invalid HOTPATH_LOGS_REDACT pattern, redacting all logs: {e}");
+        }
+        Some(regex)
+    });
+
+    match pattern {
+        None => msg,
+        Some(Ok(regex)) => match regex.replace_all(&msg, REDACTED) {
+            std::borrow::Cow::Borrowed(_) => msg,
+            std::borrow::Cow::Owned(redacted) => redacted,
+        },
+        Some(Err(_)) => REDACTED.to_string(),
+    }
+}
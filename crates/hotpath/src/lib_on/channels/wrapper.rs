@@ -1,8 +1,14 @@
+#[cfg(feature = "async-channel")]
+pub(crate) mod async_channel;
 pub(crate) mod common;
 #[cfg(feature = "crossbeam")]
 pub(crate) mod crossbeam;
+#[cfg(feature = "flume")]
+pub(crate) mod flume;
 #[cfg(feature = "futures")]
 pub(crate) mod ftc;
+#[cfg(feature = "kanal")]
+pub(crate) mod kanal;
 pub(crate) mod std;
 #[cfg(feature = "tokio")]
 pub(crate) mod tokio;
@@ -0,0 +1,113 @@
+use async_channel::{Receiver, Sender};
+
+use crate::channels::wrapper::common::{
+    register_channel, Instant, ReceiverCount, RegisteredChannel,
+};
+use crate::channels::{ChannelEvent, ChannelType, LogRecord};
+
+/// Internal implementation for wrapping async-channel channels with optional logging.
+/// Uses single proxy design: User -> [Original] -> Thread -> [Proxy] -> User
+///
+/// The forwarder uses the blocking API, so no async runtime is required. The proxy
+/// receiver can be cloned, each message is still delivered to exactly one of the clones.
+fn wrap_channel_impl<T, F>(
+    inner: (Sender<T>, Receiver<T>),
+    source: &'static str,
+    label: Option<String>,
+    mut inspect_msg: F,
+) -> (Sender<T>, Receiver<T>)
+where
+    T: Send + 'static,
+    F: FnMut(&T) -> (LogRecord, Option<usize>) + Send + 'static,
+{
+    let (inner_tx, inner_rx) = inner;
+    let (channel_type, (proxy_tx, proxy_rx)) = match inner_tx.capacity() {
+        Some(capacity) => (
+            ChannelType::Bounded(capacity),
+            async_channel::bounded::<T>(1),
+        ),
+        None => (ChannelType::Unbounded, async_channel::unbounded::<T>()),
+    };
+
+    let RegisteredChannel { id, stats_tx } = register_channel::<T>(source, label, channel_type);
+
+    // Single forwarder: inner_rx -> proxy_tx
+    std::thread::spawn(move || {
+        let mut receivers = ReceiverCount::new(id);
+        while let Ok(msg) = inner_rx.recv_blocking() {
+            let (log, size) = inspect_msg(&msg);
+            let _ = stats_tx.send(ChannelEvent::MessageSent {
+                id,
+                log,
+                size,
+                timestamp: Instant::now(),
+            });
+            receivers.update(&stats_tx, proxy_tx.receiver_count());
+            if proxy_tx.send_blocking(msg).is_ok() {
+                let _ = stats_tx.send(ChannelEvent::MessageReceived {
+                    id,
+                    timestamp: Instant::now(),
+                });
+            } else {
+                // All proxy receivers dropped
+                break;
+            }
+        }
+        receivers.update(&stats_tx, proxy_tx.receiver_count());
+        let _ = stats_tx.send(ChannelEvent::Closed { id });
+    });
+
+    (inner_tx, proxy_rx)
+}
+
+use crate::channels::InstrumentChannel;
+
+impl<T: Send + 'static> InstrumentChannel for (Sender<T>, Receiver<T>) {
+    type Output = (Sender<T>, Receiver<T>);
+    fn instrument(
+        self,
+        source: &'static str,
+        label: Option<String>,
+        _capacity: Option<usize>,
+    ) -> Self::Output {
+        wrap_channel_impl(self, source, label, |_| (LogRecord::Off, None))
+    }
+}
+
+use crate::channels::InstrumentChannelLog;
+
+impl<T: Send + std::fmt::Debug + 'static> InstrumentChannelLog for (Sender<T>, Receiver<T>) {
+    type Output = (Sender<T>, Receiver<T>);
+    fn instrument_log(
+        self,
+        source: &'static str,
+        label: Option<String>,
+        _capacity: Option<usize>,
+    ) -> Self::Output {
+        wrap_channel_impl(self, source, label, |msg| {
+            (LogRecord::Message(format!("{:?}", msg)), None)
+        })
+    }
+}
+
+use crate::channels::{InstrumentChannelOptions, MessageLog};
+
+impl<T: Send + 'static> InstrumentChannelOptions for (Sender<T>, Receiver<T>) {
+    type Message = T;
+    type Output = (Sender<T>, Receiver<T>);
+    fn instrument_with_options(
+        self,
+        source: &'static str,
+        label: Option<String>,
+        _capacity: Option<usize>,
+        log: Option<MessageLog<T>>,
+        size: Option<fn(&T) -> usize>,
+    ) -> Self::Output {
+        wrap_channel_impl(self, source, label, move |msg| {
+            (
+                LogRecord::capture(log.as_ref(), msg),
+                size.map(|size| size(msg)),
+            )
+        })
+    }
+}
@@ -21,6 +21,24 @@ pub fn register_channel<T>(
     source: &'static str,
     label: Option<String>,
     channel_type: ChannelType,
+) -> RegisteredChannel {
+    register::<T>(source, label, channel_type, false)
+}
+
+/// Registers a channel instrumented without a proxy, so queued messages are counted exactly.
+pub fn register_inline_channel<T>(
+    source: &'static str,
+    label: Option<String>,
+    channel_type: ChannelType,
+) -> RegisteredChannel {
+    register::<T>(source, label, channel_type, true)
+}
+
+fn register<T>(
+    source: &'static str,
+    label: Option<String>,
+    channel_type: ChannelType,
+    inline: bool,
 ) -> RegisteredChannel {
     let type_name = std::any::type_name::<T>();
     let (stats_tx, _) = init_channels_state();
@@ -33,6 +51,8 @@ pub fn register_channel<T>(
         channel_type,
         type_name,
         type_size: mem::size_of::<T>(),
+        inline,
+        timestamp: Instant::now(),
     });
 
     RegisteredChannel {
@@ -40,3 +60,24 @@ pub fn register_channel<T>(
         stats_tx: stats_tx.clone(),
     }
 }
+
+/// Reports the receiver handle count of a multi-consumer channel whenever it changes.
+#[cfg(any(feature = "flume", feature = "async-channel", feature = "kanal"))]
+pub(crate) struct ReceiverCount {
+    id: u64,
+    last: Option<usize>,
+}
+
+#[cfg(any(feature = "flume", feature = "async-channel", feature = "kanal"))]
+impl ReceiverCount {
+    pub(crate) fn new(id: u64) -> Self {
+        Self { id, last: None }
+    }
+
+    pub(crate) fn update(&mut self, stats_tx: &CbSender<ChannelEvent>, count: usize) {
+        if self.last != Some(count) {
+            self.last = Some(count);
+            let _ = stats_tx.send(ChannelEvent::ReceiverCount { id: self.id, count });
+        }
+    }
+}
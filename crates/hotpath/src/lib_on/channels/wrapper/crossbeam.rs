@@ -1,7 +1,7 @@
 use crossbeam_channel::{self, Receiver, Sender};
 
 use crate::channels::wrapper::common::{register_channel, Instant, RegisteredChannel};
-use crate::channels::{ChannelEvent, ChannelType};
+use crate::channels::{ChannelEvent, ChannelType, LogRecord};
 
 /// Internal implementation for wrapping bounded crossbeam channels with optional logging.
 fn wrap_bounded_impl<T, F>(
@@ -9,11 +9,11 @@ fn wrap_bounded_impl<T, F>(
     source: &'static str,
     label: Option<String>,
     capacity: usize,
-    mut log_on_send: F,
+    mut inspect_msg: F,
 ) -> (Sender<T>, Receiver<T>)
 where
     T: Send + 'static,
-    F: FnMut(&T) -> Option<String> + Send + 'static,
+    F: FnMut(&T) -> (LogRecord, Option<usize>) + Send + 'static,
 {
     let (inner_tx, inner_rx) = inner;
     let (proxy_tx, proxy_rx) = crossbeam_channel::bounded::<T>(1);
@@ -24,10 +24,11 @@ where
     // Single forwarder: inner_rx -> proxy_tx
     std::thread::spawn(move || {
         while let Ok(msg) = inner_rx.recv() {
-            let log = log_on_send(&msg);
+            let (log, size) = inspect_msg(&msg);
             let _ = stats_tx.send(ChannelEvent::MessageSent {
                 id,
                 log,
+                size,
                 timestamp: Instant::now(),
             });
             if proxy_tx.send(msg).is_ok() {
@@ -54,7 +55,7 @@ pub(crate) fn wrap_bounded<T: Send + 'static>(
     label: Option<String>,
     capacity: usize,
 ) -> (Sender<T>, Receiver<T>) {
-    wrap_bounded_impl(inner, source, label, capacity, |_| None)
+    wrap_bounded_impl(inner, source, label, capacity, |_| (LogRecord::Off, None))
 }
 
 /// Wrap a bounded crossbeam channel with logging enabled. Returns (outer_tx, outer_rx).
@@ -65,7 +66,7 @@ pub(crate) fn wrap_bounded_log<T: Send + std::fmt::Debug + 'static>(
     capacity: usize,
 ) -> (Sender<T>, Receiver<T>) {
     wrap_bounded_impl(inner, source, label, capacity, |msg| {
-        Some(format!("{:?}", msg))
+        (LogRecord::Message(format!("{:?}", msg)), None)
     })
 }
 
@@ -75,11 +76,11 @@ fn wrap_unbounded_impl<T, F>(
     inner: (Sender<T>, Receiver<T>),
     source: &'static str,
     label: Option<String>,
-    mut log_on_send: F,
+    mut inspect_msg: F,
 ) -> (Sender<T>, Receiver<T>)
 where
     T: Send + 'static,
-    F: FnMut(&T) -> Option<String> + Send + 'static,
+    F: FnMut(&T) -> (LogRecord, Option<usize>) + Send + 'static,
 {
     let (inner_tx, inner_rx) = inner;
     let (proxy_tx, proxy_rx) = crossbeam_channel::unbounded::<T>();
@@ -90,10 +91,11 @@ where
     // Single forwarder: inner_rx -> proxy_tx
     std::thread::spawn(move || {
         while let Ok(msg) = inner_rx.recv() {
-            let log = log_on_send(&msg);
+            let (log, size) = inspect_msg(&msg);
             let _ = stats_tx.send(ChannelEvent::MessageSent {
                 id,
                 log,
+                size,
                 timestamp: Instant::now(),
             });
             // MessageReceived logged before user receives
@@ -119,7 +121,7 @@ pub(crate) fn wrap_unbounded<T: Send + 'static>(
     source: &'static str,
     label: Option<String>,
 ) -> (Sender<T>, Receiver<T>) {
-    wrap_unbounded_impl(inner, source, label, |_| None)
+    wrap_unbounded_impl(inner, source, label, |_| (LogRecord::Off, None))
 }
 
 /// Wrap an unbounded crossbeam channel with logging enabled. Returns (outer_tx, outer_rx).
@@ -128,7 +130,9 @@ pub(crate) fn wrap_unbounded_log<T: Send + std::fmt::Debug + 'static>(
     source: &'static str,
     label: Option<String>,
 ) -> (Sender<T>, Receiver<T>) {
-    wrap_unbounded_impl(inner, source, label, |msg| Some(format!("{:?}", msg)))
+    wrap_unbounded_impl(inner, source, label, |msg| {
+        (LogRecord::Message(format!("{:?}", msg)), None)
+    })
 }
 
 use crate::channels::InstrumentChannel;
@@ -172,3 +176,29 @@ impl<T: Send + std::fmt::Debug + 'static> InstrumentChannelLog
         }
     }
 }
+
+use crate::channels::{InstrumentChannelOptions, MessageLog};
+
+impl<T: Send + 'static> InstrumentChannelOptions for (Sender<T>, Receiver<T>) {
+    type Message = T;
+    type Output = (Sender<T>, Receiver<T>);
+    fn instrument_with_options(
+        self,
+        source: &'static str,
+        label: Option<String>,
+        _capacity: Option<usize>,
+        log: Option<MessageLog<T>>,
+        size: Option<fn(&T) -> usize>,
+    ) -> Self::Output {
+        let inspect_msg = move |msg: &T| {
+            (
+                LogRecord::capture(log.as_ref(), msg),
+                size.map(|size| size(msg)),
+            )
+        };
+        match self.0.capacity() {
+            Some(capacity) => wrap_bounded_impl(self, source, label, capacity, inspect_msg),
+            None => wrap_unbounded_impl(self, source, label, inspect_msg),
+        }
+    }
+}
@@ -4,7 +4,7 @@ use futures_channel::oneshot;
 use futures_util::sink::SinkExt;
 
 use crate::channels::wrapper::common::{register_channel, Instant, RegisteredChannel};
-use crate::channels::{ChannelEvent, ChannelType, RT};
+use crate::channels::{ChannelEvent, ChannelType, LogRecord, RT};
 
 /// Internal implementation for wrapping bounded futures channels with optional logging.
 fn wrap_channel_impl<T, F>(
@@ -12,11 +12,11 @@ fn wrap_channel_impl<T, F>(
     source: &'static str,
     label: Option<String>,
     capacity: usize,
-    mut get_msg_log: F,
+    mut inspect_msg: F,
 ) -> (Sender<T>, Receiver<T>)
 where
     T: Send + 'static,
-    F: FnMut(&T) -> Option<String> + Send + 'static + Clone,
+    F: FnMut(&T) -> (LogRecord, Option<usize>) + Send + 'static + Clone,
 {
     let (inner_tx, mut inner_rx) = inner;
     let (mut proxy_tx, proxy_rx) = mpsc::channel::<T>(1);
@@ -28,10 +28,11 @@ where
     RT.spawn(async move {
         use futures_util::stream::StreamExt;
         while let Some(msg) = inner_rx.next().await {
-            let log = get_msg_log(&msg);
+            let (log, size) = inspect_msg(&msg);
             let _ = stats_tx.send(ChannelEvent::MessageSent {
                 id,
                 log,
+                size,
                 timestamp: Instant::now(),
             });
             if proxy_tx.send(msg).await.is_ok() {
@@ -59,7 +60,7 @@ pub(crate) fn wrap_channel<T: Send + 'static>(
     label: Option<String>,
     capacity: usize,
 ) -> (Sender<T>, Receiver<T>) {
-    wrap_channel_impl(inner, source, label, capacity, |_| None)
+    wrap_channel_impl(inner, source, label, capacity, |_| (LogRecord::Off, None))
 }
 
 /// Wrap a bounded futures channel with logging enabled. Returns (outer_tx, outer_rx).
@@ -70,7 +71,7 @@ pub(crate) fn wrap_channel_log<T: Send + std::fmt::Debug + 'static>(
     capacity: usize,
 ) -> (Sender<T>, Receiver<T>) {
     wrap_channel_impl(inner, source, label, capacity, |msg| {
-        Some(format!("{:?}", msg))
+        (LogRecord::Message(format!("{:?}", msg)), None)
     })
 }
 
@@ -80,11 +81,11 @@ fn wrap_unbounded_impl<T, F>(
     inner: (UnboundedSender<T>, UnboundedReceiver<T>),
     source: &'static str,
     label: Option<String>,
-    mut get_msg_log: F,
+    mut inspect_msg: F,
 ) -> (UnboundedSender<T>, UnboundedReceiver<T>)
 where
     T: Send + 'static,
-    F: FnMut(&T) -> Option<String> + Send + 'static + Clone,
+    F: FnMut(&T) -> (LogRecord, Option<usize>) + Send + 'static + Clone,
 {
     let (inner_tx, mut inner_rx) = inner;
     let (proxy_tx, proxy_rx) = mpsc::unbounded::<T>();
@@ -96,10 +97,11 @@ where
     RT.spawn(async move {
         use futures_util::stream::StreamExt;
         while let Some(msg) = inner_rx.next().await {
-            let log = get_msg_log(&msg);
+            let (log, size) = inspect_msg(&msg);
             let _ = stats_tx.send(ChannelEvent::MessageSent {
                 id,
                 log,
+                size,
                 timestamp: Instant::now(),
             });
             if proxy_tx.unbounded_send(msg).is_ok() {
@@ -125,7 +127,7 @@ pub(crate) fn wrap_unbounded<T: Send + 'static>(
     source: &'static str,
     label: Option<String>,
 ) -> (UnboundedSender<T>, UnboundedReceiver<T>) {
-    wrap_unbounded_impl(inner, source, label, |_| None)
+    wrap_unbounded_impl(inner, source, label, |_| (LogRecord::Off, None))
 }
 
 /// Wrap an unbounded futures channel with logging enabled. Returns (outer_tx, outer_rx).
@@ -134,7 +136,9 @@ pub(crate) fn wrap_unbounded_log<T: Send + std::fmt::Debug + 'static>(
     source: &'static str,
     label: Option<String>,
 ) -> (UnboundedSender<T>, UnboundedReceiver<T>) {
-    wrap_unbounded_impl(inner, source, label, |msg| Some(format!("{:?}", msg)))
+    wrap_unbounded_impl(inner, source, label, |msg| {
+        (LogRecord::Message(format!("{:?}", msg)), None)
+    })
 }
 
 /// Internal implementation for wrapping oneshot futures channels with optional logging.
@@ -142,11 +146,11 @@ fn wrap_oneshot_impl<T, F>(
     inner: (oneshot::Sender<T>, oneshot::Receiver<T>),
     source: &'static str,
     label: Option<String>,
-    mut get_msg_log: F,
+    mut inspect_msg: F,
 ) -> (oneshot::Sender<T>, oneshot::Receiver<T>)
 where
     T: Send + 'static,
-    F: FnMut(&T) -> Option<String> + Send + 'static + Clone,
+    F: FnMut(&T) -> (LogRecord, Option<usize>) + Send + 'static + Clone,
 {
     let (inner_tx, inner_rx) = inner;
     let (proxy_tx, proxy_rx) = oneshot::channel::<T>();
@@ -164,10 +168,11 @@ where
             msg = async { inner_rx.take().unwrap().await }, if inner_rx.is_some() => {
                 match msg {
                     Ok(msg) => {
-                        let log = get_msg_log(&msg);
+                        let (log, size) = inspect_msg(&msg);
                         let _ = stats_tx.send(ChannelEvent::MessageSent {
                             id,
                             log,
+                            size,
                             timestamp: Instant::now(),
                         });
                         let _ = stats_tx.send(ChannelEvent::Notified { id });
@@ -205,7 +210,7 @@ pub(crate) fn wrap_oneshot<T: Send + 'static>(
     source: &'static str,
     label: Option<String>,
 ) -> (oneshot::Sender<T>, oneshot::Receiver<T>) {
-    wrap_oneshot_impl(inner, source, label, |_| None)
+    wrap_oneshot_impl(inner, source, label, |_| (LogRecord::Off, None))
 }
 
 /// Wrap a oneshot futures channel with logging enabled. Returns (outer_tx, outer_rx).
@@ -214,7 +219,9 @@ pub(crate) fn wrap_oneshot_log<T: Send + std::fmt::Debug + 'static>(
     source: &'static str,
     label: Option<String>,
 ) -> (oneshot::Sender<T>, oneshot::Receiver<T>) {
-    wrap_oneshot_impl(inner, source, label, |msg| Some(format!("{:?}", msg)))
+    wrap_oneshot_impl(inner, source, label, |msg| {
+        (LogRecord::Message(format!("{:?}", msg)), None)
+    })
 }
 
 use crate::channels::InstrumentChannel;
@@ -346,3 +353,68 @@ impl<T: Send + std::fmt::Debug + 'static> InstrumentChannelLog
         wrap_oneshot_log(self, source, label)
     }
 }
+
+use crate::channels::{InstrumentChannelOptions, MessageLog};
+
+impl<T: Send + 'static> InstrumentChannelOptions for (Sender<T>, Receiver<T>) {
+    type Message = T;
+    type Output = (Sender<T>, Receiver<T>);
+    fn instrument_with_options(
+        self,
+        source: &'static str,
+        label: Option<String>,
+        capacity: Option<usize>,
+        log: Option<MessageLog<T>>,
+        size: Option<fn(&T) -> usize>,
+    ) -> Self::Output {
+        if capacity.is_none() {
+            panic!("Capacity is required for bounded futures channels, because they don't expose their capacity in a public API");
+        }
+        wrap_channel_impl(self, source, label, capacity.unwrap(), move |msg| {
+            (
+                LogRecord::capture(log.as_ref(), msg),
+                size.map(|size| size(msg)),
+            )
+        })
+    }
+}
+
+impl<T: Send + 'static> InstrumentChannelOptions for (UnboundedSender<T>, UnboundedReceiver<T>) {
+    type Message = T;
+    type Output = (UnboundedSender<T>, UnboundedReceiver<T>);
+    fn instrument_with_options(
+        self,
+        source: &'static str,
+        label: Option<String>,
+        _capacity: Option<usize>,
+        log: Option<MessageLog<T>>,
+        size: Option<fn(&T) -> usize>,
+    ) -> Self::Output {
+        wrap_unbounded_impl(self, source, label, move |msg| {
+            (
+                LogRecord::capture(log.as_ref(), msg),
+                size.map(|size| size(msg)),
+            )
+        })
+    }
+}
+
+impl<T: Send + 'static> InstrumentChannelOptions for (oneshot::Sender<T>, oneshot::Receiver<T>) {
+    type Message = T;
+    type Output = (oneshot::Sender<T>, oneshot::Receiver<T>);
+    fn instrument_with_options(
+        self,
+        source: &'static str,
+        label: Option<String>,
+        _capacity: Option<usize>,
+        log: Option<MessageLog<T>>,
+        size: Option<fn(&T) -> usize>,
+    ) -> Self::Output {
+        wrap_oneshot_impl(self, source, label, move |msg| {
+            (
+                LogRecord::capture(log.as_ref(), msg),
+                size.map(|size| size(msg)),
+            )
+        })
+    }
+}
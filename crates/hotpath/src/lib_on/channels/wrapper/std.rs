@@ -1,7 +1,7 @@
 use std::sync::mpsc::{self, Receiver, Sender, SyncSender};
 
 use crate::channels::wrapper::common::{register_channel, Instant, RegisteredChannel};
-use crate::channels::{ChannelEvent, ChannelType};
+use crate::channels::{ChannelEvent, ChannelType, LogRecord};
 
 /// Internal implementation for wrapping bounded std channels with optional logging.
 fn wrap_sync_channel_impl<T, F>(
@@ -9,11 +9,11 @@ fn wrap_sync_channel_impl<T, F>(
     source: &'static str,
     label: Option<String>,
     capacity: usize,
-    mut log_on_send: F,
+    mut inspect_msg: F,
 ) -> (SyncSender<T>, Receiver<T>)
 where
     T: Send + 'static,
-    F: FnMut(&T) -> Option<String> + Send + 'static,
+    F: FnMut(&T) -> (LogRecord, Option<usize>) + Send + 'static,
 {
     let (inner_tx, inner_rx) = inner;
     let (proxy_tx, proxy_rx) = mpsc::sync_channel::<T>(1);
@@ -24,10 +24,11 @@ where
     // Single forwarder: inner_rx -> proxy_tx
     std::thread::spawn(move || {
         while let Ok(msg) = inner_rx.recv() {
-            let log = log_on_send(&msg);
+            let (log, size) = inspect_msg(&msg);
             let _ = stats_tx.send(ChannelEvent::MessageSent {
                 id,
                 log,
+                size,
                 timestamp: Instant::now(),
             });
             if proxy_tx.send(msg).is_ok() {
@@ -54,7 +55,7 @@ pub(crate) fn wrap_sync_channel<T: Send + 'static>(
     label: Option<String>,
     capacity: usize,
 ) -> (SyncSender<T>, Receiver<T>) {
-    wrap_sync_channel_impl(inner, source, label, capacity, |_| None)
+    wrap_sync_channel_impl(inner, source, label, capacity, |_| (LogRecord::Off, None))
 }
 
 /// Wrap a bounded std channel with logging enabled. Returns (outer_tx, outer_rx).
@@ -65,7 +66,7 @@ pub(crate) fn wrap_sync_channel_log<T: Send + std::fmt::Debug + 'static>(
     capacity: usize,
 ) -> (SyncSender<T>, Receiver<T>) {
     wrap_sync_channel_impl(inner, source, label, capacity, |msg| {
-        Some(format!("{:?}", msg))
+        (LogRecord::Message(format!("{:?}", msg)), None)
     })
 }
 
@@ -75,11 +76,11 @@ fn wrap_channel_impl<T, F>(
     inner: (Sender<T>, Receiver<T>),
     source: &'static str,
     label: Option<String>,
-    mut log_on_send: F,
+    mut inspect_msg: F,
 ) -> (Sender<T>, Receiver<T>)
 where
     T: Send + 'static,
-    F: FnMut(&T) -> Option<String> + Send + 'static,
+    F: FnMut(&T) -> (LogRecord, Option<usize>) + Send + 'static,
 {
     let (inner_tx, inner_rx) = inner;
     let (proxy_tx, proxy_rx) = mpsc::channel::<T>();
@@ -90,10 +91,11 @@ where
     // Single forwarder: inner_rx -> proxy_tx
     std::thread::spawn(move || {
         while let Ok(msg) = inner_rx.recv() {
-            let log = log_on_send(&msg);
+            let (log, size) = inspect_msg(&msg);
             let _ = stats_tx.send(ChannelEvent::MessageSent {
                 id,
                 log,
+                size,
                 timestamp: Instant::now(),
             });
             if proxy_tx.send(msg).is_ok() {
@@ -118,7 +120,7 @@ pub(crate) fn wrap_channel<T: Send + 'static>(
     source: &'static str,
     label: Option<String>,
 ) -> (Sender<T>, Receiver<T>) {
-    wrap_channel_impl(inner, source, label, |_| None)
+    wrap_channel_impl(inner, source, label, |_| (LogRecord::Off, None))
 }
 
 /// Wrap an unbounded std channel with logging enabled. Returns (outer_tx, outer_rx).
@@ -127,7 +129,9 @@ pub(crate) fn wrap_channel_log<T: Send + std::fmt::Debug + 'static>(
     source: &'static str,
     label: Option<String>,
 ) -> (Sender<T>, Receiver<T>) {
-    wrap_channel_impl(inner, source, label, |msg| Some(format!("{:?}", msg)))
+    wrap_channel_impl(inner, source, label, |msg| {
+        (LogRecord::Message(format!("{:?}", msg)), None)
+    })
 }
 
 use crate::channels::InstrumentChannel;
@@ -195,3 +199,52 @@ impl<T: Send + std::fmt::Debug + 'static> InstrumentChannelLog
         wrap_sync_channel_log(self, source, label, capacity.unwrap())
     }
 }
+
+use crate::channels::{InstrumentChannelOptions, MessageLog};
+
+impl<T: Send + 'static> InstrumentChannelOptions
+    for (std::sync::mpsc::Sender<T>, std::sync::mpsc::Receiver<T>)
+{
+    type Message = T;
+    type Output = (std::sync::mpsc::Sender<T>, std::sync::mpsc::Receiver<T>);
+    fn instrument_with_options(
+        self,
+        source: &'static str,
+        label: Option<String>,
+        _capacity: Option<usize>,
+        log: Option<MessageLog<T>>,
+        size: Option<fn(&T) -> usize>,
+    ) -> Self::Output {
+        wrap_channel_impl(self, source, label, move |msg| {
+            (
+                LogRecord::capture(log.as_ref(), msg),
+                size.map(|size| size(msg)),
+            )
+        })
+    }
+}
+
+impl<T: Send + 'static> InstrumentChannelOptions
+    for (std::sync::mpsc::SyncSender<T>, std::sync::mpsc::Receiver<T>)
+{
+    type Message = T;
+    type Output = (std::sync::mpsc::SyncSender<T>, std::sync::mpsc::Receiver<T>);
+    fn instrument_with_options(
+        self,
+        source: &'static str,
+        label: Option<String>,
+        capacity: Option<usize>,
+        log: Option<MessageLog<T>>,
+        size: Option<fn(&T) -> usize>,
+    ) -> Self::Output {
+        if capacity.is_none() {
+            panic!("Capacity is required for bounded std channels, because they don't expose their capacity in a public API");
+        }
+        wrap_sync_channel_impl(self, source, label, capacity.unwrap(), move |msg| {
+            (
+                LogRecord::capture(log.as_ref(), msg),
+                size.map(|size| size(msg)),
+            )
+        })
+    }
+}
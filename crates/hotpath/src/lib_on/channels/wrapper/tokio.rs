@@ -3,18 +3,18 @@ use tokio::sync::mpsc::{Receiver, Sender, UnboundedReceiver, UnboundedSender};
 use tokio::sync::oneshot;
 
 use crate::channels::wrapper::common::{register_channel, Instant, RegisteredChannel};
-use crate::channels::{ChannelEvent, ChannelType, RT};
+use crate::channels::{ChannelEvent, ChannelType, LogRecord, RT};
 
 /// Internal implementation for wrapping bounded Tokio channels with optional logging.
 fn wrap_channel_impl<T, F>(
     inner: (Sender<T>, Receiver<T>),
     source: &'static str,
     label: Option<String>,
-    mut log_on_send: F,
+    mut inspect_msg: F,
 ) -> (Sender<T>, Receiver<T>)
 where
     T: Send + 'static,
-    F: FnMut(&T) -> Option<String> + Send + 'static,
+    F: FnMut(&T) -> (LogRecord, Option<usize>) + Send + 'static,
 {
     let (inner_tx, mut inner_rx) = inner;
     let capacity = inner_tx.capacity();
@@ -30,10 +30,11 @@ where
                 msg = inner_rx.recv() => {
                     match msg {
                         Some(msg) => {
-                            let log = log_on_send(&msg);
+                            let (log, size) = inspect_msg(&msg);
                             let _ = stats_tx.send(ChannelEvent::MessageSent {
                                 id,
                                 log,
+                                size,
                                 timestamp: Instant::now(),
                             });
                             if proxy_tx.send(msg).await.is_ok() {
@@ -68,7 +69,7 @@ pub(crate) fn wrap_channel<T: Send + 'static>(
     source: &'static str,
     label: Option<String>,
 ) -> (Sender<T>, Receiver<T>) {
-    wrap_channel_impl(inner, source, label, |_| None)
+    wrap_channel_impl(inner, source, label, |_| (LogRecord::Off, None))
 }
 
 /// Wrap a bounded Tokio channel with logging enabled. Returns (outer_tx, outer_rx).
@@ -77,7 +78,9 @@ pub(crate) fn wrap_channel_log<T: Send + std::fmt::Debug + 'static>(
     source: &'static str,
     label: Option<String>,
 ) -> (Sender<T>, Receiver<T>) {
-    wrap_channel_impl(inner, source, label, |msg| Some(format!("{:?}", msg)))
+    wrap_channel_impl(inner, source, label, |msg| {
+        (LogRecord::Message(format!("{:?}", msg)), None)
+    })
 }
 
 /// Internal implementation for wrapping unbounded Tokio channels with optional logging.
@@ -86,11 +89,11 @@ fn wrap_unbounded_impl<T, F>(
     inner: (UnboundedSender<T>, UnboundedReceiver<T>),
     source: &'static str,
     label: Option<String>,
-    mut log_on_send: F,
+    mut inspect_msg: F,
 ) -> (UnboundedSender<T>, UnboundedReceiver<T>)
 where
     T: Send + 'static,
-    F: FnMut(&T) -> Option<String> + Send + 'static,
+    F: FnMut(&T) -> (LogRecord, Option<usize>) + Send + 'static,
 {
     let (inner_tx, mut inner_rx) = inner;
     let (proxy_tx, proxy_rx) = mpsc::unbounded_channel::<T>();
@@ -105,10 +108,11 @@ where
                 msg = inner_rx.recv() => {
                     match msg {
                         Some(msg) => {
-                            let log = log_on_send(&msg);
+                            let (log, size) = inspect_msg(&msg);
                             let _ = stats_tx.send(ChannelEvent::MessageSent {
                                 id,
                                 log,
+                                size,
                                 timestamp: Instant::now(),
                             });
                             if proxy_tx.send(msg).is_ok() {
@@ -142,7 +146,7 @@ pub(crate) fn wrap_unbounded<T: Send + 'static>(
     source: &'static str,
     label: Option<String>,
 ) -> (UnboundedSender<T>, UnboundedReceiver<T>) {
-    wrap_unbounded_impl(inner, source, label, |_| None)
+    wrap_unbounded_impl(inner, source, label, |_| (LogRecord::Off, None))
 }
 
 /// Wrap an unbounded Tokio channel with logging enabled. Returns (outer_tx, outer_rx).
@@ -151,7 +155,9 @@ pub(crate) fn wrap_unbounded_log<T: Send + std::fmt::Debug + 'static>(
     source: &'static str,
     label: Option<String>,
 ) -> (UnboundedSender<T>, UnboundedReceiver<T>) {
-    wrap_unbounded_impl(inner, source, label, |msg| Some(format!("{:?}", msg)))
+    wrap_unbounded_impl(inner, source, label, |msg| {
+        (LogRecord::Message(format!("{:?}", msg)), None)
+    })
 }
 
 /// Internal implementation for wrapping oneshot Tokio channels with optional logging.
@@ -159,11 +165,11 @@ fn wrap_oneshot_impl<T, F>(
     inner: (oneshot::Sender<T>, oneshot::Receiver<T>),
     source: &'static str,
     label: Option<String>,
-    mut log_on_send: F,
+    mut inspect_msg: F,
 ) -> (oneshot::Sender<T>, oneshot::Receiver<T>)
 where
     T: Send + 'static,
-    F: FnMut(&T) -> Option<String> + Send + 'static,
+    F: FnMut(&T) -> (LogRecord, Option<usize>) + Send + 'static,
 {
     let (inner_tx, inner_rx) = inner;
     let (proxy_tx, proxy_rx) = oneshot::channel::<T>();
@@ -181,10 +187,11 @@ where
             msg = async { inner_rx.take().unwrap().await }, if inner_rx.is_some() => {
                 match msg {
                     Ok(msg) => {
-                        let log = log_on_send(&msg);
+                        let (log, size) = inspect_msg(&msg);
                         let _ = stats_tx.send(ChannelEvent::MessageSent {
                             id,
                             log,
+                            size,
                             timestamp: Instant::now(),
                         });
                         let _ = stats_tx.send(ChannelEvent::Notified { id });
@@ -221,7 +228,7 @@ pub(crate) fn wrap_oneshot<T: Send + 'static>(
     source: &'static str,
     label: Option<String>,
 ) -> (oneshot::Sender<T>, oneshot::Receiver<T>) {
-    wrap_oneshot_impl(inner, source, label, |_| None)
+    wrap_oneshot_impl(inner, source, label, |_| (LogRecord::Off, None))
 }
 
 /// Wrap a oneshot Tokio channel with logging enabled. Returns (outer_tx, outer_rx).
@@ -230,7 +237,9 @@ pub(crate) fn wrap_oneshot_log<T: Send + std::fmt::Debug + 'static>(
     source: &'static str,
     label: Option<String>,
 ) -> (oneshot::Sender<T>, oneshot::Receiver<T>) {
-    wrap_oneshot_impl(inner, source, label, |msg| Some(format!("{:?}", msg)))
+    wrap_oneshot_impl(inner, source, label, |msg| {
+        (LogRecord::Message(format!("{:?}", msg)), None)
+    })
 }
 
 use crate::channels::InstrumentChannel;
@@ -312,3 +321,65 @@ impl<T: Send + std::fmt::Debug + 'static> InstrumentChannelLog
         wrap_oneshot_log(self, source, label)
     }
 }
+
+use crate::channels::{InstrumentChannelOptions, MessageLog};
+
+impl<T: Send + 'static> InstrumentChannelOptions for (Sender<T>, Receiver<T>) {
+    type Message = T;
+    type Output = (Sender<T>, Receiver<T>);
+    fn instrument_with_options(
+        self,
+        source: &'static str,
+        label: Option<String>,
+        _capacity: Option<usize>,
+        log: Option<MessageLog<T>>,
+        size: Option<fn(&T) -> usize>,
+    ) -> Self::Output {
+        wrap_channel_impl(self, source, label, move |msg| {
+            (
+                LogRecord::capture(log.as_ref(), msg),
+                size.map(|size| size(msg)),
+            )
+        })
+    }
+}
+
+impl<T: Send + 'static> InstrumentChannelOptions for (UnboundedSender<T>, UnboundedReceiver<T>) {
+    type Message = T;
+    type Output = (UnboundedSender<T>, UnboundedReceiver<T>);
+    fn instrument_with_options(
+        self,
+        source: &'static str,
+        label: Option<String>,
+        _capacity: Option<usize>,
+        log: Option<MessageLog<T>>,
+        size: Option<fn(&T) -> usize>,
+    ) -> Self::Output {
+        wrap_unbounded_impl(self, source, label, move |msg| {
+            (
+                LogRecord::capture(log.as_ref(), msg),
+                size.map(|size| size(msg)),
+            )
+        })
+    }
+}
+
+impl<T: Send + 'static> InstrumentChannelOptions for (oneshot::Sender<T>, oneshot::Receiver<T>) {
+    type Message = T;
+    type Output = (oneshot::Sender<T>, oneshot::Receiver<T>);
+    fn instrument_with_options(
+        self,
+        source: &'static str,
+        label: Option<String>,
+        _capacity: Option<usize>,
+        log: Option<MessageLog<T>>,
+        size: Option<fn(&T) -> usize>,
+    ) -> Self::Output {
+        wrap_oneshot_impl(self, source, label, move |msg| {
+            (
+                LogRecord::capture(log.as_ref(), msg),
+                size.map(|size| size(msg)),
+            )
+        })
+    }
+}
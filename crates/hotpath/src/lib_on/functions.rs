@@ -11,7 +11,6 @@ cfg_if::cfg_if! {
     if #[cfg(feature = "hotpath-alloc")] {
         pub mod alloc;
         use alloc::state::FunctionsState;
-        use tokio::runtime::{Handle, RuntimeFlavor};
         pub use alloc::guard::{MeasurementGuard, MeasurementGuardWithLog};
         pub use alloc::state::FunctionStats;
     } else {
@@ -26,56 +25,14 @@ pub(crate) use crate::output::truncate_result;
 
 impl MeasurementGuard {
     pub fn build(measurement_name: &'static str, wrapper: bool, _is_async: bool) -> Self {
-        #[allow(clippy::needless_bool)]
-        let unsupported_async = if wrapper {
-            // Top wrapper functions are not inside a runtime
-            false
-        } else {
-            cfg_if::cfg_if! {
-                if #[cfg(feature = "hotpath-alloc")] {
-                    // For allocation profiling: mark async as unsupported unless
-                    // running on Tokio CurrentThread. Non-Tokio runtimes are unsupported.
-                    if _is_async {
-                        match Handle::try_current() {
-                            Ok(h) => h.runtime_flavor() != RuntimeFlavor::CurrentThread,
-                            Err(_) => true,
-                        }
-                    } else {
-                        false
-                    }
-                } else {
-                    false
-                }
-            }
-        };
-
-        MeasurementGuard::new(measurement_name, wrapper, unsupported_async)
+        // Async functions in alloc mode are measured per poll by `MeasuredFuture`
+        MeasurementGuard::new(measurement_name, wrapper, false)
     }
 }
 
 impl MeasurementGuardWithLog {
     pub fn build(measurement_name: &'static str, wrapper: bool, _is_async: bool) -> Self {
-        #[allow(clippy::needless_bool)]
-        let unsupported_async = if wrapper {
-            false
-        } else {
-            cfg_if::cfg_if! {
-                if #[cfg(feature = "hotpath-alloc")] {
-                    if _is_async {
-                        match Handle::try_current() {
-                            Ok(h) => h.runtime_flavor() != RuntimeFlavor::CurrentThread,
-                            Err(_) => true,
-                        }
-                    } else {
-                        false
-                    }
-                } else {
-                    false
-                }
-            }
-        };
-
-        MeasurementGuardWithLog::new(measurement_name, wrapper, unsupported_async)
+        MeasurementGuardWithLog::new(measurement_name, wrapper, false)
     }
 }
 
@@ -94,6 +51,30 @@ pub fn measure_with_log<T: std::fmt::Debug, F: FnOnce() -> T>(
     result
 }
 
+/// Measure an async function.
+///
+/// In alloc mode the future is wrapped so that allocations are attributed per poll,
+/// which keeps the numbers valid when the task migrates between runtime threads.
+#[doc(hidden)]
+pub async fn measure_async<T, Fut>(name: &'static str, fut: Fut) -> T
+where
+    Fut: std::future::Future<Output = T>,
+{
+    cfg_if::cfg_if! {
+        if #[cfg(feature = "hotpath-alloc")] {
+            alloc::future::MeasuredFuture::new(name, fut).await
+        } else {
+            let _guard = MeasurementGuard::build(name, false, true);
+            match *crate::blocking::BLOCKING_POLL_THRESHOLD_NS {
+                Some(threshold_ns) => {
+                    crate::blocking::DetectBlockingPolls::new(name, fut, threshold_ns).await
+                }
+                None => fut.await,
+            }
+        }
+    }
+}
+
 /// Measure an async function and log its return value.
 #[doc(hidden)]
 pub async fn measure_with_log_async<T: std::fmt::Debug, F, Fut>(name: &'static str, f: F) -> T
@@ -101,10 +82,21 @@ where
     F: FnOnce() -> Fut,
     Fut: std::future::Future<Output = T>,
 {
-    let guard = MeasurementGuardWithLog::build(name, false, true);
-    let result = f().await;
-    guard.finish_with_result(&result);
-    result
+    cfg_if::cfg_if! {
+        if #[cfg(feature = "hotpath-alloc")] {
+            alloc::future::MeasuredFuture::new_with_log(name, f()).await
+        } else {
+            let guard = MeasurementGuardWithLog::build(name, false, true);
+            let result = match *crate::blocking::BLOCKING_POLL_THRESHOLD_NS {
+                Some(threshold_ns) => {
+                    crate::blocking::DetectBlockingPolls::new(name, f(), threshold_ns).await
+                }
+                None => f().await,
+            };
+            guard.finish_with_result(&result);
+            result
+        }
+    }
 }
 
 pub(crate) static FUNCTIONS_STATE: OnceLock<ArcSwapOption<RwLock<FunctionsState>>> =
@@ -167,6 +159,10 @@ pub(crate) fn get_functions_timing_json() -> FunctionsJson {
         caller_name: "hotpath".to_string(),
         percentiles: vec![95],
         data: Vec::new(),
+        live_allocs: None,
+        depth_overflows: None,
+        call_sites: None,
+        runtime: None,
     }
 }
 
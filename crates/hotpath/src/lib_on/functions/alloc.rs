@@ -1,6 +1,9 @@
 pub mod allocator;
+pub mod callsites;
 pub mod core;
+pub mod future;
 pub mod guard;
+pub mod leaks;
 pub mod report;
 pub mod shared;
 pub mod state;
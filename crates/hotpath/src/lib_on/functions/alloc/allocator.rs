@@ -15,13 +15,13 @@ pub struct CountingAllocator;
 
 unsafe impl GlobalAlloc for CountingAllocator {
     unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
-        super::core::track_alloc(layout.size());
-
-        unsafe { System.alloc(layout) }
+        let ptr = unsafe { System.alloc(layout) };
+        super::core::track_alloc(ptr, layout.size());
+        ptr
     }
 
     unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
-        super::core::track_dealloc(layout.size());
+        super::core::track_dealloc(ptr, layout.size());
 
         unsafe {
             System.dealloc(ptr, layout);
@@ -0,0 +1,370 @@
+//! Sampled capture of the call sites that allocate inside measured functions.
+//!
+//! Every Nth allocation (`HOTPATH_ALLOC_CALLSITES`), or roughly every N allocated bytes
+//! (`HOTPATH_ALLOC_CALLSITES_BYTES`), the allocator records the raw instruction pointers
+//! of the current stack. Symbol resolution is far too slow for the allocation path, so raw
+//! stacks are pushed to a bounded channel, preallocated so the allocation path never
+//! allocates, and aggregated by a background worker. Each unique stack is resolved only
+//! when a report is requested.
+
+use std::cell::Cell;
+use std::collections::HashMap;
+use std::ffi::c_void;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{LazyLock, Mutex, OnceLock};
+
+use crossbeam_channel::{bounded, Receiver, Sender};
+
+use crate::output::{CallSiteEntry, CallSitesJson};
+
+/// Maximum number of frames captured per sample, allocator internals included
+const MAX_FRAMES: usize = 32;
+
+/// Number of call sites reported for each function
+const TOP_CALL_SITES: usize = 5;
+
+/// Samples buffered for the worker, a full buffer drops new samples
+const CHANNEL_CAPACITY: usize = 4096;
+
+/// Distinct stacks aggregated separately, samples of new stacks beyond that are folded
+/// into a single bucket per function so memory stays bounded in long sessions
+const MAX_STACKS: usize = 8192;
+
+/// Stack of the per-function bucket that collects samples beyond `MAX_STACKS`
+const OTHER_STACK: Stack = [0; MAX_FRAMES];
+
+/// Stack walking with `trace_unsynchronized` is thread-safe with the libunwind based
+/// unwinder on unix and `RtlVirtualUnwind` on 64-bit Windows. Other targets go through
+/// `dbghelp`, which is not, so call site sampling stays disabled there.
+const UNSYNCHRONIZED_TRACE_IS_SAFE: bool = cfg!(any(
+    unix,
+    all(
+        windows,
+        any(target_arch = "x86_64", target_arch = "aarch64")
+    )
+));
+
+/// Sample every N allocations, or every N bytes in byte mode (0 means disabled)
+static SAMPLE_EVERY: AtomicU64 = AtomicU64::new(0);
+
+static SAMPLE_BYTES: AtomicBool = AtomicBool::new(false);
+
+/// Samples discarded because the channel to the worker was full
+static DROPPED_SAMPLES: AtomicU64 = AtomicU64::new(0);
+
+/// Samples folded into the per-function bucket because `MAX_STACKS` was reached
+static DROPPED_STACKS: AtomicU64 = AtomicU64::new(0);
+
+type Stack = [usize; MAX_FRAMES];
+
+/// Owning function and raw stack of a group of samples
+type StackKey = (&'static str, Stack);
+
+struct Sample {
+    name: &'static str,
+    frames: Stack,
+    bytes: u64,
+}
+
+struct Channel {
+    tx: Sender<Sample>,
+    rx: Receiver<Sample>,
+}
+
+static CHANNEL: OnceLock<Channel> = OnceLock::new();
+
+/// Sample count and estimated bytes by owning function and raw stack
+static AGGREGATED: LazyLock<Mutex<HashMap<StackKey, (u64, u64)>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// Resolved call site label by instruction pointer, `None` for allocator and std frames
+static RESOLVED: LazyLock<Mutex<HashMap<usize, Option<String>>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+thread_local! {
+    /// Allocations or bytes left until the next sample on this thread
+    static COUNTDOWN: Cell<i64> = const { Cell::new(0) };
+
+    /// Per-thread xorshift state used to jitter the allocation countdown
+    static JITTER_STATE: Cell<u64> = const { Cell::new(0x9E37_79B9_7F4A_7C15) };
+}
+
+/// Random interval in `1..2 * every`, so samples average out to one in `every`
+/// without locking onto allocation patterns that repeat with a fixed period.
+fn jittered_interval(every: u64) -> i64 {
+    JITTER_STATE.with(|state| {
+        let mut x = state.get();
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        state.set(x);
+        (x % (2 * every - 1) + 1) as i64
+    })
+}
+
+/// Enables call site sampling if `HOTPATH_ALLOC_CALLSITES` or
+/// `HOTPATH_ALLOC_CALLSITES_BYTES` is set. Must be called while allocation tracking is
+/// disabled on the current thread.
+pub(crate) fn init_call_site_tracking() {
+    let parse = |var: &str| {
+        std::env::var(var)
+            .ok()
+            .and_then(|s| s.parse::<u64>().ok())
+            .filter(|&n| n > 0)
+    };
+
+    let (sample_every, sample_bytes) = match (
+        parse("HOTPATH_ALLOC_CALLSITES_BYTES"),
+        parse("HOTPATH_ALLOC_CALLSITES"),
+    ) {
+        (Some(bytes), _) => (bytes, true),
+        (None, Some(allocs)) => (allocs, false),
+        (None, None) => return,
+    };
+
+    if !UNSYNCHRONIZED_TRACE_IS_SAFE {
+        eprintln!("[hotpath] Allocation call site sampling is not supported on this target");
+        return;
+    }
+
+    CHANNEL.get_or_init(|| {
+        let (tx, rx) = bounded::<Sample>(CHANNEL_CAPACITY);
+        let worker_rx = rx.clone();
+
+        std::thread::Builder::new()
+            .name("hp-alloc-sites".into())
+            .spawn(move || {
+                while let Ok(sample) = worker_rx.recv() {
+                    aggregate(sample);
+                }
+            })
+            .expect("Failed to spawn hp-alloc-sites thread");
+
+        Channel { tx, rx }
+    });
+    LazyLock::force(&AGGREGATED);
+
+    SAMPLE_BYTES.store(sample_bytes, Ordering::Relaxed);
+    SAMPLE_EVERY.store(sample_every, Ordering::Release);
+}
+
+#[inline]
+pub(crate) fn is_enabled() -> bool {
+    SAMPLE_EVERY.load(Ordering::Relaxed) != 0
+}
+
+/// Captures the current stack if this allocation is picked by the sampler.
+///
+/// Called from the allocator, so nothing here may allocate: the stack is captured into a
+/// fixed-size array and pushed to the preallocated channel, or dropped if it is full.
+#[inline]
+pub(crate) fn maybe_capture(size: usize, name: &'static str) {
+    let sample_every = SAMPLE_EVERY.load(Ordering::Relaxed);
+    if sample_every == 0 {
+        return;
+    }
+    // Each sample stands for `every` allocations or `every` bytes
+    let bytes = COUNTDOWN.with(|countdown| {
+        if SAMPLE_BYTES.load(Ordering::Relaxed) {
+            let left = countdown.get() - size as i64;
+            if left > 0 {
+                countdown.set(left);
+                return 0;
+            }
+            let samples = left.unsigned_abs() / sample_every + 1;
+            countdown.set(left + (samples * sample_every) as i64);
+            samples * sample_every
+        } else {
+            let left = countdown.get() - 1;
+            if left > 0 {
+                countdown.set(left);
+                return 0;
+            }
+            countdown.set(jittered_interval(sample_every));
+            size as u64 * sample_every
+        }
+    });
+    if bytes == 0 {
+        return;
+    }
+
+    let Some(channel) = CHANNEL.get() else {
+        return;
+    };
+
+    let mut frames: Stack = [0; MAX_FRAMES];
+    let mut len = 0;
+    // SAFETY: the synchronized variant takes a lock that symbol resolution holds for
+    // a long time, which would stall every sampled allocation. Sampling is only enabled
+    // on targets where the unwinder is thread-safe, see `UNSYNCHRONIZED_TRACE_IS_SAFE`.
+    unsafe {
+        backtrace::trace_unsynchronized(|frame| {
+            frames[len] = frame.ip() as usize;
+            len += 1;
+            len < MAX_FRAMES
+        });
+    }
+
+    if channel
+        .tx
+        .try_send(Sample {
+            name,
+            frames,
+            bytes,
+        })
+        .is_err()
+    {
+        DROPPED_SAMPLES.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+fn aggregate(sample: Sample) {
+    if let Ok(mut aggregated) = AGGREGATED.lock() {
+        let mut key = (sample.name, sample.frames);
+        if aggregated.len() >= MAX_STACKS && !aggregated.contains_key(&key) {
+            key.1 = OTHER_STACK;
+            DROPPED_STACKS.fetch_add(1, Ordering::Relaxed);
+        }
+        let entry = aggregated.entry(key).or_insert((0, 0));
+        entry.0 += 1;
+        entry.1 += sample.bytes;
+    }
+}
+
+/// Frames that belong to hotpath, the allocator or the standard library containers
+/// rather than to the code that asked for the memory.
+fn is_internal_frame(name: &str) -> bool {
+    const INTERNAL_PREFIXES: [&str; 8] = [
+        "alloc::",
+        "core::",
+        "std::",
+        "backtrace::",
+        "hotpath::",
+        "hashbrown::",
+        "__rust",
+        "__rdl",
+    ];
+
+    let name = name.trim_start_matches('<');
+    INTERNAL_PREFIXES
+        .iter()
+        .any(|prefix| name.starts_with(prefix))
+        // e.g. `<u8 as alloc::vec::spec_from_elem::SpecFromElem>::from_elem`
+        || name.contains(" as alloc::")
+}
+
+/// Resolves `ip` to "function (file:line)" of its innermost non-internal symbol.
+///
+/// With inlining one address maps to several symbols, innermost first, so an inlined
+/// `Vec::with_capacity` is skipped in favour of the function it was inlined into.
+fn resolve_ip(ip: usize) -> Option<String> {
+    let mut label = None;
+
+    backtrace::resolve(ip as *mut c_void, |symbol| {
+        if label.is_some() {
+            return;
+        }
+        let Some(name) = symbol.name() else {
+            return;
+        };
+        let name = format!("{:#}", name);
+        if is_internal_frame(&name) {
+            return;
+        }
+
+        label = Some(match (symbol.filename(), symbol.lineno()) {
+            (Some(file), Some(line)) => {
+                let file = std::env::current_dir()
+                    .ok()
+                    .and_then(|cwd| file.strip_prefix(cwd).ok())
+                    .unwrap_or(file);
+                format!("{} ({}:{})", name, file.display(), line)
+            }
+            _ => name,
+        });
+    });
+
+    label
+}
+
+/// Returns the top allocating call sites of each measured function seen so far.
+///
+/// Returns `None` if call site sampling is not enabled for this session.
+pub(crate) fn call_sites() -> Option<CallSitesJson> {
+    let sample_every = SAMPLE_EVERY.load(Ordering::Acquire);
+    if sample_every == 0 {
+        return None;
+    }
+
+    // Pick up samples the worker has not aggregated yet
+    if let Some(channel) = CHANNEL.get() {
+        while let Ok(sample) = channel.rx.try_recv() {
+            aggregate(sample);
+        }
+    }
+
+    let stacks: Vec<(StackKey, (u64, u64))> = AGGREGATED
+        .lock()
+        .ok()?
+        .iter()
+        .map(|(key, value)| (*key, *value))
+        .collect();
+
+    let mut resolved = RESOLVED.lock().ok()?;
+    let mut by_site: HashMap<(&'static str, String), (u64, u64)> = HashMap::new();
+
+    for ((name, frames), (samples, bytes)) in stacks {
+        let location = if frames == OTHER_STACK {
+            "<other>".to_string()
+        } else {
+            frames
+                .iter()
+                .take_while(|&&ip| ip != 0)
+                .find_map(|&ip| resolved.entry(ip).or_insert_with(|| resolve_ip(ip)).clone())
+                .unwrap_or_else(|| "<unknown>".to_string())
+        };
+
+        let entry = by_site.entry((name, location)).or_insert((0, 0));
+        entry.0 += samples;
+        entry.1 += bytes;
+    }
+
+    let mut by_function: HashMap<&'static str, Vec<CallSiteEntry>> = HashMap::new();
+    for ((name, location), (samples, bytes)) in by_site {
+        by_function.entry(name).or_default().push(CallSiteEntry {
+            name: name.to_string(),
+            location,
+            samples,
+            bytes,
+        });
+    }
+
+    let mut data: Vec<CallSiteEntry> = Vec::new();
+    for (_, mut sites) in by_function {
+        sites.sort_by(|a, b| {
+            b.bytes
+                .cmp(&a.bytes)
+                .then_with(|| a.location.cmp(&b.location))
+        });
+        sites.truncate(TOP_CALL_SITES);
+        data.extend(sites);
+    }
+    data.sort_by(|a, b| {
+        a.name
+            .cmp(&b.name)
+            .then_with(|| b.bytes.cmp(&a.bytes))
+            .then_with(|| a.location.cmp(&b.location))
+    });
+
+    Some(CallSitesJson {
+        sample_every,
+        sample_unit: if SAMPLE_BYTES.load(Ordering::Relaxed) {
+            "bytes".to_string()
+        } else {
+            "allocs".to_string()
+        },
+        dropped_samples: DROPPED_SAMPLES.load(Ordering::Relaxed),
+        dropped_stacks: DROPPED_STACKS.load(Ordering::Relaxed),
+        data,
+    })
+}
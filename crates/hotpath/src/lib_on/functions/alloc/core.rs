@@ -1,16 +1,54 @@
+use std::alloc::{GlobalAlloc, Layout, System};
 use std::cell::Cell;
-use std::sync::atomic::{AtomicU64, Ordering};
+use std::ptr;
+use std::sync::atomic::{AtomicPtr, AtomicU64, Ordering};
 
 use crate::tid::current_tid;
 
-pub const MAX_DEPTH: usize = 64;
+const DEFAULT_MAX_DEPTH: usize = 64;
 
-/// Maximum number of threads we can track (fixed size to avoid allocations in allocator)
-const MAX_THREADS: usize = 256;
+/// Maximum nesting depth of measured functions per thread. Calls nested deeper are
+/// folded into the deepest frame. Override at build time with `HOTPATH_ALLOC_MAX_DEPTH`.
+pub const MAX_DEPTH: usize = parse_max_depth(option_env!("HOTPATH_ALLOC_MAX_DEPTH"));
+
+const fn parse_max_depth(value: Option<&str>) -> usize {
+    let Some(value) = value else {
+        return DEFAULT_MAX_DEPTH;
+    };
+
+    let bytes = value.as_bytes();
+    assert!(
+        !bytes.is_empty(),
+        "HOTPATH_ALLOC_MAX_DEPTH must be a positive integer"
+    );
+
+    let mut depth = 0usize;
+    let mut i = 0;
+    while i < bytes.len() {
+        assert!(
+            bytes[i].is_ascii_digit(),
+            "HOTPATH_ALLOC_MAX_DEPTH must be a positive integer"
+        );
+        depth = depth * 10 + (bytes[i] - b'0') as usize;
+        i += 1;
+    }
+
+    // Frame 0 collects allocations made outside of any measured function
+    assert!(depth >= 2, "HOTPATH_ALLOC_MAX_DEPTH must be at least 2");
+    depth
+}
+
+/// Number of thread slots in each registry segment
+const SEGMENT_SLOTS: usize = 256;
+
+/// Slot is not owned by any thread
+const SLOT_FREE: u64 = 0;
+/// Slot is being claimed and its counters are not reset yet
+const SLOT_CLAIMING: u64 = u64::MAX;
 
 /// Per-thread allocation statistics (lock-free)
 pub struct ThreadAllocStats {
-    /// Thread ID (0 means slot is unused)
+    /// Thread ID of the owning thread (0 means slot is unused)
     pub tid: AtomicU64,
     pub alloc_bytes: AtomicU64,
     pub dealloc_bytes: AtomicU64,
@@ -25,21 +63,90 @@ impl Default for ThreadAllocStats {
 impl ThreadAllocStats {
     pub const fn new() -> Self {
         Self {
-            tid: AtomicU64::new(0),
+            tid: AtomicU64::new(SLOT_FREE),
             alloc_bytes: AtomicU64::new(0),
             dealloc_bytes: AtomicU64::new(0),
         }
     }
 }
 
-#[allow(clippy::declare_interior_mutable_const)]
-static THREAD_ALLOC_STATS: [ThreadAllocStats; MAX_THREADS] = {
-    const INIT: ThreadAllocStats = ThreadAllocStats::new();
-    [INIT; MAX_THREADS]
-};
+/// Fixed-size block of thread slots. Segments form an append-only linked list, so the
+/// registry can grow without locks and slots never move once handed out.
+struct Segment {
+    slots: [ThreadAllocStats; SEGMENT_SLOTS],
+    next: AtomicPtr<Segment>,
+}
+
+impl Segment {
+    const fn new() -> Self {
+        Self {
+            slots: [const { ThreadAllocStats::new() }; SEGMENT_SLOTS],
+            next: AtomicPtr::new(ptr::null_mut()),
+        }
+    }
+
+    /// Allocates a new segment straight from the system allocator, so that growing
+    /// the registry never re-enters the counting allocator.
+    fn alloc() -> Option<&'static Segment> {
+        let layout = Layout::new::<Segment>();
+        unsafe {
+            let segment = System.alloc(layout) as *mut Segment;
+            if segment.is_null() {
+                return None;
+            }
+            segment.write(Segment::new());
+            Some(&*segment)
+        }
+    }
+
+    fn dealloc(segment: &'static Segment) {
+        unsafe {
+            System.dealloc(
+                segment as *const Segment as *mut u8,
+                Layout::new::<Segment>(),
+            );
+        }
+    }
+}
+
+static FIRST_SEGMENT: Segment = Segment::new();
+
+fn segments() -> impl Iterator<Item = &'static Segment> {
+    std::iter::successors(Some(&FIRST_SEGMENT), |segment| unsafe {
+        segment.next.load(Ordering::Acquire).as_ref()
+    })
+}
 
 static THREAD_TRACKING_ENABLED: AtomicU64 = AtomicU64::new(0);
 
+/// Number of threads that could not get a slot (e.g. when growing the registry failed)
+static UNTRACKED_THREADS: AtomicU64 = AtomicU64::new(0);
+
+/// Owns the current thread's slot and returns it to the registry on thread exit.
+struct ThreadSlot {
+    slot: Cell<Option<&'static ThreadAllocStats>>,
+    untracked: Cell<bool>,
+}
+
+impl Drop for ThreadSlot {
+    fn drop(&mut self) {
+        if let Some(slot) = self.slot.take() {
+            slot.tid.store(SLOT_FREE, Ordering::Release);
+        }
+    }
+}
+
+thread_local! {
+    static THREAD_SLOT: ThreadSlot = const { ThreadSlot {
+        slot: Cell::new(None),
+        untracked: Cell::new(false),
+    } };
+
+    /// Set while looking up the thread slot. Registering the TLS destructor may
+    /// allocate on some platforms, and those allocations must not recurse into the lookup.
+    static IN_SLOT_LOOKUP: Cell<bool> = const { Cell::new(false) };
+}
+
 /// Initialize the thread allocation tracking system
 pub fn init_thread_alloc_tracking() {
     THREAD_TRACKING_ENABLED.store(1, Ordering::Release);
@@ -51,48 +158,110 @@ pub fn get_thread_alloc_stats(os_tid: u64) -> Option<(u64, u64)> {
         return None;
     }
 
-    for slot in &THREAD_ALLOC_STATS {
-        let slot_tid = slot.tid.load(Ordering::Acquire);
-        if slot_tid == os_tid {
-            return Some((
+    segments()
+        .flat_map(|segment| segment.slots.iter())
+        .find(|slot| slot.tid.load(Ordering::Acquire) == os_tid)
+        .map(|slot| {
+            (
                 slot.alloc_bytes.load(Ordering::Relaxed),
                 slot.dealloc_bytes.load(Ordering::Relaxed),
-            ));
-        }
-        if slot_tid == 0 {
-            break;
-        }
-    }
-    None
+            )
+        })
 }
 
-#[inline]
-fn get_or_create_slot(tid: u64) -> Option<&'static ThreadAllocStats> {
-    for slot in &THREAD_ALLOC_STATS {
-        let slot_tid = slot.tid.load(Ordering::Acquire);
+/// Get the number of threads whose allocations could not be tracked
+pub fn get_untracked_threads() -> Option<u64> {
+    if THREAD_TRACKING_ENABLED.load(Ordering::Acquire) == 0 {
+        return None;
+    }
 
-        if slot_tid == tid {
-            return Some(slot);
-        }
+    Some(UNTRACKED_THREADS.load(Ordering::Relaxed))
+}
+
+fn claim_slot(tid: u64) -> Option<&'static ThreadAllocStats> {
+    let mut segment = &FIRST_SEGMENT;
 
-        if slot_tid == 0 {
-            match slot
-                .tid
-                .compare_exchange(0, tid, Ordering::AcqRel, Ordering::Acquire)
+    loop {
+        for slot in &segment.slots {
+            if slot.tid.load(Ordering::Relaxed) == SLOT_FREE
+                && slot
+                    .tid
+                    .compare_exchange(
+                        SLOT_FREE,
+                        SLOT_CLAIMING,
+                        Ordering::AcqRel,
+                        Ordering::Relaxed,
+                    )
+                    .is_ok()
             {
-                Ok(_) => return Some(slot),
-                Err(current) if current == tid => return Some(slot),
-                Err(_) => continue,
+                // Slots are reused after thread exit, drop the previous owner's totals
+                slot.alloc_bytes.store(0, Ordering::Relaxed);
+                slot.dealloc_bytes.store(0, Ordering::Relaxed);
+                slot.tid.store(tid, Ordering::Release);
+                return Some(slot);
             }
         }
+
+        let next = segment.next.load(Ordering::Acquire);
+        segment = match unsafe { next.as_ref() } {
+            Some(next) => next,
+            None => {
+                let new_segment = Segment::alloc()?;
+                match segment.next.compare_exchange(
+                    ptr::null_mut(),
+                    new_segment as *const Segment as *mut Segment,
+                    Ordering::AcqRel,
+                    Ordering::Acquire,
+                ) {
+                    Ok(_) => new_segment,
+                    Err(existing) => {
+                        // Another thread grew the registry first
+                        Segment::dealloc(new_segment);
+                        unsafe { &*existing }
+                    }
+                }
+            }
+        };
+    }
+}
+
+#[inline]
+fn current_thread_slot() -> Option<&'static ThreadAllocStats> {
+    if IN_SLOT_LOOKUP.with(|in_lookup| in_lookup.replace(true)) {
+        return None;
     }
-    None
+
+    let slot = THREAD_SLOT
+        .try_with(|thread_slot| {
+            if let Some(slot) = thread_slot.slot.get() {
+                return Some(slot);
+            }
+            if thread_slot.untracked.get() {
+                return None;
+            }
+
+            let slot = claim_slot(current_tid());
+            match slot {
+                Some(slot) => thread_slot.slot.set(Some(slot)),
+                None => {
+                    thread_slot.untracked.set(true);
+                    UNTRACKED_THREADS.fetch_add(1, Ordering::Relaxed);
+                }
+            }
+            slot
+        })
+        .ok()
+        .flatten();
+
+    IN_SLOT_LOOKUP.with(|in_lookup| in_lookup.set(false));
+    slot
 }
 
 pub struct AllocationInfo {
+    /// Measured function that owns this frame, used to attribute live allocations
+    pub name: Cell<&'static str>,
     pub bytes_total: Cell<u64>,
     pub count_total: Cell<u64>,
-    pub unsupported_async: Cell<bool>,
 }
 
 impl std::ops::AddAssign for AllocationInfo {
@@ -101,8 +270,6 @@ impl std::ops::AddAssign for AllocationInfo {
             .set(self.bytes_total.get() + other.bytes_total.get());
         self.count_total
             .set(self.count_total.get() + other.count_total.get());
-        self.unsupported_async
-            .set(self.unsupported_async.get() | other.unsupported_async.get());
     }
 }
 
@@ -116,16 +283,63 @@ thread_local! {
     pub static ALLOCATIONS: AllocationInfoStack = const { AllocationInfoStack {
         depth: Cell::new(0),
         elements: [const { AllocationInfo {
+            name: Cell::new(""),
             bytes_total: Cell::new(0),
             count_total: Cell::new(0),
-            unsupported_async: Cell::new(false)
         } }; MAX_DEPTH],
         tracking_enabled: Cell::new(true),
     } };
 }
 
+/// Pushes a new frame owned by `name` onto the current thread's allocation stack.
+///
+/// Returns `false` without pushing if the stack is already `MAX_DEPTH` deep. Allocations
+/// of such a call are then attributed to the deepest frame, and `pop_frame` must not be
+/// called for it.
 #[inline]
-pub fn track_alloc(size: usize) {
+pub fn push_frame(name: &'static str) -> bool {
+    ALLOCATIONS.with(|stack| {
+        let depth = stack.depth.get() as usize + 1;
+        if depth >= MAX_DEPTH {
+            return false;
+        }
+
+        stack.depth.set(depth as u32);
+        stack.elements[depth].name.set(name);
+        stack.elements[depth].bytes_total.set(0);
+        stack.elements[depth].count_total.set(0);
+        true
+    })
+}
+
+/// Pops the top frame and returns its `(bytes, count)` totals.
+///
+/// In cumulative mode the totals are also added to the parent frame.
+#[inline]
+pub fn pop_frame() -> (u64, u64) {
+    ALLOCATIONS.with(|stack| {
+        let depth = stack.depth.get() as usize;
+        let bytes = stack.elements[depth].bytes_total.get();
+        let count = stack.elements[depth].count_total.get();
+
+        stack.depth.set(stack.depth.get() - 1);
+
+        if !super::shared::is_alloc_self_enabled() {
+            let parent = stack.depth.get() as usize;
+            stack.elements[parent]
+                .bytes_total
+                .set(stack.elements[parent].bytes_total.get() + bytes);
+            stack.elements[parent]
+                .count_total
+                .set(stack.elements[parent].count_total.get() + count);
+        }
+
+        (bytes, count)
+    })
+}
+
+#[inline]
+pub fn track_alloc(ptr: *mut u8, size: usize) {
     ALLOCATIONS.with(|stack| {
         if !stack.tracking_enabled.get() {
             return;
@@ -134,21 +348,30 @@ pub fn track_alloc(size: usize) {
         let info = &stack.elements[depth];
         info.bytes_total.set(info.bytes_total.get() + size as u64);
         info.count_total.set(info.count_total.get() + 1);
+
+        if depth > 0 && (super::leaks::is_enabled() || super::callsites::is_enabled()) {
+            stack.tracking_enabled.set(false);
+            super::leaks::maybe_record(ptr, size, info.name.get());
+            super::callsites::maybe_capture(size, info.name.get());
+            stack.tracking_enabled.set(true);
+        }
     });
 
     if THREAD_TRACKING_ENABLED.load(Ordering::Relaxed) != 0 {
-        let tid = current_tid();
-        if let Some(slot) = get_or_create_slot(tid) {
+        if let Some(slot) = current_thread_slot() {
             slot.alloc_bytes.fetch_add(size as u64, Ordering::Relaxed);
         }
     }
 }
 
 #[inline]
-pub fn track_dealloc(size: usize) {
+pub fn track_dealloc(ptr: *mut u8, size: usize) {
+    if super::leaks::is_enabled() {
+        super::leaks::forget(ptr);
+    }
+
     if THREAD_TRACKING_ENABLED.load(Ordering::Relaxed) != 0 {
-        let tid = current_tid();
-        if let Some(slot) = get_or_create_slot(tid) {
+        if let Some(slot) = current_thread_slot() {
             slot.dealloc_bytes.fetch_add(size as u64, Ordering::Relaxed);
         }
     }
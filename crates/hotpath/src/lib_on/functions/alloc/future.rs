@@ -0,0 +1,179 @@
+//! Allocation tracking for async functions that may migrate between threads.
+//!
+//! A guard held across `.await` points cannot attribute allocations on a multi-threaded
+//! runtime, because the thread-local allocation stack it pushed onto may belong to a
+//! different worker by the time it is dropped. `MeasuredFuture` instead pushes a fresh
+//! frame around every `poll`, so allocations are always recorded on the polling
+//! thread's stack, and accumulates the per-poll totals into its own counters.
+
+#[cfg(target_os = "linux")]
+use quanta::Instant;
+
+#[cfg(not(target_os = "linux"))]
+use std::time::Instant;
+
+use pin_project_lite::pin_project;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+pin_project! {
+    /// Future wrapper used by `#[hotpath::measure]` on async functions in alloc mode.
+    pub struct MeasuredFuture<F: Future> {
+        #[pin]
+        inner: F,
+        name: &'static str,
+        bytes_total: u64,
+        count_total: u64,
+        start: Option<Instant>,
+        // Whether any poll got its own frame, and whether any was nested too deeply for one
+        measured_polls: bool,
+        overflowed_polls: bool,
+        log_result: Option<fn(&F::Output) -> String>,
+        finished: bool,
+    }
+
+    impl<F: Future> PinnedDrop for MeasuredFuture<F> {
+        fn drop(this: Pin<&mut Self>) {
+            let this = this.project();
+            if let Some(start) = this.start {
+                if !*this.finished {
+                    send(this.name, *this.bytes_total, *this.count_total, start.elapsed(), *this.measured_polls, *this.overflowed_polls, None);
+                }
+            }
+        }
+    }
+}
+
+impl<F: Future> MeasuredFuture<F> {
+    pub fn new(name: &'static str, inner: F) -> Self {
+        Self {
+            inner,
+            name,
+            bytes_total: 0,
+            count_total: 0,
+            start: None,
+            measured_polls: false,
+            overflowed_polls: false,
+            log_result: None,
+            finished: false,
+        }
+    }
+}
+
+impl<F: Future> MeasuredFuture<F>
+where
+    F::Output: std::fmt::Debug,
+{
+    /// Creates a measured future that also logs the output value.
+    pub fn new_with_log(name: &'static str, inner: F) -> Self {
+        let mut future = Self::new(name, inner);
+        future.log_result = Some(|output| super::super::truncate_result(format!("{:?}", output)));
+        future
+    }
+}
+
+impl<F: Future> Future for MeasuredFuture<F> {
+    type Output = F::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        let start = *this.start.get_or_insert_with(Instant::now);
+
+        let poll_start = Instant::now();
+        let result = if super::core::push_frame(this.name) {
+            *this.measured_polls = true;
+            let _frame = PollFrameGuard {
+                bytes_total: this.bytes_total,
+                count_total: this.count_total,
+            };
+            this.inner.poll(cx)
+        } else {
+            // Too deeply nested, allocations of this poll go to the deepest frame
+            *this.overflowed_polls = true;
+            this.inner.poll(cx)
+        };
+        check_blocking_poll(this.name, poll_start.elapsed().as_nanos() as u64);
+
+        if let Poll::Ready(output) = &result {
+            *this.finished = true;
+            let result_log = this.log_result.map(|log| log(output));
+            send(
+                this.name,
+                *this.bytes_total,
+                *this.count_total,
+                start.elapsed(),
+                *this.measured_polls,
+                *this.overflowed_polls,
+                result_log,
+            );
+        }
+
+        result
+    }
+}
+
+/// Pops the frame pushed for a single poll, also when the inner future panics, so a
+/// runtime that catches the panic doesn't keep charging the worker's allocations to it.
+struct PollFrameGuard<'a> {
+    bytes_total: &'a mut u64,
+    count_total: &'a mut u64,
+}
+
+impl Drop for PollFrameGuard<'_> {
+    fn drop(&mut self) {
+        let (bytes, count) = super::core::pop_frame();
+        *self.bytes_total += bytes;
+        *self.count_total += count;
+    }
+}
+
+fn check_blocking_poll(name: &'static str, poll_ns: u64) {
+    let threshold_ns = *crate::blocking::BLOCKING_POLL_THRESHOLD_NS;
+    if threshold_ns.is_none_or(|threshold_ns| poll_ns <= threshold_ns) {
+        return;
+    }
+
+    // Recording allocates, keep it out of the measured allocations
+    let was_tracking = super::core::ALLOCATIONS.with(|stack| stack.tracking_enabled.replace(false));
+
+    crate::blocking::check_poll(
+        name,
+        crate::blocking::BlockingPollSource::Function,
+        poll_ns,
+        threshold_ns,
+    );
+
+    super::core::ALLOCATIONS.with(|stack| {
+        stack.tracking_enabled.set(was_tracking);
+    });
+}
+
+fn send(
+    name: &'static str,
+    bytes_total: u64,
+    count_total: u64,
+    duration: std::time::Duration,
+    measured_polls: bool,
+    overflowed_polls: bool,
+    result_log: Option<String>,
+) {
+    let was_tracking = super::core::ALLOCATIONS.with(|stack| stack.tracking_enabled.replace(false));
+
+    super::state::send_alloc_measurement_with_log(
+        name,
+        bytes_total,
+        count_total,
+        duration,
+        false,
+        false,
+        overflowed_polls && !measured_polls,
+        overflowed_polls && measured_polls,
+        Some(crate::tid::current_tid()),
+        result_log,
+    );
+
+    super::core::ALLOCATIONS.with(|stack| {
+        stack.tracking_enabled.set(was_tracking);
+    });
+}
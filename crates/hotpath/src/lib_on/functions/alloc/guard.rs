@@ -10,30 +10,20 @@ use super::super::truncate_result;
 pub struct MeasurementGuard {
     name: &'static str,
     wrapper: bool,
-    unsupported_async: bool,
+    depth_overflow: bool,
     tid: u64,
     start: Instant,
 }
 
 impl MeasurementGuard {
     #[inline]
-    pub fn new(name: &'static str, wrapper: bool, unsupported_async: bool) -> Self {
-        if !unsupported_async {
-            super::core::ALLOCATIONS.with(|stack| {
-                let current_depth = stack.depth.get();
-                stack.depth.set(current_depth + 1);
-                assert!((stack.depth.get() as usize) < super::core::MAX_DEPTH);
-                let depth = stack.depth.get() as usize;
-                stack.elements[depth].bytes_total.set(0);
-                stack.elements[depth].count_total.set(0);
-                stack.elements[depth].unsupported_async.set(false);
-            });
-        }
+    pub fn new(name: &'static str, wrapper: bool, _unsupported_async: bool) -> Self {
+        let depth_overflow = !super::core::push_frame(name);
 
         Self {
             name,
             wrapper,
-            unsupported_async,
+            depth_overflow,
             tid: crate::tid::current_tid(),
             start: Instant::now(),
         }
@@ -46,34 +36,11 @@ impl Drop for MeasurementGuard {
         let duration = self.start.elapsed();
         let cross_thread = crate::tid::current_tid() != self.tid;
 
-        let (bytes_total, count_total, unsupported_async) =
-            if self.unsupported_async || cross_thread {
-                (0, 0, self.unsupported_async)
-            } else {
-                super::core::ALLOCATIONS.with(|stack| {
-                    let depth = stack.depth.get() as usize;
-                    let bytes = stack.elements[depth].bytes_total.get();
-                    let count = stack.elements[depth].count_total.get();
-                    let unsup_async = stack.elements[depth].unsupported_async.get();
-
-                    stack.depth.set(stack.depth.get() - 1);
-
-                    if !super::shared::is_alloc_self_enabled() {
-                        let parent = stack.depth.get() as usize;
-                        stack.elements[parent]
-                            .bytes_total
-                            .set(stack.elements[parent].bytes_total.get() + bytes);
-                        stack.elements[parent]
-                            .count_total
-                            .set(stack.elements[parent].count_total.get() + count);
-                        stack.elements[parent]
-                            .unsupported_async
-                            .set(stack.elements[parent].unsupported_async.get() | unsup_async);
-                    }
-
-                    (bytes, count, unsup_async)
-                })
-            };
+        let (bytes_total, count_total) = if cross_thread || self.depth_overflow {
+            (0, 0)
+        } else {
+            super::core::pop_frame()
+        };
 
         super::core::ALLOCATIONS.with(|stack| {
             stack.tracking_enabled.set(false);
@@ -85,9 +52,9 @@ impl Drop for MeasurementGuard {
             bytes_total,
             count_total,
             duration,
-            unsupported_async,
             self.wrapper,
             cross_thread,
+            self.depth_overflow,
             tid,
         );
 
@@ -101,7 +68,7 @@ impl Drop for MeasurementGuard {
 pub struct MeasurementGuardWithLog {
     name: &'static str,
     wrapper: bool,
-    unsupported_async: bool,
+    depth_overflow: bool,
     tid: u64,
     start: Instant,
     finished: bool,
@@ -109,23 +76,13 @@ pub struct MeasurementGuardWithLog {
 
 impl MeasurementGuardWithLog {
     #[inline]
-    pub fn new(name: &'static str, wrapper: bool, unsupported_async: bool) -> Self {
-        if !unsupported_async {
-            super::core::ALLOCATIONS.with(|stack| {
-                let current_depth = stack.depth.get();
-                stack.depth.set(current_depth + 1);
-                assert!((stack.depth.get() as usize) < super::core::MAX_DEPTH);
-                let depth = stack.depth.get() as usize;
-                stack.elements[depth].bytes_total.set(0);
-                stack.elements[depth].count_total.set(0);
-                stack.elements[depth].unsupported_async.set(false);
-            });
-        }
+    pub fn new(name: &'static str, wrapper: bool, _unsupported_async: bool) -> Self {
+        let depth_overflow = !super::core::push_frame(name);
 
         Self {
             name,
             wrapper,
-            unsupported_async,
+            depth_overflow,
             tid: crate::tid::current_tid(),
             start: Instant::now(),
             finished: false,
@@ -140,34 +97,11 @@ impl MeasurementGuardWithLog {
         let duration = self.start.elapsed();
         let cross_thread = crate::tid::current_tid() != self.tid;
 
-        let (bytes_total, count_total, unsupported_async) =
-            if self.unsupported_async || cross_thread {
-                (0, 0, self.unsupported_async)
-            } else {
-                super::core::ALLOCATIONS.with(|stack| {
-                    let depth = stack.depth.get() as usize;
-                    let bytes = stack.elements[depth].bytes_total.get();
-                    let count = stack.elements[depth].count_total.get();
-                    let unsup_async = stack.elements[depth].unsupported_async.get();
-
-                    stack.depth.set(stack.depth.get() - 1);
-
-                    if !super::shared::is_alloc_self_enabled() {
-                        let parent = stack.depth.get() as usize;
-                        stack.elements[parent]
-                            .bytes_total
-                            .set(stack.elements[parent].bytes_total.get() + bytes);
-                        stack.elements[parent]
-                            .count_total
-                            .set(stack.elements[parent].count_total.get() + count);
-                        stack.elements[parent]
-                            .unsupported_async
-                            .set(stack.elements[parent].unsupported_async.get() | unsup_async);
-                    }
-
-                    (bytes, count, unsup_async)
-                })
-            };
+        let (bytes_total, count_total) = if cross_thread || self.depth_overflow {
+            (0, 0)
+        } else {
+            super::core::pop_frame()
+        };
 
         super::core::ALLOCATIONS.with(|stack| {
             stack.tracking_enabled.set(false);
@@ -179,9 +113,10 @@ impl MeasurementGuardWithLog {
             bytes_total,
             count_total,
             duration,
-            unsupported_async,
             self.wrapper,
             cross_thread,
+            self.depth_overflow,
+            false,
             tid,
             Some(result_str),
         );
@@ -199,34 +134,11 @@ impl Drop for MeasurementGuardWithLog {
             let duration = self.start.elapsed();
             let cross_thread = crate::tid::current_tid() != self.tid;
 
-            let (bytes_total, count_total, unsupported_async) =
-                if self.unsupported_async || cross_thread {
-                    (0, 0, self.unsupported_async)
-                } else {
-                    super::core::ALLOCATIONS.with(|stack| {
-                        let depth = stack.depth.get() as usize;
-                        let bytes = stack.elements[depth].bytes_total.get();
-                        let count = stack.elements[depth].count_total.get();
-                        let unsup_async = stack.elements[depth].unsupported_async.get();
-
-                        stack.depth.set(stack.depth.get() - 1);
-
-                        if !super::shared::is_alloc_self_enabled() {
-                            let parent = stack.depth.get() as usize;
-                            stack.elements[parent]
-                                .bytes_total
-                                .set(stack.elements[parent].bytes_total.get() + bytes);
-                            stack.elements[parent]
-                                .count_total
-                                .set(stack.elements[parent].count_total.get() + count);
-                            stack.elements[parent]
-                                .unsupported_async
-                                .set(stack.elements[parent].unsupported_async.get() | unsup_async);
-                        }
-
-                        (bytes, count, unsup_async)
-                    })
-                };
+            let (bytes_total, count_total) = if cross_thread || self.depth_overflow {
+                (0, 0)
+            } else {
+                super::core::pop_frame()
+            };
 
             super::core::ALLOCATIONS.with(|stack| {
                 stack.tracking_enabled.set(false);
@@ -238,9 +150,10 @@ impl Drop for MeasurementGuardWithLog {
                 bytes_total,
                 count_total,
                 duration,
-                unsupported_async,
                 self.wrapper,
                 cross_thread,
+                self.depth_overflow,
+                false,
                 tid,
                 None,
             );
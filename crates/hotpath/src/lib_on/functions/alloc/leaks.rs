@@ -0,0 +1,194 @@
+//! Sampled tracking of allocations that are still live when the profiling session ends.
+//!
+//! One in N allocations made inside a measured function is recorded together with the
+//! name of the innermost measured function. Freed allocations are removed again, so
+//! whatever is left when `FunctionsGuard` drops is memory that function still holds.
+
+use std::cell::Cell;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::sync::{LazyLock, Mutex};
+
+use crate::output::{LiveAllocEntry, LiveAllocsJson};
+
+/// Maximum number of sampled allocations that can be live at the same time
+const MAX_LIVE_SAMPLES: usize = 1 << 16;
+
+/// Maximum number of slots probed before giving up on an insert or lookup
+const MAX_PROBES: usize = 64;
+
+const EMPTY: usize = 0;
+const TOMBSTONE: usize = usize::MAX;
+
+const DEFAULT_SAMPLE_RATE: u64 = 16;
+
+/// Sample one in N allocations on average (0 means leak tracking is disabled)
+static SAMPLE_RATE: AtomicU64 = AtomicU64::new(0);
+
+static DROPPED_SAMPLES: AtomicU64 = AtomicU64::new(0);
+
+/// Lock-free open addressing set of sampled pointers. It lets `dealloc` skip the
+/// `LIVE_SAMPLES` lock for the vast majority of frees, which were never sampled.
+#[allow(clippy::declare_interior_mutable_const)]
+static SAMPLED_PTRS: [AtomicUsize; MAX_LIVE_SAMPLES] = {
+    const INIT: AtomicUsize = AtomicUsize::new(EMPTY);
+    [INIT; MAX_LIVE_SAMPLES]
+};
+
+/// Owning function and size of every sampled allocation that has not been freed yet
+static LIVE_SAMPLES: LazyLock<Mutex<HashMap<usize, (&'static str, u64)>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+thread_local! {
+    /// Per-thread xorshift state. Random sampling avoids aliasing with allocation
+    /// patterns that repeat with a fixed period, which a plain counter would hit.
+    static SAMPLER_STATE: Cell<u64> = const { Cell::new(0x2545_F491_4F6C_DD1D) };
+}
+
+pub(crate) fn is_alloc_leaks_enabled() -> bool {
+    std::env::var("HOTPATH_ALLOC_LEAKS")
+        .map(|v| v.eq_ignore_ascii_case("true") || v == "1")
+        .unwrap_or(false)
+}
+
+/// Enables leak tracking if `HOTPATH_ALLOC_LEAKS` is set. Must be called while
+/// allocation tracking is disabled on the current thread.
+pub(crate) fn init_leak_tracking() {
+    if !is_alloc_leaks_enabled() {
+        return;
+    }
+
+    let sample_rate = std::env::var("HOTPATH_ALLOC_LEAKS_SAMPLE")
+        .ok()
+        .and_then(|s| s.parse::<u64>().ok())
+        .filter(|&rate| rate > 0)
+        .unwrap_or(DEFAULT_SAMPLE_RATE);
+
+    LazyLock::force(&LIVE_SAMPLES);
+    SAMPLE_RATE.store(sample_rate, Ordering::Release);
+}
+
+#[inline]
+pub(crate) fn is_enabled() -> bool {
+    SAMPLE_RATE.load(Ordering::Relaxed) != 0
+}
+
+#[inline]
+fn slot_index(ptr: usize) -> usize {
+    // Allocations are at least 8-byte aligned, low bits carry no information
+    ((ptr >> 3).wrapping_mul(0x9E37_79B9_7F4A_7C15) >> 16) % MAX_LIVE_SAMPLES
+}
+
+/// Records `ptr` as owned by `name` if this allocation is picked by the sampler.
+///
+/// Called from the allocator with allocation tracking disabled on the current
+/// thread, so the `LIVE_SAMPLES` bookkeeping itself is never sampled.
+#[inline]
+pub(crate) fn maybe_record(ptr: *mut u8, size: usize, name: &'static str) {
+    let sample_rate = SAMPLE_RATE.load(Ordering::Relaxed);
+    if sample_rate == 0 || ptr.is_null() {
+        return;
+    }
+
+    let sampled = SAMPLER_STATE.with(|state| {
+        let mut x = state.get();
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        state.set(x);
+        x % sample_rate == 0
+    });
+    if !sampled {
+        return;
+    }
+
+    let ptr = ptr as usize;
+    let start = slot_index(ptr);
+
+    for probe in 0..MAX_PROBES {
+        let slot = &SAMPLED_PTRS[(start + probe) % MAX_LIVE_SAMPLES];
+        let current = slot.load(Ordering::Acquire);
+        if (current == EMPTY || current == TOMBSTONE)
+            && slot
+                .compare_exchange(current, ptr, Ordering::AcqRel, Ordering::Acquire)
+                .is_ok()
+        {
+            if let Ok(mut live) = LIVE_SAMPLES.lock() {
+                live.insert(ptr, (name, size as u64));
+            }
+            return;
+        }
+    }
+
+    DROPPED_SAMPLES.fetch_add(1, Ordering::Relaxed);
+}
+
+/// Forgets `ptr` if it was sampled. Cheap for pointers that were never sampled.
+#[inline]
+pub(crate) fn forget(ptr: *mut u8) {
+    let ptr = ptr as usize;
+    let start = slot_index(ptr);
+
+    for probe in 0..MAX_PROBES {
+        let slot = &SAMPLED_PTRS[(start + probe) % MAX_LIVE_SAMPLES];
+        let current = slot.load(Ordering::Acquire);
+        if current == EMPTY {
+            return;
+        }
+        if current == ptr {
+            if slot
+                .compare_exchange(ptr, TOMBSTONE, Ordering::AcqRel, Ordering::Acquire)
+                .is_ok()
+            {
+                if let Ok(mut live) = LIVE_SAMPLES.lock() {
+                    live.remove(&ptr);
+                }
+            }
+            return;
+        }
+    }
+}
+
+/// Stops sampling and aggregates the allocations that are still live by owning function.
+///
+/// Returns `None` if leak tracking was not enabled for this session.
+pub(crate) fn take_live_allocs() -> Option<LiveAllocsJson> {
+    let sample_rate = SAMPLE_RATE.swap(0, Ordering::AcqRel);
+    if sample_rate == 0 {
+        return None;
+    }
+
+    let live: Vec<(&'static str, u64)> = {
+        let mut live = LIVE_SAMPLES.lock().ok()?;
+        let entries = live.values().copied().collect();
+        live.clear();
+        entries
+    };
+
+    for slot in &SAMPLED_PTRS {
+        slot.store(EMPTY, Ordering::Release);
+    }
+
+    let mut by_function: HashMap<&'static str, (u64, u64)> = HashMap::new();
+    for (name, size) in live {
+        let entry = by_function.entry(name).or_insert((0, 0));
+        entry.0 += 1;
+        entry.1 += size;
+    }
+
+    let mut data: Vec<LiveAllocEntry> = by_function
+        .into_iter()
+        .map(|(name, (count, bytes))| LiveAllocEntry {
+            name: name.to_string(),
+            count: count * sample_rate,
+            bytes: bytes * sample_rate,
+        })
+        .collect();
+    data.sort_by(|a, b| b.bytes.cmp(&a.bytes).then_with(|| a.name.cmp(&b.name)));
+
+    Some(LiveAllocsJson {
+        sample_rate,
+        dropped_samples: DROPPED_SAMPLES.swap(0, Ordering::Relaxed),
+        data,
+    })
+}
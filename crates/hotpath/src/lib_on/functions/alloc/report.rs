@@ -3,7 +3,10 @@ use std::collections::HashMap;
 use std::time::Duration;
 
 use super::state::FunctionStats;
-use crate::output::{MetricType, MetricsProvider};
+use crate::json::RuntimeSummaryJson;
+use crate::output::{
+    CallSitesJson, DepthOverflowEntry, LiveAllocsJson, MetricType, MetricsProvider,
+};
 
 pub struct StatsData<'a> {
     pub stats: &'a HashMap<&'static str, FunctionStats>,
@@ -11,6 +14,9 @@ pub struct StatsData<'a> {
     pub percentiles: Vec<u8>,
     pub caller_name: &'static str,
     pub limit: usize,
+    pub live_allocs: Option<LiveAllocsJson>,
+    pub call_sites: Option<CallSitesJson>,
+    pub runtime: Option<RuntimeSummaryJson>,
 }
 
 pub struct TimingStatsData<'a> {
@@ -35,6 +41,9 @@ impl<'a> MetricsProvider<'a> for StatsData<'a> {
             percentiles,
             caller_name,
             limit,
+            live_allocs: None,
+            call_sites: None,
+            runtime: None,
         }
     }
 
@@ -54,8 +63,31 @@ impl<'a> MetricsProvider<'a> for StatsData<'a> {
         self.percentiles.clone()
     }
 
-    fn has_unsupported_async(&self) -> bool {
-        self.stats.values().any(|s| s.has_unsupported_async)
+    fn runtime(&self) -> Option<RuntimeSummaryJson> {
+        self.runtime.clone()
+    }
+
+    fn live_allocs(&self) -> Option<LiveAllocsJson> {
+        self.live_allocs.clone()
+    }
+
+    fn call_sites(&self) -> Option<CallSitesJson> {
+        self.call_sites.clone()
+    }
+
+    fn depth_overflows(&self) -> Vec<DepthOverflowEntry> {
+        let mut overflows: Vec<DepthOverflowEntry> = self
+            .stats
+            .iter()
+            .filter(|(_, s)| s.depth_overflows > 0 || s.partial_depth_overflows > 0)
+            .map(|(name, s)| DepthOverflowEntry {
+                name: name.to_string(),
+                count: s.depth_overflows + s.partial_depth_overflows,
+                partial: s.partial_depth_overflows,
+            })
+            .collect();
+        overflows.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.name.cmp(&b.name)));
+        overflows
     }
 
     fn metric_data(&self) -> Vec<(String, Vec<MetricType>)> {
@@ -121,7 +153,7 @@ impl<'a> MetricsProvider<'a> for StatsData<'a> {
                     0.0
                 };
 
-                let mut metrics = if stats.has_unsupported_async || stats.cross_thread {
+                let mut metrics = if stats.cross_thread {
                     vec![MetricType::CallsCount(stats.count), MetricType::Unsupported]
                 } else {
                     vec![
@@ -131,7 +163,7 @@ impl<'a> MetricsProvider<'a> for StatsData<'a> {
                 };
 
                 for &p in &self.percentiles {
-                    if stats.has_unsupported_async || stats.cross_thread {
+                    if stats.cross_thread {
                         metrics.push(MetricType::Unsupported);
                     } else {
                         let bytes_total = stats.bytes_total_percentile(p as f64);
@@ -140,7 +172,7 @@ impl<'a> MetricsProvider<'a> for StatsData<'a> {
                     }
                 }
 
-                if stats.has_unsupported_async || stats.cross_thread {
+                if stats.cross_thread {
                     metrics.push(MetricType::Unsupported);
                     metrics.push(MetricType::Unsupported);
                 } else {
@@ -207,10 +239,6 @@ impl<'a> MetricsProvider<'a> for TimingStatsData<'a> {
         self.percentiles.clone()
     }
 
-    fn has_unsupported_async(&self) -> bool {
-        false
-    }
-
     fn metric_data(&self) -> Vec<(String, Vec<MetricType>)> {
         let mut filtered_stats: Vec<_> = self.stats.iter().filter(|(_, s)| s.has_data).collect();
 
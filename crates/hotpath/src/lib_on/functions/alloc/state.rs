@@ -32,9 +32,10 @@ impl MeasurementBatch {
         bytes_total: u64,
         count_total: u64,
         duration: Duration,
-        unsupported_async: bool,
         wrapper: bool,
         cross_thread: bool,
+        depth_overflow: bool,
+        partial: bool,
         tid: Option<u64>,
         result_log: Option<String>,
     ) {
@@ -59,9 +60,10 @@ impl MeasurementBatch {
             count_total,
             duration,
             measurement_time: Instant::now(),
-            unsupported_async,
             wrapper,
             cross_thread,
+            depth_overflow,
+            partial,
             tid,
             result_log,
         };
@@ -112,9 +114,13 @@ pub struct Measurement {
     pub count_total: u64,
     pub duration: Duration,
     pub measurement_time: Instant,
-    pub unsupported_async: bool,
     pub wrapper: bool,
     pub cross_thread: bool,
+    /// Call was nested deeper than `MAX_DEPTH`, its allocations went to the deepest frame
+    pub depth_overflow: bool,
+    /// Only some polls of an async call were nested deeper than `MAX_DEPTH`, the totals
+    /// cover the polls that got their own frame
+    pub partial: bool,
     pub tid: Option<u64>,
     pub result_log: Option<String>,
 }
@@ -136,9 +142,12 @@ pub struct FunctionStats {
     duration_hist: Option<Histogram<u64>>,
     pub total_duration_ns: u64,
     pub has_data: bool,
-    pub has_unsupported_async: bool,
     pub wrapper: bool,
     pub cross_thread: bool,
+    /// Number of calls nested deeper than `MAX_DEPTH`
+    pub depth_overflows: u64,
+    /// Number of async calls with only some polls nested deeper than `MAX_DEPTH`
+    pub partial_depth_overflows: u64,
     pub recent_logs: VecDeque<LogEntry>,
 }
 
@@ -157,9 +166,10 @@ impl FunctionStats {
         count_total: u64,
         duration: Duration,
         elapsed: Duration,
-        unsupported_async: bool,
         wrapper: bool,
         cross_thread: bool,
+        depth_overflow: bool,
+        partial: bool,
         recent_logs_limit: usize,
         tid: Option<u64>,
         result_log: Option<String>,
@@ -181,7 +191,7 @@ impl FunctionStats {
 
         let duration_ns = duration.as_nanos() as u64;
         let mut recent_logs = VecDeque::with_capacity(recent_logs_limit);
-        let (bytes_opt, count_opt) = if unsupported_async || cross_thread {
+        let (bytes_opt, count_opt) = if cross_thread || depth_overflow {
             (None, None)
         } else {
             (Some(bytes_total), Some(count_total))
@@ -195,12 +205,15 @@ impl FunctionStats {
             duration_hist: Some(duration_hist),
             total_duration_ns: duration_ns,
             has_data: true,
-            has_unsupported_async: unsupported_async,
             wrapper,
             cross_thread,
+            depth_overflows: depth_overflow as u64,
+            partial_depth_overflows: partial as u64,
             recent_logs,
         };
-        s.record_alloc(bytes_total, count_total);
+        if !depth_overflow {
+            s.record_alloc(bytes_total, count_total);
+        }
         s.record_duration(duration_ns);
         s
     }
@@ -239,15 +252,22 @@ impl FunctionStats {
         count_total: u64,
         duration: Duration,
         elapsed: Duration,
-        unsupported_async: bool,
         cross_thread: bool,
+        depth_overflow: bool,
+        partial: bool,
         tid: Option<u64>,
         result_log: Option<String>,
     ) {
         self.count += 1;
-        self.has_unsupported_async |= unsupported_async;
         self.cross_thread |= cross_thread;
-        self.record_alloc(bytes_total, count_total);
+        if partial {
+            self.partial_depth_overflows += 1;
+        }
+        if depth_overflow {
+            self.depth_overflows += 1;
+        } else {
+            self.record_alloc(bytes_total, count_total);
+        }
 
         let duration_ns = duration.as_nanos() as u64;
         self.total_duration_ns += duration_ns;
@@ -257,7 +277,7 @@ impl FunctionStats {
         {
             self.recent_logs.pop_front();
         }
-        let (bytes_opt, count_opt) = if unsupported_async || cross_thread {
+        let (bytes_opt, count_opt) = if cross_thread || depth_overflow {
             (None, None)
         } else {
             (Some(bytes_total), Some(count_total))
@@ -290,6 +310,12 @@ impl FunctionStats {
             .value_at_percentile(p)
     }
 
+    /// Calls whose allocations were recorded, depth overflows are counted but never measured
+    #[inline]
+    fn measured_count(&self) -> u64 {
+        self.count.saturating_sub(self.depth_overflows)
+    }
+
     #[inline]
     pub fn total_bytes(&self) -> u64 {
         if self.count == 0 || self.bytes_total_hist.is_none() {
@@ -297,7 +323,7 @@ impl FunctionStats {
         }
         let hist = self.bytes_total_hist.as_ref().unwrap();
         let mean = hist.mean();
-        (mean * self.count as f64) as u64
+        (mean * self.measured_count() as f64) as u64
     }
 
     #[inline]
@@ -315,7 +341,7 @@ impl FunctionStats {
         }
         let hist = self.count_total_hist.as_ref().unwrap();
         let mean = hist.mean();
-        (mean * self.count as f64) as u64
+        (mean * self.measured_count() as f64) as u64
     }
 
     #[inline]
@@ -368,8 +394,9 @@ pub(crate) fn process_measurement(
             m.count_total,
             m.duration,
             elapsed,
-            m.unsupported_async,
             m.cross_thread,
+            m.depth_overflow,
+            m.partial,
             m.tid,
             m.result_log,
         );
@@ -381,9 +408,10 @@ pub(crate) fn process_measurement(
                 m.count_total,
                 m.duration,
                 elapsed,
-                m.unsupported_async,
                 m.wrapper,
                 m.cross_thread,
+                m.depth_overflow,
+                m.partial,
                 recent_logs_limit,
                 m.tid,
                 m.result_log,
@@ -400,9 +428,9 @@ pub fn send_alloc_measurement(
     bytes_total: u64,
     count_total: u64,
     duration: Duration,
-    unsupported_async: bool,
     wrapper: bool,
     cross_thread: bool,
+    depth_overflow: bool,
     tid: Option<u64>,
 ) {
     send_alloc_measurement_with_log(
@@ -410,9 +438,10 @@ pub fn send_alloc_measurement(
         bytes_total,
         count_total,
         duration,
-        unsupported_async,
         wrapper,
         cross_thread,
+        depth_overflow,
+        false,
         tid,
         None,
     );
@@ -424,9 +453,10 @@ pub fn send_alloc_measurement_with_log(
     bytes_total: u64,
     count_total: u64,
     duration: Duration,
-    unsupported_async: bool,
     wrapper: bool,
     cross_thread: bool,
+    depth_overflow: bool,
+    partial: bool,
     tid: Option<u64>,
     result_log: Option<String>,
 ) {
@@ -442,9 +472,10 @@ pub fn send_alloc_measurement_with_log(
             bytes_total,
             count_total,
             duration,
-            unsupported_async,
             wrapper,
             cross_thread,
+            depth_overflow,
+            partial,
             tid,
             result_log,
         );
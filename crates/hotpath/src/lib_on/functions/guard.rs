@@ -369,6 +369,8 @@ impl FunctionsGuard {
                 stack.tracking_enabled.set(false);
             });
             super::alloc::core::init_thread_alloc_tracking();
+            super::alloc::leaks::init_leak_tracking();
+            super::alloc::callsites::init_call_site_tracking();
         }
 
         let percentiles = percentiles.to_vec();
@@ -433,13 +435,14 @@ impl FunctionsGuard {
                                                 // Create allocation metrics snapshot
                                                 use crate::output::MetricsProvider;
                                                 let total_elapsed = worker_start_time.elapsed();
-                                                let metrics_provider = StatsData::new(
+                                                let mut metrics_provider = StatsData::new(
                                                     &local_stats,
                                                     total_elapsed,
                                                     worker_percentiles.clone(),
                                                     worker_caller_name,
                                                     worker_limit,
                                                 );
+                                                metrics_provider.call_sites = super::alloc::callsites::call_sites();
                                                 let metrics_json = FunctionsJson::from(&metrics_provider as &dyn MetricsProvider);
                                                 let _ = response_tx.send(Some(metrics_json));
                                             } else {
@@ -631,7 +634,8 @@ impl Drop for FunctionsGuard {
                 if let Ok(stats) = rx.recv() {
                     if let Ok(state_guard) = state.read() {
                         let total_elapsed = end_time.duration_since(state_guard.start_time);
-                        let metrics_provider = StatsData::new(
+                        #[allow(unused_mut)]
+                        let mut metrics_provider = StatsData::new(
                             &stats,
                             total_elapsed,
                             state_guard.percentiles.clone(),
@@ -639,6 +643,17 @@ impl Drop for FunctionsGuard {
                             state_guard.limit,
                         );
 
+                        #[cfg(feature = "hotpath-alloc")]
+                        {
+                            metrics_provider.live_allocs = super::alloc::leaks::take_live_allocs();
+                            metrics_provider.call_sites = super::alloc::callsites::call_sites();
+                        }
+
+                        #[cfg(feature = "tokio-runtime-metrics")]
+                        {
+                            metrics_provider.runtime = crate::runtime::runtime_summary();
+                        }
+
                         match self.reporter.report(&metrics_provider) {
                             Ok(()) => (),
                             Err(e) => eprintln!("Failed to report hotpath metrics: {}", e),
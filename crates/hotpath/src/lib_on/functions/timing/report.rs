@@ -4,6 +4,7 @@ use std::time::Duration;
 use crate::ProfilingMode;
 
 use super::state::FunctionStats;
+use crate::json::RuntimeSummaryJson;
 use crate::output::{MetricType, MetricsProvider};
 
 pub struct StatsData<'a> {
@@ -12,6 +13,7 @@ pub struct StatsData<'a> {
     pub percentiles: Vec<u8>,
     pub caller_name: &'static str,
     pub limit: usize,
+    pub runtime: Option<RuntimeSummaryJson>,
 }
 
 impl<'a> MetricsProvider<'a> for StatsData<'a> {
@@ -28,6 +30,7 @@ impl<'a> MetricsProvider<'a> for StatsData<'a> {
             percentiles,
             caller_name,
             limit,
+            runtime: None,
         }
     }
 
@@ -35,6 +38,10 @@ impl<'a> MetricsProvider<'a> for StatsData<'a> {
         self.percentiles.clone()
     }
 
+    fn runtime(&self) -> Option<RuntimeSummaryJson> {
+        self.runtime.clone()
+    }
+
     fn description(&self) -> String {
         "Execution duration of functions.".to_string()
     }
@@ -1,11 +1,14 @@
 //! Futures instrumentation module - tracks async Future lifecycle and poll statistics.
 
-use crate::channels::{get_log_limit, resolve_label, START_TIME};
+use crate::channels::{get_log_limit, percentile, resolve_label, START_TIME};
 use crate::metrics_server::METRICS_SERVER_PORT;
-use crossbeam_channel::{unbounded, Sender as CbSender};
+use crate::output::format_duration;
+use crossbeam_channel::{unbounded, RecvTimeoutError, Sender as CbSender};
+use hdrhistogram::Histogram;
 use std::collections::{HashMap, VecDeque};
 use std::sync::atomic::AtomicU64;
 use std::sync::{Arc, OnceLock, RwLock};
+use std::time::Duration;
 
 #[cfg(target_os = "linux")]
 use quanta::Instant;
@@ -19,7 +22,10 @@ pub(crate) mod wrapper;
 pub use guard::{FuturesGuard, FuturesGuardBuilder};
 pub use wrapper::{InstrumentedFuture, InstrumentedFutureLog};
 
-pub use crate::json::{FutureCall, FutureCalls, FutureState, FuturesJson, SerializableFutureStats};
+pub use crate::json::{
+    FutureCall, FutureCalls, FutureChildStats, FutureState, FuturesJson, InFlightFuture,
+    InFlightFuturesJson, SerializableFutureStats,
+};
 pub use crate::Format;
 
 pub(crate) static FUTURE_ID_COUNTER: AtomicU64 = AtomicU64::new(0);
@@ -27,6 +33,18 @@ pub(crate) static FUTURE_CALL_ID_COUNTER: AtomicU64 = AtomicU64::new(0);
 
 use std::sync::LazyLock;
 
+/// Age from `HOTPATH_STUCK_FUTURE_MS` past which an in-flight call is reported as stuck.
+static STUCK_FUTURE_THRESHOLD_NS: LazyLock<Option<u64>> = LazyLock::new(|| {
+    std::env::var("HOTPATH_STUCK_FUTURE_MS")
+        .ok()
+        .and_then(|ms| ms.parse::<u64>().ok())
+        .filter(|&ms| ms > 0)
+        .map(|ms| ms.saturating_mul(1_000_000))
+});
+
+/// Upper bound on how often in-flight calls are checked against the stuck threshold
+const STUCK_CHECK_INTERVAL: Duration = Duration::from_secs(1);
+
 /// Thread-safe map from source location to future_id
 static SOURCE_TO_FUTURE_ID: LazyLock<RwLock<HashMap<&'static str, u64>>> =
     LazyLock::new(|| RwLock::new(HashMap::new()));
@@ -54,6 +72,20 @@ pub(crate) fn get_or_create_future_id(source: &'static str) -> (u64, bool) {
     (future_id, true)
 }
 
+/// A call that has not completed nor been dropped yet, tracked even after it is evicted from
+/// the retained `calls`.
+#[derive(Debug, Clone)]
+struct InFlightCall {
+    created_at_ns: u64,
+    state: FutureState,
+    poll_count: u64,
+    last_poll_ns: Option<u64>,
+    /// Whether a stuck warning was already printed for this call
+    warned: bool,
+    /// `(future_id, call_id)` of the call this one was created in
+    parent: Option<(u64, u64)>,
+}
+
 /// Aggregated statistics for a source location.
 #[derive(Debug, Clone)]
 pub struct FutureStats {
@@ -62,9 +94,34 @@ pub struct FutureStats {
     pub label: Option<String>,
     pub calls: VecDeque<FutureCall>,
     pub call_count: u64,
+    /// Position of each retained call in `calls`, offset by `evicted_calls`
+    call_index: HashMap<u64, u64>,
+    evicted_calls: u64,
+    in_flight_calls: HashMap<u64, InFlightCall>,
+    pub(crate) total_polls: u64,
+    pub(crate) completed_count: u64,
+    pub(crate) cancelled_count: u64,
+    /// Time from creation until ready of every completed call
+    pub(crate) lifetimes: Histogram<u64>,
+    pub(crate) lifetime_max_ns: u64,
+    pub(crate) total_lifetime_ns: u64,
+    pub(crate) parent_ids: Vec<u64>,
+    /// Finished calls of other futures created inside calls of this one, by child future ID
+    pub(crate) children: HashMap<u64, FutureChildStats>,
+    pub(crate) poll_durations: Histogram<u64>,
+    pub(crate) poll_max_ns: u64,
+    pub(crate) blocking_polls: u64,
+    pub(crate) total_wakes: u64,
+    pub(crate) spurious_polls: u64,
+    pub(crate) wake_to_poll: Histogram<u64>,
+    pub(crate) wake_to_poll_max_ns: u64,
 }
 
 impl FutureStats {
+    const LOW_NS: u64 = 1;
+    const HIGH_NS: u64 = 3_600_000_000_000; // 1h
+    const SIGFIGS: u8 = 2;
+
     fn new(id: u64, source: &'static str, label: Option<String>) -> Self {
         Self {
             id,
@@ -72,23 +129,114 @@ impl FutureStats {
             label,
             calls: VecDeque::new(),
             call_count: 0,
+            call_index: HashMap::new(),
+            evicted_calls: 0,
+            in_flight_calls: HashMap::new(),
+            total_polls: 0,
+            completed_count: 0,
+            cancelled_count: 0,
+            lifetimes: Self::new_histogram(),
+            lifetime_max_ns: 0,
+            total_lifetime_ns: 0,
+            parent_ids: Vec::new(),
+            children: HashMap::new(),
+            poll_durations: Self::new_histogram(),
+            poll_max_ns: 0,
+            blocking_polls: 0,
+            total_wakes: 0,
+            spurious_polls: 0,
+            wake_to_poll: Self::new_histogram(),
+            wake_to_poll_max_ns: 0,
         }
     }
 
-    /// Total polls across all invocations
+    fn new_histogram() -> Histogram<u64> {
+        Histogram::<u64>::new_with_bounds(Self::LOW_NS, Self::HIGH_NS, Self::SIGFIGS)
+            .expect("hdrhistogram init")
+    }
+
+    /// Records a poll duration, including polls of calls already evicted from `calls`
+    fn record_poll(&mut self, poll_ns: u64) {
+        self.poll_max_ns = self.poll_max_ns.max(poll_ns);
+        let _ = self
+            .poll_durations
+            .record(poll_ns.clamp(Self::LOW_NS, Self::HIGH_NS));
+    }
+
+    /// Records the delay between a wake and the poll it scheduled
+    fn record_wake_to_poll(&mut self, delay_ns: u64) {
+        self.wake_to_poll_max_ns = self.wake_to_poll_max_ns.max(delay_ns);
+        let _ = self
+            .wake_to_poll
+            .record(delay_ns.clamp(Self::LOW_NS, Self::HIGH_NS));
+    }
+
+    /// Records the time a completed call took from creation until ready
+    fn record_lifetime(&mut self, lifetime_ns: u64) {
+        self.lifetime_max_ns = self.lifetime_max_ns.max(lifetime_ns);
+        let _ = self
+            .lifetimes
+            .record(lifetime_ns.clamp(Self::LOW_NS, Self::HIGH_NS));
+    }
+
+    pub(crate) fn poll_percentile(&self, p: f64) -> Option<u64> {
+        percentile(&self.poll_durations, self.poll_max_ns, p)
+    }
+
+    pub(crate) fn wake_to_poll_percentile(&self, p: f64) -> Option<u64> {
+        percentile(&self.wake_to_poll, self.wake_to_poll_max_ns, p)
+    }
+
+    pub(crate) fn lifetime_percentile(&self, p: f64) -> Option<u64> {
+        percentile(&self.lifetimes, self.lifetime_max_ns, p)
+    }
+
+    /// Total polls across all invocations, including evicted ones
     pub fn total_polls(&self) -> u64 {
-        self.calls.iter().map(|c| c.poll_count).sum()
+        self.total_polls
+    }
+
+    /// Calls that have neither completed nor been cancelled yet
+    pub fn in_flight(&self) -> u64 {
+        self.in_flight_calls.len() as u64
+    }
+
+    /// Retains a new call, evicting the oldest one past `HOTPATH_LOGS_LIMIT`
+    fn push_call(&mut self, call: FutureCall) {
+        if self.calls.len() >= get_log_limit() {
+            if let Some(evicted) = self.calls.pop_front() {
+                self.call_index.remove(&evicted.id);
+                self.evicted_calls += 1;
+            }
+        }
+        self.call_index
+            .insert(call.id, self.evicted_calls + self.calls.len() as u64);
+        self.calls.push_back(call);
     }
 
-    /// Find a call by ID
+    /// Find a retained call by ID
     fn find_call_mut(&mut self, id: u64) -> Option<&mut FutureCall> {
-        self.calls.iter_mut().find(|c| c.id == id)
+        let position = self.call_index.get(&id)? - self.evicted_calls;
+        self.calls.get_mut(position as usize)
     }
 }
 
+pub(crate) fn elapsed_ns() -> u64 {
+    START_TIME
+        .get()
+        .map(|t| t.elapsed().as_nanos() as u64)
+        .unwrap_or(0)
+}
+
 impl From<&FutureStats> for SerializableFutureStats {
     fn from(future_stats: &FutureStats) -> Self {
         let label = resolve_label(future_stats.source, future_stats.label.as_deref(), None);
+        let mut children: Vec<FutureChildStats> = future_stats.children.values().cloned().collect();
+        children.sort_by(|a, b| {
+            b.total_lifetime_ns
+                .cmp(&a.total_lifetime_ns)
+                .then(a.future_id.cmp(&b.future_id))
+        });
 
         Self {
             id: future_stats.id,
@@ -97,6 +245,26 @@ impl From<&FutureStats> for SerializableFutureStats {
             has_custom_label: future_stats.label.is_some(),
             call_count: future_stats.call_count,
             total_polls: future_stats.total_polls(),
+            poll_p50_ns: future_stats.poll_percentile(50.0),
+            poll_p95_ns: future_stats.poll_percentile(95.0),
+            poll_p99_ns: future_stats.poll_percentile(99.0),
+            poll_max_ns: future_stats.poll_max_ns,
+            blocking_polls: future_stats.blocking_polls,
+            total_wakes: future_stats.total_wakes,
+            spurious_polls: future_stats.spurious_polls,
+            wake_to_poll_p50_ns: future_stats.wake_to_poll_percentile(50.0),
+            wake_to_poll_p95_ns: future_stats.wake_to_poll_percentile(95.0),
+            wake_to_poll_max_ns: future_stats.wake_to_poll_max_ns,
+            completed_count: future_stats.completed_count,
+            cancelled_count: future_stats.cancelled_count,
+            in_flight: future_stats.in_flight(),
+            lifetime_p50_ns: future_stats.lifetime_percentile(50.0),
+            lifetime_p95_ns: future_stats.lifetime_percentile(95.0),
+            lifetime_p99_ns: future_stats.lifetime_percentile(99.0),
+            lifetime_max_ns: future_stats.lifetime_max_ns,
+            total_lifetime_ns: future_stats.total_lifetime_ns,
+            parent_ids: future_stats.parent_ids.clone(),
+            children,
         }
     }
 }
@@ -119,20 +287,38 @@ pub(crate) enum FutureEvent {
     CallCreated {
         future_id: u64,
         call_id: u64,
+        /// Nanoseconds since start when the call was created
+        created_at_ns: u64,
+        /// `(future_id, call_id)` of the instrumented call being polled when this one was created
+        parent: Option<(u64, u64)>,
     },
     Polled {
         future_id: u64,
         call_id: u64,
         result: PollResult,
+        /// Time spent inside the inner future's `poll`
+        poll_ns: u64,
+        /// Whether the poll exceeded the blocking poll threshold
+        blocking: bool,
+        /// Wakes received since the previous poll
+        wakes: u64,
+        /// Time from the first of those wakes until this poll started
+        wake_to_poll_ns: Option<u64>,
+        /// Polled again without a wake since the previous poll
+        spurious: bool,
         log_message: Option<String>,
     },
     Completed {
         future_id: u64,
         call_id: u64,
+        /// Time from creation of the call until it became ready
+        lifetime_ns: u64,
     },
     Cancelled {
         future_id: u64,
         call_id: u64,
+        /// Time from creation of the call until it was dropped
+        lifetime_ns: u64,
     },
 }
 
@@ -159,9 +345,31 @@ pub fn init_futures_state() {
         std::thread::Builder::new()
             .name("hp-futures".into())
             .spawn(move || {
-                while let Ok(event) = event_rx.recv() {
+                let Some(threshold_ns) = *STUCK_FUTURE_THRESHOLD_NS else {
+                    while let Ok(event) = event_rx.recv() {
+                        let mut stats = stats_map_clone.write().unwrap();
+                        process_future_event(&mut stats, event);
+                    }
+                    return;
+                };
+
+                // Stuck calls emit no events, so wake up periodically to check them
+                let check_interval = Duration::from_nanos(threshold_ns).min(STUCK_CHECK_INTERVAL);
+                let mut last_check = Instant::now();
+                loop {
+                    let event = match event_rx.recv_timeout(check_interval) {
+                        Ok(event) => Some(event),
+                        Err(RecvTimeoutError::Timeout) => None,
+                        Err(RecvTimeoutError::Disconnected) => break,
+                    };
                     let mut stats = stats_map_clone.write().unwrap();
-                    process_future_event(&mut stats, event);
+                    if let Some(event) = event {
+                        process_future_event(&mut stats, event);
+                    }
+                    if last_check.elapsed() >= check_interval {
+                        warn_stuck_futures(&mut stats, threshold_ns);
+                        last_check = Instant::now();
+                    }
                 }
             })
             .expect("Failed to spawn futures event collector thread");
@@ -183,27 +391,72 @@ fn process_future_event(stats_map: &mut HashMap<u64, FutureStats>, event: Future
                 FutureStats::new(future_id, source, display_label),
             );
         }
-        FutureEvent::CallCreated { future_id, call_id } => {
+        FutureEvent::CallCreated {
+            future_id,
+            call_id,
+            created_at_ns,
+            parent,
+        } => {
             if let Some(future_stats) = stats_map.get_mut(&future_id) {
                 future_stats.call_count += 1;
-                let limit = get_log_limit();
-                if future_stats.calls.len() >= limit {
-                    future_stats.calls.pop_front();
+                if let Some((parent_future_id, _)) = parent {
+                    if !future_stats.parent_ids.contains(&parent_future_id) {
+                        future_stats.parent_ids.push(parent_future_id);
+                    }
                 }
-                future_stats
-                    .calls
-                    .push_back(FutureCall::new(call_id, future_id));
+                future_stats.in_flight_calls.insert(
+                    call_id,
+                    InFlightCall {
+                        created_at_ns,
+                        state: FutureState::Pending,
+                        poll_count: 0,
+                        last_poll_ns: None,
+                        warned: false,
+                        parent,
+                    },
+                );
+                let mut call = FutureCall::new(call_id, future_id, created_at_ns);
+                call.parent_future_id = parent.map(|(future_id, _)| future_id);
+                call.parent_call_id = parent.map(|(_, call_id)| call_id);
+                future_stats.push_call(call);
             }
         }
         FutureEvent::Polled {
             future_id,
             call_id,
             result,
+            poll_ns,
+            blocking,
+            wakes,
+            wake_to_poll_ns,
+            spurious,
             log_message,
         } => {
             if let Some(future_stats) = stats_map.get_mut(&future_id) {
+                future_stats.record_poll(poll_ns);
+                future_stats.total_polls += 1;
+                if blocking {
+                    future_stats.blocking_polls += 1;
+                }
+                future_stats.total_wakes += wakes;
+                if spurious {
+                    future_stats.spurious_polls += 1;
+                }
+                if let Some(delay_ns) = wake_to_poll_ns {
+                    future_stats.record_wake_to_poll(delay_ns);
+                }
+                if let Some(in_flight) = future_stats.in_flight_calls.get_mut(&call_id) {
+                    in_flight.poll_count += 1;
+                    in_flight.last_poll_ns = Some(elapsed_ns());
+                    if result == PollResult::Pending {
+                        in_flight.state = FutureState::Suspended;
+                    }
+                }
                 if let Some(call) = future_stats.find_call_mut(call_id) {
                     call.poll_count += 1;
+                    call.wake_count += wakes;
+                    call.busy_ns += poll_ns;
+                    call.max_poll_ns = call.max_poll_ns.max(poll_ns);
                     match result {
                         PollResult::Pending => {
                             call.state = FutureState::Suspended;
@@ -211,28 +464,98 @@ fn process_future_event(stats_map: &mut HashMap<u64, FutureStats>, event: Future
                         PollResult::Ready => {
                             call.state = FutureState::Ready;
                             if log_message.is_some() {
-                                call.result = log_message;
+                                call.result = log_message.map(crate::channels::redact_log);
                             }
                         }
                     };
                 }
             }
         }
-        FutureEvent::Completed { future_id, call_id } => {
+        FutureEvent::Completed {
+            future_id,
+            call_id,
+            lifetime_ns,
+        } => {
             if let Some(future_stats) = stats_map.get_mut(&future_id) {
+                future_stats.completed_count += 1;
+                future_stats.record_lifetime(lifetime_ns);
                 if let Some(call) = future_stats.find_call_mut(call_id) {
                     call.state = FutureState::Ready;
                 }
             }
+            finish_call(stats_map, future_id, call_id, lifetime_ns);
         }
-        FutureEvent::Cancelled { future_id, call_id } => {
+        FutureEvent::Cancelled {
+            future_id,
+            call_id,
+            lifetime_ns,
+        } => {
             if let Some(future_stats) = stats_map.get_mut(&future_id) {
+                future_stats.cancelled_count += 1;
                 if let Some(call) = future_stats.find_call_mut(call_id) {
                     if call.state != FutureState::Ready {
                         call.state = FutureState::Cancelled;
                     }
                 }
             }
+            finish_call(stats_map, future_id, call_id, lifetime_ns);
+        }
+    }
+}
+
+/// Removes a completed or cancelled call from the in-flight calls and adds its lifetime to
+/// the future it was created in.
+fn finish_call(
+    stats_map: &mut HashMap<u64, FutureStats>,
+    future_id: u64,
+    call_id: u64,
+    lifetime_ns: u64,
+) {
+    let Some(future_stats) = stats_map.get_mut(&future_id) else {
+        return;
+    };
+    future_stats.total_lifetime_ns += lifetime_ns;
+    if let Some(call) = future_stats.find_call_mut(call_id) {
+        call.lifetime_ns = Some(lifetime_ns);
+    }
+    let parent = future_stats
+        .in_flight_calls
+        .remove(&call_id)
+        .and_then(|call| call.parent);
+
+    if let Some((parent_future_id, _)) = parent {
+        if let Some(parent_stats) = stats_map.get_mut(&parent_future_id) {
+            let child = parent_stats
+                .children
+                .entry(future_id)
+                .or_insert(FutureChildStats {
+                    future_id,
+                    calls: 0,
+                    total_lifetime_ns: 0,
+                });
+            child.calls += 1;
+            child.total_lifetime_ns += lifetime_ns;
+        }
+    }
+}
+
+/// Prints a warning for every in-flight call that crossed the stuck threshold since the last check.
+fn warn_stuck_futures(stats_map: &mut HashMap<u64, FutureStats>, threshold_ns: u64) {
+    let now_ns = elapsed_ns();
+    for future_stats in stats_map.values_mut() {
+        let label = resolve_label(future_stats.source, future_stats.label.as_deref(), None);
+        for (call_id, call) in future_stats.in_flight_calls.iter_mut() {
+            let age_ns = now_ns.saturating_sub(call.created_at_ns);
+            if call.warned || age_ns <= threshold_ns {
+                continue;
+            }
+            call.warned = true;
+            eprintln!(
+                "[hotpath] Future {label} call {call_id} in flight for {} after {} polls (threshold {})",
+                format_duration(age_ns),
+                call.poll_count,
+                format_duration(threshold_ns),
+            );
         }
     }
 }
@@ -315,17 +638,55 @@ pub fn get_futures_json() -> FuturesJson {
         .map(SerializableFutureStats::from)
         .collect();
 
-    let current_elapsed_ns = START_TIME
-        .get()
-        .map(|t| t.elapsed().as_nanos() as u64)
-        .unwrap_or(0);
-
     FuturesJson {
-        current_elapsed_ns,
+        current_elapsed_ns: elapsed_ns(),
         futures,
     }
 }
 
+/// In-flight calls of all futures, oldest first, up to `HOTPATH_LOGS_LIMIT`.
+pub fn get_in_flight_futures_json() -> InFlightFuturesJson {
+    let current_elapsed_ns = elapsed_ns();
+    let threshold_ns = *STUCK_FUTURE_THRESHOLD_NS;
+
+    let mut calls: Vec<InFlightFuture> = get_all_future_stats()
+        .values()
+        .flat_map(|future_stats| {
+            let label = resolve_label(future_stats.source, future_stats.label.as_deref(), None);
+            future_stats
+                .in_flight_calls
+                .iter()
+                .map(move |(&call_id, call)| {
+                    let age_ns = current_elapsed_ns.saturating_sub(call.created_at_ns);
+                    InFlightFuture {
+                        future_id: future_stats.id,
+                        call_id,
+                        label: label.clone(),
+                        state: call.state,
+                        poll_count: call.poll_count,
+                        created_at_ns: call.created_at_ns,
+                        age_ns,
+                        last_poll_ns: call.last_poll_ns,
+                        stuck: threshold_ns.is_some_and(|threshold_ns| age_ns > threshold_ns),
+                    }
+                })
+        })
+        .collect();
+
+    calls.sort_by_key(|call| (call.created_at_ns, call.call_id));
+    let total = calls.len() as u64;
+    let stuck = calls.iter().filter(|call| call.stuck).count() as u64;
+    calls.truncate(get_log_limit());
+
+    InFlightFuturesJson {
+        current_elapsed_ns,
+        threshold_ns,
+        total,
+        stuck,
+        calls,
+    }
+}
+
 pub fn get_future_calls(future_id: u64) -> Option<FutureCalls> {
     let stats = get_all_future_stats();
     stats.get(&future_id).map(|s| FutureCalls {
@@ -340,6 +701,8 @@ pub fn get_future_calls(future_id: u64) -> Option<FutureCalls> {
 ///
 /// - `future!(expr)` - No Debug requirement, prints `Ready` without the value
 /// - `future!(expr, log = true)` - Requires Debug, prints `Ready(value)`
+/// - `future!(expr, blocking_threshold_ms = 10)` - Flags polls longer than 10ms as blocking,
+///   overriding `HOTPATH_BLOCKING_POLL_MS`. Can be combined with `log = true`.
 ///
 /// # Examples
 ///
@@ -367,4 +730,21 @@ macro_rules! future {
         $crate::futures::init_futures_state();
         $crate::InstrumentFutureLog::instrument_future_log($fut, FUTURE_LOC)
     }};
+
+    ($fut:expr, blocking_threshold_ms = $ms:expr) => {{
+        const FUTURE_LOC: &'static str = concat!(file!(), ":", line!());
+        $crate::futures::init_futures_state();
+        $crate::InstrumentFuture::instrument_future($fut, FUTURE_LOC).blocking_threshold_ms($ms)
+    }};
+
+    ($fut:expr, log = true, blocking_threshold_ms = $ms:expr) => {{
+        const FUTURE_LOC: &'static str = concat!(file!(), ":", line!());
+        $crate::futures::init_futures_state();
+        $crate::InstrumentFutureLog::instrument_future_log($fut, FUTURE_LOC)
+            .blocking_threshold_ms($ms)
+    }};
+
+    ($fut:expr, blocking_threshold_ms = $ms:expr, log = true) => {
+        $crate::future!($fut, log = true, blocking_threshold_ms = $ms)
+    };
 }
@@ -7,6 +7,7 @@ use std::time::Instant;
 use prettytable::{Cell, Row, Table};
 
 use crate::futures::{get_futures_json, init_futures_state, FuturesJson};
+use crate::output::format_duration;
 use crate::Format;
 
 /// Builder for creating a FuturesGuard with custom configuration.
@@ -145,13 +146,46 @@ impl Drop for FuturesGuard {
                     Cell::new("Future"),
                     Cell::new("Calls"),
                     Cell::new("Polls"),
+                    Cell::new("Wakes"),
+                    Cell::new("Spurious"),
+                    Cell::new("Poll P95"),
+                    Cell::new("Max Poll"),
+                    Cell::new("Wake→Poll P95"),
+                    Cell::new("Completed"),
+                    Cell::new("Cancelled"),
+                    Cell::new("In Flight"),
+                    Cell::new("Lifetime P95"),
                 ]));
 
                 for future_stats in &futures_json.futures {
+                    let (p95, max) = match future_stats.poll_p95_ns {
+                        Some(p95) => (
+                            format_duration(p95),
+                            format_duration(future_stats.poll_max_ns),
+                        ),
+                        None => ("-".to_string(), "-".to_string()),
+                    };
+                    let wake_to_poll = future_stats
+                        .wake_to_poll_p95_ns
+                        .map(format_duration)
+                        .unwrap_or_else(|| "-".to_string());
+                    let lifetime = future_stats
+                        .lifetime_p95_ns
+                        .map(format_duration)
+                        .unwrap_or_else(|| "-".to_string());
                     table.add_row(Row::new(vec![
                         Cell::new(&future_stats.label),
                         Cell::new(&future_stats.call_count.to_string()),
                         Cell::new(&future_stats.total_polls.to_string()),
+                        Cell::new(&future_stats.total_wakes.to_string()),
+                        Cell::new(&future_stats.spurious_polls.to_string()),
+                        Cell::new(&p95),
+                        Cell::new(&max),
+                        Cell::new(&wake_to_poll),
+                        Cell::new(&future_stats.completed_count.to_string()),
+                        Cell::new(&future_stats.cancelled_count.to_string()),
+                        Cell::new(&future_stats.in_flight.to_string()),
+                        Cell::new(&lifetime),
                     ]));
                 }
 
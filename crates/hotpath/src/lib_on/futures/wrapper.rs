@@ -1,20 +1,85 @@
 //! Instrumented Future wrapper that tracks lifecycle events.
 
+use crate::blocking::{check_poll, BlockingPollSource, BLOCKING_POLL_THRESHOLD_NS};
 use crate::functions::truncate_result;
 
 use super::{
-    get_or_create_future_id, send_future_event, FutureEvent, PollResult, FUTURE_CALL_ID_COUNTER,
+    elapsed_ns, get_or_create_future_id, send_future_event, FutureEvent, PollResult,
+    FUTURE_CALL_ID_COUNTER,
 };
 use pin_project_lite::pin_project;
+use std::cell::Cell;
 use std::future::Future;
 use std::mem::ManuallyDrop;
 use std::pin::Pin;
-use std::sync::atomic::Ordering;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
 use std::task::{Context, Poll, RawWaker, RawWakerVTable, Waker};
 
+#[cfg(target_os = "linux")]
+use quanta::Instant;
+
+#[cfg(not(target_os = "linux"))]
+use std::time::Instant;
+
+thread_local! {
+    /// `(future_id, call_id)` of the instrumented call being polled on this thread, recorded as
+    /// the parent of calls created inside it
+    static CURRENT_CALL: Cell<Option<(u64, u64)>> = const { Cell::new(None) };
+}
+
+/// Marks a call as the one being polled on this thread, restoring the outer call on drop so
+/// a panicking inner future doesn't leave a stale parent behind.
+struct CurrentCallGuard {
+    outer: Option<(u64, u64)>,
+}
+
+impl CurrentCallGuard {
+    fn enter(future_id: u64, call_id: u64) -> Self {
+        Self {
+            outer: CURRENT_CALL.with(|current| current.replace(Some((future_id, call_id)))),
+        }
+    }
+}
+
+impl Drop for CurrentCallGuard {
+    fn drop(&mut self) {
+        CURRENT_CALL.with(|current| current.set(self.outer));
+    }
+}
+
+/// Wakes of a single call, shared by every waker handed to its inner future.
+#[derive(Default)]
+pub(crate) struct WakeState {
+    wakes: AtomicU64,
+    /// Time of the first wake since the last poll, 0 when none is pending
+    first_wake_ns: AtomicU64,
+}
+
+impl WakeState {
+    fn record_wake(&self) {
+        self.wakes.fetch_add(1, Ordering::Relaxed);
+        let _ = self.first_wake_ns.compare_exchange(
+            0,
+            elapsed_ns().max(1),
+            Ordering::Relaxed,
+            Ordering::Relaxed,
+        );
+    }
+
+    /// Takes the wakes since the last poll, and how long ago the first of them happened.
+    fn take(&self) -> (u64, Option<u64>) {
+        let wakes = self.wakes.swap(0, Ordering::Relaxed);
+        let first_wake_ns = self.first_wake_ns.swap(0, Ordering::Relaxed);
+        let wake_to_poll_ns =
+            (first_wake_ns != 0).then(|| elapsed_ns().saturating_sub(first_wake_ns));
+        (wakes, wake_to_poll_ns)
+    }
+}
+
 struct WakerData {
     inner: Waker,
+    state: Arc<WakeState>,
 }
 
 fn waker_clone(data: *const ()) -> RawWaker {
@@ -24,13 +89,14 @@ fn waker_clone(data: *const ()) -> RawWaker {
 }
 
 fn waker_wake(data: *const ()) {
-    unsafe { Arc::increment_strong_count(data as *const WakerData) };
     let arc = unsafe { Arc::from_raw(data as *const WakerData) };
+    arc.state.record_wake();
     arc.inner.wake_by_ref();
 }
 
 fn waker_wake_by_ref(data: *const ()) {
     let arc = ManuallyDrop::new(unsafe { Arc::from_raw(data as *const WakerData) });
+    arc.state.record_wake();
     arc.inner.wake_by_ref();
 }
 
@@ -43,9 +109,10 @@ fn waker_drop(data: *const ()) {
 static VTABLE: RawWakerVTable =
     RawWakerVTable::new(waker_clone, waker_wake, waker_wake_by_ref, waker_drop);
 
-fn create_instrumented_waker(waker: &Waker) -> Waker {
+fn create_instrumented_waker(waker: &Waker, state: &Arc<WakeState>) -> Waker {
     let data = Arc::new(WakerData {
         inner: waker.clone(),
+        state: Arc::clone(state),
     });
     let raw = RawWaker::new(Arc::into_raw(data) as *const (), &VTABLE);
     unsafe { Waker::from_raw(raw) }
@@ -56,7 +123,7 @@ pin_project! {
     ///
     /// Created via the `future!` macro, this wrapper tracks:
     /// - Creation
-    /// - Each poll call with result (Pending/Ready) and thread ID
+    /// - Each poll call with result (Pending/Ready), duration and thread ID
     /// - Drop (cancellation if not completed)
     ///
     /// This variant does NOT require `Debug` on the output type.
@@ -66,13 +133,22 @@ pin_project! {
         inner: F,
         future_id: u64,
         call_id: u64,
+        source: &'static str,
+        blocking_threshold_ns: Option<u64>,
+        wake_state: Arc<WakeState>,
+        created_at: Instant,
+        polled: bool,
         completed: bool,
     }
 
     impl<F: Future> PinnedDrop for InstrumentedFuture<F> {
         fn drop(this: Pin<&mut Self>) {
             if !this.completed {
-                send_future_event(FutureEvent::Cancelled { future_id: this.future_id, call_id: this.call_id });
+                send_future_event(FutureEvent::Cancelled {
+                    future_id: this.future_id,
+                    call_id: this.call_id,
+                    lifetime_ns: this.created_at.elapsed().as_nanos() as u64,
+                });
             }
         }
     }
@@ -92,15 +168,32 @@ impl<F: Future> InstrumentedFuture<F> {
             });
         }
 
-        send_future_event(FutureEvent::CallCreated { future_id, call_id });
+        send_future_event(FutureEvent::CallCreated {
+            future_id,
+            call_id,
+            created_at_ns: elapsed_ns(),
+            parent: CURRENT_CALL.with(Cell::get),
+        });
 
         Self {
             inner,
             future_id,
             call_id,
+            source: location,
+            blocking_threshold_ns: *BLOCKING_POLL_THRESHOLD_NS,
+            wake_state: Arc::default(),
+            created_at: Instant::now(),
+            polled: false,
             completed: false,
         }
     }
+
+    /// Flags polls longer than `ms` as blocking, overriding `HOTPATH_BLOCKING_POLL_MS`.
+    #[doc(hidden)]
+    pub fn blocking_threshold_ms(mut self, ms: u64) -> Self {
+        self.blocking_threshold_ns = Some(ms.saturating_mul(1_000_000));
+        self
+    }
 }
 
 impl<F: Future> Future for InstrumentedFuture<F> {
@@ -111,10 +204,26 @@ impl<F: Future> Future for InstrumentedFuture<F> {
         let future_id = *this.future_id;
         let call_id = *this.call_id;
 
-        let instrumented_waker = create_instrumented_waker(cx.waker());
+        let (wakes, wake_to_poll_ns) = this.wake_state.take();
+        // Polled again without being woken, e.g. by a `join!` driving its siblings
+        let spurious = *this.polled && wakes == 0;
+        *this.polled = true;
+
+        let instrumented_waker = create_instrumented_waker(cx.waker(), this.wake_state);
         let mut instrumented_cx = Context::from_waker(&instrumented_waker);
 
-        let result = this.inner.poll(&mut instrumented_cx);
+        let poll_start = Instant::now();
+        let result = {
+            let _current = CurrentCallGuard::enter(future_id, call_id);
+            this.inner.poll(&mut instrumented_cx)
+        };
+        let poll_ns = poll_start.elapsed().as_nanos() as u64;
+        let blocking = check_poll(
+            this.source,
+            BlockingPollSource::Future,
+            poll_ns,
+            *this.blocking_threshold_ns,
+        );
 
         let poll_result = match &result {
             Poll::Pending => PollResult::Pending,
@@ -128,11 +237,20 @@ impl<F: Future> Future for InstrumentedFuture<F> {
             future_id,
             call_id,
             result: poll_result,
+            poll_ns,
+            blocking,
+            wakes,
+            wake_to_poll_ns,
+            spurious,
             log_message: None,
         });
 
         if *this.completed {
-            send_future_event(FutureEvent::Completed { future_id, call_id });
+            send_future_event(FutureEvent::Completed {
+                future_id,
+                call_id,
+                lifetime_ns: this.created_at.elapsed().as_nanos() as u64,
+            });
         }
 
         result
@@ -144,7 +262,7 @@ pin_project! {
     ///
     /// Created via the `future!(expr, log = true)` macro, this wrapper tracks:
     /// - Creation
-    /// - Each poll call with result (Pending/Ready with Debug output) and thread ID
+    /// - Each poll call with result (Pending/Ready with Debug output), duration and thread ID
     /// - Drop (cancellation if not completed)
     ///
     /// This variant requires `Debug` on the output type to log the value.
@@ -153,13 +271,22 @@ pin_project! {
         inner: F,
         future_id: u64,
         call_id: u64,
+        source: &'static str,
+        blocking_threshold_ns: Option<u64>,
+        wake_state: Arc<WakeState>,
+        created_at: Instant,
+        polled: bool,
         completed: bool,
     }
 
     impl<F: Future> PinnedDrop for InstrumentedFutureLog<F> {
         fn drop(this: Pin<&mut Self>) {
             if !this.completed {
-                send_future_event(FutureEvent::Cancelled { future_id: this.future_id, call_id: this.call_id });
+                send_future_event(FutureEvent::Cancelled {
+                    future_id: this.future_id,
+                    call_id: this.call_id,
+                    lifetime_ns: this.created_at.elapsed().as_nanos() as u64,
+                });
             }
         }
     }
@@ -179,15 +306,32 @@ impl<F: Future> InstrumentedFutureLog<F> {
             });
         }
 
-        send_future_event(FutureEvent::CallCreated { future_id, call_id });
+        send_future_event(FutureEvent::CallCreated {
+            future_id,
+            call_id,
+            created_at_ns: elapsed_ns(),
+            parent: CURRENT_CALL.with(Cell::get),
+        });
 
         Self {
             inner,
             future_id,
             call_id,
+            source: location,
+            blocking_threshold_ns: *BLOCKING_POLL_THRESHOLD_NS,
+            wake_state: Arc::default(),
+            created_at: Instant::now(),
+            polled: false,
             completed: false,
         }
     }
+
+    /// Flags polls longer than `ms` as blocking, overriding `HOTPATH_BLOCKING_POLL_MS`.
+    #[doc(hidden)]
+    pub fn blocking_threshold_ms(mut self, ms: u64) -> Self {
+        self.blocking_threshold_ns = Some(ms.saturating_mul(1_000_000));
+        self
+    }
 }
 
 impl<F: Future> Future for InstrumentedFutureLog<F>
@@ -201,10 +345,26 @@ where
         let future_id = *this.future_id;
         let call_id = *this.call_id;
 
-        let instrumented_waker = create_instrumented_waker(cx.waker());
+        let (wakes, wake_to_poll_ns) = this.wake_state.take();
+        // Polled again without being woken, e.g. by a `join!` driving its siblings
+        let spurious = *this.polled && wakes == 0;
+        *this.polled = true;
+
+        let instrumented_waker = create_instrumented_waker(cx.waker(), this.wake_state);
         let mut instrumented_cx = Context::from_waker(&instrumented_waker);
 
-        let result = this.inner.poll(&mut instrumented_cx);
+        let poll_start = Instant::now();
+        let result = {
+            let _current = CurrentCallGuard::enter(future_id, call_id);
+            this.inner.poll(&mut instrumented_cx)
+        };
+        let poll_ns = poll_start.elapsed().as_nanos() as u64;
+        let blocking = check_poll(
+            this.source,
+            BlockingPollSource::Future,
+            poll_ns,
+            *this.blocking_threshold_ns,
+        );
 
         let (poll_result, log_message) = match &result {
             Poll::Pending => (PollResult::Pending, None),
@@ -221,11 +381,20 @@ where
             future_id,
             call_id,
             result: poll_result,
+            poll_ns,
+            blocking,
+            wakes,
+            wake_to_poll_ns,
+            spurious,
             log_message,
         });
 
         if *this.completed {
-            send_future_event(FutureEvent::Completed { future_id, call_id });
+            send_future_event(FutureEvent::Completed {
+                future_id,
+                call_id,
+                lifetime_ns: this.created_at.elapsed().as_nanos() as u64,
+            });
         }
 
         result
@@ -0,0 +1,520 @@
+//! Lock instrumentation module - tracks acquisition wait time, hold time and contention.
+
+use ::std::collections::HashMap;
+use ::std::sync::atomic::{AtomicU64, Ordering};
+use ::std::sync::{Arc, OnceLock, RwLock};
+use crossbeam_channel::{unbounded, Sender as CbSender};
+use hdrhistogram::Histogram;
+
+#[cfg(target_os = "linux")]
+use quanta::Instant;
+
+#[cfg(not(target_os = "linux"))]
+use ::std::time::Instant;
+
+pub mod guard;
+pub use guard::{LocksGuard, LocksGuardBuilder};
+
+#[cfg(feature = "parking_lot")]
+pub mod parking_lot;
+pub mod std;
+#[cfg(feature = "tokio")]
+pub mod tokio;
+
+pub use crate::json::{LockBackend, LockType, LocksJson, SerializableLockStats};
+use crate::metrics_server::METRICS_SERVER_PORT;
+pub use crate::Format;
+
+/// Statistics for a single instrumented lock.
+#[derive(Debug, Clone)]
+pub(crate) struct LockStats {
+    pub(crate) id: u64,
+    pub(crate) source: &'static str,
+    pub(crate) label: Option<String>,
+    pub(crate) lock_type: LockType,
+    pub(crate) backend: LockBackend,
+    pub(crate) type_name: &'static str,
+    pub(crate) iter: u32,
+    pub(crate) acquisitions: u64,
+    pub(crate) read_acquisitions: u64,
+    pub(crate) contentions: u64,
+    pub(crate) wait_total_ns: u64,
+    pub(crate) wait_max_ns: u64,
+    pub(crate) wait: Histogram<u64>,
+    pub(crate) hold_total_ns: u64,
+    pub(crate) hold_max_ns: u64,
+    pub(crate) hold: Histogram<u64>,
+    pub(crate) holder_tid: Option<u64>,
+    pub(crate) readers: u64,
+}
+
+impl LockStats {
+    const LOW_NS: u64 = 1;
+    const HIGH_NS: u64 = 3_600_000_000_000; // 1h
+    const SIGFIGS: u8 = 2;
+
+    fn new(
+        id: u64,
+        source: &'static str,
+        label: Option<String>,
+        lock_type: LockType,
+        backend: LockBackend,
+        type_name: &'static str,
+        iter: u32,
+    ) -> Self {
+        let histogram = || {
+            Histogram::<u64>::new_with_bounds(Self::LOW_NS, Self::HIGH_NS, Self::SIGFIGS)
+                .expect("hdrhistogram init")
+        };
+        Self {
+            id,
+            source,
+            label,
+            lock_type,
+            backend,
+            type_name,
+            iter,
+            acquisitions: 0,
+            read_acquisitions: 0,
+            contentions: 0,
+            wait_total_ns: 0,
+            wait_max_ns: 0,
+            wait: histogram(),
+            hold_total_ns: 0,
+            hold_max_ns: 0,
+            hold: histogram(),
+            holder_tid: None,
+            readers: 0,
+        }
+    }
+
+    fn record_acquired(&mut self, access: Access, wait_ns: u64, contended: bool, tid: u64) {
+        self.acquisitions += 1;
+        if contended {
+            self.contentions += 1;
+        }
+        self.wait_total_ns += wait_ns;
+        self.wait_max_ns = self.wait_max_ns.max(wait_ns);
+        let _ = self.wait.record(wait_ns.clamp(Self::LOW_NS, Self::HIGH_NS));
+        match access {
+            Access::Exclusive => self.holder_tid = Some(tid),
+            Access::Shared => {
+                self.read_acquisitions += 1;
+                self.readers += 1;
+            }
+        }
+    }
+
+    fn record_released(&mut self, access: Access, hold_ns: u64) {
+        self.hold_total_ns += hold_ns;
+        self.hold_max_ns = self.hold_max_ns.max(hold_ns);
+        let _ = self.hold.record(hold_ns.clamp(Self::LOW_NS, Self::HIGH_NS));
+        match access {
+            Access::Exclusive => self.holder_tid = None,
+            Access::Shared => self.readers = self.readers.saturating_sub(1),
+        }
+    }
+
+    pub(crate) fn wait_percentile(&self, p: f64) -> Option<u64> {
+        if self.wait.is_empty() {
+            return None;
+        }
+        Some(self.wait.value_at_quantile(p / 100.0))
+    }
+
+    pub(crate) fn hold_percentile(&self, p: f64) -> Option<u64> {
+        if self.hold.is_empty() {
+            return None;
+        }
+        Some(self.hold.value_at_quantile(p / 100.0))
+    }
+}
+
+impl From<&LockStats> for SerializableLockStats {
+    fn from(lock_stats: &LockStats) -> Self {
+        let label = crate::channels::resolve_label(
+            lock_stats.source,
+            lock_stats.label.as_deref(),
+            Some(lock_stats.iter),
+        );
+
+        Self {
+            id: lock_stats.id,
+            source: lock_stats.source.to_string(),
+            label,
+            has_custom_label: lock_stats.label.is_some(),
+            lock_type: lock_stats.lock_type,
+            backend: lock_stats.backend,
+            type_name: lock_stats.type_name.to_string(),
+            iter: lock_stats.iter,
+            acquisitions: lock_stats.acquisitions,
+            read_acquisitions: lock_stats.read_acquisitions,
+            contentions: lock_stats.contentions,
+            wait_total_ns: lock_stats.wait_total_ns,
+            wait_max_ns: lock_stats.wait_max_ns,
+            wait_p50_ns: lock_stats.wait_percentile(50.0),
+            wait_p95_ns: lock_stats.wait_percentile(95.0),
+            wait_p99_ns: lock_stats.wait_percentile(99.0),
+            hold_total_ns: lock_stats.hold_total_ns,
+            hold_max_ns: lock_stats.hold_max_ns,
+            hold_p95_ns: lock_stats.hold_percentile(95.0),
+            holder_tid: lock_stats.holder_tid,
+            readers: lock_stats.readers,
+        }
+    }
+}
+
+/// Whether a guard grants exclusive (mutex, write) or shared (read) access.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Access {
+    Exclusive,
+    Shared,
+}
+
+/// Events sent to the background lock statistics collection thread.
+#[derive(Debug)]
+pub(crate) enum LockEvent {
+    Created {
+        id: u64,
+        source: &'static str,
+        display_label: Option<String>,
+        lock_type: LockType,
+        backend: LockBackend,
+        type_name: &'static str,
+    },
+    Acquired {
+        id: u64,
+        access: Access,
+        wait_ns: u64,
+        contended: bool,
+        tid: u64,
+    },
+    Released {
+        id: u64,
+        access: Access,
+        hold_ns: u64,
+    },
+    Flush {
+        done: CbSender<()>,
+    },
+}
+
+type LockStatsState = (CbSender<LockEvent>, Arc<RwLock<HashMap<u64, LockStats>>>);
+
+static LOCKS_STATE: OnceLock<LockStatsState> = OnceLock::new();
+
+static LOCK_ID_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/// Initialize the lock statistics collection system (called on first instrumented lock).
+/// Returns a reference to the global state.
+fn init_locks_state() -> &'static LockStatsState {
+    LOCKS_STATE.get_or_init(|| {
+        crate::channels::START_TIME.get_or_init(Instant::now);
+
+        let (tx, rx) = unbounded::<LockEvent>();
+        let stats_map = Arc::new(RwLock::new(HashMap::<u64, LockStats>::new()));
+        let stats_map_clone = Arc::clone(&stats_map);
+
+        ::std::thread::Builder::new()
+            .name("hp-locks".into())
+            .spawn(move || {
+                while let Ok(event) = rx.recv() {
+                    let mut stats = stats_map_clone.write().unwrap();
+                    match event {
+                        LockEvent::Created {
+                            id,
+                            source,
+                            display_label,
+                            lock_type,
+                            backend,
+                            type_name,
+                        } => {
+                            // Count existing items with the same source location
+                            let iter = stats.values().filter(|s| s.source == source).count() as u32;
+
+                            stats.insert(
+                                id,
+                                LockStats::new(
+                                    id,
+                                    source,
+                                    display_label,
+                                    lock_type,
+                                    backend,
+                                    type_name,
+                                    iter,
+                                ),
+                            );
+                        }
+                        LockEvent::Acquired {
+                            id,
+                            access,
+                            wait_ns,
+                            contended,
+                            tid,
+                        } => {
+                            if let Some(lock_stats) = stats.get_mut(&id) {
+                                lock_stats.record_acquired(access, wait_ns, contended, tid);
+                            }
+                        }
+                        LockEvent::Released {
+                            id,
+                            access,
+                            hold_ns,
+                        } => {
+                            if let Some(lock_stats) = stats.get_mut(&id) {
+                                lock_stats.record_released(access, hold_ns);
+                            }
+                        }
+                        LockEvent::Flush { done } => {
+                            let _ = done.send(());
+                        }
+                    }
+                }
+            })
+            .expect("Failed to spawn lock-stats-collector thread");
+
+        crate::metrics_server::start_metrics_server_once(*METRICS_SERVER_PORT);
+
+        (tx, stats_map)
+    })
+}
+
+/// Registration of a single instrumented lock, shared by all backend wrappers.
+#[derive(Debug)]
+pub(crate) struct LockHandle {
+    id: u64,
+    stats_tx: CbSender<LockEvent>,
+}
+
+impl LockHandle {
+    pub(crate) fn register<T: ?Sized>(
+        source: &'static str,
+        label: Option<String>,
+        lock_type: LockType,
+        backend: LockBackend,
+    ) -> Self {
+        let (stats_tx, _) = init_locks_state();
+        let id = LOCK_ID_COUNTER.fetch_add(1, Ordering::Relaxed);
+
+        let _ = stats_tx.send(LockEvent::Created {
+            id,
+            source,
+            display_label: label,
+            lock_type,
+            backend,
+            type_name: ::std::any::type_name::<T>(),
+        });
+
+        Self {
+            id,
+            stats_tx: stats_tx.clone(),
+        }
+    }
+
+    /// Records an acquisition that started at `started` and returns the hold start time.
+    pub(crate) fn acquired(&self, access: Access, started: Instant, contended: bool) -> Instant {
+        let now = Instant::now();
+        let _ = self.stats_tx.send(LockEvent::Acquired {
+            id: self.id,
+            access,
+            wait_ns: now.duration_since(started).as_nanos() as u64,
+            contended,
+            tid: crate::tid::current_tid(),
+        });
+        now
+    }
+
+    /// Must be called before the inner guard is dropped, so that the release is
+    /// ordered before the next holder's acquisition.
+    pub(crate) fn released(&self, access: Access, held_since: Instant) {
+        let _ = self.stats_tx.send(LockEvent::Released {
+            id: self.id,
+            access,
+            hold_ns: held_since.elapsed().as_nanos() as u64,
+        });
+    }
+}
+
+/// Guard returned by instrumented locks, records the hold time when dropped.
+///
+/// Dereferences to the protected value like the wrapped guard `G`.
+pub struct InstrumentedGuard<'a, G> {
+    inner: G,
+    handle: &'a LockHandle,
+    access: Access,
+    held_since: Instant,
+}
+
+impl<'a, G> InstrumentedGuard<'a, G> {
+    pub(crate) fn new(
+        inner: G,
+        handle: &'a LockHandle,
+        access: Access,
+        held_since: Instant,
+    ) -> Self {
+        Self {
+            inner,
+            handle,
+            access,
+            held_since,
+        }
+    }
+}
+
+impl<G> Drop for InstrumentedGuard<'_, G> {
+    fn drop(&mut self) {
+        // Runs before `inner` is dropped, while the lock is still held
+        self.handle.released(self.access, self.held_since);
+    }
+}
+
+impl<G: ::std::ops::Deref> ::std::ops::Deref for InstrumentedGuard<'_, G> {
+    type Target = G::Target;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<G: ::std::ops::DerefMut> ::std::ops::DerefMut for InstrumentedGuard<'_, G> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.inner
+    }
+}
+
+impl<G: ::std::fmt::Debug> ::std::fmt::Debug for InstrumentedGuard<'_, G> {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        self.inner.fmt(f)
+    }
+}
+
+impl<G: ::std::fmt::Display> ::std::fmt::Display for InstrumentedGuard<'_, G> {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        self.inner.fmt(f)
+    }
+}
+
+/// Trait for instrumenting mutexes.
+///
+/// This trait is not intended for direct use. Use the `mutex!` macro instead.
+#[doc(hidden)]
+pub trait InstrumentMutex {
+    type Output;
+    fn instrument_mutex(self, source: &'static str, label: Option<String>) -> Self::Output;
+}
+
+/// Trait for instrumenting read-write locks.
+///
+/// This trait is not intended for direct use. Use the `rwlock!` macro instead.
+#[doc(hidden)]
+pub trait InstrumentRwLock {
+    type Output;
+    fn instrument_rwlock(self, source: &'static str, label: Option<String>) -> Self::Output;
+}
+
+/// Instrument a mutex to track acquisition wait time, hold time and contention.
+///
+/// Supports `std::sync::Mutex`, `parking_lot::Mutex` (with the `parking_lot` feature)
+/// and `tokio::sync::Mutex` (with the `tokio` feature). The returned wrapper exposes
+/// the same locking methods as the wrapped mutex.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use std::sync::Mutex;
+///
+/// let counter = hotpath::mutex!(Mutex::new(0), label = "counter");
+/// *counter.lock().unwrap() += 1;
+/// ```
+#[macro_export]
+macro_rules! mutex {
+    ($expr:expr) => {{
+        const LOCK_ID: &'static str = concat!(file!(), ":", line!());
+        $crate::InstrumentMutex::instrument_mutex($expr, LOCK_ID, None)
+    }};
+
+    ($expr:expr, label = $label:expr) => {{
+        const LOCK_ID: &'static str = concat!(file!(), ":", line!());
+        $crate::InstrumentMutex::instrument_mutex($expr, LOCK_ID, Some($label.to_string()))
+    }};
+}
+
+/// Instrument a read-write lock to track acquisition wait time, hold time and contention.
+///
+/// Supports `std::sync::RwLock`, `parking_lot::RwLock` (with the `parking_lot` feature)
+/// and `tokio::sync::RwLock` (with the `tokio` feature). Read and write acquisitions
+/// are both counted, only writers are reported as the current holder.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use std::sync::RwLock;
+///
+/// let config = hotpath::rwlock!(RwLock::new(String::new()), label = "config");
+/// let len = config.read().unwrap().len();
+/// ```
+#[macro_export]
+macro_rules! rwlock {
+    ($expr:expr) => {{
+        const LOCK_ID: &'static str = concat!(file!(), ":", line!());
+        $crate::InstrumentRwLock::instrument_rwlock($expr, LOCK_ID, None)
+    }};
+
+    ($expr:expr, label = $label:expr) => {{
+        const LOCK_ID: &'static str = concat!(file!(), ":", line!());
+        $crate::InstrumentRwLock::instrument_rwlock($expr, LOCK_ID, Some($label.to_string()))
+    }};
+}
+
+fn get_all_lock_stats() -> HashMap<u64, LockStats> {
+    if let Some((_, stats_map)) = LOCKS_STATE.get() {
+        stats_map.read().unwrap().clone()
+    } else {
+        HashMap::new()
+    }
+}
+
+/// Compare two lock stats for sorting.
+/// Custom labels come first (sorted alphabetically), then auto-generated labels (sorted by source and iter).
+fn compare_lock_stats(a: &LockStats, b: &LockStats) -> ::std::cmp::Ordering {
+    match (&a.label, &b.label) {
+        (Some(_), None) => ::std::cmp::Ordering::Less,
+        (None, Some(_)) => ::std::cmp::Ordering::Greater,
+        (Some(a_label), Some(b_label)) => a_label.cmp(b_label).then_with(|| a.iter.cmp(&b.iter)),
+        (None, None) => a.source.cmp(b.source).then_with(|| a.iter.cmp(&b.iter)),
+    }
+}
+
+/// Waits until the stats thread has processed every event sent so far.
+pub(crate) fn flush_lock_events() {
+    let Some((tx, _)) = LOCKS_STATE.get() else {
+        return;
+    };
+    let (done_tx, done_rx) = crossbeam_channel::bounded(1);
+    if tx.send(LockEvent::Flush { done: done_tx }).is_ok() {
+        let _ = done_rx.recv_timeout(::std::time::Duration::from_secs(1));
+    }
+}
+
+pub(crate) fn get_sorted_lock_stats() -> Vec<LockStats> {
+    let mut stats: Vec<LockStats> = get_all_lock_stats().into_values().collect();
+    stats.sort_by(compare_lock_stats);
+    stats
+}
+
+pub fn get_locks_json() -> LocksJson {
+    let locks = get_sorted_lock_stats()
+        .iter()
+        .map(SerializableLockStats::from)
+        .collect();
+
+    let current_elapsed_ns = crate::channels::START_TIME
+        .get()
+        .map(|start| start.elapsed().as_nanos() as u64)
+        .unwrap_or(0);
+
+    LocksJson {
+        current_elapsed_ns,
+        locks,
+    }
+}
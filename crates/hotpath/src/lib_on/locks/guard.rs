@@ -0,0 +1,190 @@
+#[cfg(target_os = "linux")]
+use quanta::Instant;
+
+#[cfg(not(target_os = "linux"))]
+use std::time::Instant;
+
+use prettytable::{Cell, Row, Table};
+
+use crate::channels::resolve_label;
+use crate::locks::{flush_lock_events, get_sorted_lock_stats, LocksJson, SerializableLockStats};
+use crate::output::format_duration;
+use crate::Format;
+
+/// Builder for creating a LocksGuard with custom configuration.
+///
+/// # Examples
+///
+/// ```no_run
+/// use hotpath::locks::{LocksGuardBuilder, Format};
+///
+/// let _guard = LocksGuardBuilder::new()
+///     .format(Format::JsonPretty)
+///     .build();
+/// // Statistics will be printed as pretty JSON when _guard is dropped
+/// ```
+#[must_use = "builder is discarded without creating a guard"]
+pub struct LocksGuardBuilder {
+    format: Format,
+}
+
+impl LocksGuardBuilder {
+    /// Create a new locks guard builder.
+    pub fn new() -> Self {
+        Self {
+            format: Format::default(),
+        }
+    }
+
+    /// Set the output format for statistics.
+    pub fn format(mut self, format: Format) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Build and return the LocksGuard.
+    /// Statistics will be printed when the guard is dropped.
+    pub fn build(self) -> LocksGuard {
+        LocksGuard {
+            start_time: Instant::now(),
+            format: self.format,
+        }
+    }
+}
+
+impl Default for LocksGuardBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Guard for lock statistics collection.
+/// When dropped, prints a summary of all instrumented locks and their contention.
+///
+/// Use `LocksGuardBuilder` to create a guard with custom configuration.
+///
+/// # Examples
+///
+/// ```no_run
+/// use hotpath::locks::LocksGuard;
+///
+/// let _guard = LocksGuard::new();
+/// // Your code with instrumented locks here
+/// // Statistics will be printed when _guard is dropped
+/// ```
+#[must_use = "guard is dropped immediately without printing statistics"]
+pub struct LocksGuard {
+    start_time: Instant,
+    format: Format,
+}
+
+impl LocksGuard {
+    /// Create a new locks guard with default settings (table format).
+    /// Statistics will be printed when this guard is dropped.
+    ///
+    /// For custom configuration, use `LocksGuardBuilder::new()` instead.
+    pub fn new() -> Self {
+        Self {
+            start_time: Instant::now(),
+            format: Format::default(),
+        }
+    }
+
+    /// Set the output format for statistics.
+    pub fn format(mut self, format: Format) -> Self {
+        self.format = format;
+        self
+    }
+}
+
+impl Default for LocksGuard {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for LocksGuard {
+    fn drop(&mut self) {
+        let elapsed = self.start_time.elapsed();
+        flush_lock_events();
+        let locks = get_sorted_lock_stats();
+
+        if locks.is_empty() {
+            println!("\nNo instrumented locks found.");
+            return;
+        }
+
+        match self.format {
+            Format::Table => {
+                println!(
+                    "\n=== Lock Statistics (runtime: {:.2}s) ===",
+                    elapsed.as_secs_f64()
+                );
+
+                let mut table = Table::new();
+
+                table.add_row(Row::new(vec![
+                    Cell::new("Lock"),
+                    Cell::new("Type"),
+                    Cell::new("Acquired"),
+                    Cell::new("Contended"),
+                    Cell::new("Wait P50"),
+                    Cell::new("Wait P99"),
+                    Cell::new("Wait Max"),
+                    Cell::new("Hold P95"),
+                    Cell::new("Hold Max"),
+                    Cell::new("Holder"),
+                ]));
+
+                for lock_stats in &locks {
+                    let label = resolve_label(
+                        lock_stats.source,
+                        lock_stats.label.as_deref(),
+                        Some(lock_stats.iter),
+                    );
+                    let duration = |ns: Option<u64>| ns.map_or("-".to_string(), format_duration);
+                    let holder = match (lock_stats.holder_tid, lock_stats.readers) {
+                        (Some(tid), _) => format!("tid {tid}"),
+                        (None, 0) => "-".to_string(),
+                        (None, readers) => format!("{readers} readers"),
+                    };
+                    table.add_row(Row::new(vec![
+                        Cell::new(&label),
+                        Cell::new(&format!("{}::{}", lock_stats.backend, lock_stats.lock_type)),
+                        Cell::new(&lock_stats.acquisitions.to_string()),
+                        Cell::new(&lock_stats.contentions.to_string()),
+                        Cell::new(&duration(lock_stats.wait_percentile(50.0))),
+                        Cell::new(&duration(lock_stats.wait_percentile(99.0))),
+                        Cell::new(&duration(Some(lock_stats.wait_max_ns))),
+                        Cell::new(&duration(lock_stats.hold_percentile(95.0))),
+                        Cell::new(&duration(Some(lock_stats.hold_max_ns))),
+                        Cell::new(&holder),
+                    ]));
+                }
+
+                println!("\nLocks:");
+                table.printstd();
+            }
+            Format::Json => {
+                let locks_json = LocksJson {
+                    current_elapsed_ns: elapsed.as_nanos() as u64,
+                    locks: locks.iter().map(SerializableLockStats::from).collect(),
+                };
+                match serde_json::to_string(&locks_json) {
+                    Ok(json) => println!("{}", json),
+                    Err(e) => eprintln!("Failed to serialize statistics to JSON: {}", e),
+                }
+            }
+            Format::JsonPretty => {
+                let locks_json = LocksJson {
+                    current_elapsed_ns: elapsed.as_nanos() as u64,
+                    locks: locks.iter().map(SerializableLockStats::from).collect(),
+                };
+                match serde_json::to_string_pretty(&locks_json) {
+                    Ok(json) => println!("{}", json),
+                    Err(e) => eprintln!("Failed to serialize statistics to pretty JSON: {}", e),
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,186 @@
+//! Instrumentation for `parking_lot::Mutex` and `parking_lot::RwLock`.
+
+use std::ops::Deref;
+
+#[cfg(target_os = "linux")]
+use quanta::Instant;
+
+#[cfg(not(target_os = "linux"))]
+use std::time::Instant;
+
+use super::{
+    Access, InstrumentMutex, InstrumentRwLock, InstrumentedGuard, LockBackend, LockHandle, LockType,
+};
+
+/// Guard returned by [`Mutex::lock`].
+pub type MutexGuard<'a, T> = InstrumentedGuard<'a, parking_lot::MutexGuard<'a, T>>;
+/// Guard returned by [`RwLock::read`].
+pub type RwLockReadGuard<'a, T> = InstrumentedGuard<'a, parking_lot::RwLockReadGuard<'a, T>>;
+/// Guard returned by [`RwLock::write`].
+pub type RwLockWriteGuard<'a, T> = InstrumentedGuard<'a, parking_lot::RwLockWriteGuard<'a, T>>;
+
+/// Tries a non-blocking acquisition first to detect contention.
+fn acquire<G>(
+    handle: &LockHandle,
+    access: Access,
+    try_acquire: impl FnOnce() -> Option<G>,
+    acquire: impl FnOnce() -> G,
+) -> InstrumentedGuard<'_, G> {
+    let started = Instant::now();
+    let (guard, contended) = match try_acquire() {
+        Some(guard) => (guard, false),
+        None => (acquire(), true),
+    };
+    let held_since = handle.acquired(access, started, contended);
+    InstrumentedGuard::new(guard, handle, access, held_since)
+}
+
+fn try_acquire<G>(
+    handle: &LockHandle,
+    access: Access,
+    try_acquire: impl FnOnce() -> Option<G>,
+) -> Option<InstrumentedGuard<'_, G>> {
+    let started = Instant::now();
+    let guard = try_acquire()?;
+    let held_since = handle.acquired(access, started, false);
+    Some(InstrumentedGuard::new(guard, handle, access, held_since))
+}
+
+/// Instrumented `parking_lot::Mutex`.
+pub struct Mutex<T> {
+    inner: parking_lot::Mutex<T>,
+    handle: LockHandle,
+}
+
+impl<T> Mutex<T> {
+    /// See [`parking_lot::Mutex::lock`].
+    pub fn lock(&self) -> MutexGuard<'_, T> {
+        acquire(
+            &self.handle,
+            Access::Exclusive,
+            || self.inner.try_lock(),
+            || self.inner.lock(),
+        )
+    }
+
+    /// See [`parking_lot::Mutex::try_lock`].
+    pub fn try_lock(&self) -> Option<MutexGuard<'_, T>> {
+        try_acquire(&self.handle, Access::Exclusive, || self.inner.try_lock())
+    }
+
+    /// See [`parking_lot::Mutex::get_mut`].
+    pub fn get_mut(&mut self) -> &mut T {
+        self.inner.get_mut()
+    }
+
+    /// See [`parking_lot::Mutex::into_inner`].
+    pub fn into_inner(self) -> T {
+        self.inner.into_inner()
+    }
+}
+
+impl<T> Deref for Mutex<T> {
+    type Target = parking_lot::Mutex<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<T: std::fmt::Debug> std::fmt::Debug for Mutex<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.inner.fmt(f)
+    }
+}
+
+/// Instrumented `parking_lot::RwLock`.
+pub struct RwLock<T> {
+    inner: parking_lot::RwLock<T>,
+    handle: LockHandle,
+}
+
+impl<T> RwLock<T> {
+    /// See [`parking_lot::RwLock::read`].
+    pub fn read(&self) -> RwLockReadGuard<'_, T> {
+        acquire(
+            &self.handle,
+            Access::Shared,
+            || self.inner.try_read(),
+            || self.inner.read(),
+        )
+    }
+
+    /// See [`parking_lot::RwLock::write`].
+    pub fn write(&self) -> RwLockWriteGuard<'_, T> {
+        acquire(
+            &self.handle,
+            Access::Exclusive,
+            || self.inner.try_write(),
+            || self.inner.write(),
+        )
+    }
+
+    /// See [`parking_lot::RwLock::try_read`].
+    pub fn try_read(&self) -> Option<RwLockReadGuard<'_, T>> {
+        try_acquire(&self.handle, Access::Shared, || self.inner.try_read())
+    }
+
+    /// See [`parking_lot::RwLock::try_write`].
+    pub fn try_write(&self) -> Option<RwLockWriteGuard<'_, T>> {
+        try_acquire(&self.handle, Access::Exclusive, || self.inner.try_write())
+    }
+
+    /// See [`parking_lot::RwLock::get_mut`].
+    pub fn get_mut(&mut self) -> &mut T {
+        self.inner.get_mut()
+    }
+
+    /// See [`parking_lot::RwLock::into_inner`].
+    pub fn into_inner(self) -> T {
+        self.inner.into_inner()
+    }
+}
+
+impl<T> Deref for RwLock<T> {
+    type Target = parking_lot::RwLock<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<T: std::fmt::Debug> std::fmt::Debug for RwLock<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.inner.fmt(f)
+    }
+}
+
+impl<T> InstrumentMutex for parking_lot::Mutex<T> {
+    type Output = Mutex<T>;
+    fn instrument_mutex(self, source: &'static str, label: Option<String>) -> Self::Output {
+        Mutex {
+            inner: self,
+            handle: LockHandle::register::<T>(
+                source,
+                label,
+                LockType::Mutex,
+                LockBackend::ParkingLot,
+            ),
+        }
+    }
+}
+
+impl<T> InstrumentRwLock for parking_lot::RwLock<T> {
+    type Output = RwLock<T>;
+    fn instrument_rwlock(self, source: &'static str, label: Option<String>) -> Self::Output {
+        RwLock {
+            inner: self,
+            handle: LockHandle::register::<T>(
+                source,
+                label,
+                LockType::RwLock,
+                LockBackend::ParkingLot,
+            ),
+        }
+    }
+}
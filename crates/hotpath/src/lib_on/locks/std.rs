@@ -0,0 +1,196 @@
+//! Instrumentation for `std::sync::Mutex` and `std::sync::RwLock`.
+
+use ::std::ops::Deref;
+use ::std::sync::{self, LockResult, PoisonError, TryLockError, TryLockResult};
+
+#[cfg(target_os = "linux")]
+use quanta::Instant;
+
+#[cfg(not(target_os = "linux"))]
+use ::std::time::Instant;
+
+use super::{
+    Access, InstrumentMutex, InstrumentRwLock, InstrumentedGuard, LockBackend, LockHandle, LockType,
+};
+
+/// Guard returned by [`Mutex::lock`].
+pub type MutexGuard<'a, T> = InstrumentedGuard<'a, sync::MutexGuard<'a, T>>;
+/// Guard returned by [`RwLock::read`].
+pub type RwLockReadGuard<'a, T> = InstrumentedGuard<'a, sync::RwLockReadGuard<'a, T>>;
+/// Guard returned by [`RwLock::write`].
+pub type RwLockWriteGuard<'a, T> = InstrumentedGuard<'a, sync::RwLockWriteGuard<'a, T>>;
+
+/// Maps the guard inside a possibly poisoned lock result.
+fn map_lock_result<G, U>(result: LockResult<G>, f: impl FnOnce(G) -> U) -> LockResult<U> {
+    match result {
+        Ok(guard) => Ok(f(guard)),
+        Err(poisoned) => Err(PoisonError::new(f(poisoned.into_inner()))),
+    }
+}
+
+/// Tries a non-blocking acquisition first, so that waiting for another holder can be
+/// told apart from an uncontended acquisition.
+fn acquire<G>(
+    handle: &LockHandle,
+    access: Access,
+    try_acquire: impl FnOnce() -> TryLockResult<G>,
+    acquire: impl FnOnce() -> LockResult<G>,
+) -> LockResult<InstrumentedGuard<'_, G>> {
+    let started = Instant::now();
+    let (result, contended) = match try_acquire() {
+        Ok(guard) => (Ok(guard), false),
+        Err(TryLockError::Poisoned(poisoned)) => (Err(poisoned), false),
+        Err(TryLockError::WouldBlock) => (acquire(), true),
+    };
+    let held_since = handle.acquired(access, started, contended);
+    map_lock_result(result, |guard| {
+        InstrumentedGuard::new(guard, handle, access, held_since)
+    })
+}
+
+fn try_acquire<G>(
+    handle: &LockHandle,
+    access: Access,
+    try_acquire: impl FnOnce() -> TryLockResult<G>,
+) -> TryLockResult<InstrumentedGuard<'_, G>> {
+    let started = Instant::now();
+    let result = match try_acquire() {
+        Ok(guard) => Ok(guard),
+        Err(TryLockError::Poisoned(poisoned)) => Err(poisoned),
+        Err(TryLockError::WouldBlock) => return Err(TryLockError::WouldBlock),
+    };
+    let held_since = handle.acquired(access, started, false);
+    map_lock_result(result, |guard| {
+        InstrumentedGuard::new(guard, handle, access, held_since)
+    })
+    .map_err(TryLockError::Poisoned)
+}
+
+/// Instrumented `std::sync::Mutex`.
+pub struct Mutex<T> {
+    inner: sync::Mutex<T>,
+    handle: LockHandle,
+}
+
+impl<T> Mutex<T> {
+    /// See [`std::sync::Mutex::lock`].
+    pub fn lock(&self) -> LockResult<MutexGuard<'_, T>> {
+        acquire(
+            &self.handle,
+            Access::Exclusive,
+            || self.inner.try_lock(),
+            || self.inner.lock(),
+        )
+    }
+
+    /// See [`std::sync::Mutex::try_lock`].
+    pub fn try_lock(&self) -> TryLockResult<MutexGuard<'_, T>> {
+        try_acquire(&self.handle, Access::Exclusive, || self.inner.try_lock())
+    }
+
+    /// See [`std::sync::Mutex::get_mut`].
+    pub fn get_mut(&mut self) -> LockResult<&mut T> {
+        self.inner.get_mut()
+    }
+
+    /// See [`std::sync::Mutex::into_inner`].
+    pub fn into_inner(self) -> LockResult<T> {
+        self.inner.into_inner()
+    }
+}
+
+impl<T> Deref for Mutex<T> {
+    type Target = sync::Mutex<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<T: ::std::fmt::Debug> ::std::fmt::Debug for Mutex<T> {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        self.inner.fmt(f)
+    }
+}
+
+/// Instrumented `std::sync::RwLock`.
+pub struct RwLock<T> {
+    inner: sync::RwLock<T>,
+    handle: LockHandle,
+}
+
+impl<T> RwLock<T> {
+    /// See [`std::sync::RwLock::read`].
+    pub fn read(&self) -> LockResult<RwLockReadGuard<'_, T>> {
+        acquire(
+            &self.handle,
+            Access::Shared,
+            || self.inner.try_read(),
+            || self.inner.read(),
+        )
+    }
+
+    /// See [`std::sync::RwLock::write`].
+    pub fn write(&self) -> LockResult<RwLockWriteGuard<'_, T>> {
+        acquire(
+            &self.handle,
+            Access::Exclusive,
+            || self.inner.try_write(),
+            || self.inner.write(),
+        )
+    }
+
+    /// See [`std::sync::RwLock::try_read`].
+    pub fn try_read(&self) -> TryLockResult<RwLockReadGuard<'_, T>> {
+        try_acquire(&self.handle, Access::Shared, || self.inner.try_read())
+    }
+
+    /// See [`std::sync::RwLock::try_write`].
+    pub fn try_write(&self) -> TryLockResult<RwLockWriteGuard<'_, T>> {
+        try_acquire(&self.handle, Access::Exclusive, || self.inner.try_write())
+    }
+
+    /// See [`std::sync::RwLock::get_mut`].
+    pub fn get_mut(&mut self) -> LockResult<&mut T> {
+        self.inner.get_mut()
+    }
+
+    /// See [`std::sync::RwLock::into_inner`].
+    pub fn into_inner(self) -> LockResult<T> {
+        self.inner.into_inner()
+    }
+}
+
+impl<T> Deref for RwLock<T> {
+    type Target = sync::RwLock<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<T: ::std::fmt::Debug> ::std::fmt::Debug for RwLock<T> {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        self.inner.fmt(f)
+    }
+}
+
+impl<T> InstrumentMutex for sync::Mutex<T> {
+    type Output = Mutex<T>;
+    fn instrument_mutex(self, source: &'static str, label: Option<String>) -> Self::Output {
+        Mutex {
+            inner: self,
+            handle: LockHandle::register::<T>(source, label, LockType::Mutex, LockBackend::Std),
+        }
+    }
+}
+
+impl<T> InstrumentRwLock for sync::RwLock<T> {
+    type Output = RwLock<T>;
+    fn instrument_rwlock(self, source: &'static str, label: Option<String>) -> Self::Output {
+        RwLock {
+            inner: self,
+            handle: LockHandle::register::<T>(source, label, LockType::RwLock, LockBackend::Std),
+        }
+    }
+}
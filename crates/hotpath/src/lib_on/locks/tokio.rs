@@ -0,0 +1,179 @@
+//! Instrumentation for `tokio::sync::Mutex` and `tokio::sync::RwLock`.
+//!
+//! Wait time includes the time the acquiring task spent suspended, so it reflects
+//! how long the task was held up rather than CPU time spent spinning.
+
+use std::ops::Deref;
+
+#[cfg(target_os = "linux")]
+use quanta::Instant;
+
+#[cfg(not(target_os = "linux"))]
+use std::time::Instant;
+
+use tokio::sync::{self, TryLockError};
+
+use super::{
+    Access, InstrumentMutex, InstrumentRwLock, InstrumentedGuard, LockBackend, LockHandle, LockType,
+};
+
+/// Guard returned by [`Mutex::lock`].
+pub type MutexGuard<'a, T> = InstrumentedGuard<'a, sync::MutexGuard<'a, T>>;
+/// Guard returned by [`RwLock::read`].
+pub type RwLockReadGuard<'a, T> = InstrumentedGuard<'a, sync::RwLockReadGuard<'a, T>>;
+/// Guard returned by [`RwLock::write`].
+pub type RwLockWriteGuard<'a, T> = InstrumentedGuard<'a, sync::RwLockWriteGuard<'a, T>>;
+
+fn try_acquire<G>(
+    handle: &LockHandle,
+    access: Access,
+    try_acquire: impl FnOnce() -> Result<G, TryLockError>,
+) -> Result<InstrumentedGuard<'_, G>, TryLockError> {
+    let started = Instant::now();
+    let guard = try_acquire()?;
+    let held_since = handle.acquired(access, started, false);
+    Ok(InstrumentedGuard::new(guard, handle, access, held_since))
+}
+
+/// Instrumented `tokio::sync::Mutex`.
+pub struct Mutex<T> {
+    inner: sync::Mutex<T>,
+    handle: LockHandle,
+}
+
+impl<T> Mutex<T> {
+    /// See [`tokio::sync::Mutex::lock`].
+    pub async fn lock(&self) -> MutexGuard<'_, T> {
+        let started = Instant::now();
+        let (guard, contended) = match self.inner.try_lock() {
+            Ok(guard) => (guard, false),
+            Err(_) => (self.inner.lock().await, true),
+        };
+        let held_since = self.handle.acquired(Access::Exclusive, started, contended);
+        InstrumentedGuard::new(guard, &self.handle, Access::Exclusive, held_since)
+    }
+
+    /// See [`tokio::sync::Mutex::blocking_lock`].
+    pub fn blocking_lock(&self) -> MutexGuard<'_, T> {
+        let started = Instant::now();
+        let (guard, contended) = match self.inner.try_lock() {
+            Ok(guard) => (guard, false),
+            Err(_) => (self.inner.blocking_lock(), true),
+        };
+        let held_since = self.handle.acquired(Access::Exclusive, started, contended);
+        InstrumentedGuard::new(guard, &self.handle, Access::Exclusive, held_since)
+    }
+
+    /// See [`tokio::sync::Mutex::try_lock`].
+    pub fn try_lock(&self) -> Result<MutexGuard<'_, T>, TryLockError> {
+        try_acquire(&self.handle, Access::Exclusive, || self.inner.try_lock())
+    }
+
+    /// See [`tokio::sync::Mutex::get_mut`].
+    pub fn get_mut(&mut self) -> &mut T {
+        self.inner.get_mut()
+    }
+
+    /// See [`tokio::sync::Mutex::into_inner`].
+    pub fn into_inner(self) -> T {
+        self.inner.into_inner()
+    }
+}
+
+impl<T> Deref for Mutex<T> {
+    type Target = sync::Mutex<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<T: std::fmt::Debug> std::fmt::Debug for Mutex<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.inner.fmt(f)
+    }
+}
+
+/// Instrumented `tokio::sync::RwLock`.
+pub struct RwLock<T> {
+    inner: sync::RwLock<T>,
+    handle: LockHandle,
+}
+
+impl<T> RwLock<T> {
+    /// See [`tokio::sync::RwLock::read`].
+    pub async fn read(&self) -> RwLockReadGuard<'_, T> {
+        let started = Instant::now();
+        let (guard, contended) = match self.inner.try_read() {
+            Ok(guard) => (guard, false),
+            Err(_) => (self.inner.read().await, true),
+        };
+        let held_since = self.handle.acquired(Access::Shared, started, contended);
+        InstrumentedGuard::new(guard, &self.handle, Access::Shared, held_since)
+    }
+
+    /// See [`tokio::sync::RwLock::write`].
+    pub async fn write(&self) -> RwLockWriteGuard<'_, T> {
+        let started = Instant::now();
+        let (guard, contended) = match self.inner.try_write() {
+            Ok(guard) => (guard, false),
+            Err(_) => (self.inner.write().await, true),
+        };
+        let held_since = self.handle.acquired(Access::Exclusive, started, contended);
+        InstrumentedGuard::new(guard, &self.handle, Access::Exclusive, held_since)
+    }
+
+    /// See [`tokio::sync::RwLock::try_read`].
+    pub fn try_read(&self) -> Result<RwLockReadGuard<'_, T>, TryLockError> {
+        try_acquire(&self.handle, Access::Shared, || self.inner.try_read())
+    }
+
+    /// See [`tokio::sync::RwLock::try_write`].
+    pub fn try_write(&self) -> Result<RwLockWriteGuard<'_, T>, TryLockError> {
+        try_acquire(&self.handle, Access::Exclusive, || self.inner.try_write())
+    }
+
+    /// See [`tokio::sync::RwLock::get_mut`].
+    pub fn get_mut(&mut self) -> &mut T {
+        self.inner.get_mut()
+    }
+
+    /// See [`tokio::sync::RwLock::into_inner`].
+    pub fn into_inner(self) -> T {
+        self.inner.into_inner()
+    }
+}
+
+impl<T> Deref for RwLock<T> {
+    type Target = sync::RwLock<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<T: std::fmt::Debug> std::fmt::Debug for RwLock<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.inner.fmt(f)
+    }
+}
+
+impl<T> InstrumentMutex for sync::Mutex<T> {
+    type Output = Mutex<T>;
+    fn instrument_mutex(self, source: &'static str, label: Option<String>) -> Self::Output {
+        Mutex {
+            inner: self,
+            handle: LockHandle::register::<T>(source, label, LockType::Mutex, LockBackend::Tokio),
+        }
+    }
+}
+
+impl<T> InstrumentRwLock for sync::RwLock<T> {
+    type Output = RwLock<T>;
+    fn instrument_rwlock(self, source: &'static str, label: Option<String>) -> Self::Output {
+        RwLock {
+            inner: self,
+            handle: LockHandle::register::<T>(source, label, LockType::RwLock, LockBackend::Tokio),
+        }
+    }
+}
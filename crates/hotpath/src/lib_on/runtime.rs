@@ -0,0 +1,188 @@
+//! Tokio runtime metrics module - periodically samples `Handle::metrics()` of a registered
+//! runtime and keeps a short history of the samples.
+
+use crate::channels::START_TIME;
+use std::collections::VecDeque;
+use std::sync::{Arc, OnceLock, RwLock};
+use std::time::Duration;
+use tokio::runtime::{Handle, RuntimeFlavor};
+
+#[cfg(target_os = "linux")]
+use quanta::Instant;
+
+#[cfg(not(target_os = "linux"))]
+use std::time::Instant;
+
+pub use crate::json::{RuntimeJson, RuntimeSample, RuntimeSummaryJson};
+
+const DEFAULT_SAMPLE_INTERVAL_MS: u64 = 1000;
+/// Number of samples kept in the history
+const HISTORY_LIMIT: usize = 120;
+
+struct RuntimeState {
+    handle: Handle,
+    flavor: &'static str,
+    sample_interval: Duration,
+    samples: VecDeque<RuntimeSample>,
+    /// Samples taken since registration, including ones evicted from the history
+    sample_count: u64,
+    peak_alive_tasks: usize,
+    peak_global_queue_depth: usize,
+    peak_blocking_threads: Option<usize>,
+}
+
+impl RuntimeState {
+    fn record_sample(&mut self) {
+        let sample = take_sample(&self.handle);
+
+        self.sample_count += 1;
+        self.peak_alive_tasks = self.peak_alive_tasks.max(sample.num_alive_tasks);
+        self.peak_global_queue_depth = self.peak_global_queue_depth.max(sample.global_queue_depth);
+        if let Some(blocking) = sample.num_blocking_threads {
+            self.peak_blocking_threads =
+                Some(self.peak_blocking_threads.unwrap_or(0).max(blocking));
+        }
+
+        if self.samples.len() >= HISTORY_LIMIT {
+            self.samples.pop_front();
+        }
+        self.samples.push_back(sample);
+    }
+}
+
+type RuntimeStateRef = Arc<RwLock<RuntimeState>>;
+
+static RUNTIME_STATE: OnceLock<RuntimeStateRef> = OnceLock::new();
+
+/// Registers a tokio runtime for periodic metrics sampling.
+///
+/// Only the first registered runtime is sampled, later calls are ignored. The sampling
+/// interval can be changed with the `HOTPATH_RUNTIME_INTERVAL` env var (milliseconds,
+/// default 1000, `0` falls back to the default).
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// #[tokio::main]
+/// async fn main() {
+///     hotpath::runtime::register_handle(&tokio::runtime::Handle::current());
+/// }
+/// ```
+pub fn register_handle(handle: &Handle) {
+    RUNTIME_STATE.get_or_init(|| {
+        START_TIME.get_or_init(Instant::now);
+
+        let sample_interval_ms = std::env::var("HOTPATH_RUNTIME_INTERVAL")
+            .ok()
+            .and_then(|ms| ms.parse::<u64>().ok())
+            .filter(|&ms| ms > 0)
+            .unwrap_or(DEFAULT_SAMPLE_INTERVAL_MS);
+        let sample_interval = Duration::from_millis(sample_interval_ms);
+
+        let flavor = match handle.runtime_flavor() {
+            RuntimeFlavor::CurrentThread => "current_thread",
+            _ => "multi_thread",
+        };
+
+        let state = Arc::new(RwLock::new(RuntimeState {
+            handle: handle.clone(),
+            flavor,
+            sample_interval,
+            samples: VecDeque::with_capacity(HISTORY_LIMIT),
+            sample_count: 0,
+            peak_alive_tasks: 0,
+            peak_global_queue_depth: 0,
+            peak_blocking_threads: None,
+        }));
+
+        let state_clone = Arc::clone(&state);
+
+        std::thread::Builder::new()
+            .name("hp-runtime".into())
+            .spawn(move || loop {
+                if let Ok(mut state_guard) = state_clone.write() {
+                    state_guard.record_sample();
+                }
+                std::thread::sleep(sample_interval);
+            })
+            .expect("Failed to spawn runtime-metrics-collector thread");
+
+        state
+    });
+}
+
+fn take_sample(handle: &Handle) -> RuntimeSample {
+    let metrics = handle.metrics();
+    let num_workers = metrics.num_workers();
+
+    #[cfg(target_has_atomic = "64")]
+    let worker_park_counts = (0..num_workers)
+        .map(|worker| metrics.worker_park_count(worker))
+        .collect();
+    #[cfg(not(target_has_atomic = "64"))]
+    let worker_park_counts = Vec::new();
+
+    #[cfg(tokio_unstable)]
+    let num_blocking_threads = Some(metrics.num_blocking_threads());
+    #[cfg(not(tokio_unstable))]
+    let num_blocking_threads = None;
+
+    RuntimeSample {
+        timestamp_ns: elapsed_ns(),
+        num_workers,
+        num_alive_tasks: metrics.num_alive_tasks(),
+        global_queue_depth: metrics.global_queue_depth(),
+        num_blocking_threads,
+        worker_park_counts,
+    }
+}
+
+fn elapsed_ns() -> u64 {
+    START_TIME
+        .get()
+        .map(|t| t.elapsed().as_nanos() as u64)
+        .unwrap_or(0)
+}
+
+/// Get sampled runtime metrics as JSON
+pub fn get_runtime_json() -> RuntimeJson {
+    if let Some(state) = RUNTIME_STATE.get() {
+        if let Ok(state_guard) = state.read() {
+            return RuntimeJson {
+                current_elapsed_ns: elapsed_ns(),
+                registered: true,
+                flavor: Some(state_guard.flavor.to_string()),
+                sample_interval_ms: state_guard.sample_interval.as_millis() as u64,
+                samples: state_guard.samples.iter().cloned().collect(),
+            };
+        }
+    }
+
+    RuntimeJson {
+        current_elapsed_ns: elapsed_ns(),
+        sample_interval_ms: DEFAULT_SAMPLE_INTERVAL_MS,
+        ..Default::default()
+    }
+}
+
+/// Takes a final sample and summarizes the whole session for the exit report.
+pub(crate) fn runtime_summary() -> Option<RuntimeSummaryJson> {
+    let state = RUNTIME_STATE.get()?;
+    let mut state_guard = state.write().ok()?;
+    state_guard.record_sample();
+
+    let last = state_guard.samples.back()?;
+
+    Some(RuntimeSummaryJson {
+        flavor: state_guard.flavor.to_string(),
+        num_workers: last.num_workers,
+        samples: state_guard.sample_count,
+        num_alive_tasks: last.num_alive_tasks,
+        peak_alive_tasks: state_guard.peak_alive_tasks,
+        global_queue_depth: last.global_queue_depth,
+        peak_global_queue_depth: state_guard.peak_global_queue_depth,
+        num_blocking_threads: last.num_blocking_threads,
+        peak_blocking_threads: state_guard.peak_blocking_threads,
+        total_parks: last.worker_park_counts.iter().sum(),
+    })
+}
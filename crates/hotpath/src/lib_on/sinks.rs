@@ -0,0 +1,413 @@
+//! Sink instrumentation module - tracks items sent, backpressure, flushes and close.
+
+use crossbeam_channel::{unbounded, Sender as CbSender};
+use hdrhistogram::Histogram;
+use std::collections::{HashMap, VecDeque};
+use std::sync::atomic::AtomicU64;
+use std::sync::{Arc, OnceLock, RwLock};
+
+#[cfg(target_os = "linux")]
+use quanta::Instant;
+
+#[cfg(not(target_os = "linux"))]
+use std::time::Instant;
+
+pub mod guard;
+pub use guard::{SinksGuard, SinksGuardBuilder};
+
+pub(crate) mod wrapper;
+
+use crate::channels::{LogRecord, MessageLog};
+pub use crate::json::{ChannelState, LogEntry, SerializableSinkStats, SinkLogs, SinksJson};
+use crate::metrics_server::METRICS_SERVER_PORT;
+pub use crate::Format;
+
+/// Statistics for a single instrumented sink.
+#[derive(Debug, Clone)]
+pub(crate) struct SinkStats {
+    pub(crate) id: u64,
+    pub(crate) source: &'static str,
+    pub(crate) label: Option<String>,
+    pub(crate) state: ChannelState, // Only Active or Closed
+    pub(crate) items_sent: u64,
+    pub(crate) type_name: &'static str,
+    pub(crate) type_size: usize,
+    pub(crate) logs: VecDeque<LogEntry>,
+    pub(crate) iter: u32,
+    /// `poll_ready` calls that returned `Poll::Pending`
+    pub(crate) pending_ready_polls: u64,
+    /// Time spent waiting for `poll_ready` to return `Poll::Ready`
+    pub(crate) backpressure_ns: u64,
+    pub(crate) flushes: u64,
+    pub(crate) flush: Histogram<u64>,
+    pub(crate) flush_max_ns: u64,
+    pub(crate) close_ns: Option<u64>,
+}
+
+impl From<&SinkStats> for SerializableSinkStats {
+    fn from(sink_stats: &SinkStats) -> Self {
+        let label = crate::channels::resolve_label(
+            sink_stats.source,
+            sink_stats.label.as_deref(),
+            Some(sink_stats.iter),
+        );
+
+        Self {
+            id: sink_stats.id,
+            source: sink_stats.source.to_string(),
+            label,
+            has_custom_label: sink_stats.label.is_some(),
+            state: sink_stats.state,
+            items_sent: sink_stats.items_sent,
+            type_name: sink_stats.type_name.to_string(),
+            type_size: sink_stats.type_size,
+            iter: sink_stats.iter,
+            pending_ready_polls: sink_stats.pending_ready_polls,
+            backpressure_ns: sink_stats.backpressure_ns,
+            flushes: sink_stats.flushes,
+            flush_p50_ns: sink_stats.flush_percentile(50.0),
+            flush_p95_ns: sink_stats.flush_percentile(95.0),
+            flush_p99_ns: sink_stats.flush_percentile(99.0),
+            flush_max_ns: sink_stats.flush_max_ns,
+            close_ns: sink_stats.close_ns,
+        }
+    }
+}
+
+impl SinkStats {
+    const LOW_NS: u64 = 1;
+    const HIGH_NS: u64 = 3_600_000_000_000; // 1h
+    const SIGFIGS: u8 = 2;
+
+    fn new(
+        id: u64,
+        source: &'static str,
+        label: Option<String>,
+        type_name: &'static str,
+        type_size: usize,
+        iter: u32,
+    ) -> Self {
+        Self {
+            id,
+            source,
+            label,
+            state: ChannelState::Active,
+            items_sent: 0,
+            type_name,
+            type_size,
+            logs: VecDeque::new(),
+            iter,
+            pending_ready_polls: 0,
+            backpressure_ns: 0,
+            flushes: 0,
+            flush: Histogram::<u64>::new_with_bounds(Self::LOW_NS, Self::HIGH_NS, Self::SIGFIGS)
+                .expect("hdrhistogram init"),
+            flush_max_ns: 0,
+            close_ns: None,
+        }
+    }
+
+    pub(crate) fn flush_percentile(&self, p: f64) -> Option<u64> {
+        crate::channels::percentile(&self.flush, self.flush_max_ns, p)
+    }
+}
+
+/// Events sent to the background sink statistics collection thread.
+#[derive(Debug)]
+pub(crate) enum SinkEvent {
+    Created {
+        id: u64,
+        source: &'static str,
+        display_label: Option<String>,
+        type_name: &'static str,
+        type_size: usize,
+    },
+    Sent {
+        id: u64,
+        log: LogRecord,
+        timestamp: Instant,
+    },
+    ReadyPending {
+        id: u64,
+    },
+    /// `poll_ready` returned `Poll::Ready` after at least one pending poll
+    Ready {
+        id: u64,
+        wait_ns: u64,
+    },
+    Flushed {
+        id: u64,
+        flush_ns: u64,
+    },
+    Closed {
+        id: u64,
+        close_ns: u64,
+    },
+    Flush {
+        done: CbSender<()>,
+    },
+}
+
+type SinkStatsState = (CbSender<SinkEvent>, Arc<RwLock<HashMap<u64, SinkStats>>>);
+
+static SINKS_STATE: OnceLock<SinkStatsState> = OnceLock::new();
+
+pub(crate) static SINK_ID_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/// Initialize the sink statistics collection system (called on first instrumented sink).
+/// Returns a reference to the global state.
+pub(crate) fn init_sinks_state() -> &'static SinkStatsState {
+    SINKS_STATE.get_or_init(|| {
+        crate::channels::START_TIME.get_or_init(Instant::now);
+
+        let (tx, rx) = unbounded::<SinkEvent>();
+        let stats_map = Arc::new(RwLock::new(HashMap::<u64, SinkStats>::new()));
+        let stats_map_clone = Arc::clone(&stats_map);
+
+        std::thread::Builder::new()
+            .name("hp-sinks".into())
+            .spawn(move || {
+                while let Ok(event) = rx.recv() {
+                    let mut stats = stats_map_clone.write().unwrap();
+                    match event {
+                        SinkEvent::Created {
+                            id,
+                            source,
+                            display_label,
+                            type_name,
+                            type_size,
+                        } => {
+                            // Count existing items with the same source location
+                            let iter = stats.values().filter(|s| s.source == source).count() as u32;
+
+                            stats.insert(
+                                id,
+                                SinkStats::new(
+                                    id,
+                                    source,
+                                    display_label,
+                                    type_name,
+                                    type_size,
+                                    iter,
+                                ),
+                            );
+                        }
+                        SinkEvent::Sent { id, log, timestamp } => {
+                            if let Some(sink_stats) = stats.get_mut(&id) {
+                                sink_stats.items_sent += 1;
+
+                                // Items dropped by `log_filter` or `log_sample` keep no log entry
+                                if !log.is_skipped() {
+                                    let limit = crate::channels::get_log_limit();
+                                    if sink_stats.logs.len() >= limit {
+                                        sink_stats.logs.pop_front();
+                                    }
+                                    sink_stats.logs.push_back(LogEntry::new(
+                                        sink_stats.items_sent,
+                                        crate::channels::timestamp_nanos(timestamp),
+                                        log.into_message(),
+                                        None,
+                                    ));
+                                }
+                            }
+                        }
+                        SinkEvent::ReadyPending { id } => {
+                            if let Some(sink_stats) = stats.get_mut(&id) {
+                                sink_stats.pending_ready_polls += 1;
+                            }
+                        }
+                        SinkEvent::Ready { id, wait_ns } => {
+                            if let Some(sink_stats) = stats.get_mut(&id) {
+                                sink_stats.backpressure_ns += wait_ns;
+                            }
+                        }
+                        SinkEvent::Flushed { id, flush_ns } => {
+                            if let Some(sink_stats) = stats.get_mut(&id) {
+                                sink_stats.flushes += 1;
+                                sink_stats.flush_max_ns = sink_stats.flush_max_ns.max(flush_ns);
+                                let _ = sink_stats
+                                    .flush
+                                    .record(flush_ns.clamp(SinkStats::LOW_NS, SinkStats::HIGH_NS));
+                            }
+                        }
+                        SinkEvent::Closed { id, close_ns } => {
+                            if let Some(sink_stats) = stats.get_mut(&id) {
+                                sink_stats.state = ChannelState::Closed;
+                                sink_stats.close_ns.get_or_insert(close_ns);
+                            }
+                        }
+                        SinkEvent::Flush { done } => {
+                            let _ = done.send(());
+                        }
+                    }
+                }
+            })
+            .expect("Failed to spawn sink-stats-collector thread");
+
+        crate::metrics_server::start_metrics_server_once(*METRICS_SERVER_PORT);
+
+        (tx, stats_map)
+    })
+}
+
+/// Trait for instrumenting sinks.
+///
+/// This trait is not intended for direct use. Use the `sink!` macro instead.
+#[doc(hidden)]
+pub trait InstrumentSink<Item> {
+    type Output;
+    fn instrument_sink(
+        self,
+        source: &'static str,
+        label: Option<String>,
+        log: Option<MessageLog<Item>>,
+    ) -> Self::Output;
+}
+
+// Implement InstrumentSink for all Sink types
+impl<S, Item> InstrumentSink<Item> for S
+where
+    S: futures_util::Sink<Item>,
+{
+    type Output = crate::sinks::wrapper::InstrumentedSink<S, Item>;
+
+    fn instrument_sink(
+        self,
+        source: &'static str,
+        label: Option<String>,
+        log: Option<MessageLog<Item>>,
+    ) -> Self::Output {
+        crate::sinks::wrapper::InstrumentedSink::new(self, source, label, log)
+    }
+}
+
+/// Instrument a sink to track items sent, backpressure, flushes and close.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use futures::SinkExt;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let mut sink = hotpath::sink!(futures::sink::drain::<u32>(), label = "writer", log = true);
+///
+///     sink.send(1).await.unwrap();
+///     sink.close().await.unwrap();
+/// }
+/// ```
+///
+/// Accepts the same `label`, `log`, `log_format`, `log_filter` and `log_sample` options
+/// as `stream!`.
+#[macro_export]
+macro_rules! sink {
+    // Internal rules for option combinations
+    (@opts $expr:expr, $id:ident, [$label:expr] [$log:tt] [$format:expr] [$filter:expr] [$sample:expr]) => {
+        $crate::InstrumentSink::instrument_sink(
+            $expr,
+            $id,
+            $label,
+            $crate::sink!(@log $log, $format, $filter, $sample),
+        )
+    };
+    (@opts $expr:expr, $id:ident, [$label:expr] [$log:tt] [$format:expr] [$filter:expr] [$sample:expr] label = $l:expr $(, $($rest:tt)*)?) => {
+        $crate::sink!(@opts $expr, $id, [Some($l.to_string())] [$log] [$format] [$filter] [$sample] $($($rest)*)?)
+    };
+    (@opts $expr:expr, $id:ident, [$label:expr] [$log:tt] [$format:expr] [$filter:expr] [$sample:expr] log = true $(, $($rest:tt)*)?) => {
+        $crate::sink!(@opts $expr, $id, [$label] [true] [$format] [$filter] [$sample] $($($rest)*)?)
+    };
+    (@opts $expr:expr, $id:ident, [$label:expr] [$log:tt] [$format:expr] [$filter:expr] [$sample:expr] log_format = $f:expr $(, $($rest:tt)*)?) => {
+        $crate::sink!(@opts $expr, $id, [$label] [true] [$f] [$filter] [$sample] $($($rest)*)?)
+    };
+    (@opts $expr:expr, $id:ident, [$label:expr] [$log:tt] [$format:expr] [$filter:expr] [$sample:expr] log_filter = $f:expr $(, $($rest:tt)*)?) => {
+        $crate::sink!(@opts $expr, $id, [$label] [true] [$format] [$f] [$sample] $($($rest)*)?)
+    };
+    (@opts $expr:expr, $id:ident, [$label:expr] [$log:tt] [$format:expr] [$filter:expr] [$sample:expr] log_sample = $n:expr $(, $($rest:tt)*)?) => {
+        $crate::sink!(@opts $expr, $id, [$label] [true] [$format] [$filter] [$n] $($($rest)*)?)
+    };
+    (@log false, $format:expr, $filter:expr, $sample:expr) => {
+        None
+    };
+    (@log true, $format:expr, $filter:expr, $sample:expr) => {
+        Some($crate::channels::MessageLog::new($format, $filter, $sample))
+    };
+
+    ($expr:expr) => {{
+        const SINK_ID: &'static str = concat!(file!(), ":", line!());
+        $crate::InstrumentSink::instrument_sink($expr, SINK_ID, None, None)
+    }};
+
+    ($expr:expr, $($rest:tt)+) => {{
+        const SINK_ID: &'static str = concat!(file!(), ":", line!());
+        $crate::sink!(@opts $expr, SINK_ID, [None] [false] [$crate::channels::inline::log_debug] [|_| true] [1] $($rest)+)
+    }};
+}
+
+fn get_all_sink_stats() -> HashMap<u64, SinkStats> {
+    if let Some((_, stats_map)) = SINKS_STATE.get() {
+        stats_map.read().unwrap().clone()
+    } else {
+        HashMap::new()
+    }
+}
+
+/// Compare two sink stats for sorting.
+/// Custom labels come first (sorted alphabetically), then auto-generated labels (sorted by source and iter).
+fn compare_sink_stats(a: &SinkStats, b: &SinkStats) -> std::cmp::Ordering {
+    match (&a.label, &b.label) {
+        (Some(_), None) => std::cmp::Ordering::Less,
+        (None, Some(_)) => std::cmp::Ordering::Greater,
+        (Some(a_label), Some(b_label)) => a_label.cmp(b_label).then_with(|| a.iter.cmp(&b.iter)),
+        (None, None) => a.source.cmp(b.source).then_with(|| a.iter.cmp(&b.iter)),
+    }
+}
+
+/// Waits until the stats thread has processed every event sent so far.
+pub(crate) fn flush_sink_events() {
+    let Some((tx, _)) = SINKS_STATE.get() else {
+        return;
+    };
+    let (done_tx, done_rx) = crossbeam_channel::bounded(1);
+    if tx.send(SinkEvent::Flush { done: done_tx }).is_ok() {
+        let _ = done_rx.recv_timeout(std::time::Duration::from_secs(1));
+    }
+}
+
+pub(crate) fn get_sorted_sink_stats() -> Vec<SinkStats> {
+    let mut stats: Vec<SinkStats> = get_all_sink_stats().into_values().collect();
+    stats.sort_by(compare_sink_stats);
+    stats
+}
+
+pub fn get_sinks_json() -> SinksJson {
+    let sinks = get_sorted_sink_stats()
+        .iter()
+        .map(SerializableSinkStats::from)
+        .collect();
+
+    let current_elapsed_ns = crate::channels::START_TIME
+        .get()
+        .map(|start| start.elapsed().as_nanos() as u64)
+        .unwrap_or(0);
+
+    SinksJson {
+        current_elapsed_ns,
+        sinks,
+    }
+}
+
+pub fn get_sink_logs(sink_id: &str) -> Option<SinkLogs> {
+    let id = sink_id.parse::<u64>().ok()?;
+    let stats = get_all_sink_stats();
+    stats.get(&id).map(|sink_stats| {
+        let mut sent_logs: Vec<LogEntry> = sink_stats.logs.iter().cloned().collect();
+
+        // Sort by index descending (most recent first)
+        sent_logs.sort_by_key(|b| std::cmp::Reverse(b.index));
+
+        SinkLogs {
+            id: sink_id.to_string(),
+            logs: sent_logs,
+        }
+    })
+}
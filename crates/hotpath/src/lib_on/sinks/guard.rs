@@ -0,0 +1,183 @@
+#[cfg(target_os = "linux")]
+use quanta::Instant;
+
+#[cfg(not(target_os = "linux"))]
+use std::time::Instant;
+
+use prettytable::{Cell, Row, Table};
+
+use crate::channels::resolve_label;
+use crate::output::format_duration;
+use crate::sinks::{flush_sink_events, get_sorted_sink_stats, SerializableSinkStats, SinksJson};
+use crate::Format;
+
+/// Builder for creating a SinksGuard with custom configuration.
+///
+/// # Examples
+///
+/// ```no_run
+/// use hotpath::sinks::{SinksGuardBuilder, Format};
+///
+/// let _guard = SinksGuardBuilder::new()
+///     .format(Format::JsonPretty)
+///     .build();
+/// // Statistics will be printed as pretty JSON when _guard is dropped
+/// ```
+#[must_use = "builder is discarded without creating a guard"]
+pub struct SinksGuardBuilder {
+    format: Format,
+}
+
+impl SinksGuardBuilder {
+    /// Create a new sinks guard builder.
+    pub fn new() -> Self {
+        Self {
+            format: Format::default(),
+        }
+    }
+
+    /// Set the output format for statistics.
+    pub fn format(mut self, format: Format) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Build and return the SinksGuard.
+    /// Statistics will be printed when the guard is dropped.
+    pub fn build(self) -> SinksGuard {
+        SinksGuard {
+            start_time: Instant::now(),
+            format: self.format,
+        }
+    }
+}
+
+impl Default for SinksGuardBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Guard for sink statistics collection.
+/// When dropped, prints a summary of all instrumented sinks and their statistics.
+///
+/// Use `SinksGuardBuilder` to create a guard with custom configuration.
+///
+/// # Examples
+///
+/// ```no_run
+/// use hotpath::sinks::SinksGuard;
+///
+/// let _guard = SinksGuard::new();
+/// // Your code with instrumented sinks here
+/// // Statistics will be printed when _guard is dropped
+/// ```
+#[must_use = "guard is dropped immediately without printing statistics"]
+pub struct SinksGuard {
+    start_time: Instant,
+    format: Format,
+}
+
+impl SinksGuard {
+    /// Create a new sinks guard with default settings (table format).
+    /// Statistics will be printed when this guard is dropped.
+    ///
+    /// For custom configuration, use `SinksGuardBuilder::new()` instead.
+    pub fn new() -> Self {
+        Self {
+            start_time: Instant::now(),
+            format: Format::default(),
+        }
+    }
+
+    /// Set the output format for statistics.
+    pub fn format(mut self, format: Format) -> Self {
+        self.format = format;
+        self
+    }
+}
+
+impl Default for SinksGuard {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for SinksGuard {
+    fn drop(&mut self) {
+        let elapsed = self.start_time.elapsed();
+        flush_sink_events();
+        let sinks = get_sorted_sink_stats();
+
+        if sinks.is_empty() {
+            println!("\nNo instrumented sinks found.");
+            return;
+        }
+
+        match self.format {
+            Format::Table => {
+                println!(
+                    "\n=== Sink Statistics (runtime: {:.2}s) ===",
+                    elapsed.as_secs_f64()
+                );
+
+                let mut table = Table::new();
+
+                table.add_row(Row::new(vec![
+                    Cell::new("Sink"),
+                    Cell::new("State"),
+                    Cell::new("Sent"),
+                    Cell::new("Backpressure"),
+                    Cell::new("Blocked"),
+                    Cell::new("Flushes"),
+                    Cell::new("Flush P95"),
+                    Cell::new("Flush Max"),
+                ]));
+
+                for sink_stats in &sinks {
+                    let label = resolve_label(
+                        sink_stats.source,
+                        sink_stats.label.as_deref(),
+                        Some(sink_stats.iter),
+                    );
+                    let duration = |ns: Option<u64>| ns.map_or("-".to_string(), format_duration);
+                    table.add_row(Row::new(vec![
+                        Cell::new(&label),
+                        Cell::new(sink_stats.state.as_str()),
+                        Cell::new(&sink_stats.items_sent.to_string()),
+                        Cell::new(&sink_stats.pending_ready_polls.to_string()),
+                        Cell::new(&format_duration(sink_stats.backpressure_ns)),
+                        Cell::new(&sink_stats.flushes.to_string()),
+                        Cell::new(&duration(sink_stats.flush_percentile(95.0))),
+                        Cell::new(&duration(
+                            (sink_stats.flushes > 0).then_some(sink_stats.flush_max_ns),
+                        )),
+                    ]));
+                }
+
+                println!("\nSinks:");
+                table.printstd();
+            }
+            Format::Json => {
+                let sinks_json = SinksJson {
+                    current_elapsed_ns: elapsed.as_nanos() as u64,
+                    sinks: sinks.iter().map(SerializableSinkStats::from).collect(),
+                };
+                match serde_json::to_string(&sinks_json) {
+                    Ok(json) => println!("{}", json),
+                    Err(e) => eprintln!("Failed to serialize statistics to JSON: {}", e),
+                }
+            }
+            Format::JsonPretty => {
+                let sinks_json = SinksJson {
+                    current_elapsed_ns: elapsed.as_nanos() as u64,
+                    sinks: sinks.iter().map(SerializableSinkStats::from).collect(),
+                };
+                match serde_json::to_string_pretty(&sinks_json) {
+                    Ok(json) => println!("{}", json),
+                    Err(e) => eprintln!("Failed to serialize statistics to pretty JSON: {}", e),
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,151 @@
+use crate::channels::{LogRecord, MessageLog};
+use crate::sinks::{init_sinks_state, SinkEvent, SINK_ID_COUNTER};
+use crossbeam_channel::Sender as CbSender;
+use futures_util::Sink;
+use pin_project_lite::pin_project;
+use std::marker::PhantomData;
+use std::pin::Pin;
+use std::sync::atomic::Ordering;
+use std::task::{Context, Poll};
+
+#[cfg(target_os = "linux")]
+use quanta::Instant;
+
+#[cfg(not(target_os = "linux"))]
+use std::time::Instant;
+
+pin_project! {
+    /// Wrapper around a `Sink` that instruments it with statistics collection.
+    ///
+    /// This struct implements the `Sink` trait and forwards all calls to the inner sink
+    /// while recording sent items, backpressure, flushes and close.
+    pub struct InstrumentedSink<S, Item> {
+        #[pin]
+        inner: S,
+        stats_tx: CbSender<SinkEvent>,
+        id: u64,
+        log: Option<MessageLog<Item>>,
+        // Start of the current wait for readiness, flush and close, cleared once it completes.
+        // Any other operation clears the readiness wait, it belongs to an abandoned send.
+        ready_wait_start: Option<Instant>,
+        flush_start: Option<Instant>,
+        close_start: Option<Instant>,
+        _item: PhantomData<fn(Item)>,
+    }
+}
+
+impl<S, Item> InstrumentedSink<S, Item>
+where
+    S: Sink<Item>,
+{
+    /// Create a new instrumented sink wrapper.
+    ///
+    /// # Parameters
+    /// - `sink`: The underlying sink to instrument
+    /// - `source`: Source location (file:line) for identification
+    /// - `label`: Optional custom label
+    /// - `log`: Optional formatting, filtering and sampling of logged items
+    pub(crate) fn new(
+        sink: S,
+        source: &'static str,
+        label: Option<String>,
+        log: Option<MessageLog<Item>>,
+    ) -> Self {
+        let (stats_tx, _) = init_sinks_state();
+        let id = SINK_ID_COUNTER.fetch_add(1, Ordering::Relaxed);
+
+        // Send sink creation event
+        let _ = stats_tx.send(SinkEvent::Created {
+            id,
+            source,
+            display_label: label,
+            type_name: std::any::type_name::<Item>(),
+            type_size: std::mem::size_of::<Item>(),
+        });
+
+        Self {
+            inner: sink,
+            stats_tx: stats_tx.clone(),
+            id,
+            log,
+            ready_wait_start: None,
+            flush_start: None,
+            close_start: None,
+            _item: PhantomData,
+        }
+    }
+}
+
+impl<S, Item> Sink<Item> for InstrumentedSink<S, Item>
+where
+    S: Sink<Item>,
+{
+    type Error = S::Error;
+
+    fn poll_ready(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        let this = self.project();
+
+        let poll = this.inner.poll_ready(cx);
+        match poll {
+            Poll::Pending => {
+                this.ready_wait_start.get_or_insert_with(Instant::now);
+                let _ = this.stats_tx.send(SinkEvent::ReadyPending { id: *this.id });
+            }
+            Poll::Ready(_) => {
+                if let Some(start) = this.ready_wait_start.take() {
+                    let _ = this.stats_tx.send(SinkEvent::Ready {
+                        id: *this.id,
+                        wait_ns: start.elapsed().as_nanos() as u64,
+                    });
+                }
+            }
+        }
+        poll
+    }
+
+    fn start_send(self: Pin<&mut Self>, item: Item) -> Result<(), Self::Error> {
+        let this = self.project();
+        *this.ready_wait_start = None;
+
+        let log = LogRecord::capture(this.log.as_ref(), &item);
+        this.inner.start_send(item)?;
+        let _ = this.stats_tx.send(SinkEvent::Sent {
+            id: *this.id,
+            log,
+            timestamp: Instant::now(),
+        });
+        Ok(())
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        let this = self.project();
+        *this.ready_wait_start = None;
+
+        let start = *this.flush_start.get_or_insert_with(Instant::now);
+        let poll = this.inner.poll_flush(cx);
+        if poll.is_ready() {
+            *this.flush_start = None;
+            let _ = this.stats_tx.send(SinkEvent::Flushed {
+                id: *this.id,
+                flush_ns: start.elapsed().as_nanos() as u64,
+            });
+        }
+        poll
+    }
+
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        let this = self.project();
+        *this.ready_wait_start = None;
+
+        let start = *this.close_start.get_or_insert_with(Instant::now);
+        let poll = this.inner.poll_close(cx);
+        if poll.is_ready() {
+            *this.close_start = None;
+            let _ = this.stats_tx.send(SinkEvent::Closed {
+                id: *this.id,
+                close_ns: start.elapsed().as_nanos() as u64,
+            });
+        }
+        poll
+    }
+}
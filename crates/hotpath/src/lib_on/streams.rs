@@ -1,6 +1,7 @@
 //! Stream instrumentation module - tracks items yielded and stream lifecycle.
 
 use crossbeam_channel::{unbounded, Sender as CbSender};
+use hdrhistogram::Histogram;
 use std::collections::{HashMap, VecDeque};
 use std::sync::atomic::AtomicU64;
 use std::sync::{Arc, OnceLock, RwLock};
@@ -16,6 +17,7 @@ pub use guard::{StreamsGuard, StreamsGuardBuilder};
 
 pub(crate) mod wrapper;
 
+use crate::channels::{LogRecord, MessageLog};
 pub use crate::json::{ChannelState, LogEntry, SerializableStreamStats, StreamLogs, StreamsJson};
 use crate::metrics_server::METRICS_SERVER_PORT;
 pub use crate::Format;
@@ -30,8 +32,21 @@ pub(crate) struct StreamStats {
     pub(crate) items_yielded: u64,
     pub(crate) type_name: &'static str,
     pub(crate) type_size: usize,
+    /// Sum of measured item sizes, only tracked with `stream!(..., measure_size = true)`
+    pub(crate) yielded_bytes: Option<u64>,
     pub(crate) logs: VecDeque<LogEntry>,
     pub(crate) iter: u32,
+    pub(crate) created_ns: u64,
+    pub(crate) last_item_ns: Option<u64>,
+    /// Time between consecutive items
+    pub(crate) inter_item: Histogram<u64>,
+    pub(crate) inter_item_max_ns: u64,
+    /// Polls that returned `Poll::Pending`
+    pub(crate) pending_polls: u64,
+    /// Time spent inside the inner stream's `poll_next`, summed over all polls
+    pub(crate) busy_ns: u64,
+    pub(crate) time_to_first_item_ns: Option<u64>,
+    pub(crate) time_to_completion_ns: Option<u64>,
 }
 
 impl From<&StreamStats> for SerializableStreamStats {
@@ -51,12 +66,25 @@ impl From<&StreamStats> for SerializableStreamStats {
             items_yielded: stream_stats.items_yielded,
             type_name: stream_stats.type_name.to_string(),
             type_size: stream_stats.type_size,
+            yielded_bytes: stream_stats.yielded_bytes,
             iter: stream_stats.iter,
+            inter_item_p50_ns: stream_stats.inter_item_percentile(50.0),
+            inter_item_p95_ns: stream_stats.inter_item_percentile(95.0),
+            inter_item_p99_ns: stream_stats.inter_item_percentile(99.0),
+            inter_item_max_ns: stream_stats.inter_item_max_ns,
+            pending_polls: stream_stats.pending_polls,
+            busy_ns: stream_stats.busy_ns,
+            time_to_first_item_ns: stream_stats.time_to_first_item_ns,
+            time_to_completion_ns: stream_stats.time_to_completion_ns,
         }
     }
 }
 
 impl StreamStats {
+    const LOW_NS: u64 = 1;
+    const HIGH_NS: u64 = 3_600_000_000_000; // 1h
+    const SIGFIGS: u8 = 2;
+
     fn new(
         id: u64,
         source: &'static str,
@@ -64,6 +92,7 @@ impl StreamStats {
         type_name: &'static str,
         type_size: usize,
         iter: u32,
+        created_ns: u64,
     ) -> Self {
         Self {
             id,
@@ -73,9 +102,44 @@ impl StreamStats {
             items_yielded: 0,
             type_name,
             type_size,
+            yielded_bytes: None,
             logs: VecDeque::new(),
             iter,
+            created_ns,
+            last_item_ns: None,
+            inter_item: Histogram::<u64>::new_with_bounds(
+                Self::LOW_NS,
+                Self::HIGH_NS,
+                Self::SIGFIGS,
+            )
+            .expect("hdrhistogram init"),
+            inter_item_max_ns: 0,
+            pending_polls: 0,
+            busy_ns: 0,
+            time_to_first_item_ns: None,
+            time_to_completion_ns: None,
+        }
+    }
+
+    /// Records the arrival of an item, timing it against the previous one.
+    fn record_item(&mut self, timestamp_ns: u64) {
+        match self.last_item_ns {
+            Some(last_item_ns) => {
+                let gap_ns = timestamp_ns.saturating_sub(last_item_ns);
+                self.inter_item_max_ns = self.inter_item_max_ns.max(gap_ns);
+                let _ = self
+                    .inter_item
+                    .record(gap_ns.clamp(Self::LOW_NS, Self::HIGH_NS));
+            }
+            None => {
+                self.time_to_first_item_ns = Some(timestamp_ns.saturating_sub(self.created_ns));
+            }
         }
+        self.last_item_ns = Some(timestamp_ns);
+    }
+
+    pub(crate) fn inter_item_percentile(&self, p: f64) -> Option<u64> {
+        crate::channels::percentile(&self.inter_item, self.inter_item_max_ns, p)
     }
 }
 
@@ -88,14 +152,25 @@ pub(crate) enum StreamEvent {
         display_label: Option<String>,
         type_name: &'static str,
         type_size: usize,
+        timestamp: Instant,
     },
     Yielded {
         id: u64,
-        log: Option<String>,
+        log: LogRecord,
+        /// Measured item size in bytes, see `stream!(..., measure_size = true)`
+        size: Option<usize>,
+        /// Time spent inside the inner stream's `poll_next`
+        poll_ns: u64,
         timestamp: Instant,
     },
+    Pending {
+        id: u64,
+        poll_ns: u64,
+    },
     Completed {
         id: u64,
+        poll_ns: u64,
+        timestamp: Instant,
     },
 }
 
@@ -130,6 +205,7 @@ pub(crate) fn init_streams_state() -> &'static StreamStatsState {
                             display_label,
                             type_name,
                             type_size,
+                            timestamp,
                         } => {
                             // Count existing items with the same source location
                             let iter = stats.values().filter(|s| s.source == source).count() as u32;
@@ -143,28 +219,62 @@ pub(crate) fn init_streams_state() -> &'static StreamStatsState {
                                     type_name,
                                     type_size,
                                     iter,
+                                    crate::channels::timestamp_nanos(timestamp),
                                 ),
                             );
                         }
-                        StreamEvent::Yielded { id, log, timestamp } => {
+                        StreamEvent::Yielded {
+                            id,
+                            log,
+                            size,
+                            poll_ns,
+                            timestamp,
+                        } => {
                             if let Some(stream_stats) = stats.get_mut(&id) {
                                 stream_stats.items_yielded += 1;
+                                stream_stats.busy_ns += poll_ns;
+                                stream_stats
+                                    .record_item(crate::channels::timestamp_nanos(timestamp));
+                                if let Some(size) = size {
+                                    *stream_stats.yielded_bytes.get_or_insert(0) += size as u64;
+                                }
 
-                                let limit = crate::channels::get_log_limit();
-                                if stream_stats.logs.len() >= limit {
-                                    stream_stats.logs.pop_front();
+                                // Items dropped by `log_filter` or `log_sample` keep no log entry
+                                if !log.is_skipped() {
+                                    let limit = crate::channels::get_log_limit();
+                                    if stream_stats.logs.len() >= limit {
+                                        stream_stats.logs.pop_front();
+                                    }
+                                    stream_stats.logs.push_back(
+                                        LogEntry::new(
+                                            stream_stats.items_yielded,
+                                            crate::channels::timestamp_nanos(timestamp),
+                                            log.into_message(),
+                                            None,
+                                        )
+                                        .with_size(size),
+                                    );
                                 }
-                                stream_stats.logs.push_back(LogEntry::new(
-                                    stream_stats.items_yielded,
-                                    crate::channels::timestamp_nanos(timestamp),
-                                    log,
-                                    None,
-                                ));
                             }
                         }
-                        StreamEvent::Completed { id } => {
+                        StreamEvent::Pending { id, poll_ns } => {
+                            if let Some(stream_stats) = stats.get_mut(&id) {
+                                stream_stats.pending_polls += 1;
+                                stream_stats.busy_ns += poll_ns;
+                            }
+                        }
+                        StreamEvent::Completed {
+                            id,
+                            poll_ns,
+                            timestamp,
+                        } => {
                             if let Some(stream_stats) = stats.get_mut(&id) {
                                 stream_stats.state = ChannelState::Closed;
+                                stream_stats.busy_ns += poll_ns;
+                                stream_stats.time_to_completion_ns.get_or_insert(
+                                    crate::channels::timestamp_nanos(timestamp)
+                                        .saturating_sub(stream_stats.created_ns),
+                                );
                             }
                         }
                     }
@@ -196,6 +306,23 @@ pub trait InstrumentStreamLog {
     fn instrument_stream_log(self, source: &'static str, label: Option<String>) -> Self::Output;
 }
 
+/// Trait for instrumenting streams with item size measurement or custom logging.
+///
+/// This trait is not intended for direct use. Use the `stream!` macro with `measure_size = true`
+/// or the `log_filter`, `log_format` and `log_sample` options instead.
+#[doc(hidden)]
+pub trait InstrumentStreamOptions {
+    type Item;
+    type Output;
+    fn instrument_stream_with_options(
+        self,
+        source: &'static str,
+        label: Option<String>,
+        log: Option<MessageLog<Self::Item>>,
+        size: Option<fn(&Self::Item) -> usize>,
+    ) -> Self::Output;
+}
+
 // Implement InstrumentStream for all Stream types
 impl<S> InstrumentStream for S
 where
@@ -221,6 +348,25 @@ where
     }
 }
 
+// Implement InstrumentStreamOptions for all Stream types
+impl<S> InstrumentStreamOptions for S
+where
+    S: futures_util::Stream,
+{
+    type Item = S::Item;
+    type Output = crate::streams::wrapper::InstrumentedStreamWithOptions<S, S::Item>;
+
+    fn instrument_stream_with_options(
+        self,
+        source: &'static str,
+        label: Option<String>,
+        log: Option<MessageLog<S::Item>>,
+        size: Option<fn(&S::Item) -> usize>,
+    ) -> Self::Output {
+        crate::streams::wrapper::InstrumentedStreamWithOptions::new(self, source, label, log, size)
+    }
+}
+
 /// Instrument a stream to track its item yields.
 ///
 /// # Examples
@@ -245,6 +391,47 @@ where
 /// See the `stream!` macro documentation for full usage details.
 #[macro_export]
 macro_rules! stream {
+    // Internal rules for option combinations including `measure_size = true` or `log_*`
+    (@opts $expr:expr, $id:ident, [$label:expr] [$log:tt] [$size:tt] [$format:expr] [$filter:expr] [$sample:expr]) => {
+        $crate::InstrumentStreamOptions::instrument_stream_with_options(
+            $expr,
+            $id,
+            $label,
+            $crate::stream!(@log $log, $format, $filter, $sample),
+            $crate::stream!(@size $size),
+        )
+    };
+    (@opts $expr:expr, $id:ident, [$label:expr] [$log:tt] [$size:tt] [$format:expr] [$filter:expr] [$sample:expr] label = $l:expr $(, $($rest:tt)*)?) => {
+        $crate::stream!(@opts $expr, $id, [Some($l.to_string())] [$log] [$size] [$format] [$filter] [$sample] $($($rest)*)?)
+    };
+    (@opts $expr:expr, $id:ident, [$label:expr] [$log:tt] [$size:tt] [$format:expr] [$filter:expr] [$sample:expr] log = true $(, $($rest:tt)*)?) => {
+        $crate::stream!(@opts $expr, $id, [$label] [true] [$size] [$format] [$filter] [$sample] $($($rest)*)?)
+    };
+    (@opts $expr:expr, $id:ident, [$label:expr] [$log:tt] [$size:tt] [$format:expr] [$filter:expr] [$sample:expr] log_format = $f:expr $(, $($rest:tt)*)?) => {
+        $crate::stream!(@opts $expr, $id, [$label] [true] [$size] [$f] [$filter] [$sample] $($($rest)*)?)
+    };
+    (@opts $expr:expr, $id:ident, [$label:expr] [$log:tt] [$size:tt] [$format:expr] [$filter:expr] [$sample:expr] log_filter = $f:expr $(, $($rest:tt)*)?) => {
+        $crate::stream!(@opts $expr, $id, [$label] [true] [$size] [$format] [$f] [$sample] $($($rest)*)?)
+    };
+    (@opts $expr:expr, $id:ident, [$label:expr] [$log:tt] [$size:tt] [$format:expr] [$filter:expr] [$sample:expr] log_sample = $n:expr $(, $($rest:tt)*)?) => {
+        $crate::stream!(@opts $expr, $id, [$label] [true] [$size] [$format] [$filter] [$n] $($($rest)*)?)
+    };
+    (@opts $expr:expr, $id:ident, [$label:expr] [$log:tt] [$size:tt] [$format:expr] [$filter:expr] [$sample:expr] measure_size = true $(, $($rest:tt)*)?) => {
+        $crate::stream!(@opts $expr, $id, [$label] [$log] [true] [$format] [$filter] [$sample] $($($rest)*)?)
+    };
+    (@log false, $format:expr, $filter:expr, $sample:expr) => {
+        None
+    };
+    (@log true, $format:expr, $filter:expr, $sample:expr) => {
+        Some($crate::channels::MessageLog::new($format, $filter, $sample))
+    };
+    (@size false) => {
+        None
+    };
+    (@size true) => {
+        Some($crate::heap_size_of)
+    };
+
     ($expr:expr) => {{
         const STREAM_ID: &'static str = concat!(file!(), ":", line!());
         $crate::InstrumentStream::instrument_stream($expr, STREAM_ID, None)
@@ -277,6 +464,12 @@ macro_rules! stream {
             Some($label.to_string()),
         )
     }};
+
+    // Any other combination of options, including `measure_size` and `log_*`
+    ($expr:expr, $($rest:tt)+) => {{
+        const STREAM_ID: &'static str = concat!(file!(), ":", line!());
+        $crate::stream!(@opts $expr, STREAM_ID, [None] [false] [false] [$crate::channels::inline::log_debug] [|_| true] [1] $($rest)+)
+    }};
 }
 
 fn get_all_stream_stats() -> HashMap<u64, StreamStats> {
@@ -337,7 +530,7 @@ pub fn get_stream_logs(stream_id: &str) -> Option<StreamLogs> {
         let mut yielded_logs: Vec<LogEntry> = stream_stats.logs.iter().cloned().collect();
 
         // Sort by index descending (most recent first)
-        yielded_logs.sort_by(|a, b| b.index.cmp(&a.index));
+        yielded_logs.sort_by_key(|b| std::cmp::Reverse(b.index));
 
         StreamLogs {
             id: stream_id.to_string(),
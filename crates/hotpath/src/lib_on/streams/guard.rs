@@ -7,6 +7,7 @@ use std::time::Instant;
 use prettytable::{Cell, Row, Table};
 
 use crate::channels::resolve_label;
+use crate::output::format_duration;
 use crate::streams::{get_sorted_stream_stats, SerializableStreamStats, StreamsJson};
 use crate::Format;
 
@@ -144,6 +145,11 @@ impl Drop for StreamsGuard {
                     Cell::new("Stream"),
                     Cell::new("State"),
                     Cell::new("Yielded"),
+                    Cell::new("Pending"),
+                    Cell::new("Busy"),
+                    Cell::new("Item P95"),
+                    Cell::new("First Item"),
+                    Cell::new("Completion"),
                 ]));
 
                 for stream_stats in streams {
@@ -152,10 +158,27 @@ impl Drop for StreamsGuard {
                         stream_stats.label.as_deref(),
                         Some(stream_stats.iter),
                     );
+                    let item_p95 = stream_stats
+                        .inter_item_percentile(95.0)
+                        .map(format_duration)
+                        .unwrap_or_else(|| "-".to_string());
+                    let first_item = stream_stats
+                        .time_to_first_item_ns
+                        .map(format_duration)
+                        .unwrap_or_else(|| "-".to_string());
+                    let completion = stream_stats
+                        .time_to_completion_ns
+                        .map(format_duration)
+                        .unwrap_or_else(|| "-".to_string());
                     table.add_row(Row::new(vec![
                         Cell::new(&label),
                         Cell::new(stream_stats.state.as_str()),
                         Cell::new(&stream_stats.items_yielded.to_string()),
+                        Cell::new(&stream_stats.pending_polls.to_string()),
+                        Cell::new(&format_duration(stream_stats.busy_ns)),
+                        Cell::new(&item_p95),
+                        Cell::new(&first_item),
+                        Cell::new(&completion),
                     ]));
                 }
 
@@ -1,4 +1,4 @@
-use crate::output::truncate_result;
+use crate::channels::{LogRecord, MessageLog};
 use crate::streams::{init_streams_state, StreamEvent, STREAM_ID_COUNTER};
 use crossbeam_channel::Sender as CbSender;
 use futures_util::Stream;
@@ -47,6 +47,7 @@ impl<S> InstrumentedStream<S> {
             display_label: label,
             type_name: std::any::type_name::<S::Item>(),
             type_size: std::mem::size_of::<S::Item>(),
+            timestamp: Instant::now(),
         });
 
         Self {
@@ -63,20 +64,37 @@ impl<S: Stream> Stream for InstrumentedStream<S> {
     fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
         let this = self.project();
 
-        match this.inner.poll_next(cx) {
+        let start = Instant::now();
+        let poll = this.inner.poll_next(cx);
+        let end = Instant::now();
+        let poll_ns = end.duration_since(start).as_nanos() as u64;
+
+        match poll {
             Poll::Ready(Some(item)) => {
                 let _ = this.stats_tx.send(StreamEvent::Yielded {
                     id: *this.id,
-                    log: None,
-                    timestamp: Instant::now(),
+                    log: LogRecord::Off,
+                    size: None,
+                    poll_ns,
+                    timestamp: end,
                 });
                 Poll::Ready(Some(item))
             }
             Poll::Ready(None) => {
-                let _ = this.stats_tx.send(StreamEvent::Completed { id: *this.id });
+                let _ = this.stats_tx.send(StreamEvent::Completed {
+                    id: *this.id,
+                    poll_ns,
+                    timestamp: end,
+                });
                 Poll::Ready(None)
             }
-            Poll::Pending => Poll::Pending,
+            Poll::Pending => {
+                let _ = this.stats_tx.send(StreamEvent::Pending {
+                    id: *this.id,
+                    poll_ns,
+                });
+                Poll::Pending
+            }
         }
     }
 }
@@ -109,6 +127,7 @@ impl<S> InstrumentedStreamLog<S> {
             display_label: label,
             type_name: std::any::type_name::<S::Item>(),
             type_size: std::mem::size_of::<S::Item>(),
+            timestamp: Instant::now(),
         });
 
         Self {
@@ -128,21 +147,124 @@ where
     fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
         let this = self.project();
 
-        match this.inner.poll_next(cx) {
+        let start = Instant::now();
+        let poll = this.inner.poll_next(cx);
+        let end = Instant::now();
+        let poll_ns = end.duration_since(start).as_nanos() as u64;
+
+        match poll {
             Poll::Ready(Some(item)) => {
-                let log_msg = truncate_result(format!("{:?}", item));
                 let _ = this.stats_tx.send(StreamEvent::Yielded {
                     id: *this.id,
-                    log: Some(log_msg),
-                    timestamp: Instant::now(),
+                    log: LogRecord::Message(format!("{:?}", item)),
+                    size: None,
+                    poll_ns,
+                    timestamp: end,
                 });
                 Poll::Ready(Some(item))
             }
             Poll::Ready(None) => {
-                let _ = this.stats_tx.send(StreamEvent::Completed { id: *this.id });
+                let _ = this.stats_tx.send(StreamEvent::Completed {
+                    id: *this.id,
+                    poll_ns,
+                    timestamp: end,
+                });
                 Poll::Ready(None)
             }
-            Poll::Pending => Poll::Pending,
+            Poll::Pending => {
+                let _ = this.stats_tx.send(StreamEvent::Pending {
+                    id: *this.id,
+                    poll_ns,
+                });
+                Poll::Pending
+            }
+        }
+    }
+}
+
+pin_project! {
+    /// Wrapper around a `Stream` with item size measurement or custom logging.
+    ///
+    /// Created by `stream!` with `measure_size = true` or any of the `log_*` options.
+    pub struct InstrumentedStreamWithOptions<S, T> {
+        #[pin]
+        inner: S,
+        stats_tx: CbSender<StreamEvent>,
+        id: u64,
+        log: Option<MessageLog<T>>,
+        size: Option<fn(&T) -> usize>,
+    }
+}
+
+impl<S: Stream> InstrumentedStreamWithOptions<S, S::Item> {
+    /// Create a new instrumented stream wrapper with the given logging and measurement.
+    pub(crate) fn new(
+        stream: S,
+        source: &'static str,
+        label: Option<String>,
+        log: Option<MessageLog<S::Item>>,
+        size: Option<fn(&S::Item) -> usize>,
+    ) -> Self {
+        let (stats_tx, _) = init_streams_state();
+        let id = STREAM_ID_COUNTER.fetch_add(1, Ordering::Relaxed);
+
+        // Send stream creation event
+        let _ = stats_tx.send(StreamEvent::Created {
+            id,
+            source,
+            display_label: label,
+            type_name: std::any::type_name::<S::Item>(),
+            type_size: std::mem::size_of::<S::Item>(),
+            timestamp: Instant::now(),
+        });
+
+        Self {
+            inner: stream,
+            stats_tx: stats_tx.clone(),
+            id,
+            log,
+            size,
+        }
+    }
+}
+
+impl<S: Stream> Stream for InstrumentedStreamWithOptions<S, S::Item> {
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+
+        let start = Instant::now();
+        let poll = this.inner.poll_next(cx);
+        let end = Instant::now();
+        let poll_ns = end.duration_since(start).as_nanos() as u64;
+
+        match poll {
+            Poll::Ready(Some(item)) => {
+                let _ = this.stats_tx.send(StreamEvent::Yielded {
+                    id: *this.id,
+                    log: LogRecord::capture(this.log.as_ref(), &item),
+                    size: this.size.map(|size| size(&item)),
+                    poll_ns,
+                    timestamp: end,
+                });
+                Poll::Ready(Some(item))
+            }
+            Poll::Ready(None) => {
+                let _ = this.stats_tx.send(StreamEvent::Completed {
+                    id: *this.id,
+                    poll_ns,
+                    timestamp: end,
+                });
+                Poll::Ready(None)
+            }
+            Poll::Pending => {
+                let _ = this.stats_tx.send(StreamEvent::Pending {
+                    id: *this.id,
+                    poll_ns,
+                });
+                Poll::Pending
+            }
         }
     }
 }
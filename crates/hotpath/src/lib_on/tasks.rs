@@ -0,0 +1,468 @@
+//! Task instrumentation module - registers spawned tasks with their spawn location, parent task,
+//! poll statistics and outcome.
+
+use crate::channels::{get_log_limit, resolve_label, START_TIME};
+use crate::metrics_server::METRICS_SERVER_PORT;
+use crossbeam_channel::{unbounded, Sender as CbSender};
+use pin_project_lite::pin_project;
+use std::any::Any;
+use std::cell::Cell;
+use std::collections::{HashMap, VecDeque};
+use std::future::Future;
+use std::panic::{catch_unwind, resume_unwind, AssertUnwindSafe};
+use std::pin::Pin;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, OnceLock, RwLock};
+use std::task::{Context, Poll};
+
+#[cfg(target_os = "linux")]
+use quanta::Instant;
+
+#[cfg(not(target_os = "linux"))]
+use std::time::Instant;
+
+pub use crate::json::{SerializableTaskStats, TaskKind, TaskState, TasksJson};
+
+static TASK_ID_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+thread_local! {
+    /// Instrumented task running on this thread, recorded as the parent of tasks it spawns
+    static CURRENT_TASK: Cell<Option<u64>> = const { Cell::new(None) };
+}
+
+/// Registry entry of a single spawned task.
+#[derive(Debug, Clone)]
+pub(crate) struct TaskStats {
+    pub(crate) id: u64,
+    pub(crate) source: &'static str,
+    pub(crate) label: Option<String>,
+    pub(crate) kind: TaskKind,
+    pub(crate) parent_id: Option<u64>,
+    pub(crate) state: TaskState,
+    pub(crate) spawned_at_ns: u64,
+    pub(crate) finished_at_ns: Option<u64>,
+    pub(crate) poll_count: u64,
+    pub(crate) busy_ns: u64,
+    pub(crate) max_poll_ns: u64,
+    pub(crate) panic_message: Option<String>,
+}
+
+impl From<&TaskStats> for SerializableTaskStats {
+    fn from(task: &TaskStats) -> Self {
+        Self {
+            id: task.id,
+            source: task.source.to_string(),
+            label: resolve_label(task.source, task.label.as_deref(), None),
+            has_custom_label: task.label.is_some(),
+            kind: task.kind,
+            parent_id: task.parent_id,
+            state: task.state,
+            spawned_at_ns: task.spawned_at_ns,
+            finished_at_ns: task.finished_at_ns,
+            poll_count: task.poll_count,
+            busy_ns: task.busy_ns,
+            max_poll_ns: task.max_poll_ns,
+            panic_message: task.panic_message.clone(),
+        }
+    }
+}
+
+/// Events sent to the background task registry thread.
+#[derive(Debug)]
+pub(crate) enum TaskEvent {
+    Spawned {
+        id: u64,
+        source: &'static str,
+        label: Option<String>,
+        kind: TaskKind,
+        parent_id: Option<u64>,
+        spawned_at_ns: u64,
+    },
+    PollStarted {
+        id: u64,
+    },
+    Polled {
+        id: u64,
+        poll_ns: u64,
+    },
+    Finished {
+        id: u64,
+        state: TaskState,
+        finished_at_ns: u64,
+        panic_message: Option<String>,
+    },
+}
+
+/// Live tasks and the most recently finished ones.
+#[derive(Debug, Default)]
+pub(crate) struct TaskRegistry {
+    tasks: HashMap<u64, TaskStats>,
+    /// Finished task IDs, oldest first
+    finished: VecDeque<u64>,
+}
+
+impl TaskRegistry {
+    fn process_event(&mut self, event: TaskEvent) {
+        match event {
+            TaskEvent::Spawned {
+                id,
+                source,
+                label,
+                kind,
+                parent_id,
+                spawned_at_ns,
+            } => {
+                self.tasks.insert(
+                    id,
+                    TaskStats {
+                        id,
+                        source,
+                        label,
+                        kind,
+                        parent_id,
+                        state: TaskState::Idle,
+                        spawned_at_ns,
+                        finished_at_ns: None,
+                        poll_count: 0,
+                        busy_ns: 0,
+                        max_poll_ns: 0,
+                        panic_message: None,
+                    },
+                );
+            }
+            TaskEvent::PollStarted { id } => {
+                if let Some(task) = self.tasks.get_mut(&id) {
+                    task.state = TaskState::Running;
+                }
+            }
+            TaskEvent::Polled { id, poll_ns } => {
+                if let Some(task) = self.tasks.get_mut(&id) {
+                    task.poll_count += 1;
+                    task.busy_ns += poll_ns;
+                    task.max_poll_ns = task.max_poll_ns.max(poll_ns);
+                    if task.state == TaskState::Running {
+                        task.state = TaskState::Idle;
+                    }
+                }
+            }
+            TaskEvent::Finished {
+                id,
+                state,
+                finished_at_ns,
+                panic_message,
+            } => {
+                if let Some(task) = self.tasks.get_mut(&id) {
+                    task.state = state;
+                    task.finished_at_ns = Some(finished_at_ns);
+                    task.panic_message = panic_message.map(crate::channels::redact_log);
+                    self.finished.push_back(id);
+                    if self.finished.len() > get_log_limit() {
+                        if let Some(evicted) = self.finished.pop_front() {
+                            self.tasks.remove(&evicted);
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+type TasksStatsState = (CbSender<TaskEvent>, Arc<RwLock<TaskRegistry>>);
+
+static TASKS_STATE: OnceLock<TasksStatsState> = OnceLock::new();
+
+/// Initialize the task registry (called on the first instrumented spawn).
+fn init_tasks_state() -> &'static TasksStatsState {
+    TASKS_STATE.get_or_init(|| {
+        START_TIME.get_or_init(Instant::now);
+
+        crate::metrics_server::start_metrics_server_once(*METRICS_SERVER_PORT);
+
+        let (event_tx, event_rx) = unbounded::<TaskEvent>();
+        let registry = Arc::new(RwLock::new(TaskRegistry::default()));
+        let registry_clone = Arc::clone(&registry);
+
+        std::thread::Builder::new()
+            .name("hp-tasks".into())
+            .spawn(move || {
+                while let Ok(event) = event_rx.recv() {
+                    registry_clone.write().unwrap().process_event(event);
+                }
+            })
+            .expect("Failed to spawn task registry thread");
+
+        (event_tx, registry)
+    })
+}
+
+fn send_task_event(event: TaskEvent) {
+    if let Some((tx, _)) = TASKS_STATE.get() {
+        let _ = tx.send(event);
+    }
+}
+
+fn elapsed_ns() -> u64 {
+    START_TIME
+        .get()
+        .map(|t| t.elapsed().as_nanos() as u64)
+        .unwrap_or(0)
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "Box<dyn Any>".to_string()
+    }
+}
+
+/// Registration of a single spawned task, reported as aborted if dropped before finishing.
+#[derive(Debug)]
+struct TaskHandle {
+    id: u64,
+    finished: bool,
+}
+
+impl TaskHandle {
+    fn register(source: &'static str, label: Option<String>, kind: TaskKind) -> Self {
+        init_tasks_state();
+        let id = TASK_ID_COUNTER.fetch_add(1, Ordering::Relaxed);
+
+        send_task_event(TaskEvent::Spawned {
+            id,
+            source,
+            label,
+            kind,
+            parent_id: CURRENT_TASK.with(Cell::get),
+            spawned_at_ns: elapsed_ns(),
+        });
+
+        Self {
+            id,
+            finished: false,
+        }
+    }
+
+    /// Runs a poll or blocking closure of this task. A panic is recorded before it is resumed.
+    fn run<R>(&mut self, f: impl FnOnce() -> R) -> R {
+        send_task_event(TaskEvent::PollStarted { id: self.id });
+
+        let parent = CURRENT_TASK.with(|current| current.replace(Some(self.id)));
+        let start = Instant::now();
+        let result = catch_unwind(AssertUnwindSafe(f));
+        let poll_ns = start.elapsed().as_nanos() as u64;
+        CURRENT_TASK.with(|current| current.set(parent));
+
+        send_task_event(TaskEvent::Polled {
+            id: self.id,
+            poll_ns,
+        });
+
+        match result {
+            Ok(value) => value,
+            Err(payload) => {
+                self.finish(TaskState::Panicked, Some(panic_message(&*payload)));
+                resume_unwind(payload)
+            }
+        }
+    }
+
+    fn finish(&mut self, state: TaskState, panic_message: Option<String>) {
+        self.finished = true;
+        send_task_event(TaskEvent::Finished {
+            id: self.id,
+            state,
+            finished_at_ns: elapsed_ns(),
+            panic_message,
+        });
+    }
+}
+
+impl Drop for TaskHandle {
+    fn drop(&mut self) {
+        if !self.finished {
+            self.finish(TaskState::Aborted, None);
+        }
+    }
+}
+
+pin_project! {
+    /// A spawned future registered in the task registry.
+    ///
+    /// Created via the `spawn!` macro, this wrapper tracks:
+    /// - Spawn location and the instrumented task that spawned it
+    /// - Each poll with its duration
+    /// - Completion, abort (dropped before completing) or panic
+    pub struct InstrumentedTask<F: Future> {
+        #[pin]
+        inner: F,
+        handle: TaskHandle,
+    }
+}
+
+impl<F: Future> Future for InstrumentedTask<F> {
+    type Output = F::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        let inner = this.inner;
+        let result = this.handle.run(|| inner.poll(cx));
+        if result.is_ready() {
+            this.handle.finish(TaskState::Completed, None);
+        }
+        result
+    }
+}
+
+/// Registers a future about to be spawned.
+///
+/// This function is not intended for direct use. Use the `spawn!` macro instead.
+#[doc(hidden)]
+pub fn instrument_task<F: Future>(
+    fut: F,
+    source: &'static str,
+    label: Option<String>,
+    kind: TaskKind,
+) -> InstrumentedTask<F> {
+    InstrumentedTask {
+        inner: fut,
+        handle: TaskHandle::register(source, label, kind),
+    }
+}
+
+/// Registers a blocking closure about to be spawned, its single run is recorded as one poll.
+///
+/// This function is not intended for direct use. Use `spawn!(f, blocking = true)` instead.
+#[doc(hidden)]
+pub fn instrument_blocking<F, R>(
+    f: F,
+    source: &'static str,
+    label: Option<String>,
+) -> impl FnOnce() -> R + Send + 'static
+where
+    F: FnOnce() -> R + Send + 'static,
+{
+    let mut handle = TaskHandle::register(source, label, TaskKind::TokioBlocking);
+    move || {
+        let result = handle.run(f);
+        handle.finish(TaskState::Completed, None);
+        result
+    }
+}
+
+pub fn get_tasks_json() -> TasksJson {
+    let Some((_, registry)) = TASKS_STATE.get() else {
+        return TasksJson::default();
+    };
+
+    let mut tasks: Vec<SerializableTaskStats> = {
+        let registry = registry.read().unwrap();
+        registry
+            .tasks
+            .values()
+            .map(SerializableTaskStats::from)
+            .collect()
+    };
+    // Live tasks in spawn order, then finished ones, most recent first
+    tasks.sort_by_key(|task| match task.finished_at_ns {
+        None => (0, task.id),
+        Some(finished_at_ns) => (1, u64::MAX - finished_at_ns),
+    });
+    let live = tasks
+        .iter()
+        .filter(|task| !task.state.is_finished())
+        .count() as u64;
+
+    TasksJson {
+        current_elapsed_ns: elapsed_ns(),
+        total_spawned: TASK_ID_COUNTER.load(Ordering::Relaxed),
+        live,
+        tasks,
+    }
+}
+
+/// Spawns a future or a blocking closure and registers it in the task registry.
+///
+/// The task list is served on the `/tasks` route and shown in the TUI "Tasks" tab. The runtime
+/// crate must be a dependency of the calling crate.
+///
+/// # Variants
+///
+/// - `spawn!(fut)` - `tokio::spawn`
+/// - `spawn!(fut, runtime = "smol")` - `smol::spawn`
+/// - `spawn!(f, blocking = true)` - `tokio::task::spawn_blocking`
+/// - `label = "name"` can be added to any of them, before or after the other option
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// let handle = hotpath::spawn!(async { fetch().await }, label = "fetch");
+/// let checksum = hotpath::spawn!(move || crc32(&data), blocking = true).await?;
+/// let task = hotpath::spawn!(async { 42 }, runtime = "smol");
+/// ```
+#[macro_export]
+macro_rules! spawn {
+    ($fut:expr) => {{
+        const TASK_LOC: &'static str = concat!(file!(), ":", line!());
+        ::tokio::spawn($crate::tasks::instrument_task(
+            $fut,
+            TASK_LOC,
+            None,
+            $crate::tasks::TaskKind::Tokio,
+        ))
+    }};
+
+    ($fut:expr, label = $label:expr) => {{
+        const TASK_LOC: &'static str = concat!(file!(), ":", line!());
+        ::tokio::spawn($crate::tasks::instrument_task(
+            $fut,
+            TASK_LOC,
+            Some($label.to_string()),
+            $crate::tasks::TaskKind::Tokio,
+        ))
+    }};
+
+    ($f:expr, blocking = true) => {{
+        const TASK_LOC: &'static str = concat!(file!(), ":", line!());
+        ::tokio::task::spawn_blocking($crate::tasks::instrument_blocking($f, TASK_LOC, None))
+    }};
+
+    ($f:expr, label = $label:expr, blocking = true) => {{
+        const TASK_LOC: &'static str = concat!(file!(), ":", line!());
+        ::tokio::task::spawn_blocking($crate::tasks::instrument_blocking(
+            $f,
+            TASK_LOC,
+            Some($label.to_string()),
+        ))
+    }};
+
+    ($f:expr, blocking = true, label = $label:expr) => {
+        $crate::spawn!($f, label = $label, blocking = true)
+    };
+
+    ($fut:expr, runtime = "smol") => {{
+        const TASK_LOC: &'static str = concat!(file!(), ":", line!());
+        ::smol::spawn($crate::tasks::instrument_task(
+            $fut,
+            TASK_LOC,
+            None,
+            $crate::tasks::TaskKind::Smol,
+        ))
+    }};
+
+    ($fut:expr, label = $label:expr, runtime = "smol") => {{
+        const TASK_LOC: &'static str = concat!(file!(), ":", line!());
+        ::smol::spawn($crate::tasks::instrument_task(
+            $fut,
+            TASK_LOC,
+            Some($label.to_string()),
+            $crate::tasks::TaskKind::Smol,
+        ))
+    }};
+
+    ($fut:expr, runtime = "smol", label = $label:expr) => {
+        $crate::spawn!($fut, label = $label, runtime = "smol")
+    };
+}
@@ -151,6 +151,7 @@ fn get_rss_bytes() -> Option<u64> {
 /// Get current thread metrics as JSON
 pub fn get_threads_json() -> ThreadsJson {
     let rss_bytes = get_rss_bytes();
+    let untracked_threads = get_untracked_threads();
 
     if let Some(state) = THREADS_STATE.get() {
         if let Ok(state_guard) = state.read() {
@@ -162,6 +163,7 @@ pub fn get_threads_json() -> ThreadsJson {
                 threads: state_guard.current_metrics.clone(),
                 thread_count: state_guard.current_metrics.len(),
                 rss_bytes,
+                untracked_threads,
             };
         }
     }
@@ -172,5 +174,16 @@ pub fn get_threads_json() -> ThreadsJson {
         threads: Vec::new(),
         thread_count: 0,
         rss_bytes,
+        untracked_threads,
     }
 }
+
+#[cfg(feature = "hotpath-alloc")]
+fn get_untracked_threads() -> Option<u64> {
+    super::functions::alloc::core::get_untracked_threads()
+}
+
+#[cfg(not(feature = "hotpath-alloc"))]
+fn get_untracked_threads() -> Option<u64> {
+    None
+}
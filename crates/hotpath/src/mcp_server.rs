@@ -16,14 +16,18 @@ use std::sync::{Arc, LazyLock, OnceLock};
 use std::time::Duration;
 use tokio_util::sync::CancellationToken;
 
-use crate::channels::{get_channel_logs, get_channels_json};
+use crate::blocking::get_blocking_polls_json;
+use crate::channels::{get_channel_history, get_channel_logs, get_channels_json};
 use crate::functions::{
     get_function_logs_alloc, get_function_logs_timing, get_functions_alloc_json,
     get_functions_timing_json,
 };
-use crate::futures::{get_future_calls, get_futures_json};
+use crate::futures::{get_future_calls, get_futures_json, get_in_flight_futures_json};
+use crate::locks::get_locks_json;
 use crate::mcp_server::output::FunctionsMCPJson;
+use crate::sinks::{get_sink_logs, get_sinks_json};
 use crate::streams::{get_stream_logs, get_streams_json};
+use crate::tasks::get_tasks_json;
 use crate::threads::get_threads_json;
 
 #[derive(Debug, Deserialize, JsonSchema)]
@@ -44,6 +48,12 @@ struct StreamIdParam {
     stream_id: String,
 }
 
+#[derive(Debug, Deserialize, JsonSchema)]
+struct SinkIdParam {
+    #[schemars(description = "Sink identifier from the sinks list")]
+    sink_id: String,
+}
+
 #[derive(Debug, Deserialize, JsonSchema)]
 struct FutureIdParam {
     #[schemars(description = "Future identifier from the futures list")]
@@ -118,17 +128,26 @@ Returns error if hotpath-alloc feature is not enabled. Cross-reference with func
     }
 
     #[tool(
-        description = r#"Get metrics for all monitored async channels (tokio, crossbeam, std, futures-channel).
+        description = r#"Get metrics for all monitored async channels (tokio, crossbeam, std, futures-channel, flume, async-channel, kanal).
 
 Returns JSON array with:
 - id: channel identifier
 - label: optional custom label
-- channel_type: "bounded", "unbounded", or "oneshot"
+- channel_type: "bounded", "unbounded", "oneshot", "broadcast" or "watch"
 - sent/received: message counts
 - queue_size: current pending messages (high values indicate backpressure)
 - state: "active", "closed", "full"
-
-Look for channels with growing queue_size or "full" state to identify bottlenecks."#
+- queue_latency_p50_ns/p95_ns/p99_ns: time messages waited in the queue between send and receive
+- lagged_count/dropped_count/receivers: broadcast only - messages missed by lagging receivers, sends without receivers, and per-receiver lag
+- last_change_ns: watch only - when the value last changed
+- receiver_count: flume, async-channel and kanal only - live receiver handles sharing the channel
+- max_queued: highest queue depth observed
+- full_time_ns/full_time_percent/full_transitions: time spent in the "full" state, as a share of the channel lifetime, and how often it filled up
+- blocked_sends, send_block_p50_ns/p95_ns/p99_ns/max_ns: inline bounded channels only - sends that waited for capacity on a full channel and how long they waited
+- sent_rate_history: recent per-second send rates, oldest first
+- queued_bytes: memory held by queued messages, measured per message for channels created with measure_size = true
+
+Look for channels with growing queue_size, a high full_time_percent or "full" state to identify bottlenecks. High queue latency percentiles mean consumers can't keep up with producers, and many blocked sends mean producers are throttled by backpressure."#
     )]
     async fn channels(&self) -> Result<CallToolResult, McpError> {
         log_debug("Tool called: channels");
@@ -145,7 +164,12 @@ Returns JSON array with:
 - id: stream identifier
 - label: optional custom label
 - items_yielded: count of items produced
+- yielded_bytes: total size of yielded items, only for streams created with measure_size = true
 - state: "active" or "closed"
+- inter_item_p50_ns/p95_ns/p99_ns, inter_item_max_ns: time between consecutive items
+- pending_polls: polls that returned Pending
+- busy_ns: total time spent inside poll_next
+- time_to_first_item_ns, time_to_completion_ns: time from stream creation
 
 Use to track stream throughput and identify stalled streams."#)]
     async fn streams(&self) -> Result<CallToolResult, McpError> {
@@ -157,15 +181,46 @@ Use to track stream throughput and identify stalled streams."#)]
         )?)]))
     }
 
+    #[tool(description = r#"Get metrics for all monitored async sinks.
+
+Returns JSON array with:
+- id: sink identifier
+- label: optional custom label
+- items_sent: count of items accepted by start_send
+- state: "active" or "closed" (after poll_close completed)
+- pending_ready_polls: poll_ready calls that returned Pending, i.e. backpressure
+- backpressure_ns: total time spent waiting for the sink to become ready
+- flushes, flush_p50_ns/p95_ns/p99_ns, flush_max_ns: completed flushes and their latency
+- close_ns: time spent closing the sink
+
+Use to find slow writers and sinks that push back on their producers."#)]
+    async fn sinks(&self) -> Result<CallToolResult, McpError> {
+        log_debug("Tool called: sinks");
+
+        let sinks = get_sinks_json();
+        Ok(CallToolResult::success(vec![Content::text(to_json(
+            &sinks,
+        )?)]))
+    }
+
     #[tool(description = r#"Get lifecycle metrics for all monitored futures.
 
 Returns JSON array with:
 - id: future identifier
 - label: optional custom label
 - poll_count: number of times polled (high counts may indicate inefficient futures)
+- poll_p50_ns / poll_p95_ns / poll_p99_ns / poll_max_ns: time spent inside a single poll
+- total_wakes / spurious_polls: wakes received, and polls that happened without a preceding wake
+- wake_to_poll_p50_ns / wake_to_poll_p95_ns / wake_to_poll_max_ns: scheduler delay between a wake and the next poll
+- call_count / completed_count / cancelled_count / in_flight: calls created, finished and still pending since start
+- lifetime_p50_ns / lifetime_p95_ns / lifetime_p99_ns / lifetime_max_ns: time from creation until ready of completed calls
+- total_lifetime_ns: summed lifetime of completed and cancelled calls
+- parent_ids / children: futures this one was created inside, and the calls and summed lifetime of futures created inside it
 - state: "active", "completed", or "cancelled"
 
-High poll counts with "active" state suggest futures that wake frequently without progress."#)]
+High poll counts with "active" state suggest futures that wake frequently without progress.
+Many spurious polls mean the future is driven by a combinator polling it on a sibling's wake. A high wake to poll delay means the runtime is saturated.
+Polls taking milliseconds block the executor thread, usually due to blocking I/O or heavy computation inside async code."#)]
     async fn futures(&self) -> Result<CallToolResult, McpError> {
         log_debug("Tool called: futures");
 
@@ -191,6 +246,102 @@ Sampled at configurable interval (HOTPATH_THREADS_INTERVAL env var, default 1000
         )?)]))
     }
 
+    #[tool(
+        description = r#"Get contention metrics for all instrumented Mutex and RwLock instances.
+
+Returns JSON array with:
+- id: lock identifier
+- label: optional custom label
+- lock_type / backend: "mutex" or "rwlock", and "std", "parking_lot" or "tokio"
+- acquisitions / contentions: total acquisitions and how many had to wait for another holder
+- wait_p50_ns / wait_p99_ns / wait_max_ns: time spent waiting to acquire
+- hold_p95_ns / hold_max_ns: time the lock was held
+- holder_tid / readers: current exclusive holder thread or active reader count
+
+A high contention ratio or long hold times point at locks that serialize hot paths."#
+    )]
+    async fn locks(&self) -> Result<CallToolResult, McpError> {
+        log_debug("Tool called: locks");
+
+        let locks = get_locks_json();
+        Ok(CallToolResult::success(vec![Content::text(to_json(
+            &locks,
+        )?)]))
+    }
+
+    #[tool(description = r#"Get recent polls that blocked the executor thread.
+
+Returns JSON with:
+- threshold_ns: global threshold from HOTPATH_BLOCKING_POLL_MS (null when detection is off)
+- total: blocking polls detected since start
+- polls: most recent first, each with site, source ("future" or "function"), tid, duration_ns and timestamp_ns
+
+A poll running for milliseconds stalls every other task on that worker thread, usually due to blocking I/O, std locks or CPU-heavy work in async code."#)]
+    async fn blocking_polls(&self) -> Result<CallToolResult, McpError> {
+        log_debug("Tool called: blocking_polls");
+
+        let blocking_polls = get_blocking_polls_json();
+        Ok(CallToolResult::success(vec![Content::text(to_json(
+            &blocking_polls,
+        )?)]))
+    }
+
+    #[tool(description = r#"Get tasks spawned with hotpath::spawn!.
+
+Returns JSON with:
+- total_spawned / live: tasks spawned since start and tasks not finished yet
+- tasks: every live task, then the most recently finished ones, each with:
+  - id / label / source: task identifier, optional custom label and spawn location
+  - kind: "tokio", "tokio_blocking" or "smol"
+  - parent_id: task that was running when this one was spawned
+  - state: "idle", "running", "completed", "aborted" or "panicked"
+  - spawned_at_ns / finished_at_ns: time since start
+  - poll_count / busy_ns / max_poll_ns: polls and time spent inside them
+  - panic_message: payload of the panic that ended the task
+
+Tasks stuck in "running" block a worker thread. A growing number of live tasks points at a task leak."#)]
+    async fn tasks(&self) -> Result<CallToolResult, McpError> {
+        log_debug("Tool called: tasks");
+
+        let tasks = get_tasks_json();
+        Ok(CallToolResult::success(vec![Content::text(to_json(
+            &tasks,
+        )?)]))
+    }
+
+    #[tool(
+        description = r#"Get tokio runtime metrics sampled from the handle registered with hotpath::runtime::register_handle.
+
+Returns JSON with:
+- registered: false when no runtime handle was registered
+- flavor: "current_thread" or "multi_thread"
+- sample_interval_ms: sampling interval (HOTPATH_RUNTIME_INTERVAL env var, default 1000ms)
+- samples: most recent samples, oldest first, each with:
+  - num_workers / num_alive_tasks: worker threads and tasks not yet completed
+  - global_queue_depth: tasks waiting in the global injection queue
+  - num_blocking_threads: only with --cfg tokio_unstable
+  - worker_park_counts: times each worker parked since start
+
+A growing global queue means workers cannot keep up. Workers that rarely park are saturated."#
+    )]
+    async fn runtime(&self) -> Result<CallToolResult, McpError> {
+        log_debug("Tool called: runtime");
+
+        #[cfg(feature = "tokio-runtime-metrics")]
+        {
+            let runtime = crate::runtime::get_runtime_json();
+            Ok(CallToolResult::success(vec![Content::text(to_json(
+                &runtime,
+            )?)]))
+        }
+        #[cfg(not(feature = "tokio-runtime-metrics"))]
+        {
+            Ok(CallToolResult::error(vec![Content::text(
+                "Runtime metrics not available - enable tokio-runtime-metrics feature",
+            )]))
+        }
+    }
+
     #[tool(description = r#"Get detailed timing logs for a specific function.
 
 Returns JSON array of recent execution logs with timestamps and duration. Use functions_timing first to get function names, then use this tool to get detailed logs."#)]
@@ -260,6 +411,26 @@ Returns JSON array of recent send/receive events with timestamps. Use channels f
         }
     }
 
+    #[tool(description = r#"Get throughput history for a specific channel.
+
+Returns per-second samples (oldest first) of sent_per_sec, received_per_sec and queued. A received rate that stays below the sent rate while queued grows means the consumer can't keep up."#)]
+    async fn channel_history(
+        &self,
+        params: Parameters<ChannelIdParam>,
+    ) -> Result<CallToolResult, McpError> {
+        let channel_id = &params.0.channel_id;
+        log_debug(&format!("Tool called: channel_history({})", channel_id));
+
+        match get_channel_history(channel_id) {
+            Some(history) => Ok(CallToolResult::success(vec![Content::text(to_json(
+                &history,
+            )?)])),
+            None => Ok(CallToolResult::error(vec![Content::text(
+                "Channel not found",
+            )])),
+        }
+    }
+
     #[tool(description = r#"Get detailed item logs for a specific stream.
 
 Returns JSON array of recent yield events with timestamps. Use streams first to get stream IDs, then use this tool to get detailed logs."#)]
@@ -280,6 +451,21 @@ Returns JSON array of recent yield events with timestamps. Use streams first to
         }
     }
 
+    #[tool(description = r#"Get detailed item logs for a specific sink.
+
+Returns JSON array of recent send events with timestamps. Only sinks created with log = true keep item contents. Use sinks first to get sink IDs, then use this tool to get detailed logs."#)]
+    async fn sink_logs(&self, params: Parameters<SinkIdParam>) -> Result<CallToolResult, McpError> {
+        let sink_id = &params.0.sink_id;
+        log_debug(&format!("Tool called: sink_logs({})", sink_id));
+
+        match get_sink_logs(sink_id) {
+            Some(logs) => Ok(CallToolResult::success(vec![Content::text(to_json(
+                &logs,
+            )?)])),
+            None => Ok(CallToolResult::error(vec![Content::text("Sink not found")])),
+        }
+    }
+
     #[tool(description = r#"Get detailed call/poll logs for a specific future.
 
 Returns JSON array of poll events and completion status. Use futures first to get future IDs, then use this tool to get detailed logs."#)]
@@ -303,6 +489,25 @@ Returns JSON array of poll events and completion status. Use futures first to ge
             )])),
         }
     }
+
+    #[tool(
+        description = r#"Get future calls that are still in flight, oldest first.
+
+Returns JSON with:
+- threshold_ns: stuck age threshold from HOTPATH_STUCK_FUTURE_MS (null when unset)
+- total / stuck: in-flight calls and how many of them are older than the threshold
+- calls: each with future_id, call_id, label, state, poll_count, created_at_ns, age_ns, last_poll_ns and stuck
+
+Old calls that are no longer polled usually mean a hung request or a leaked future that is never driven to completion."#
+    )]
+    async fn in_flight_futures(&self) -> Result<CallToolResult, McpError> {
+        log_debug("Tool called: in_flight_futures");
+
+        let in_flight = get_in_flight_futures_json();
+        Ok(CallToolResult::success(vec![Content::text(to_json(
+            &in_flight,
+        )?)]))
+    }
 }
 
 #[tool_handler]
@@ -117,6 +117,10 @@ mod tests {
                     MetricType::Percentage(3884),
                 ],
             )],
+            live_allocs: None,
+            depth_overflows: None,
+            call_sites: None,
+            runtime: None,
         };
 
         let formatted = FunctionsMCPJson::from(&raw);
@@ -21,9 +21,13 @@ pub(crate) static METRICS_SERVER_DISABLED: LazyLock<bool> = LazyLock::new(|| {
 
 pub(crate) static RECV_TIMEOUT_MS: u64 = 250;
 
-use crate::channels::{get_channel_logs, get_channels_json};
-use crate::futures::{get_future_calls, get_futures_json};
+use crate::blocking::get_blocking_polls_json;
+use crate::channels::{get_channel_history, get_channel_logs, get_channels_json};
+use crate::futures::{get_future_calls, get_futures_json, get_in_flight_futures_json};
+use crate::locks::get_locks_json;
+use crate::sinks::{get_sink_logs, get_sinks_json};
 use crate::streams::{get_stream_logs, get_streams_json};
+use crate::tasks::get_tasks_json;
 use serde::Serialize;
 use std::fmt::Display;
 use std::sync::OnceLock;
@@ -92,10 +96,43 @@ fn handle_request(request: Request) {
             let streams = get_streams_json();
             respond_json(request, &streams);
         }
+        Ok(Route::Sinks) => {
+            let sinks = get_sinks_json();
+            respond_json(request, &sinks);
+        }
         Ok(Route::Futures) => {
             let futures = get_futures_json();
             respond_json(request, &futures);
         }
+        Ok(Route::Locks) => {
+            let locks = get_locks_json();
+            respond_json(request, &locks);
+        }
+        Ok(Route::BlockingPolls) => {
+            let blocking_polls = get_blocking_polls_json();
+            respond_json(request, &blocking_polls);
+        }
+        Ok(Route::InFlightFutures) => {
+            let in_flight = get_in_flight_futures_json();
+            respond_json(request, &in_flight);
+        }
+        Ok(Route::Tasks) => {
+            let tasks = get_tasks_json();
+            respond_json(request, &tasks);
+        }
+        #[cfg(feature = "tokio-runtime-metrics")]
+        Ok(Route::Runtime) => {
+            let runtime = crate::runtime::get_runtime_json();
+            respond_json(request, &runtime);
+        }
+        #[cfg(not(feature = "tokio-runtime-metrics"))]
+        Ok(Route::Runtime) => {
+            respond_error(
+                request,
+                404,
+                "Runtime metrics not available - enable tokio-runtime-metrics feature",
+            );
+        }
         Ok(Route::FunctionTimingLogs { function_name }) => {
             match get_function_logs_timing(&function_name) {
                 Some(logs) => respond_json(request, &logs),
@@ -120,10 +157,20 @@ fn handle_request(request: Request) {
             Some(logs) => respond_json(request, &logs),
             None => respond_error(request, 404, "Channel not found"),
         },
+        Ok(Route::ChannelHistory { channel_id }) => {
+            match get_channel_history(&channel_id.to_string()) {
+                Some(history) => respond_json(request, &history),
+                None => respond_error(request, 404, "Channel not found"),
+            }
+        }
         Ok(Route::StreamLogs { stream_id }) => match get_stream_logs(&stream_id.to_string()) {
             Some(logs) => respond_json(request, &logs),
             None => respond_error(request, 404, "Stream not found"),
         },
+        Ok(Route::SinkLogs { sink_id }) => match get_sink_logs(&sink_id.to_string()) {
+            Some(logs) => respond_json(request, &logs),
+            None => respond_error(request, 404, "Sink not found"),
+        },
         Ok(Route::FutureCalls { future_id }) => match get_future_calls(future_id) {
             Some(calls) => respond_json(request, &calls),
             None => respond_error(request, 404, "Future not found"),
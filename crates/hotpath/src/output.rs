@@ -4,6 +4,8 @@ use serde::{
 };
 use std::collections::HashMap;
 use std::fmt;
+
+use crate::json::RuntimeSummaryJson;
 #[cfg(feature = "hotpath")]
 use std::time::Duration;
 
@@ -227,6 +229,67 @@ pub struct FunctionLogsJson {
     pub count: usize,
 }
 
+/// Memory still held at the end of a session by allocations made inside a measured function.
+///
+/// Values are estimates: only every `sample_rate`-th allocation is tracked and the
+/// sampled totals are scaled back up.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LiveAllocEntry {
+    pub name: String,
+    /// Estimated number of allocations that were never freed
+    pub count: u64,
+    /// Estimated number of bytes that were never freed
+    pub bytes: u64,
+}
+
+/// Leak report collected when `HOTPATH_ALLOC_LEAKS` is enabled.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LiveAllocsJson {
+    /// One in `sample_rate` allocations was tracked
+    pub sample_rate: u64,
+    /// Samples discarded because the tracking table was full
+    pub dropped_samples: u64,
+    /// Entries sorted by live bytes, largest first
+    pub data: Vec<LiveAllocEntry>,
+}
+
+/// Sampled allocations of a measured function attributed to a single source location.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CallSiteEntry {
+    pub name: String,
+    /// First frame outside of hotpath and the standard library, as "function (file:line)"
+    pub location: String,
+    pub samples: u64,
+    /// Estimated bytes, the sampled sizes scaled by the sampling interval
+    pub bytes: u64,
+}
+
+/// Top allocating call sites of each measured function.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CallSitesJson {
+    pub sample_every: u64,
+    /// Either "allocs" or "bytes"
+    pub sample_unit: String,
+    /// Samples discarded because the background worker fell behind
+    pub dropped_samples: u64,
+    /// Samples of stacks beyond the aggregation limit, counted under `<other>` instead
+    pub dropped_stacks: u64,
+    pub data: Vec<CallSiteEntry>,
+}
+
+/// Number of calls of a function that were nested deeper than the allocation stack
+/// depth limit (`HOTPATH_ALLOC_MAX_DEPTH`). Their allocations are attributed to the
+/// deepest tracked frame instead.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DepthOverflowEntry {
+    pub name: String,
+    pub count: u64,
+    /// Async calls among `count` with only some polls over the limit. Their reported
+    /// allocations cover the remaining polls.
+    #[serde(default)]
+    pub partial: u64,
+}
+
 /// JSON representation of profiling metrics.
 #[derive(Debug, Clone)]
 pub struct FunctionsJson {
@@ -236,6 +299,14 @@ pub struct FunctionsJson {
     pub caller_name: String,
     pub percentiles: Vec<u8>,
     pub data: FunctionsDataJson,
+    /// Present only in alloc mode with `HOTPATH_ALLOC_LEAKS` enabled
+    pub live_allocs: Option<LiveAllocsJson>,
+    /// Present only in alloc mode when some calls exceeded the depth limit
+    pub depth_overflows: Option<Vec<DepthOverflowEntry>>,
+    /// Present only in alloc mode with `HOTPATH_ALLOC_CALLSITES` enabled
+    pub call_sites: Option<CallSitesJson>,
+    /// Present only in the exit report when a tokio runtime handle was registered
+    pub runtime: Option<RuntimeSummaryJson>,
 }
 
 #[derive(Deserialize)]
@@ -245,6 +316,14 @@ struct MetricsJsonRaw {
     description: String,
     caller_name: String,
     data: serde_json::Value,
+    #[serde(default)]
+    live_allocs: Option<LiveAllocsJson>,
+    #[serde(default)]
+    depth_overflows: Option<Vec<DepthOverflowEntry>>,
+    #[serde(default)]
+    call_sites: Option<CallSitesJson>,
+    #[serde(default)]
+    runtime: Option<RuntimeSummaryJson>,
 }
 
 impl TryFrom<MetricsJsonRaw> for FunctionsJson {
@@ -264,6 +343,10 @@ impl TryFrom<MetricsJsonRaw> for FunctionsJson {
             caller_name: raw.caller_name,
             percentiles,
             data,
+            live_allocs: raw.live_allocs,
+            depth_overflows: raw.depth_overflows,
+            call_sites: raw.call_sites,
+            runtime: raw.runtime,
         })
     }
 }
@@ -331,7 +414,12 @@ impl Serialize for FunctionsJson {
         use serde::ser::SerializeStruct;
 
         let headers = build_headers(&self.percentiles);
-        let mut state = serializer.serialize_struct("MetricsJson", 5)?;
+        let field_count = 5
+            + self.live_allocs.is_some() as usize
+            + self.depth_overflows.is_some() as usize
+            + self.call_sites.is_some() as usize
+            + self.runtime.is_some() as usize;
+        let mut state = serializer.serialize_struct("MetricsJson", field_count)?;
 
         state.serialize_field("hotpath_profiling_mode", &self.hotpath_profiling_mode)?;
         state.serialize_field("total_elapsed", &self.total_elapsed)?;
@@ -344,6 +432,22 @@ impl Serialize for FunctionsJson {
         };
         state.serialize_field("data", &data_serializer)?;
 
+        if let Some(live_allocs) = &self.live_allocs {
+            state.serialize_field("live_allocs", live_allocs)?;
+        }
+
+        if let Some(depth_overflows) = &self.depth_overflows {
+            state.serialize_field("depth_overflows", depth_overflows)?;
+        }
+
+        if let Some(call_sites) = &self.call_sites {
+            state.serialize_field("call_sites", call_sites)?;
+        }
+
+        if let Some(runtime) = &self.runtime {
+            state.serialize_field("runtime", runtime)?;
+        }
+
         state.end()
     }
 }
@@ -519,8 +623,24 @@ pub trait MetricsProvider<'a> {
         }
     }
 
-    fn has_unsupported_async(&self) -> bool {
-        false
+    /// Allocations still live when the session ended, if leak tracking was enabled.
+    fn live_allocs(&self) -> Option<LiveAllocsJson> {
+        None
+    }
+
+    /// Top allocating call sites by function, when call site sampling is enabled.
+    fn call_sites(&self) -> Option<CallSitesJson> {
+        None
+    }
+
+    /// Functions with calls nested deeper than the allocation stack depth limit.
+    fn depth_overflows(&self) -> Vec<DepthOverflowEntry> {
+        Vec::new()
+    }
+
+    /// Tokio runtime summary, if a runtime handle was registered for sampling.
+    fn runtime(&self) -> Option<RuntimeSummaryJson> {
+        None
     }
 
     fn entry_counts(&self) -> (usize, usize);
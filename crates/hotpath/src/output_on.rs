@@ -1,4 +1,8 @@
-use crate::output::{shorten_function_name, FunctionsJson, MetricType, MetricsProvider, Reporter};
+use crate::json::RuntimeSummaryJson;
+use crate::output::{
+    format_bytes, shorten_function_name, CallSitesJson, FunctionsJson, LiveAllocsJson, MetricType,
+    MetricsProvider, Reporter,
+};
 use colored::*;
 use prettytable::{color, Attr, Cell, Row, Table};
 use std::time::Duration;
@@ -84,20 +88,229 @@ pub(crate) fn display_table(metrics_provider: &dyn MetricsProvider<'_>) {
 
     table.printstd();
 
-    if metrics_provider.has_unsupported_async() {
+    let depth_overflows = metrics_provider.depth_overflows();
+    if !depth_overflows.is_empty() {
         println!();
         println!(
-            "* {} for async methods is currently only available for tokio {} runtime.",
-            "alloc profiling".yellow().bold(),
-            "current_thread".green().bold()
+            "* {} exceeded the allocation tracking depth limit. Their allocations are attributed to the deepest tracked caller:",
+            "Calls nested too deeply".yellow().bold()
         );
+        for entry in &depth_overflows {
+            if entry.partial > 0 {
+                println!(
+                    "  {}: {} calls ({} partially measured)",
+                    shorten_function_name(&entry.name),
+                    entry.count,
+                    entry.partial
+                );
+            } else {
+                println!(
+                    "  {}: {} calls",
+                    shorten_function_name(&entry.name),
+                    entry.count
+                );
+            }
+        }
+        println!(
+            "  Rebuild with a higher {} to track them separately.",
+            "HOTPATH_ALLOC_MAX_DEPTH".cyan().bold()
+        );
+    }
+
+    if let Some(call_sites) = metrics_provider.call_sites() {
+        display_call_sites_table(&call_sites, use_colors);
+    }
+
+    if let Some(live_allocs) = metrics_provider.live_allocs() {
+        display_live_allocs_table(&live_allocs, use_colors);
+    }
+
+    if let Some(runtime) = metrics_provider.runtime() {
+        display_runtime_table(&runtime, use_colors);
+    }
+}
+
+fn display_call_sites_table(call_sites: &CallSitesJson, use_colors: bool) {
+    let mut table = Table::new();
+
+    let header_cells: Vec<Cell> = ["Function", "Call Site", "Samples", "Est. Bytes"]
+        .into_iter()
+        .map(|header| {
+            if use_colors {
+                Cell::new(header)
+                    .with_style(Attr::Bold)
+                    .with_style(Attr::ForegroundColor(color::CYAN))
+            } else {
+                Cell::new(header).with_style(Attr::Bold)
+            }
+        })
+        .collect();
+    table.add_row(Row::new(header_cells));
+
+    let mut previous_name = None;
+    for entry in &call_sites.data {
+        // Print each function name once, above its call sites
+        let name = if previous_name == Some(&entry.name) {
+            String::new()
+        } else {
+            shorten_function_name(&entry.name)
+        };
+        previous_name = Some(&entry.name);
+
+        table.add_row(Row::new(vec![
+            Cell::new(&name),
+            Cell::new(&entry.location),
+            Cell::new(&entry.samples.to_string()),
+            Cell::new(&format_bytes(entry.bytes)),
+        ]));
+    }
+
+    println!();
+    println!(
+        "{} alloc-sites - Top allocating call sites by function (sampled every {} {}).",
+        "[hotpath]".blue().bold(),
+        call_sites.sample_every,
+        call_sites.sample_unit
+    );
+
+    if call_sites.data.is_empty() {
+        println!("No allocations were sampled inside measured functions.");
+    } else {
+        table.printstd();
+    }
+
+    if call_sites.dropped_samples > 0 {
+        println!(
+            "{} {} samples were dropped because the worker fell behind, consider raising {}.",
+            "*".yellow().bold(),
+            call_sites.dropped_samples,
+            if call_sites.sample_unit == "bytes" {
+                "HOTPATH_ALLOC_CALLSITES_BYTES"
+            } else {
+                "HOTPATH_ALLOC_CALLSITES"
+            }
+            .cyan()
+            .bold()
+        );
+    }
+
+    if call_sites.dropped_stacks > 0 {
+        println!(
+            "{} {} samples came from stacks beyond the aggregation limit and are reported as <other>.",
+            "*".yellow().bold(),
+            call_sites.dropped_stacks
+        );
+    }
+}
+
+fn display_live_allocs_table(live_allocs: &LiveAllocsJson, use_colors: bool) {
+    let mut table = Table::new();
+
+    let header_cells: Vec<Cell> = ["Function", "Live Allocs", "Live Bytes", "% Live"]
+        .into_iter()
+        .map(|header| {
+            if use_colors {
+                Cell::new(header)
+                    .with_style(Attr::Bold)
+                    .with_style(Attr::ForegroundColor(color::CYAN))
+            } else {
+                Cell::new(header).with_style(Attr::Bold)
+            }
+        })
+        .collect();
+    table.add_row(Row::new(header_cells));
+
+    let total_bytes: u64 = live_allocs.data.iter().map(|entry| entry.bytes).sum();
+
+    for entry in &live_allocs.data {
+        let percentage = if total_bytes > 0 {
+            entry.bytes as f64 / total_bytes as f64 * 100.0
+        } else {
+            0.0
+        };
+
+        table.add_row(Row::new(vec![
+            Cell::new(&shorten_function_name(&entry.name)),
+            Cell::new(&entry.count.to_string()),
+            Cell::new(&format_bytes(entry.bytes)),
+            Cell::new(&format!("{:.2}%", percentage)),
+        ]));
+    }
+
+    println!();
+    println!(
+        "{} alloc-leaks - Memory still live at the end of the session by function (sampled 1/{}).",
+        "[hotpath]".blue().bold(),
+        live_allocs.sample_rate
+    );
+
+    if live_allocs.data.is_empty() {
+        println!("No live allocations were attributed to measured functions.");
+    } else {
+        table.printstd();
+    }
+
+    if live_allocs.dropped_samples > 0 {
         println!(
-            "  Please use {} to enable it.",
-            "#[tokio::main(flavor = \"current_thread\")]".cyan().bold()
+            "{} {} samples were dropped because the tracking table was full, consider raising {}.",
+            "*".yellow().bold(),
+            live_allocs.dropped_samples,
+            "HOTPATH_ALLOC_LEAKS_SAMPLE".cyan().bold()
         );
     }
 }
 
+fn display_runtime_table(runtime: &RuntimeSummaryJson, use_colors: bool) {
+    let mut table = Table::new();
+
+    let header_cells: Vec<Cell> = ["Metric", "Last", "Peak"]
+        .into_iter()
+        .map(|header| {
+            if use_colors {
+                Cell::new(header)
+                    .with_style(Attr::Bold)
+                    .with_style(Attr::ForegroundColor(color::CYAN))
+            } else {
+                Cell::new(header).with_style(Attr::Bold)
+            }
+        })
+        .collect();
+    table.add_row(Row::new(header_cells));
+
+    let optional = |value: Option<usize>| value.map_or("-".to_string(), |v| v.to_string());
+
+    table.add_row(Row::new(vec![
+        Cell::new("Alive tasks"),
+        Cell::new(&runtime.num_alive_tasks.to_string()),
+        Cell::new(&runtime.peak_alive_tasks.to_string()),
+    ]));
+    table.add_row(Row::new(vec![
+        Cell::new("Global queue depth"),
+        Cell::new(&runtime.global_queue_depth.to_string()),
+        Cell::new(&runtime.peak_global_queue_depth.to_string()),
+    ]));
+    table.add_row(Row::new(vec![
+        Cell::new("Blocking threads"),
+        Cell::new(&optional(runtime.num_blocking_threads)),
+        Cell::new(&optional(runtime.peak_blocking_threads)),
+    ]));
+    table.add_row(Row::new(vec![
+        Cell::new("Worker parks"),
+        Cell::new(&runtime.total_parks.to_string()),
+        Cell::new("-"),
+    ]));
+
+    println!();
+    println!(
+        "{} tokio-runtime - {} runtime with {} workers ({} samples).",
+        "[hotpath]".blue().bold(),
+        runtime.flavor,
+        runtime.num_workers,
+        runtime.samples
+    );
+    table.printstd();
+}
+
 fn display_no_measurements_message(total_elapsed: Duration, caller_name: &str) {
     let title = format!(
         "\n{} No measurements recorded from {} (Total time: {:.2?})",
@@ -201,6 +414,10 @@ impl From<&dyn MetricsProvider<'_>> for FunctionsJson {
             caller_name: metrics.caller_name().to_string(),
             percentiles,
             data,
+            live_allocs: metrics.live_allocs(),
+            depth_overflows: Some(metrics.depth_overflows()).filter(|d| !d.is_empty()),
+            call_sites: metrics.call_sites(),
+            runtime: metrics.runtime(),
         }
     }
 }
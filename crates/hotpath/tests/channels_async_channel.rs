@@ -0,0 +1,134 @@
+#[cfg(test)]
+pub mod tests {
+    use std::process::Command;
+
+    fn path_sep() -> &'static str {
+        if cfg!(windows) {
+            "\\"
+        } else {
+            "/"
+        }
+    }
+
+    // cargo run -p test-channels-async-channel --example basic_async_channel --features hotpath
+    #[test]
+    fn test_basic_output() {
+        let output = Command::new("cargo")
+            .args([
+                "run",
+                "-p",
+                "test-channels-async-channel",
+                "--example",
+                "basic_async_channel",
+                "--features",
+                "hotpath",
+            ])
+            .output()
+            .expect("Failed to execute command");
+
+        assert!(
+            output.status.success(),
+            "Command failed with status: {}",
+            output.status
+        );
+
+        let sep = path_sep();
+        let basic_path = format!("examples{sep}basic_async_channel.rs");
+        let all_expected = [
+            basic_path.as_str(),
+            "hello-there",
+            "unbounded",
+            "bounded[10]",
+            "bounded[1]",
+        ];
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        for expected in all_expected {
+            assert!(
+                stdout.contains(expected),
+                "Expected:\n{expected}\n\nGot:\n{stdout}",
+            );
+        }
+    }
+
+    // cargo run -p test-channels-async-channel --example closed_async_channel --features hotpath
+    #[test]
+    fn test_closed_channels_output() {
+        let output = Command::new("cargo")
+            .args([
+                "run",
+                "-p",
+                "test-channels-async-channel",
+                "--example",
+                "closed_async_channel",
+                "--features",
+                "hotpath",
+            ])
+            .output()
+            .expect("Failed to execute command");
+
+        assert!(
+            output.status.success(),
+            "Command failed with status: {}",
+            output.status
+        );
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+
+        for label in ["closed-sender", "closed-receiver", "closed-unbounded"] {
+            let row = stdout
+                .lines()
+                .find(|line| line.starts_with(&format!("| {label}")))
+                .unwrap_or_else(|| panic!("Expected {label} row\nOutput:\n{stdout}"));
+            assert!(
+                row.contains("closed"),
+                "Expected {label} to be closed\nRow:\n{row}"
+            );
+        }
+    }
+
+    // cargo run -p test-channels-async-channel --example mpmc_async_channel --features hotpath
+    #[test]
+    fn test_mpmc_output() {
+        let output = Command::new("cargo")
+            .args([
+                "run",
+                "-p",
+                "test-channels-async-channel",
+                "--example",
+                "mpmc_async_channel",
+                "--features",
+                "hotpath",
+            ])
+            .output()
+            .expect("Failed to execute command");
+
+        assert!(
+            output.status.success(),
+            "Command failed with status: {}",
+            output.status
+        );
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+
+        // Every message is delivered to exactly one of the cloned receivers
+        let row = stdout
+            .lines()
+            .find(|line| line.starts_with("| jobs"))
+            .unwrap_or_else(|| panic!("Expected jobs row\nOutput:\n{stdout}"));
+        let cells: Vec<&str> = row
+            .split('|')
+            .map(str::trim)
+            .filter(|cell| !cell.is_empty())
+            .collect();
+        assert_eq!(
+            cells[1..6],
+            ["bounded[10]", "closed", "20", "20", "0"],
+            "Unexpected channel stats\nRow:\n{row}"
+        );
+        assert!(
+            stdout.contains("jobs: 3 receivers"),
+            "Expected receiver count\nOutput:\n{stdout}"
+        );
+    }
+}
@@ -204,6 +204,57 @@ pub mod tests {
         );
     }
 
+    // cargo run -p test-channels-crossbeam --example inline_crossbeam --features hotpath
+    #[test]
+    fn test_inline_mode_output() {
+        let output = Command::new("cargo")
+            .args([
+                "run",
+                "-p",
+                "test-channels-crossbeam",
+                "--example",
+                "inline_crossbeam",
+                "--features",
+                "hotpath",
+            ])
+            .output()
+            .expect("Failed to execute command");
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let stderr = String::from_utf8_lossy(&output.stderr);
+
+        // The example asserts that try_send fails exactly at capacity
+        assert!(
+            output.status.success(),
+            "Command failed with status: {}\nStdout:\n{}\nStderr:\n{}",
+            output.status,
+            stdout,
+            stderr
+        );
+
+        // Without a proxy slot the queued count matches the channel contents exactly
+        let row = stdout
+            .lines()
+            .find(|line| line.starts_with("| inline-bounded"))
+            .unwrap_or_else(|| panic!("Expected inline-bounded row\nOutput:\n{stdout}"));
+        let cells: Vec<&str> = row
+            .split('|')
+            .map(str::trim)
+            .filter(|cell| !cell.is_empty())
+            .collect();
+        assert_eq!(
+            cells[1..6],
+            ["bounded[3]", "closed", "4", "1", "3"],
+            "Unexpected inline channel stats\nRow:\n{row}"
+        );
+        // Filled up on the third send and again after the single receive
+        assert_eq!(cells[6], "3", "Unexpected peak queue depth\nRow:\n{row}");
+        assert!(
+            cells[7].ends_with("(2x)"),
+            "Expected two full transitions\nRow:\n{row}"
+        );
+    }
+
     // HOTPATH_METRICS_PORT=6771 TEST_SLEEP_SECONDS=10 cargo run -p test-channels-crossbeam --example basic_crossbeam --features hotpath
     #[test]
     fn test_data_endpoints() {
@@ -213,6 +213,70 @@ pub mod tests {
         }
     }
 
+    // cargo run -p test-channels-ftc --example inline_ftc --features hotpath
+    #[test]
+    fn test_inline_mode_output() {
+        let output = Command::new("cargo")
+            .args([
+                "run",
+                "-p",
+                "test-channels-ftc",
+                "--example",
+                "inline_ftc",
+                "--features",
+                "hotpath",
+            ])
+            .output()
+            .expect("Failed to execute command");
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let stderr = String::from_utf8_lossy(&output.stderr);
+
+        assert!(
+            output.status.success(),
+            "Command failed with status: {}\nStdout:\n{}\nStderr:\n{}",
+            output.status,
+            stdout,
+            stderr
+        );
+
+        // The third send waits until the delayed receiver frees a slot
+        let row = stdout
+            .lines()
+            .find(|line| line.starts_with("| inline-bounded"))
+            .unwrap_or_else(|| panic!("Expected inline-bounded row\nOutput:\n{stdout}"));
+        let cells: Vec<&str> = row
+            .split('|')
+            .map(str::trim)
+            .filter(|cell| !cell.is_empty())
+            .collect();
+        assert_eq!(
+            cells[1..6],
+            ["bounded[1]", "closed", "3", "3", "0"],
+            "Unexpected inline channel stats\nRow:\n{row}"
+        );
+        assert!(
+            cells[8].starts_with("1 (p95 "),
+            "Expected one blocked send\nRow:\n{row}"
+        );
+
+        let row = stdout
+            .lines()
+            .find(|line| line.starts_with("| inline-unbounded"))
+            .unwrap_or_else(|| panic!("Expected inline-unbounded row\nOutput:\n{stdout}"));
+        let cells: Vec<&str> = row
+            .split('|')
+            .map(str::trim)
+            .filter(|cell| !cell.is_empty())
+            .collect();
+        assert_eq!(
+            cells[1..6],
+            ["unbounded", "closed", "3", "3", "0"],
+            "Unexpected inline channel stats\nRow:\n{row}"
+        );
+        assert_eq!(cells[8], "-", "Unbounded sends never block\nRow:\n{row}");
+    }
+
     // cargo run -p test-channels-ftc --example slow_consumer_ftc --features hotpath
     #[test]
     fn test_slow_consumer_no_panic() {
@@ -177,10 +177,217 @@ pub mod tests {
         );
     }
 
+    // cargo run -p test-channels-std --example inline_std --features hotpath
+    #[test]
+    fn test_inline_mode_output() {
+        let output = Command::new("cargo")
+            .args([
+                "run",
+                "-p",
+                "test-channels-std",
+                "--example",
+                "inline_std",
+                "--features",
+                "hotpath",
+            ])
+            .output()
+            .expect("Failed to execute command");
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let stderr = String::from_utf8_lossy(&output.stderr);
+
+        // The example asserts that try_send fails exactly at capacity
+        assert!(
+            output.status.success(),
+            "Command failed with status: {}\nStdout:\n{}\nStderr:\n{}",
+            output.status,
+            stdout,
+            stderr
+        );
+
+        // Without a proxy slot the queued count matches the channel contents exactly
+        let row = stdout
+            .lines()
+            .find(|line| line.starts_with("| inline-sync"))
+            .unwrap_or_else(|| panic!("Expected inline-sync row\nOutput:\n{stdout}"));
+        let cells: Vec<&str> = row
+            .split('|')
+            .map(str::trim)
+            .filter(|cell| !cell.is_empty())
+            .collect();
+        assert_eq!(
+            cells[1..6],
+            ["bounded[3]", "closed", "4", "1", "3"],
+            "Unexpected inline channel stats\nRow:\n{row}"
+        );
+        assert_eq!(cells[8], "0", "Expected no blocked sends\nRow:\n{row}");
+
+        // The second send waits for the receiver, which starts after a delay
+        let row = stdout
+            .lines()
+            .find(|line| line.starts_with("| inline-blocked"))
+            .unwrap_or_else(|| panic!("Expected inline-blocked row\nOutput:\n{stdout}"));
+        let cells: Vec<&str> = row
+            .split('|')
+            .map(str::trim)
+            .filter(|cell| !cell.is_empty())
+            .collect();
+        assert!(
+            cells[8].starts_with("1 (p95 "),
+            "Expected one blocked send\nRow:\n{row}"
+        );
+    }
+
+    // cargo run -p test-channels-std --example measure_size_std --features hotpath
+    #[test]
+    fn test_measure_size_output() {
+        let output = Command::new("cargo")
+            .args([
+                "run",
+                "-p",
+                "test-channels-std",
+                "--example",
+                "measure_size_std",
+                "--features",
+                "hotpath",
+            ])
+            .output()
+            .expect("Failed to execute command");
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let stderr = String::from_utf8_lossy(&output.stderr);
+
+        assert!(
+            output.status.success(),
+            "Command failed with status: {}\nStdout:\n{}\nStderr:\n{}",
+            output.status,
+            stdout,
+            stderr
+        );
+
+        // Three queued messages own a 1 KB buffer each, size_of alone would report bytes
+        let row = stdout
+            .lines()
+            .find(|line| line.starts_with("| sized-inline"))
+            .unwrap_or_else(|| panic!("Expected sized-inline row\nOutput:\n{stdout}"));
+        let cells: Vec<&str> = row
+            .split('|')
+            .map(str::trim)
+            .filter(|cell| !cell.is_empty())
+            .collect();
+        assert_eq!(cells[5], "4", "Unexpected queued count\nRow:\n{row}");
+        assert!(
+            cells[9].starts_with("3.") && cells[9].ends_with(" KB"),
+            "Expected queued memory to include heap buffers\nRow:\n{row}"
+        );
+
+        assert!(
+            stdout.contains("| sized-proxy"),
+            "Expected sized-proxy row\nOutput:\n{stdout}"
+        );
+    }
+
+    // HOTPATH_METRICS_PORT=6778 HOTPATH_LOGS_REDACT='token=\w+' TEST_SLEEP_SECONDS=10 cargo run -p test-channels-std --example log_filter_std --features hotpath
+    #[test]
+    fn test_log_filter_endpoints() {
+        use hotpath::json::{ChannelLogs, ChannelsJson};
+        use std::{thread::sleep, time::Duration};
+
+        let mut child = Command::new("cargo")
+            .args([
+                "run",
+                "-p",
+                "test-channels-std",
+                "--example",
+                "log_filter_std",
+                "--features",
+                "hotpath",
+            ])
+            .env("HOTPATH_METRICS_PORT", "6778")
+            .env("HOTPATH_LOGS_REDACT", r"token=\w+")
+            .env("TEST_SLEEP_SECONDS", "10")
+            .spawn()
+            .expect("Failed to spawn command");
+
+        let mut channels = None;
+        let mut last_error = None;
+
+        for _attempt in 0..12 {
+            sleep(Duration::from_millis(500));
+
+            match ureq::get("http://localhost:6778/channels").call() {
+                Ok(mut response) => {
+                    let json: ChannelsJson = response
+                        .body_mut()
+                        .read_json()
+                        .expect("Failed to parse channels JSON");
+                    // The inline channel is created after the filtered one is drained
+                    if json.channels.iter().any(|c| c.label == "sampled-inline") {
+                        channels = Some(json);
+                        break;
+                    }
+                    last_error = Some("sampled-inline not reported yet".to_string());
+                }
+                Err(e) => {
+                    last_error = Some(format!("Request error: {}", e));
+                }
+            }
+        }
+
+        let Some(channels) = channels else {
+            let _ = child.kill();
+            panic!("Failed after 12 retries: {:?}", last_error);
+        };
+
+        let logs = |label: &str| -> ChannelLogs {
+            let channel = channels
+                .channels
+                .iter()
+                .find(|channel| channel.label == label)
+                .unwrap_or_else(|| panic!("Expected {label} in /channels response"));
+            ureq::get(&format!(
+                "http://localhost:6778/channels/{}/logs",
+                channel.id
+            ))
+            .call()
+            .expect("Failed to call /channels/:id/logs endpoint")
+            .body_mut()
+            .read_json()
+            .expect("Failed to parse logs JSON")
+        };
+
+        // 5 of 10 requests fail and every second one of those is logged, newest first
+        let filtered = logs("filtered-requests");
+        let messages: Vec<&str> = filtered
+            .sent_logs
+            .iter()
+            .map(|entry| entry.message.as_deref().unwrap_or_default())
+            .collect();
+        assert_eq!(
+            messages,
+            [
+                "500 /fail/9 [REDACTED]",
+                "500 /fail/5 [REDACTED]",
+                "500 /fail/1 [REDACTED]"
+            ],
+        );
+
+        let sampled = logs("sampled-inline");
+        let messages: Vec<&str> = sampled
+            .sent_logs
+            .iter()
+            .map(|entry| entry.message.as_deref().unwrap_or_default())
+            .collect();
+        assert_eq!(messages, ["6", "3", "0"]);
+
+        let _ = child.kill();
+        let _ = child.wait();
+    }
+
     // HOTPATH_METRICS_PORT=6770 TEST_SLEEP_SECONDS=10 cargo run -p test-channels-std --example basic_std --features hotpath
     #[test]
     fn test_data_endpoints() {
-        use hotpath::json::ChannelsJson;
+        use hotpath::json::{ChannelHistory, ChannelsJson};
         use std::{thread::sleep, time::Duration};
 
         let mut child = Command::new("cargo")
@@ -252,6 +459,35 @@ pub mod tests {
             );
         }
 
+        // Test /channels/:id/history endpoint, the first sample is taken after one second
+        let unbounded = channels_response
+            .channels
+            .iter()
+            .find(|channel| channel.label == "unbounded-channel")
+            .expect("Expected unbounded-channel in /channels response");
+        let history_url = format!("http://localhost:6770/channels/{}/history", unbounded.id);
+        let mut history = None;
+        for _attempt in 0..6 {
+            sleep(Duration::from_millis(500));
+            let history_json: ChannelHistory = ureq::get(&history_url)
+                .call()
+                .expect("Failed to call /channels/:id/history endpoint")
+                .body_mut()
+                .read_json()
+                .expect("Failed to parse history JSON");
+            if !history_json.samples.is_empty() {
+                history = Some(history_json);
+                break;
+            }
+        }
+        let history = history.expect("Expected history samples for unbounded-channel");
+        assert_eq!(history.interval_ms, 1000);
+        assert!(
+            history.samples.iter().any(|sample| sample.sent_per_sec > 0),
+            "Expected a non-zero send rate, got {:?}",
+            history.samples
+        );
+
         let _ = child.kill();
         let _ = child.wait();
     }
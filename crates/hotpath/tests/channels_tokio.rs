@@ -248,6 +248,141 @@ pub mod tests {
             "Expected completion message not found.\nOutput:\n{}",
             stdout
         );
+
+        // Consumer sleeps 20ms per message, so queue wait percentiles are in milliseconds
+        let row = stdout
+            .lines()
+            .find(|line| line.starts_with("| slow-consumer"))
+            .unwrap_or_else(|| panic!("Expected slow-consumer row\nOutput:\n{stdout}"));
+        let wait_cells: Vec<&str> = row
+            .split('|')
+            .map(str::trim)
+            .filter(|cell| !cell.is_empty())
+            .rev()
+            .take(3)
+            .collect();
+        for cell in wait_cells {
+            assert!(
+                cell.ends_with(" ms"),
+                "Expected queue wait in milliseconds, got {cell:?}\nRow:\n{row}"
+            );
+        }
+    }
+
+    // cargo run -p test-channels-tokio --example inline_tokio --features hotpath
+    #[test]
+    fn test_inline_mode_output() {
+        let output = Command::new("cargo")
+            .args([
+                "run",
+                "-p",
+                "test-channels-tokio",
+                "--example",
+                "inline_tokio",
+                "--features",
+                "hotpath",
+            ])
+            .output()
+            .expect("Failed to execute command");
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let stderr = String::from_utf8_lossy(&output.stderr);
+
+        // The example asserts that try_send fails exactly at capacity
+        assert!(
+            output.status.success(),
+            "Command failed with status: {}\nStdout:\n{}\nStderr:\n{}",
+            output.status,
+            stdout,
+            stderr
+        );
+
+        // Without a proxy slot the queued count matches the channel contents exactly
+        let row = stdout
+            .lines()
+            .find(|line| line.starts_with("| inline-bounded"))
+            .unwrap_or_else(|| panic!("Expected inline-bounded row\nOutput:\n{stdout}"));
+        let cells: Vec<&str> = row
+            .split('|')
+            .map(str::trim)
+            .filter(|cell| !cell.is_empty())
+            .collect();
+        assert_eq!(
+            cells[1..6],
+            ["bounded[3]", "closed", "4", "1", "3"],
+            "Unexpected inline channel stats\nRow:\n{row}"
+        );
+
+        let row = stdout
+            .lines()
+            .find(|line| line.starts_with("| inline-permits"))
+            .unwrap_or_else(|| panic!("Expected inline-permits row\nOutput:\n{stdout}"));
+        let cells: Vec<&str> = row
+            .split('|')
+            .map(str::trim)
+            .filter(|cell| !cell.is_empty())
+            .collect();
+        assert_eq!(
+            cells[1..6],
+            ["bounded[2]", "closed", "2", "2", "0"],
+            "Unexpected stats for permits and recv_many\nRow:\n{row}"
+        );
+    }
+
+    // cargo run -p test-channels-tokio --example broadcast_watch_tokio --features hotpath
+    #[test]
+    fn test_broadcast_watch_output() {
+        let output = Command::new("cargo")
+            .args([
+                "run",
+                "-p",
+                "test-channels-tokio",
+                "--example",
+                "broadcast_watch_tokio",
+                "--features",
+                "hotpath",
+            ])
+            .output()
+            .expect("Failed to execute command");
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let stderr = String::from_utf8_lossy(&output.stderr);
+
+        assert!(
+            output.status.success(),
+            "Command failed with status: {}\nStdout:\n{}\nStderr:\n{}",
+            output.status,
+            stdout,
+            stderr
+        );
+
+        let cells = |label: &str| -> Vec<String> {
+            let row = stdout
+                .lines()
+                .find(|line| line.starts_with(&format!("| {label} ")))
+                .unwrap_or_else(|| panic!("Expected {label} row\nOutput:\n{stdout}"));
+            row.split('|')
+                .map(str::trim)
+                .filter(|cell| !cell.is_empty())
+                .map(String::from)
+                .collect()
+        };
+
+        // 7 messages, read 7 times by the fast receiver and 4 times by the lagging one
+        assert_eq!(cells("events")[1..5], ["broadcast[4]", "closed", "7", "11"]);
+        assert_eq!(cells("config")[1..4], ["watch", "closed", "3"]);
+
+        let all_expected = [
+            "events: lagged 2, dropped 0, receiver lag [#0: 0, #1: 1, #2: 1]",
+            "unheard: lagged 0, dropped 1",
+            "config: 3 changes, last change",
+        ];
+        for expected in all_expected {
+            assert!(
+                stdout.contains(expected),
+                "Expected:\n{expected}\n\nGot:\n{stdout}",
+            );
+        }
     }
 
     // HOTPATH_METRICS_PORT=6773 TEST_SLEEP_SECONDS=10 cargo run -p test-channels-tokio --example basic_tokio --features hotpath
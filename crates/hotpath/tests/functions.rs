@@ -98,31 +98,47 @@ pub mod tests {
         }
     }
 
-    // cargo run -p test-tokio-async --example unsupported_async --features hotpath,hotpath-alloc
+    // cargo run -p test-tokio-async --example async_multithread_alloc --features hotpath,hotpath-alloc
     #[test]
-    fn test_unsupported_async_output() {
+    fn test_async_multithread_alloc_output() {
         let output = Command::new("cargo")
             .args([
                 "run",
                 "-p",
                 "test-tokio-async",
                 "--example",
-                "unsupported_async",
+                "async_multithread_alloc",
                 "--features",
                 "hotpath,hotpath-alloc",
             ])
+            .env("HOTPATH_JSON", "true")
             .output()
             .expect("Failed to execute command");
-        let stdout = String::from_utf8_lossy(&output.stdout);
 
-        let all_expected = ["N/A*", "only available for tokio current_thread"];
+        assert!(
+            output.status.success(),
+            "Process did not exit successfully.\n\nstderr:\n{}",
+            String::from_utf8_lossy(&output.stderr)
+        );
 
-        for expected in all_expected {
-            assert!(
-                stdout.contains(expected),
-                "Expected:\n{expected}\n\nGot:\n{stdout}",
-            );
-        }
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let json_line = stdout
+            .lines()
+            .find(|line| line.starts_with('{'))
+            .unwrap_or_else(|| panic!("Expected JSON output\n\nGot:\n{stdout}"));
+        let json: serde_json::Value = serde_json::from_str(json_line).unwrap();
+
+        let async_function = json["data"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|f| f["name"] == "async_multithread_alloc::async_function")
+            .unwrap_or_else(|| panic!("Expected async_function\n\nGot:\n{stdout}"));
+
+        // Two vec![i32; 9] allocations per call, regardless of the polling thread
+        assert_eq!(async_function["calls"], 100, "Got:\n{stdout}");
+        assert_eq!(async_function["avg"], 72, "Got:\n{stdout}");
+        assert_eq!(async_function["total"], 7200, "Got:\n{stdout}");
     }
 
     // cargo run -p test-tokio-async --example main_empty --features hotpath
@@ -297,13 +313,17 @@ pub mod tests {
             String::from_utf8_lossy(&output.stderr)
         );
 
-        let all_expected = ["N/A*", "only available for tokio current_thread"];
-
         let stdout = String::from_utf8_lossy(&output.stdout);
-        for expected in all_expected {
+        assert!(
+            stdout.contains("basic_smol::async_function"),
+            "Expected:\nbasic_smol::async_function\n\nGot:\n{stdout}",
+        );
+
+        let not_expected = ["N/A*", "only available for tokio current_thread"];
+        for not_expected in not_expected {
             assert!(
-                stdout.contains(expected),
-                "Expected:\n{expected}\n\nGot:\n{stdout}",
+                !stdout.contains(not_expected),
+                "Not expected:\n{not_expected}\n\nGot:\n{stdout}"
             );
         }
     }
@@ -670,6 +690,153 @@ pub mod tests {
         }
     }
 
+    // HOTPATH_ALLOC_LEAKS=true HOTPATH_ALLOC_LEAKS_SAMPLE=1 HOTPATH_JSON=true cargo run -p test-tokio-async --example alloc_leaks --features hotpath,hotpath-alloc
+    #[test]
+    fn test_alloc_leaks_output() {
+        let output = Command::new("cargo")
+            .args([
+                "run",
+                "-p",
+                "test-tokio-async",
+                "--example",
+                "alloc_leaks",
+                "--features",
+                "hotpath,hotpath-alloc",
+            ])
+            .env("HOTPATH_ALLOC_LEAKS", "true")
+            .env("HOTPATH_ALLOC_LEAKS_SAMPLE", "1")
+            .env("HOTPATH_JSON", "true")
+            .output()
+            .expect("Failed to execute command");
+
+        assert!(
+            output.status.success(),
+            "Process did not exit successfully.\n\nstderr:\n{}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let json_line = stdout
+            .lines()
+            .find(|line| line.starts_with('{'))
+            .unwrap_or_else(|| panic!("Expected JSON output\n\nGot:\n{stdout}"));
+        let json: serde_json::Value = serde_json::from_str(json_line).unwrap();
+
+        let live = json["live_allocs"]["data"]
+            .as_array()
+            .expect("Expected live_allocs section");
+        let names: Vec<_> = live.iter().filter_map(|e| e["name"].as_str()).collect();
+
+        assert!(
+            names.contains(&"alloc_leaks::leaky_function"),
+            "Expected leaky_function in live allocations\n\nGot:\n{stdout}"
+        );
+        assert!(
+            !names.contains(&"alloc_leaks::temporary_function"),
+            "Did not expect temporary_function in live allocations\n\nGot:\n{stdout}"
+        );
+
+        let leaky = live
+            .iter()
+            .find(|e| e["name"] == "alloc_leaks::leaky_function")
+            .unwrap();
+        assert!(leaky["bytes"].as_u64().unwrap() >= 1000 * 4096);
+    }
+
+    // HOTPATH_ALLOC_CALLSITES_BYTES=65536 HOTPATH_JSON=true cargo run -p test-tokio-async --example alloc_callsites --features hotpath,hotpath-alloc
+    #[test]
+    fn test_alloc_callsites_output() {
+        let output = Command::new("cargo")
+            .args([
+                "run",
+                "-p",
+                "test-tokio-async",
+                "--example",
+                "alloc_callsites",
+                "--features",
+                "hotpath,hotpath-alloc",
+            ])
+            .env("HOTPATH_ALLOC_CALLSITES_BYTES", "65536")
+            .env("HOTPATH_JSON", "true")
+            .output()
+            .expect("Failed to execute command");
+
+        assert!(
+            output.status.success(),
+            "Process did not exit successfully.\n\nstderr:\n{}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let json_line = stdout
+            .lines()
+            .find(|line| line.starts_with('{'))
+            .unwrap_or_else(|| panic!("Expected JSON output\n\nGot:\n{stdout}"));
+        let json: serde_json::Value = serde_json::from_str(json_line).unwrap();
+
+        assert_eq!(json["call_sites"]["sample_unit"], "bytes");
+        let sites = json["call_sites"]["data"]
+            .as_array()
+            .expect("Expected call_sites section");
+        let top = sites
+            .iter()
+            .find(|e| e["name"] == "alloc_callsites::build_buffers")
+            .unwrap_or_else(|| panic!("Expected build_buffers call sites\n\nGot:\n{stdout}"));
+
+        // The 64 KB buffer dominates, the 1 KB one is sampled far less often
+        let location = top["location"].as_str().unwrap();
+        assert!(
+            location.contains("alloc_callsites.rs:3"),
+            "Expected the large buffer as the top call site, got {location}"
+        );
+    }
+
+    // HOTPATH_JSON=true cargo run -p test-tokio-async --example alloc_deep_recursion --features hotpath,hotpath-alloc
+    #[test]
+    fn test_alloc_deep_recursion_output() {
+        let output = Command::new("cargo")
+            .args([
+                "run",
+                "-p",
+                "test-tokio-async",
+                "--example",
+                "alloc_deep_recursion",
+                "--features",
+                "hotpath,hotpath-alloc",
+            ])
+            .env("HOTPATH_JSON", "true")
+            .output()
+            .expect("Failed to execute command");
+
+        assert!(
+            output.status.success(),
+            "Process did not exit successfully.\n\nstderr:\n{}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let json_line = stdout
+            .lines()
+            .find(|line| line.starts_with('{'))
+            .unwrap_or_else(|| panic!("Expected JSON output\n\nGot:\n{stdout}"));
+        let json: serde_json::Value = serde_json::from_str(json_line).unwrap();
+
+        let overflows = json["depth_overflows"]
+            .as_array()
+            .expect("Expected depth_overflows section");
+        let recurse = overflows
+            .iter()
+            .find(|e| e["name"] == "alloc_deep_recursion::recurse")
+            .unwrap_or_else(|| panic!("Expected recurse in depth_overflows\n\nGot:\n{stdout}"));
+
+        // Outer calls fit on the stack, only the deepest ones overflow
+        let count = recurse["count"].as_u64().unwrap();
+        assert!(
+            count > 0 && count < 2010,
+            "Unexpected overflow count {count}"
+        );
+    }
+
     // HOTPATH_METRICS_PORT=6775 TEST_SLEEP_SECONDS=10 cargo run -p test-tokio-async --example basic --features hotpath,hotpath-alloc
     #[test]
     fn test_data_endpoints() {
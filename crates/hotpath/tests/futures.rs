@@ -104,10 +104,182 @@ pub mod tests {
         );
     }
 
+    // HOTPATH_LOGS_LIMIT=3 cargo run -p test-futures --example lifetime_futures --features hotpath
+    #[test]
+    fn test_lifetime_aggregates() {
+        use hotpath::json::FuturesJson;
+
+        let output = Command::new("cargo")
+            .args([
+                "run",
+                "-p",
+                "test-futures",
+                "--example",
+                "lifetime_futures",
+                "--features",
+                "hotpath",
+            ])
+            .env("HOTPATH_LOGS_LIMIT", "3")
+            .env("HOTPATH_METRICS_SERVER_OFF", "true")
+            .output()
+            .expect("Failed to execute command");
+
+        assert!(
+            output.status.success(),
+            "Command failed with status: {}",
+            output.status
+        );
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let json_line = stdout
+            .lines()
+            .find(|line| line.starts_with('{'))
+            .unwrap_or_else(|| panic!("No JSON report in output:\n{stdout}"));
+        let futures: FuturesJson =
+            serde_json::from_str(json_line).expect("Failed to parse futures JSON");
+
+        let site = |call_count: u64| {
+            futures
+                .futures
+                .iter()
+                .find(|future| future.call_count == call_count)
+                .unwrap_or_else(|| panic!("No future with {call_count} calls in {json_line}"))
+        };
+
+        // Aggregates cover all 10 calls even though only 3 are retained
+        let completed = site(10);
+        assert_eq!(completed.total_polls, 20);
+        assert_eq!(completed.completed_count, 10);
+        assert_eq!(completed.cancelled_count, 0);
+        assert_eq!(completed.in_flight, 0);
+        assert!(completed.lifetime_p95_ns.is_some());
+        assert!(completed.lifetime_max_ns >= 1_000_000);
+
+        let cancelled = site(3);
+        assert_eq!(cancelled.completed_count, 0);
+        assert_eq!(cancelled.cancelled_count, 3);
+        assert_eq!(cancelled.in_flight, 0);
+        assert!(cancelled.lifetime_p95_ns.is_none());
+
+        let in_flight = site(1);
+        assert_eq!(in_flight.total_polls, 0);
+        assert_eq!(in_flight.in_flight, 1);
+    }
+
+    // cargo run -p test-futures --example nested_futures --features hotpath
+    #[test]
+    fn test_nested_futures() {
+        use hotpath::json::FuturesJson;
+
+        let output = Command::new("cargo")
+            .args([
+                "run",
+                "-p",
+                "test-futures",
+                "--example",
+                "nested_futures",
+                "--features",
+                "hotpath",
+            ])
+            .env("HOTPATH_METRICS_SERVER_OFF", "true")
+            .output()
+            .expect("Failed to execute command");
+
+        assert!(
+            output.status.success(),
+            "Command failed with status: {}",
+            output.status
+        );
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let json_line = stdout
+            .lines()
+            .find(|line| line.starts_with('{'))
+            .unwrap_or_else(|| panic!("No JSON report in output:\n{stdout}"));
+        let futures: FuturesJson =
+            serde_json::from_str(json_line).expect("Failed to parse futures JSON");
+        let site = |line: &str| {
+            futures
+                .futures
+                .iter()
+                .find(|future| {
+                    future
+                        .source
+                        .ends_with(&format!("nested_futures.rs:{line}"))
+                })
+                .unwrap_or_else(|| panic!("No future at line {line} in {json_line}"))
+        };
+
+        let handler = site("14");
+        let query = site("16");
+        let retry = site("18");
+
+        assert!(handler.parent_ids.is_empty());
+        assert_eq!(query.parent_ids, vec![handler.id]);
+        assert_eq!(retry.parent_ids, vec![query.id]);
+        assert!(retry.children.is_empty());
+
+        let [query_child] = handler.children.as_slice() else {
+            panic!("Expected a single child, got {:?}", handler.children);
+        };
+        assert_eq!(query_child.future_id, query.id);
+        assert_eq!(query_child.calls, 2);
+        assert_eq!(query_child.total_lifetime_ns, query.total_lifetime_ns);
+        // The handler also sleeps on its own after the queries
+        assert!(handler.total_lifetime_ns > query_child.total_lifetime_ns);
+
+        let [retry_child] = query.children.as_slice() else {
+            panic!("Expected a single child, got {:?}", query.children);
+        };
+        assert_eq!(retry_child.future_id, retry.id);
+        assert_eq!(retry_child.calls, 6);
+        assert!(retry_child.total_lifetime_ns >= 6_000_000);
+        assert!(query.total_lifetime_ns >= retry_child.total_lifetime_ns);
+    }
+
+    // cargo run -p test-futures --example wake_futures --features hotpath
+    #[test]
+    fn test_wake_stats_output() {
+        let output = Command::new("cargo")
+            .args([
+                "run",
+                "-p",
+                "test-futures",
+                "--example",
+                "wake_futures",
+                "--features",
+                "hotpath",
+            ])
+            .output()
+            .expect("Failed to execute command");
+
+        assert!(
+            output.status.success(),
+            "Command failed with status: {}",
+            output.status
+        );
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+
+        let sep = path_sep();
+        // Calls, polls, wakes and spurious polls
+        let all_expected = [
+            format!("| examples{sep}wake_futures.rs:15 | 1     | 4     | 3     | 0        |"),
+            format!("| examples{sep}wake_futures.rs:25 | 1     | 7     | 1     | 5        |"),
+        ];
+
+        for expected in all_expected {
+            assert!(
+                stdout.contains(&expected),
+                "Expected:\n{expected}\n\nGot:\n{stdout}",
+            );
+        }
+    }
+
     // HOTPATH_METRICS_PORT=6775 TEST_SLEEP_SECONDS=10 cargo run -p test-futures --example basic_futures --features hotpath
     #[test]
     fn test_data_endpoints() {
-        use hotpath::json::FuturesJson;
+        use hotpath::json::{FutureCalls, FuturesJson};
         use std::{thread::sleep, time::Duration};
 
         let mut child = Command::new("cargo")
@@ -165,6 +337,33 @@ pub mod tests {
         let futures_response: FuturesJson =
             serde_json::from_str(&json_text).expect("Failed to parse futures JSON");
 
+        // The future at line 89 sleeps 20ms inside a single poll
+        let blocking = futures_response
+            .futures
+            .iter()
+            .find(|future| future.source.ends_with("basic_futures.rs:89"))
+            .unwrap_or_else(|| panic!("Expected blocking future\n\nGot:\n{json_text}"));
+        assert!(
+            blocking.poll_max_ns >= 20_000_000,
+            "Expected a 20ms poll, got {}ns",
+            blocking.poll_max_ns
+        );
+        assert!(blocking.poll_p95_ns.is_some_and(|p95| p95 >= 19_000_000));
+
+        let blocking_calls: FutureCalls = ureq::get(&format!(
+            "http://localhost:6775/futures/{}/calls",
+            blocking.id
+        ))
+        .call()
+        .expect("Failed to call /futures/{id}/calls endpoint")
+        .body_mut()
+        .read_json()
+        .expect("Failed to parse calls JSON");
+        let call = &blocking_calls.calls[0];
+        assert_eq!(call.poll_count, 1);
+        assert!(call.max_poll_ns >= 20_000_000);
+        assert_eq!(call.busy_ns, call.max_poll_ns);
+
         if let Some(first_future) = futures_response.futures.first() {
             let calls_url = format!("http://localhost:6775/futures/{}/calls", first_future.id);
             let mut response = ureq::get(&calls_url)
@@ -192,4 +391,191 @@ pub mod tests {
         let _ = child.kill();
         let _ = child.wait();
     }
+
+    // HOTPATH_METRICS_PORT=6779 HOTPATH_BLOCKING_POLL_MS=5 HOTPATH_BLOCKING_POLL_WARN=1 TEST_SLEEP_SECONDS=10 cargo run -p test-futures --example blocking_futures --features hotpath
+    #[test]
+    fn test_blocking_polls_endpoint() {
+        use hotpath::json::{BlockingPollSource, BlockingPollsJson, FuturesJson};
+        use std::process::Stdio;
+        use std::{thread::sleep, time::Duration};
+
+        let mut child = Command::new("cargo")
+            .args([
+                "run",
+                "-p",
+                "test-futures",
+                "--example",
+                "blocking_futures",
+                "--features",
+                "hotpath",
+            ])
+            .env("HOTPATH_METRICS_PORT", "6779")
+            .env("HOTPATH_BLOCKING_POLL_MS", "5")
+            .env("HOTPATH_BLOCKING_POLL_WARN", "1")
+            .env("TEST_SLEEP_SECONDS", "10")
+            .stderr(Stdio::piped())
+            .spawn()
+            .expect("Failed to spawn command");
+
+        let mut blocking_polls = None;
+        let mut last_error = None;
+
+        for _attempt in 0..12 {
+            sleep(Duration::from_millis(500));
+
+            match ureq::get("http://localhost:6779/blocking_polls").call() {
+                Ok(mut response) => {
+                    let json: BlockingPollsJson = response
+                        .body_mut()
+                        .read_json()
+                        .expect("Failed to parse blocking polls JSON");
+                    // The measured function runs last
+                    if json.total == 2 {
+                        blocking_polls = Some(json);
+                        break;
+                    }
+                    last_error = Some(format!("Expected 2 blocking polls, got {}", json.total));
+                }
+                Err(e) => {
+                    last_error = Some(format!("Request error: {}", e));
+                }
+            }
+        }
+
+        let Some(blocking_polls) = blocking_polls else {
+            let _ = child.kill();
+            panic!("Failed after 12 retries: {:?}", last_error);
+        };
+
+        assert_eq!(blocking_polls.threshold_ns, Some(5_000_000));
+        let [function, future] = blocking_polls.polls.as_slice() else {
+            panic!("Expected 2 blocking polls, got {:?}", blocking_polls.polls);
+        };
+        assert_eq!(function.source, BlockingPollSource::Function);
+        assert_eq!(function.site, "blocking_futures::blocking_function");
+        assert_eq!(future.source, BlockingPollSource::Future);
+        assert!(future.site.ends_with("blocking_futures.rs:22"));
+        for poll in &blocking_polls.polls {
+            assert!(poll.duration_ns >= 20_000_000, "Unexpected poll {poll:?}");
+            assert_eq!(poll.threshold_ns, 5_000_000);
+        }
+
+        // The future at line 38 sleeps as long but has its own 100ms threshold
+        let futures: FuturesJson = ureq::get("http://localhost:6779/futures")
+            .call()
+            .expect("Failed to call /futures endpoint")
+            .body_mut()
+            .read_json()
+            .expect("Failed to parse futures JSON");
+        let blocking_counts: Vec<(&str, u64)> = futures
+            .futures
+            .iter()
+            .map(|future| (future.label.as_str(), future.blocking_polls))
+            .collect();
+        for (label, expected) in [
+            ("blocking_futures.rs:22", 1),
+            ("blocking_futures.rs:29", 0),
+            ("blocking_futures.rs:38", 0),
+        ] {
+            assert!(
+                blocking_counts
+                    .iter()
+                    .any(|&(l, count)| l.ends_with(label) && count == expected),
+                "Expected {expected} blocking polls for {label}, got {blocking_counts:?}"
+            );
+        }
+
+        let _ = child.kill();
+        let output = child.wait_with_output().expect("Failed to read stderr");
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        assert_eq!(
+            stderr.matches("[hotpath] Blocking poll in").count(),
+            2,
+            "Expected a warning per blocking poll\nStderr:\n{stderr}"
+        );
+    }
+
+    // HOTPATH_METRICS_PORT=6783 HOTPATH_STUCK_FUTURE_MS=200 TEST_SLEEP_SECONDS=10 cargo run -p test-futures --example stuck_futures --features hotpath
+    #[test]
+    fn test_in_flight_futures_endpoint() {
+        use hotpath::json::{FutureState, InFlightFuturesJson};
+        use std::process::Stdio;
+        use std::{thread::sleep, time::Duration};
+
+        let mut child = Command::new("cargo")
+            .args([
+                "run",
+                "-p",
+                "test-futures",
+                "--example",
+                "stuck_futures",
+                "--features",
+                "hotpath",
+            ])
+            .env("HOTPATH_METRICS_PORT", "6783")
+            .env("HOTPATH_STUCK_FUTURE_MS", "200")
+            .env("TEST_SLEEP_SECONDS", "10")
+            .stderr(Stdio::piped())
+            .spawn()
+            .expect("Failed to spawn command");
+
+        let mut in_flight = None;
+        let mut last_error = None;
+
+        for _attempt in 0..12 {
+            sleep(Duration::from_millis(500));
+
+            match ureq::get("http://localhost:6783/in_flight_futures").call() {
+                Ok(mut response) => {
+                    let json: InFlightFuturesJson = response
+                        .body_mut()
+                        .read_json()
+                        .expect("Failed to parse in-flight futures JSON");
+                    if json.stuck == 2 {
+                        in_flight = Some(json);
+                        break;
+                    }
+                    last_error = Some(format!("Expected 2 stuck futures, got {}", json.stuck));
+                }
+                Err(e) => {
+                    last_error = Some(format!("Request error: {}", e));
+                }
+            }
+        }
+
+        let Some(in_flight) = in_flight else {
+            let _ = child.kill();
+            let _ = child.wait();
+            panic!("Failed after 12 retries: {:?}", last_error);
+        };
+
+        assert_eq!(in_flight.threshold_ns, Some(200_000_000));
+        assert_eq!(in_flight.total, 2);
+        // Oldest first, the completed futures at line 20 are not listed
+        let [hung, leaked] = in_flight.calls.as_slice() else {
+            panic!("Expected 2 in-flight calls, got {:?}", in_flight.calls);
+        };
+        assert!(hung.label.ends_with("stuck_futures.rs:14"));
+        assert_eq!(hung.state, FutureState::Suspended);
+        assert_eq!(hung.poll_count, 1);
+        assert!(hung.last_poll_ns.is_some());
+        assert!(leaked.label.ends_with("stuck_futures.rs:17"));
+        assert_eq!(leaked.state, FutureState::Pending);
+        assert_eq!(leaked.poll_count, 0);
+        assert!(leaked.last_poll_ns.is_none());
+        for call in &in_flight.calls {
+            assert!(call.stuck);
+            assert!(call.age_ns > 200_000_000, "Unexpected call {call:?}");
+        }
+        assert!(hung.created_at_ns <= leaked.created_at_ns);
+
+        let _ = child.kill();
+        let output = child.wait_with_output().expect("Failed to read stderr");
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        assert_eq!(
+            stderr.matches("in flight for").count(),
+            2,
+            "Expected a single warning per stuck call\nStderr:\n{stderr}"
+        );
+    }
 }
@@ -0,0 +1,120 @@
+#[cfg(test)]
+pub mod tests {
+    use std::process::Command;
+
+    fn lock_row<'a>(stdout: &'a str, label: &str) -> Vec<&'a str> {
+        let row = stdout
+            .lines()
+            .find(|line| line.starts_with(&format!("| {label} ")))
+            .unwrap_or_else(|| panic!("Expected {label} row\nOutput:\n{stdout}"));
+        row.split('|')
+            .map(str::trim)
+            .filter(|cell| !cell.is_empty())
+            .collect()
+    }
+
+    // cargo run -p test-locks --example basic_locks --features hotpath
+    #[test]
+    fn test_basic_locks_output() {
+        let output = Command::new("cargo")
+            .args([
+                "run",
+                "-p",
+                "test-locks",
+                "--example",
+                "basic_locks",
+                "--features",
+                "hotpath",
+            ])
+            .output()
+            .expect("Failed to execute command");
+
+        assert!(
+            output.status.success(),
+            "Command failed with status: {}",
+            output.status
+        );
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        assert!(
+            stdout.contains("=== Lock Statistics"),
+            "Expected lock statistics header\nOutput:\n{stdout}"
+        );
+        assert!(
+            stdout.contains("basic_locks.rs:"),
+            "Expected unlabeled lock to fall back to its source location\nOutput:\n{stdout}"
+        );
+
+        // The worker blocks on the counter exactly once while main holds it
+        let counter = lock_row(&stdout, "counter");
+        assert_eq!(
+            counter[1..4],
+            ["std::Mutex", "5", "1"],
+            "Unexpected counter stats\nRow:\n{counter:?}"
+        );
+        assert_eq!(counter[9], "-", "Counter should be released at exit");
+
+        let config = lock_row(&stdout, "config");
+        assert_eq!(config[1..3], ["std::RwLock", "4"]);
+
+        let pl_mutex = lock_row(&stdout, "pl-mutex");
+        assert_eq!(pl_mutex[1..4], ["parking_lot::Mutex", "4", "0"]);
+
+        let pl_rwlock = lock_row(&stdout, "pl-rwlock");
+        assert_eq!(pl_rwlock[1..3], ["parking_lot::RwLock", "2"]);
+    }
+
+    // cargo run -p test-locks --example tokio_locks --features hotpath
+    #[test]
+    fn test_tokio_locks_output() {
+        let output = Command::new("cargo")
+            .args([
+                "run",
+                "-p",
+                "test-locks",
+                "--example",
+                "tokio_locks",
+                "--features",
+                "hotpath",
+            ])
+            .output()
+            .expect("Failed to execute command");
+
+        assert!(
+            output.status.success(),
+            "Command failed with status: {}",
+            output.status
+        );
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+
+        let state = lock_row(&stdout, "tokio-state");
+        assert_eq!(
+            state[1..4],
+            ["tokio::Mutex", "3", "1"],
+            "Unexpected tokio-state stats\nRow:\n{state:?}"
+        );
+
+        let cache = lock_row(&stdout, "tokio-cache");
+        assert_eq!(cache[1..4], ["tokio::RwLock", "4", "0"]);
+    }
+
+    // cargo run -p test-locks --example basic_locks
+    #[test]
+    fn test_locks_without_hotpath_feature() {
+        let output = Command::new("cargo")
+            .args(["run", "-p", "test-locks", "--example", "basic_locks"])
+            .output()
+            .expect("Failed to execute command");
+
+        assert!(
+            output.status.success(),
+            "Command failed with status: {}",
+            output.status
+        );
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        assert!(stdout.contains("counter = 3"));
+        assert!(!stdout.contains("Lock Statistics"));
+    }
+}
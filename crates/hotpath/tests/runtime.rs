@@ -0,0 +1,105 @@
+#[cfg(test)]
+pub mod tests {
+    use hotpath::json::RuntimeJson;
+    use std::process::Command;
+    use std::{thread::sleep, time::Duration};
+
+    // HOTPATH_METRICS_PORT=6782 TEST_SLEEP_SECONDS=10 cargo run -p test-tokio-async --example runtime_metrics --features hotpath
+    #[test]
+    fn test_runtime_endpoint() {
+        let mut child = Command::new("cargo")
+            .args([
+                "run",
+                "-p",
+                "test-tokio-async",
+                "--example",
+                "runtime_metrics",
+                "--features",
+                "hotpath",
+            ])
+            .env("HOTPATH_METRICS_PORT", "6782")
+            .env("HOTPATH_RUNTIME_INTERVAL", "100")
+            .env("TEST_SLEEP_SECONDS", "10")
+            .spawn()
+            .expect("Failed to spawn command");
+
+        let mut last_error = None;
+        let mut runtime = None;
+
+        for _attempt in 0..30 {
+            sleep(Duration::from_millis(1000));
+
+            match ureq::get("http://localhost:6782/runtime").call() {
+                Ok(mut response) => {
+                    let json: RuntimeJson = response
+                        .body_mut()
+                        .read_json()
+                        .expect("Failed to parse runtime JSON");
+                    if json.samples.len() >= 3 {
+                        runtime = Some(json);
+                        break;
+                    }
+                    last_error = Some(format!("Expected 3 samples, got {}", json.samples.len()));
+                }
+                Err(e) => {
+                    last_error = Some(format!("Request error: {}", e));
+                }
+            }
+        }
+
+        let _ = child.kill();
+        let _ = child.wait();
+        let runtime =
+            runtime.unwrap_or_else(|| panic!("Failed after 30 retries: {:?}", last_error));
+
+        assert!(runtime.registered);
+        assert_eq!(runtime.flavor.as_deref(), Some("multi_thread"));
+        assert_eq!(runtime.sample_interval_ms, 100);
+
+        let last = runtime.samples.last().unwrap();
+        assert_eq!(last.num_workers, 2);
+        assert_eq!(last.worker_park_counts.len(), 2);
+        assert!(runtime
+            .samples
+            .windows(2)
+            .all(|pair| pair[0].timestamp_ns < pair[1].timestamp_ns));
+    }
+
+    // cargo run -p test-tokio-async --example runtime_metrics --features hotpath
+    #[test]
+    fn test_runtime_summary_output() {
+        let output = Command::new("cargo")
+            .args([
+                "run",
+                "-p",
+                "test-tokio-async",
+                "--example",
+                "runtime_metrics",
+                "--features",
+                "hotpath",
+            ])
+            .env("HOTPATH_METRICS_SERVER_OFF", "true")
+            .output()
+            .expect("Failed to execute command");
+
+        assert!(
+            output.status.success(),
+            "Process did not exit successfully.\n\nstderr:\n{}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+
+        let all_expected = [
+            "tokio-runtime - multi_thread runtime with 2 workers",
+            "| Alive tasks        | 0    |",
+            "Worker parks",
+        ];
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        for expected in all_expected {
+            assert!(
+                stdout.contains(expected),
+                "Expected:\n{expected}\n\nGot:\n{stdout}",
+            );
+        }
+    }
+}
@@ -0,0 +1,146 @@
+#[cfg(test)]
+pub mod tests {
+    use std::process::Command;
+
+    // cargo run -p test-streams --example basic_sinks --features hotpath
+    #[test]
+    fn test_basic_sinks_output() {
+        let output = Command::new("cargo")
+            .args([
+                "run",
+                "-p",
+                "test-streams",
+                "--example",
+                "basic_sinks",
+                "--features",
+                "hotpath",
+            ])
+            .output()
+            .expect("Failed to execute command");
+
+        assert!(
+            output.status.success(),
+            "Command failed with status: {}",
+            output.status
+        );
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+
+        let all_expected = [
+            "Sink example completed!",
+            "Sinks:",
+            "slow-writer",
+            "drain-sink",
+            "Backpressure",
+            "Flush P95",
+        ];
+
+        for expected in all_expected {
+            assert!(
+                stdout.contains(expected),
+                "Expected:\n{expected}\n\nGot:\n{stdout}",
+            );
+        }
+
+        // Both sinks are closed before the guard prints
+        let closed_count = stdout.matches("| closed").count();
+        assert_eq!(closed_count, 2, "Output:\n{stdout}");
+    }
+
+    // HOTPATH_METRICS_PORT=6784 TEST_SLEEP_SECONDS=10 cargo run -p test-streams --example basic_sinks --features hotpath
+    #[test]
+    fn test_sinks_endpoints() {
+        use hotpath::sinks::{SinkLogs, SinksJson};
+        use std::{thread::sleep, time::Duration};
+
+        let mut child = Command::new("cargo")
+            .args([
+                "run",
+                "-p",
+                "test-streams",
+                "--example",
+                "basic_sinks",
+                "--features",
+                "hotpath",
+            ])
+            .env("HOTPATH_METRICS_PORT", "6784")
+            .env("TEST_SLEEP_SECONDS", "10")
+            .spawn()
+            .expect("Failed to spawn command");
+
+        let mut sinks_json = None;
+        let mut last_error = None;
+
+        for _attempt in 0..12 {
+            sleep(Duration::from_millis(500));
+
+            match ureq::get("http://localhost:6784/sinks").call() {
+                Ok(mut response) => {
+                    let json: SinksJson = response
+                        .body_mut()
+                        .read_json()
+                        .expect("Failed to parse sinks JSON");
+                    if json.sinks.iter().all(|sink| sink.close_ns.is_some())
+                        && json.sinks.len() == 2
+                    {
+                        sinks_json = Some(json);
+                        break;
+                    }
+                    last_error = Some("sinks not closed yet".to_string());
+                }
+                Err(e) => {
+                    last_error = Some(format!("Request error: {}", e));
+                }
+            }
+        }
+
+        let Some(sinks_json) = sinks_json else {
+            let _ = child.kill();
+            let _ = child.wait();
+            panic!("Failed after 12 retries: {:?}", last_error);
+        };
+
+        let ms = 1_000_000;
+        let slow = sinks_json
+            .sinks
+            .iter()
+            .find(|sink| sink.label == "slow-writer")
+            .expect("Expected slow-writer");
+        assert_eq!(slow.items_sent, 5);
+        // Every write after the first waits for the previous one
+        assert!(slow.pending_ready_polls >= 4, "{slow:?}");
+        assert!(slow.backpressure_ns >= 30 * ms, "{slow:?}");
+        assert_eq!(slow.flushes, 1);
+        assert!(slow.flush_p50_ns.unwrap() >= 5 * ms, "{slow:?}");
+        assert!(slow.flush_max_ns >= slow.flush_p99_ns.unwrap());
+        assert_eq!(slow.type_name, "alloc::string::String");
+
+        let drain = sinks_json
+            .sinks
+            .iter()
+            .find(|sink| sink.label == "drain-sink")
+            .expect("Expected drain-sink");
+        assert_eq!(drain.items_sent, 3);
+        assert_eq!(drain.pending_ready_polls, 0);
+        assert_eq!(drain.backpressure_ns, 0);
+        // `SinkExt::send` flushes after every item
+        assert_eq!(drain.flushes, 3);
+
+        // Logged items, newest first
+        let logs: SinkLogs = ureq::get(&format!("http://localhost:6784/sinks/{}/logs", drain.id))
+            .call()
+            .expect("Failed to call /sinks/:id/logs endpoint")
+            .body_mut()
+            .read_json()
+            .expect("Failed to parse sink logs JSON");
+        let messages: Vec<&str> = logs
+            .logs
+            .iter()
+            .map(|entry| entry.message.as_deref().unwrap_or_default())
+            .collect();
+        assert_eq!(messages, ["3", "2", "1"]);
+
+        let _ = child.kill();
+        let _ = child.wait();
+    }
+}
@@ -80,10 +80,71 @@ pub mod tests {
         );
     }
 
+    // cargo run -p test-streams --example stream_timing --features hotpath
+    #[test]
+    fn test_stream_timing() {
+        use hotpath::streams::StreamsJson;
+
+        let output = Command::new("cargo")
+            .args([
+                "run",
+                "-p",
+                "test-streams",
+                "--example",
+                "stream_timing",
+                "--features",
+                "hotpath",
+            ])
+            .output()
+            .expect("Failed to execute command");
+
+        assert!(
+            output.status.success(),
+            "Command failed with status: {}",
+            output.status
+        );
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let json_line = stdout
+            .lines()
+            .find(|line| line.starts_with('{'))
+            .unwrap_or_else(|| panic!("Expected JSON output\n\nGot:\n{stdout}"));
+        let streams_json: StreamsJson =
+            serde_json::from_str(json_line).expect("Failed to parse streams JSON");
+
+        let ms = 1_000_000;
+        let slow = streams_json
+            .streams
+            .iter()
+            .find(|stream| stream.label == "slow-ticks")
+            .unwrap_or_else(|| panic!("Expected slow-ticks\n\nGot:\n{stdout}"));
+        assert_eq!(slow.items_yielded, 4);
+        assert!(slow.pending_polls >= 4, "{slow:?}");
+        assert!(slow.inter_item_p50_ns.unwrap() >= 15 * ms, "{slow:?}");
+        assert!(slow.inter_item_max_ns >= slow.inter_item_p99_ns.unwrap());
+        assert!(slow.time_to_first_item_ns.unwrap() >= 15 * ms, "{slow:?}");
+        assert!(slow.time_to_completion_ns.unwrap() >= 75 * ms, "{slow:?}");
+        // Waiting on the timer is not spent inside poll_next
+        assert!(
+            slow.busy_ns < slow.time_to_completion_ns.unwrap(),
+            "{slow:?}"
+        );
+
+        let ready = streams_json
+            .streams
+            .iter()
+            .find(|stream| stream.label == "ready-items")
+            .unwrap_or_else(|| panic!("Expected ready-items\n\nGot:\n{stdout}"));
+        assert_eq!(ready.items_yielded, 3);
+        assert_eq!(ready.pending_polls, 0);
+        assert!(ready.time_to_first_item_ns.is_some());
+        assert!(ready.time_to_completion_ns.is_some());
+    }
+
     // HOTPATH_METRICS_PORT=6774 TEST_SLEEP_SECONDS=10 cargo run -p test-streams --example basic_streams --features hotpath
     #[test]
     fn test_data_endpoints() {
-        use hotpath::streams::StreamsJson;
+        use hotpath::streams::{StreamLogs, StreamsJson};
         use std::{thread::sleep, time::Duration};
 
         let mut child = Command::new("cargo")
@@ -116,8 +177,12 @@ pub mod tests {
                         .body_mut()
                         .read_to_string()
                         .expect("Failed to read response body");
-                    last_error = None;
-                    break;
+                    // The last stream is created after the slower text stream completes
+                    if json_text.contains("sized-stream") {
+                        last_error = None;
+                        break;
+                    }
+                    last_error = Some("sized-stream not reported yet".to_string());
                 }
                 Err(e) => {
                     last_error = Some(format!("Request error: {}", e));
@@ -142,6 +207,44 @@ pub mod tests {
         let streams_response: StreamsJson =
             serde_json::from_str(&json_text).expect("Failed to parse streams JSON");
 
+        // Each yielded String owns 100 bytes on the heap
+        let sized_stream = streams_response
+            .streams
+            .iter()
+            .find(|stream| stream.label == "sized-stream")
+            .unwrap_or_else(|| panic!("Expected sized-stream\n\nGot:\n{json_text}"));
+        assert_eq!(
+            sized_stream.yielded_bytes,
+            Some(3 * (100 + std::mem::size_of::<String>() as u64)),
+        );
+        assert!(streams_response
+            .streams
+            .iter()
+            .filter(|stream| stream.label != "sized-stream")
+            .all(|stream| stream.yielded_bytes.is_none()));
+
+        // Every second even number is logged, newest first
+        let filtered_stream = streams_response
+            .streams
+            .iter()
+            .find(|stream| stream.label == "filtered-stream")
+            .unwrap_or_else(|| panic!("Expected filtered-stream\n\nGot:\n{json_text}"));
+        let logs: StreamLogs = ureq::get(&format!(
+            "http://localhost:6774/streams/{}/logs",
+            filtered_stream.id
+        ))
+        .call()
+        .expect("Failed to call /streams/:id/logs endpoint")
+        .body_mut()
+        .read_json()
+        .expect("Failed to parse stream logs JSON");
+        let messages: Vec<&str> = logs
+            .logs
+            .iter()
+            .map(|entry| entry.message.as_deref().unwrap_or_default())
+            .collect();
+        assert_eq!(messages, ["10", "6", "2"]);
+
         if let Some(first_stream) = streams_response.streams.first() {
             let logs_url = format!("http://localhost:6774/streams/{}/logs", first_stream.id);
             let response = ureq::get(&logs_url)
@@ -0,0 +1,135 @@
+#[cfg(test)]
+pub mod tests {
+    use hotpath::json::{SerializableTaskStats, TaskKind, TaskState, TasksJson};
+    use std::process::{Child, Command};
+    use std::{thread::sleep, time::Duration};
+
+    fn fetch_tasks(child: &mut Child, port: u16, expected_finished: usize) -> TasksJson {
+        let mut last_error = None;
+
+        for _attempt in 0..30 {
+            sleep(Duration::from_millis(1000));
+
+            match ureq::get(&format!("http://localhost:{port}/tasks")).call() {
+                Ok(mut response) => {
+                    let json: TasksJson = response
+                        .body_mut()
+                        .read_json()
+                        .expect("Failed to parse tasks JSON");
+                    let finished = json
+                        .tasks
+                        .iter()
+                        .filter(|task| task.state.is_finished())
+                        .count();
+                    if finished == expected_finished {
+                        return json;
+                    }
+                    last_error = Some(format!(
+                        "Expected {expected_finished} finished tasks, got {finished}"
+                    ));
+                }
+                Err(e) => {
+                    last_error = Some(format!("Request error: {}", e));
+                }
+            }
+        }
+
+        let _ = child.kill();
+        panic!("Failed after 30 retries: {:?}", last_error);
+    }
+
+    fn task<'a>(tasks: &'a TasksJson, label: &str) -> &'a SerializableTaskStats {
+        tasks
+            .tasks
+            .iter()
+            .find(|task| task.label == label)
+            .unwrap_or_else(|| panic!("Expected {label} task in {:?}", tasks.tasks))
+    }
+
+    // HOTPATH_METRICS_PORT=6780 TEST_SLEEP_SECONDS=10 cargo run -p test-tasks --example basic_tasks --features hotpath
+    #[test]
+    fn test_tokio_tasks_endpoint() {
+        let mut child = Command::new("cargo")
+            .args([
+                "run",
+                "-p",
+                "test-tasks",
+                "--example",
+                "basic_tasks",
+                "--features",
+                "hotpath",
+            ])
+            .env("HOTPATH_METRICS_PORT", "6780")
+            .env("TEST_SLEEP_SECONDS", "10")
+            .spawn()
+            .expect("Failed to spawn command");
+
+        let tasks = fetch_tasks(&mut child, 6780, 5);
+        let _ = child.kill();
+
+        assert_eq!(tasks.total_spawned, 6);
+        assert_eq!(tasks.live, 1);
+
+        let parent = task(&tasks, "parent");
+        assert_eq!(parent.kind, TaskKind::Tokio);
+        assert_eq!(parent.state, TaskState::Completed);
+        assert_eq!(parent.parent_id, None);
+        assert!(parent.source.contains("basic_tasks.rs:"));
+
+        let child_task = task(&tasks, "child");
+        assert_eq!(child_task.state, TaskState::Completed);
+        assert_eq!(child_task.parent_id, Some(parent.id));
+        assert_eq!(child_task.poll_count, 2);
+        assert!(child_task.finished_at_ns.is_some());
+
+        let checksum = task(&tasks, "checksum");
+        assert_eq!(checksum.kind, TaskKind::TokioBlocking);
+        assert_eq!(checksum.state, TaskState::Completed);
+        assert_eq!(checksum.poll_count, 1);
+
+        let panicker = task(&tasks, "panicker");
+        assert_eq!(panicker.state, TaskState::Panicked);
+        assert_eq!(panicker.panic_message.as_deref(), Some("boom"));
+
+        assert_eq!(task(&tasks, "sleeper").state, TaskState::Aborted);
+
+        // Unlabeled tasks fall back to their spawn location
+        let worker = tasks
+            .tasks
+            .first()
+            .expect("Expected live tasks to be listed first");
+        assert!(
+            !worker.has_custom_label && worker.label.contains("basic_tasks.rs:"),
+            "Unexpected worker {worker:?}"
+        );
+        assert_eq!(worker.state, TaskState::Idle);
+        assert_eq!(worker.finished_at_ns, None);
+    }
+
+    // HOTPATH_METRICS_PORT=6781 TEST_SLEEP_SECONDS=10 cargo run -p test-tasks --example smol_tasks --features hotpath
+    #[test]
+    fn test_smol_tasks_endpoint() {
+        let mut child = Command::new("cargo")
+            .args([
+                "run",
+                "-p",
+                "test-tasks",
+                "--example",
+                "smol_tasks",
+                "--features",
+                "hotpath",
+            ])
+            .env("HOTPATH_METRICS_PORT", "6781")
+            .env("TEST_SLEEP_SECONDS", "10")
+            .spawn()
+            .expect("Failed to spawn command");
+
+        let tasks = fetch_tasks(&mut child, 6781, 1);
+        let _ = child.kill();
+
+        let worker = task(&tasks, "smol-worker");
+        assert_eq!(worker.kind, TaskKind::Smol);
+        assert_eq!(worker.state, TaskState::Completed);
+        assert_eq!(worker.poll_count, 2);
+    }
+}
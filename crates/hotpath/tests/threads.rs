@@ -91,4 +91,72 @@ pub mod tests {
         let _ = child.kill();
         let _ = child.wait();
     }
+
+    // HOTPATH_METRICS_PORT=6777 TEST_SLEEP_SECONDS=10 cargo run -p test-tokio-async --example many_threads_alloc --features hotpath,hotpath-alloc
+    #[test]
+    fn test_threads_alloc_after_many_threads() {
+        let mut child = Command::new("cargo")
+            .args([
+                "run",
+                "-p",
+                "test-tokio-async",
+                "--example",
+                "many_threads_alloc",
+                "--features",
+                "hotpath,hotpath-alloc",
+            ])
+            .env("HOTPATH_METRICS_PORT", "6777")
+            .env("TEST_SLEEP_SECONDS", "10")
+            .spawn()
+            .expect("Failed to spawn command");
+
+        let mut last_error = None;
+        let mut worker_alloc = None;
+        let mut untracked_threads = None;
+
+        // Retry until the collector has sampled the long-lived worker thread
+        for _attempt in 0..30 {
+            sleep(Duration::from_millis(1000));
+
+            let json_text = match ureq::get("http://localhost:6777/threads").call() {
+                Ok(mut response) => response
+                    .body_mut()
+                    .read_to_string()
+                    .expect("Failed to read response body"),
+                Err(e) => {
+                    last_error = Some(format!("Request error: {}", e));
+                    continue;
+                }
+            };
+
+            let threads_response: ThreadsJson =
+                serde_json::from_str(&json_text).expect("Failed to parse threads JSON");
+            untracked_threads = threads_response.untracked_threads;
+            worker_alloc = threads_response
+                .threads
+                .iter()
+                .find(|t| t.name == "alloc-worker")
+                .map(|t| t.alloc_bytes);
+
+            if worker_alloc.is_some() {
+                last_error = None;
+                break;
+            }
+        }
+
+        let _ = child.kill();
+        let _ = child.wait();
+
+        if let Some(error) = last_error {
+            panic!("Failed after 30 retries: {}", error);
+        }
+
+        let worker_alloc = worker_alloc.expect("alloc-worker thread not found");
+        assert!(
+            worker_alloc.is_some_and(|bytes| bytes >= 64 * 1024),
+            "Expected alloc-worker allocations to be tracked, got {:?}",
+            worker_alloc
+        );
+        assert_eq!(untracked_threads, Some(0));
+    }
 }
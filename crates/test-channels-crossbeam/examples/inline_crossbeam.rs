@@ -0,0 +1,46 @@
+use crossbeam_channel::TrySendError;
+use std::thread;
+
+fn main() {
+    let _channels_guard = hotpath::channels::ChannelsGuard::new();
+
+    let (tx, rx) = hotpath::channel!(
+        crossbeam_channel::bounded::<i32>(3),
+        label = "inline-bounded",
+        mode = "inline"
+    );
+
+    // Inline mode adds no proxy slot, so try_send fails exactly at capacity
+    for i in 0..3 {
+        tx.try_send(i).expect("Channel should have free capacity");
+    }
+    assert!(matches!(tx.try_send(3), Err(TrySendError::Full(3))));
+
+    let msg = rx.recv().expect("Failed to receive");
+    println!("[Receiver] Received message: {}", msg);
+    tx.try_send(4).expect("Channel should have a free slot");
+    drop(tx);
+
+    let (utx, urx) = hotpath::channel!(
+        crossbeam_channel::unbounded::<String>(),
+        mode = "inline",
+        log = true
+    );
+
+    let urx2 = urx.clone();
+    let sender_handle = thread::spawn(move || {
+        for i in 1..=4 {
+            utx.send(format!("msg-{i}")).expect("Failed to send");
+        }
+    });
+    sender_handle.join().expect("Sender thread panicked");
+
+    for msg in urx.iter().take(2) {
+        println!("[Receiver 1] Received message: {}", msg);
+    }
+    for msg in urx2 {
+        println!("[Receiver 2] Received message: {}", msg);
+    }
+
+    println!("\nExample completed!");
+}
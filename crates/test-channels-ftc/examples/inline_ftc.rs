@@ -0,0 +1,53 @@
+use futures_util::sink::SinkExt;
+use futures_util::stream::StreamExt;
+use smol::Timer;
+use std::time::Duration;
+
+fn main() {
+    smol::block_on(async {
+        let _channels_guard = hotpath::channels::ChannelsGuard::new();
+
+        let (mut tx, mut rx) = hotpath::channel!(
+            futures_channel::mpsc::channel::<i32>(1),
+            capacity = 1,
+            label = "inline-bounded",
+            mode = "inline"
+        );
+
+        // Every futures sender has one guaranteed slot on top of the buffer
+        tx.try_send(1).expect("Channel should have free capacity");
+        tx.try_send(2).expect("Channel should have free capacity");
+
+        let receiver_handle = smol::spawn(async move {
+            Timer::after(Duration::from_millis(50)).await;
+            let mut received = 0;
+            while let Some(msg) = rx.next().await {
+                println!("[Receiver] Received message: {}", msg);
+                received += 1;
+            }
+            received
+        });
+
+        // Waits until the receiver frees a slot
+        tx.send(3).await.expect("Failed to send");
+        drop(tx);
+        let received = receiver_handle.await;
+        println!("[Receiver] Received {} messages", received);
+
+        let (utx, urx) = hotpath::channel!(
+            futures_channel::mpsc::unbounded::<String>(),
+            label = "inline-unbounded",
+            mode = "inline",
+            log = true
+        );
+        for i in 1..=3 {
+            utx.unbounded_send(format!("msg-{i}"))
+                .expect("Failed to send");
+        }
+        drop(utx);
+        let messages: Vec<String> = urx.collect().await;
+        println!("[Receiver] Received {} logged messages", messages.len());
+
+        println!("\nExample completed!");
+    });
+}
@@ -0,0 +1,27 @@
+#[tokio::main]
+async fn main() {
+    let _channels_guard = hotpath::channels::ChannelsGuard::new();
+
+    let (tx, rx) = hotpath::channel!(
+        kanal::bounded_async::<u32>(4),
+        label = "async-jobs",
+        capacity = 4
+    );
+
+    let producer = tokio::spawn(async move {
+        for i in 0..6 {
+            tx.send(i).await.expect("Failed to send");
+            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        }
+    });
+
+    while let Ok(msg) = rx.recv().await {
+        println!("[Receiver] Received message: {}", msg);
+    }
+
+    producer.await.expect("Producer task failed");
+
+    drop(_channels_guard);
+
+    println!("\nExample completed!");
+}
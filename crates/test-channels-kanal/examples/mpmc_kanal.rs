@@ -0,0 +1,44 @@
+/// Several producers share a channel with several consumers, each message
+/// is delivered to exactly one of the consumers.
+fn main() {
+    let _channels_guard = hotpath::channels::ChannelsGuard::new();
+
+    let (tx, rx) = hotpath::channel!(kanal::bounded::<u32>(10), label = "jobs", capacity = 10);
+
+    let consumers: Vec<_> = (0..3)
+        .map(|worker| {
+            let rx = rx.clone();
+            std::thread::spawn(move || {
+                let mut handled = 0;
+                while rx.recv().is_ok() {
+                    handled += 1;
+                }
+                println!("[Worker {}] Handled {} jobs", worker, handled);
+            })
+        })
+        .collect();
+    drop(rx);
+
+    let producers: Vec<_> = (0..4)
+        .map(|producer| {
+            let tx = tx.clone();
+            std::thread::spawn(move || {
+                for i in 0..5 {
+                    tx.send(producer * 100 + i).expect("Failed to send");
+                }
+            })
+        })
+        .collect();
+    drop(tx);
+
+    for handle in producers {
+        handle.join().expect("Producer thread failed");
+    }
+    for handle in consumers {
+        handle.join().expect("Consumer thread failed");
+    }
+
+    drop(_channels_guard);
+
+    println!("\nExample completed!");
+}
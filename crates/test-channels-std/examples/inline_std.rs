@@ -0,0 +1,61 @@
+use std::sync::mpsc::TrySendError;
+use std::thread;
+use std::time::Duration;
+
+fn main() {
+    let _channels_guard = hotpath::channels::ChannelsGuard::new();
+
+    let (tx, rx) = hotpath::channel!(
+        std::sync::mpsc::sync_channel::<i32>(3),
+        capacity = 3,
+        label = "inline-sync",
+        mode = "inline"
+    );
+
+    // Inline mode adds no proxy slot, so try_send fails exactly at capacity
+    for i in 0..3 {
+        tx.try_send(i).expect("Channel should have free capacity");
+    }
+    assert!(matches!(tx.try_send(3), Err(TrySendError::Full(3))));
+
+    let msg = rx.recv().expect("Failed to receive");
+    println!("[Receiver] Received message: {}", msg);
+    tx.try_send(4).expect("Channel should have a free slot");
+    drop(tx);
+
+    // A send on a full channel waits until the receiver frees a slot
+    let (btx, brx) = hotpath::channel!(
+        std::sync::mpsc::sync_channel::<i32>(1),
+        capacity = 1,
+        label = "inline-blocked",
+        mode = "inline"
+    );
+    btx.send(1).expect("Failed to send");
+    let receiver_handle = thread::spawn(move || {
+        thread::sleep(Duration::from_millis(50));
+        brx.iter().count()
+    });
+    btx.send(2).expect("Failed to send");
+    drop(btx);
+    let received = receiver_handle.join().expect("Receiver thread panicked");
+    println!("[Receiver] Received {} messages", received);
+
+    let (utx, urx) = hotpath::channel!(
+        std::sync::mpsc::channel::<String>(),
+        mode = "inline",
+        log = true
+    );
+
+    let sender_handle = thread::spawn(move || {
+        for i in 1..=3 {
+            utx.send(format!("msg-{i}")).expect("Failed to send");
+        }
+    });
+
+    for msg in urx {
+        println!("[Receiver] Received message: {}", msg);
+    }
+    sender_handle.join().expect("Sender thread panicked");
+
+    println!("\nExample completed!");
+}
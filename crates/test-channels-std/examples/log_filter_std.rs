@@ -0,0 +1,59 @@
+use std::thread;
+
+// No Debug impl, logs are built by log_format
+#[allow(dead_code)]
+struct Request {
+    status: u16,
+    path: String,
+    token: String,
+}
+
+fn main() {
+    let _channels_guard = hotpath::channels::ChannelsGuard::new();
+
+    // Logs every second failed request, run with HOTPATH_LOGS_REDACT to hide tokens
+    let (tx, rx) = hotpath::channel!(
+        std::sync::mpsc::channel::<Request>(),
+        label = "filtered-requests",
+        log_filter = |req: &Request| req.status >= 400,
+        log_format = |req: &Request| format!("{} {} token={}", req.status, req.path, req.token),
+        log_sample = 2
+    );
+
+    let sender_handle = thread::spawn(move || {
+        for i in 0..10 {
+            let status = if i % 2 == 0 { 200 } else { 500 };
+            tx.send(Request {
+                status,
+                path: format!("/fail/{i}"),
+                token: format!("secret{i}"),
+            })
+            .expect("Failed to send");
+        }
+    });
+
+    let received = rx.iter().filter(|req| req.status == 500).count();
+    println!("[Receiver] Received {received} failed requests");
+    sender_handle.join().expect("Sender thread panicked");
+
+    let (itx, irx) = hotpath::channel!(
+        std::sync::mpsc::channel::<u32>(),
+        label = "sampled-inline",
+        mode = "inline",
+        log_sample = 3
+    );
+    for i in 0..9 {
+        itx.send(i).expect("Failed to send");
+    }
+    drop(itx);
+    let sum: u32 = irx.iter().sum();
+    println!("[Receiver] Sum of sampled-inline messages: {sum}");
+
+    println!("\nLog filter example completed!");
+
+    if let Ok(secs) = std::env::var("TEST_SLEEP_SECONDS") {
+        if let Ok(duration) = secs.parse::<u64>() {
+            std::thread::sleep(std::time::Duration::from_secs(duration));
+        }
+    }
+}
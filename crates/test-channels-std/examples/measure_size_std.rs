@@ -0,0 +1,79 @@
+use std::thread;
+
+#[allow(dead_code)]
+#[derive(Debug, hotpath::HeapSize)]
+struct Payload {
+    id: u32,
+    data: Vec<u8>,
+}
+
+#[allow(dead_code)]
+#[derive(Debug, hotpath::HeapSize)]
+enum Message {
+    Ping,
+    Data(Payload),
+    Text { body: String },
+}
+
+fn main() {
+    let _channels_guard = hotpath::channels::ChannelsGuard::new();
+
+    // Queued memory includes the 1 KB buffer owned by every message
+    let (tx, rx) = hotpath::channel!(
+        std::sync::mpsc::channel::<Message>(),
+        label = "sized-inline",
+        mode = "inline",
+        measure_size = true
+    );
+
+    for id in 0..4 {
+        tx.send(Message::Data(Payload {
+            id,
+            data: vec![0; 1024],
+        }))
+        .expect("Failed to send");
+    }
+    tx.send(Message::Ping).expect("Failed to send");
+
+    let msg = rx.recv().expect("Failed to receive");
+    println!("[Receiver] Received message: {:?}", msg_id(&msg));
+    // The derived impl is available with profiling disabled too
+    println!(
+        "[Receiver] Message heap size: {} bytes",
+        hotpath::HeapSize::heap_size(&msg)
+    );
+
+    let (ptx, prx) = hotpath::channel!(
+        std::sync::mpsc::sync_channel::<Message>(4),
+        capacity = 4,
+        label = "sized-proxy",
+        log = true,
+        measure_size = true
+    );
+
+    let sender_handle = thread::spawn(move || {
+        for i in 1..=3 {
+            ptx.send(Message::Text {
+                body: format!("msg-{i}"),
+            })
+            .expect("Failed to send");
+        }
+    });
+
+    for msg in prx {
+        println!("[Receiver] Received message: {:?}", msg);
+    }
+    sender_handle.join().expect("Sender thread panicked");
+
+    drop(tx);
+    drop(rx);
+
+    println!("\nExample completed!");
+}
+
+fn msg_id(msg: &Message) -> Option<u32> {
+    match msg {
+        Message::Data(payload) => Some(payload.id),
+        _ => None,
+    }
+}
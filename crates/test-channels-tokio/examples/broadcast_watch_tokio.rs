@@ -0,0 +1,58 @@
+use std::time::Duration;
+use tokio::sync::broadcast::error::RecvError;
+use tokio::sync::{broadcast, watch};
+
+#[tokio::main]
+async fn main() {
+    let _channels_guard = hotpath::channels::ChannelsGuard::new();
+
+    let (tx, mut fast) =
+        hotpath::channel!(broadcast::channel::<u32>(4), label = "events", capacity = 4);
+    let mut slow = tx.subscribe();
+
+    for i in 0..6 {
+        tx.send(i).expect("Failed to broadcast");
+        let msg = fast.recv().await.expect("Fast receiver failed");
+        println!("[Fast] Received: {}", msg);
+    }
+
+    // The slow receiver missed the two oldest messages
+    match slow.recv().await {
+        Err(RecvError::Lagged(skipped)) => println!("[Slow] Lagged by {}", skipped),
+        other => panic!("Expected the slow receiver to lag, got {:?}", other),
+    }
+    while let Ok(msg) = slow.try_recv() {
+        println!("[Slow] Received: {}", msg);
+    }
+
+    let _idle = tx.subscribe();
+    tx.send(6).expect("Failed to broadcast");
+    fast.recv().await.expect("Fast receiver failed");
+
+    let (unheard_tx, unheard_rx) = hotpath::channel!(
+        broadcast::channel::<u32>(4),
+        label = "unheard",
+        capacity = 4
+    );
+    drop(unheard_rx);
+    assert!(unheard_tx.send(1).is_err());
+
+    let (config_tx, mut config_rx) = hotpath::channel!(watch::channel(0u32), label = "config");
+
+    let watcher = tokio::spawn(async move {
+        while config_rx.changed().await.is_ok() {
+            println!("[Watch] Config is now {}", *config_rx.borrow_and_update());
+        }
+    });
+
+    config_tx.send(1).expect("Failed to update config");
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    config_tx.send_modify(|value| *value += 1);
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    config_tx.send_replace(10);
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    drop(config_tx);
+    watcher.await.expect("Watcher task failed");
+
+    println!("\nExample completed!");
+}
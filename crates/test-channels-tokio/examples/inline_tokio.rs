@@ -0,0 +1,67 @@
+use tokio::sync::mpsc::error::TrySendError;
+
+#[tokio::main]
+async fn main() {
+    let _channels_guard = hotpath::channels::ChannelsGuard::new();
+
+    let (tx, mut rx) = hotpath::channel!(
+        tokio::sync::mpsc::channel::<i32>(3),
+        label = "inline-bounded",
+        mode = "inline"
+    );
+
+    // Inline mode adds no proxy slot, so try_send fails exactly at capacity
+    for i in 0..3 {
+        tx.try_send(i).expect("Channel should have free capacity");
+    }
+    assert!(matches!(tx.try_send(3), Err(TrySendError::Full(3))));
+
+    let msg = rx.recv().await.expect("Failed to receive");
+    println!("[Receiver] Received message: {}", msg);
+    tx.try_send(4).expect("Channel should have a free slot");
+    drop(tx);
+
+    let (ptx, mut prx) = hotpath::channel!(
+        tokio::sync::mpsc::channel::<i32>(2),
+        label = "inline-permits",
+        mode = "inline"
+    );
+
+    // Messages sent through permits and read in batches are counted too
+    ptx.reserve().await.expect("Failed to reserve").send(1);
+    ptx.try_reserve().expect("Failed to reserve").send(2);
+    drop(ptx);
+
+    let mut batch = Vec::new();
+    let received = prx.recv_many(&mut batch, 10).await;
+    println!("[Receiver] Received batch of {}: {:?}", received, batch);
+
+    let (utx, mut urx) = hotpath::channel!(
+        tokio::sync::mpsc::unbounded_channel::<String>(),
+        mode = "inline",
+        log = true
+    );
+
+    let utx2 = utx.clone();
+    utx.send("hello".to_string()).expect("Failed to send");
+    utx2.send("world".to_string()).expect("Failed to send");
+    drop(utx);
+    drop(utx2);
+
+    while let Some(msg) = urx.recv().await {
+        println!("[Receiver] Received message: {}", msg);
+    }
+
+    let (otx, orx) = hotpath::channel!(
+        tokio::sync::oneshot::channel::<String>(),
+        mode = "inline",
+        label = "inline-oneshot"
+    );
+
+    otx.send("done".to_string())
+        .expect("Failed to send oneshot");
+    let msg = orx.await.expect("Failed to receive oneshot");
+    println!("[Oneshot] Received: {}", msg);
+
+    println!("\nExample completed!");
+}
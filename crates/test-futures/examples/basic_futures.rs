@@ -85,6 +85,16 @@ async fn main() {
     let _result = attributed_no_log().await;
     let _result = attributed_with_log().await;
 
+    // Blocks the executor thread for the whole poll
+    let _result = future!(
+        async {
+            std::thread::sleep(Duration::from_millis(20));
+            7
+        },
+        log = true
+    )
+    .await;
+
     tokio::time::sleep(Duration::from_millis(10)).await;
 
     // For testing: allow configurable sleep to keep server running
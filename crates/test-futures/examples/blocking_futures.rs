@@ -0,0 +1,56 @@
+//! Example detecting polls that block the executor thread.
+//!
+//! Run with: HOTPATH_BLOCKING_POLL_MS=5 HOTPATH_BLOCKING_POLL_WARN=1 cargo run -p test-futures --example blocking_futures --features hotpath
+
+use hotpath::future;
+use hotpath::futures::FuturesGuard;
+use std::time::Duration;
+
+#[hotpath::measure]
+async fn blocking_function() -> u32 {
+    tokio::time::sleep(Duration::from_millis(1)).await;
+    std::thread::sleep(Duration::from_millis(20));
+    1
+}
+
+#[tokio::main(flavor = "current_thread")]
+#[hotpath::main]
+async fn main() {
+    let _guard = FuturesGuard::new();
+
+    // Exceeds the global threshold
+    let _result = future!(async {
+        std::thread::sleep(Duration::from_millis(20));
+        1
+    })
+    .await;
+
+    // Yields instead of blocking, every poll stays short
+    let _result = future!(async {
+        for _ in 0..4 {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        2
+    })
+    .await;
+
+    // Within its own threshold, which overrides the global one
+    let _result = future!(
+        async {
+            std::thread::sleep(Duration::from_millis(20));
+            3
+        },
+        blocking_threshold_ms = 100
+    )
+    .await;
+
+    let _result = blocking_function().await;
+
+    println!("Blocking futures example completed!");
+
+    if let Ok(secs) = std::env::var("TEST_SLEEP_SECONDS") {
+        if let Ok(secs) = secs.parse::<u64>() {
+            tokio::time::sleep(Duration::from_secs(secs)).await;
+        }
+    }
+}
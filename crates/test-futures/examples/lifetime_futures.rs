@@ -0,0 +1,33 @@
+//! Example reporting lifetime call outcomes, which stay accurate after old calls are evicted
+//! from the retained logs.
+//!
+//! Run with: HOTPATH_LOGS_LIMIT=3 cargo run -p test-futures --example lifetime_futures --features hotpath
+
+use hotpath::future;
+use hotpath::futures::{Format, FuturesGuardBuilder};
+use std::time::Duration;
+
+#[tokio::main(flavor = "current_thread")]
+async fn main() {
+    let _guard = FuturesGuardBuilder::new().format(Format::Json).build();
+
+    for _ in 0..10 {
+        future!(async {
+            tokio::time::sleep(Duration::from_millis(1)).await;
+        })
+        .await;
+    }
+
+    for _ in 0..3 {
+        let _ = tokio::time::timeout(
+            Duration::from_millis(1),
+            future!(std::future::pending::<()>()),
+        )
+        .await;
+    }
+
+    // Never polled nor dropped, so it stays in flight
+    std::mem::forget(future!(std::future::pending::<()>()));
+
+    tokio::time::sleep(Duration::from_millis(50)).await;
+}
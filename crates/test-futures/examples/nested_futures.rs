@@ -0,0 +1,28 @@
+//! Example of instrumented futures created inside other instrumented futures.
+//!
+//! Run with: cargo run -p test-futures --example nested_futures --features hotpath
+
+use hotpath::future;
+use hotpath::futures::{Format, FuturesGuardBuilder};
+use std::time::Duration;
+
+#[tokio::main(flavor = "current_thread")]
+async fn main() {
+    let _guard = FuturesGuardBuilder::new().format(Format::Json).build();
+
+    // Request handler -> db query -> retry
+    future!(async {
+        for _ in 0..2 {
+            future!(async {
+                for _ in 0..3 {
+                    future!(tokio::time::sleep(Duration::from_millis(1))).await;
+                }
+            })
+            .await;
+        }
+        tokio::time::sleep(Duration::from_millis(5)).await;
+    })
+    .await;
+
+    tokio::time::sleep(Duration::from_millis(50)).await;
+}
@@ -0,0 +1,33 @@
+//! Example reporting future calls that stay in flight longer than the stuck threshold.
+//!
+//! Run with: HOTPATH_STUCK_FUTURE_MS=200 TEST_SLEEP_SECONDS=2 cargo run -p test-futures --example stuck_futures --features hotpath
+
+use hotpath::future;
+use hotpath::futures::FuturesGuard;
+use std::time::Duration;
+
+#[tokio::main(flavor = "current_thread")]
+async fn main() {
+    let _guard = FuturesGuard::new();
+
+    // Polled once, then waits for a wake that never comes
+    tokio::spawn(future!(std::future::pending::<()>()));
+
+    // Leaked without ever being polled
+    std::mem::forget(future!(std::future::pending::<()>()));
+
+    for _ in 0..3 {
+        future!(async {
+            tokio::time::sleep(Duration::from_millis(1)).await;
+        })
+        .await;
+    }
+
+    println!("Stuck futures example completed!");
+
+    if let Ok(secs) = std::env::var("TEST_SLEEP_SECONDS") {
+        if let Ok(secs) = secs.parse::<u64>() {
+            tokio::time::sleep(Duration::from_secs(secs)).await;
+        }
+    }
+}
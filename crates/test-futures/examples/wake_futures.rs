@@ -0,0 +1,43 @@
+//! Example reporting wakes, spurious polls and wake to poll latency.
+//!
+//! Run with: cargo run -p test-futures --example wake_futures --features hotpath
+
+use hotpath::future;
+use hotpath::futures::FuturesGuard;
+use std::time::Duration;
+
+#[tokio::main(flavor = "current_thread")]
+#[hotpath::main]
+async fn main() {
+    let _guard = FuturesGuard::new();
+
+    // Woken by the timer before every poll but the first
+    let _result = future!(async {
+        for _ in 0..3 {
+            tokio::time::sleep(Duration::from_millis(5)).await;
+        }
+        1
+    })
+    .await;
+
+    // `join!` polls every branch when any of them is woken
+    let (_result, _) = tokio::join!(
+        future!(async {
+            tokio::time::sleep(Duration::from_millis(20)).await;
+            2
+        }),
+        async {
+            for _ in 0..5 {
+                tokio::task::yield_now().await;
+            }
+        }
+    );
+
+    println!("Wake futures example completed!");
+
+    if let Ok(secs) = std::env::var("TEST_SLEEP_SECONDS") {
+        if let Ok(secs) = secs.parse::<u64>() {
+            tokio::time::sleep(Duration::from_secs(secs)).await;
+        }
+    }
+}
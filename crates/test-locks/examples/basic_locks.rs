@@ -0,0 +1,62 @@
+use std::sync::{Arc, Barrier};
+use std::time::Duration;
+
+fn main() {
+    let _locks_guard = hotpath::locks::LocksGuard::new();
+
+    let counter = Arc::new(hotpath::mutex!(
+        std::sync::Mutex::new(0u64),
+        label = "counter"
+    ));
+    let config = Arc::new(hotpath::rwlock!(
+        std::sync::RwLock::new(String::from("initial")),
+        label = "config"
+    ));
+    let unlabeled = hotpath::mutex!(std::sync::Mutex::new(Vec::<u32>::new()));
+
+    // Hold the counter while a worker tries to take it, forcing one contended acquisition
+    let barrier = Arc::new(Barrier::new(2));
+    let guard = counter.lock().unwrap();
+    let worker = {
+        let counter = Arc::clone(&counter);
+        let barrier = Arc::clone(&barrier);
+        std::thread::spawn(move || {
+            barrier.wait();
+            *counter.lock().unwrap() += 1;
+        })
+    };
+    barrier.wait();
+    std::thread::sleep(Duration::from_millis(100));
+    drop(guard);
+    worker.join().expect("Worker thread failed");
+
+    for _ in 0..2 {
+        *counter.lock().unwrap() += 1;
+    }
+
+    let readers: Vec<_> = (0..3)
+        .map(|i| {
+            let config = Arc::clone(&config);
+            std::thread::spawn(move || {
+                let value = config.read().unwrap();
+                println!("[Reader {i}] config = {}", *value);
+            })
+        })
+        .collect();
+    for reader in readers {
+        reader.join().expect("Reader thread failed");
+    }
+    *config.write().unwrap() = String::from("updated");
+
+    unlabeled.lock().unwrap().push(1);
+
+    let shared = hotpath::mutex!(parking_lot::Mutex::new(0u32), label = "pl-mutex");
+    let table = hotpath::rwlock!(parking_lot::RwLock::new(vec![1, 2, 3]), label = "pl-rwlock");
+    for _ in 0..4 {
+        *shared.lock() += 1;
+    }
+    println!("table len = {}", table.read().len());
+    table.write().push(4);
+
+    println!("counter = {}", *counter.lock().unwrap());
+}
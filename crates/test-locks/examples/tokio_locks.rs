@@ -0,0 +1,42 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+#[tokio::main]
+async fn main() {
+    let _locks_guard = hotpath::locks::LocksGuard::new();
+
+    let state = Arc::new(hotpath::mutex!(
+        tokio::sync::Mutex::new(0u64),
+        label = "tokio-state"
+    ));
+    let cache = Arc::new(hotpath::rwlock!(
+        tokio::sync::RwLock::new(Vec::<u64>::new()),
+        label = "tokio-cache"
+    ));
+
+    // The first task holds the mutex across an await point, so the second one has to wait
+    let holder = {
+        let state = Arc::clone(&state);
+        tokio::spawn(async move {
+            let mut value = state.lock().await;
+            tokio::time::sleep(Duration::from_millis(100)).await;
+            *value += 1;
+        })
+    };
+    tokio::time::sleep(Duration::from_millis(20)).await;
+    let waiter = {
+        let state = Arc::clone(&state);
+        tokio::spawn(async move {
+            *state.lock().await += 1;
+        })
+    };
+    holder.await.expect("Holder task failed");
+    waiter.await.expect("Waiter task failed");
+
+    for i in 0..3 {
+        cache.write().await.push(i);
+    }
+    println!("cache len = {}", cache.read().await.len());
+
+    println!("state = {}", *state.lock().await);
+}
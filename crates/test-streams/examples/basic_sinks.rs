@@ -0,0 +1,47 @@
+//! Example of instrumented sinks: backpressure, flushes and close.
+//!
+//! Run with: cargo run -p test-streams --example basic_sinks --features hotpath
+
+use futures_util::sink::{self, SinkExt};
+use smol::Timer;
+use std::time::Duration;
+
+fn main() {
+    smol::block_on(async {
+        let _sinks_guard = hotpath::sinks::SinksGuard::new();
+
+        // Every write takes 10ms, the sink is not ready until the previous one finished
+        let mut slow = hotpath::sink!(
+            Box::pin(sink::unfold((), |(), _line: String| async move {
+                Timer::after(Duration::from_millis(10)).await;
+                Ok::<_, std::io::Error>(())
+            })),
+            label = "slow-writer"
+        );
+
+        println!("[Sink 1] Writing lines...");
+        for i in 0..5 {
+            slow.feed(format!("line {i}")).await.unwrap();
+        }
+        // Waits for the last write to finish
+        slow.flush().await.unwrap();
+        slow.close().await.unwrap();
+
+        // Always ready, every sent item is logged
+        let mut numbers = hotpath::sink!(sink::drain::<i32>(), label = "drain-sink", log = true);
+
+        println!("[Sink 2] Sending numbers...");
+        for i in 1..=3 {
+            numbers.send(i).await.unwrap();
+        }
+        numbers.close().await.unwrap();
+
+        println!("\nSink example completed!");
+
+        if let Ok(secs) = std::env::var("TEST_SLEEP_SECONDS") {
+            if let Ok(duration) = secs.parse::<u64>() {
+                std::thread::sleep(Duration::from_secs(duration));
+            }
+        }
+    })
+}
@@ -36,6 +36,29 @@ fn main() {
         let repeated: Vec<i32> = stream3.collect().await;
         println!("[Stream 3] Collected: {:?}", repeated);
 
+        // Example 4: Stream logging every second even number
+        let stream4 = hotpath::stream!(
+            stream::iter(1..=10),
+            label = "filtered-stream",
+            log_filter = |n: &i32| n % 2 == 0,
+            log_sample = 2
+        );
+
+        println!("\n[Stream 4] Collecting numbers...");
+        let numbers: Vec<i32> = stream4.collect().await;
+        println!("[Stream 4] Collected {} numbers", numbers.len());
+
+        // Example 5: Stream measuring the heap size of every item
+        let stream5 = hotpath::stream!(
+            stream::iter(vec!["a".repeat(100); 3]),
+            label = "sized-stream",
+            measure_size = true
+        );
+
+        println!("\n[Stream 5] Collecting strings...");
+        let strings: Vec<String> = stream5.collect().await;
+        println!("[Stream 5] Collected {} strings", strings.len());
+
         println!("\nStream example completed!");
 
         // Give stats collector time to process final events
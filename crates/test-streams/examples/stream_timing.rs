@@ -0,0 +1,38 @@
+//! Example of per-stream timing: inter-item latency, pending polls and time to first item.
+//!
+//! Run with: cargo run -p test-streams --example stream_timing --features hotpath
+
+use futures_util::stream::{self, StreamExt};
+use hotpath::streams::StreamsGuardBuilder;
+use smol::Timer;
+use std::time::Duration;
+
+fn main() {
+    smol::block_on(async {
+        let _streams_guard = StreamsGuardBuilder::new()
+            .format(hotpath::Format::Json)
+            .build();
+
+        // Every item arrives 20ms after the previous one
+        let ticks = hotpath::stream!(
+            stream::unfold(0, |n| async move {
+                if n == 4 {
+                    return None;
+                }
+                Timer::after(Duration::from_millis(20)).await;
+                Some((n, n + 1))
+            }),
+            label = "slow-ticks"
+        );
+        let collected: Vec<i32> = ticks.collect().await;
+        assert_eq!(collected.len(), 4);
+
+        // Always ready, never returns Poll::Pending
+        let ready = hotpath::stream!(stream::iter(1..=3), label = "ready-items");
+        let collected: Vec<i32> = ready.collect().await;
+        assert_eq!(collected.len(), 3);
+
+        // Give stats collector time to process final events
+        Timer::after(Duration::from_millis(50)).await;
+    })
+}
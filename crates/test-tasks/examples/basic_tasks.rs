@@ -0,0 +1,62 @@
+//! Run with: HOTPATH_METRICS_PORT=6780 cargo run -p test-tasks --example basic_tasks --features hotpath
+
+use std::time::Duration;
+
+#[tokio::main(flavor = "multi_thread", worker_threads = 2)]
+async fn main() {
+    let parent = hotpath::spawn!(
+        async {
+            let child = hotpath::spawn!(
+                async {
+                    tokio::time::sleep(Duration::from_millis(10)).await;
+                    2
+                },
+                label = "child"
+            );
+            child.await.expect("child task failed") * 2
+        },
+        label = "parent"
+    );
+    println!(
+        "[parent] Result: {}",
+        parent.await.expect("parent task failed")
+    );
+
+    let checksum = hotpath::spawn!(
+        || (0..1000u64).sum::<u64>(),
+        blocking = true,
+        label = "checksum"
+    );
+    println!(
+        "[checksum] Result: {}",
+        checksum.await.expect("checksum failed")
+    );
+
+    let panicker = hotpath::spawn!(
+        async {
+            tokio::time::sleep(Duration::from_millis(1)).await;
+            panic!("boom");
+        },
+        label = "panicker"
+    );
+    assert!(panicker.await.unwrap_err().is_panic());
+
+    let sleeper = hotpath::spawn!(
+        async { tokio::time::sleep(Duration::from_secs(60)).await },
+        label = "sleeper"
+    );
+    tokio::time::sleep(Duration::from_millis(10)).await;
+    sleeper.abort();
+    assert!(sleeper.await.unwrap_err().is_cancelled());
+
+    // Stays live until the runtime shuts down
+    let _worker = hotpath::spawn!(async { std::future::pending::<()>().await });
+
+    println!("Tasks example completed!");
+
+    if let Ok(secs) = std::env::var("TEST_SLEEP_SECONDS") {
+        if let Ok(secs) = secs.parse::<u64>() {
+            tokio::time::sleep(Duration::from_secs(secs)).await;
+        }
+    }
+}
@@ -0,0 +1,25 @@
+//! Run with: HOTPATH_METRICS_PORT=6781 cargo run -p test-tasks --example smol_tasks --features hotpath
+
+use std::time::Duration;
+
+fn main() {
+    smol::block_on(async {
+        let task = hotpath::spawn!(
+            async {
+                smol::Timer::after(Duration::from_millis(10)).await;
+                42
+            },
+            runtime = "smol",
+            label = "smol-worker"
+        );
+        println!("[smol-worker] Result: {}", task.await);
+
+        println!("Smol tasks example completed!");
+
+        if let Ok(secs) = std::env::var("TEST_SLEEP_SECONDS") {
+            if let Ok(secs) = secs.parse::<u64>() {
+                smol::Timer::after(Duration::from_secs(secs)).await;
+            }
+        }
+    });
+}
@@ -0,0 +1,17 @@
+#[hotpath::measure]
+fn build_buffers() -> usize {
+    let large = vec![0u8; 64 * 1024];
+    let small = vec![0u8; 1024];
+    std::hint::black_box(&large);
+    std::hint::black_box(&small);
+    large.len() + small.len()
+}
+
+#[hotpath::main]
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    for _ in 0..500 {
+        std::hint::black_box(build_buffers());
+    }
+
+    Ok(())
+}
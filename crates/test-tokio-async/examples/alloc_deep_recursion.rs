@@ -0,0 +1,19 @@
+#[hotpath::measure]
+fn recurse(depth: usize) -> usize {
+    let buffer = vec![depth as u8; 64];
+    if depth == 0 {
+        return buffer.len();
+    }
+    std::hint::black_box(&buffer);
+    recurse(depth - 1) + 1
+}
+
+#[hotpath::main]
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    // Nest well past the default allocation stack depth of 64
+    for _ in 0..10 {
+        std::hint::black_box(recurse(200));
+    }
+
+    Ok(())
+}
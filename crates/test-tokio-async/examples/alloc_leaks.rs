@@ -0,0 +1,24 @@
+use std::sync::Mutex;
+
+static CACHE: Mutex<Vec<Vec<u8>>> = Mutex::new(Vec::new());
+
+#[hotpath::measure]
+fn leaky_function(i: usize) {
+    CACHE.lock().unwrap().push(vec![i as u8; 4096]);
+}
+
+#[hotpath::measure]
+fn temporary_function(i: usize) {
+    let buffer = vec![i as u8; 4096];
+    std::hint::black_box(&buffer);
+}
+
+#[hotpath::main]
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    for i in 0..1000 {
+        leaky_function(i);
+        temporary_function(i);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,39 @@
+use std::thread;
+use std::time::Duration;
+
+#[hotpath::measure]
+fn short_lived_work(i: usize) {
+    let data = vec![i; 128];
+    std::hint::black_box(&data);
+}
+
+#[hotpath::main]
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    // Far more threads than a single registry segment holds
+    for batch in 0..10 {
+        let handles: Vec<_> = (0..100)
+            .map(|i| thread::spawn(move || short_lived_work(batch * 100 + i)))
+            .collect();
+
+        for handle in handles {
+            handle.join().expect("Thread panicked");
+        }
+    }
+
+    let sleep_seconds = std::env::var("TEST_SLEEP_SECONDS")
+        .ok()
+        .and_then(|s| s.parse::<u64>().ok())
+        .unwrap_or(0);
+
+    let worker = thread::Builder::new()
+        .name("alloc-worker".into())
+        .spawn(move || {
+            let data = vec![0u8; 64 * 1024];
+            std::hint::black_box(&data);
+            thread::sleep(Duration::from_secs(sleep_seconds));
+        })?;
+
+    worker.join().expect("Thread panicked");
+
+    Ok(())
+}
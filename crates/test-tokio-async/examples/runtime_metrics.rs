@@ -0,0 +1,30 @@
+//! Example sampling tokio runtime metrics.
+//!
+//! Run with: cargo run -p test-tokio-async --example runtime_metrics --features hotpath
+
+use std::time::Duration;
+
+#[hotpath::measure]
+async fn worker(i: u64) -> u64 {
+    tokio::time::sleep(Duration::from_millis(20)).await;
+    i * 2
+}
+
+#[tokio::main(worker_threads = 2)]
+#[hotpath::main]
+async fn main() {
+    hotpath::runtime::register_handle(&tokio::runtime::Handle::current());
+
+    let handles: Vec<_> = (0..50).map(|i| tokio::spawn(worker(i))).collect();
+    let mut sum = 0;
+    for handle in handles {
+        sum += handle.await.unwrap();
+    }
+    println!("Runtime metrics example completed: {sum}");
+
+    if let Ok(secs) = std::env::var("TEST_SLEEP_SECONDS") {
+        if let Ok(secs) = secs.parse::<u64>() {
+            tokio::time::sleep(Duration::from_secs(secs)).await;
+        }
+    }
+}